@@ -5,9 +5,11 @@
 //! a single Postcard blob.
 
 use antikythera_cli::config::*;
-use antikythera_cli::error::{CliError, CliResult};
+use antikythera_cli::domain::entities::{PROVIDER_TYPES, ProviderType};
+use antikythera_cli::error::{AppError, CliError, CliResult};
 use antikythera_log::{cli_eprint, cli_print};
 use clap::{Parser, Subcommand};
+use std::path::Path;
 
 #[derive(Parser)]
 #[command(name = "antikythera-config")]
@@ -21,8 +23,11 @@ pub struct ConfigCli {
 pub enum ConfigCommand {
     /// Initialize default configuration
     Init,
-    /// Show all configuration as JSON
-    Show,
+    /// Show all configuration (JSON unless --format toml)
+    Show {
+        #[arg(long, value_enum, default_value_t)]
+        format: ConfigFormat,
+    },
     /// Get a specific field value
     Get { field: String },
     /// Set a specific field value
@@ -30,10 +35,13 @@ pub enum ConfigCommand {
     /// Add a provider
     AddProvider {
         id: String,
+        /// Provider type: ollama, gemini, openai, or any OpenAI-compatible name
         #[arg(name = "type")]
         provider_type: String,
-        endpoint: String,
-        /// API key environment-variable name (e.g. GEMINI_API_KEY). Omit for Ollama.
+        /// Base URL. Defaults to the type's endpoint; required for other types.
+        endpoint: Option<String>,
+        /// API key environment-variable name (e.g. GEMINI_API_KEY). Defaults to
+        /// the type's variable; omit for Ollama.
         #[arg(name = "api_key")]
         api_key: Option<String>,
     },
@@ -43,10 +51,23 @@ pub enum ConfigCommand {
     SetModel { provider: String, model: String },
     /// Set the REST server bind address
     SetBind { address: String },
-    /// Export configuration as JSON
-    Export { output: Option<String> },
-    /// Import configuration from JSON
-    Import { input: String },
+    /// Export configuration (JSON unless --format toml)
+    Export {
+        output: Option<String>,
+        #[arg(long, value_enum, default_value_t)]
+        format: ConfigFormat,
+    },
+    /// Import configuration from JSON, or TOML for a `.toml` file
+    Import {
+        input: String,
+        /// Format of the input; defaults to the file extension
+        #[arg(long, value_enum)]
+        format: Option<ConfigFormat>,
+    },
+    /// List saved versions of the config, newest first
+    History,
+    /// Restore the config to a saved version
+    Rollback { version: String },
     /// Reset to default configuration
     Reset,
     /// Show config status
@@ -92,10 +113,9 @@ pub fn execute_config_cli(command: ConfigCommand) -> CliResult<()> {
             Ok(())
         }
 
-        ConfigCommand::Show => {
+        ConfigCommand::Show { format } => {
             let config = load_app_config(None)?;
-            let json = serde_json::to_string_pretty(&config)?;
-            cli_print!("{}", json);
+            cli_print!("{}", config_to_text(&config, format)?);
             Ok(())
         }
 
@@ -129,12 +149,27 @@ pub fn execute_config_cli(command: ConfigCommand) -> CliResult<()> {
                 )));
             }
 
-            let provider_type = normalize_provider_type(&provider_type);
+            let known = ProviderType::parse(&provider_type).map(ProviderType::info);
+            let endpoint = match (endpoint, known) {
+                (Some(endpoint), _) => endpoint,
+                (None, Some(info)) => info.default_endpoint.to_string(),
+                (None, None) => {
+                    let names: Vec<&str> = PROVIDER_TYPES.iter().map(|info| info.name).collect();
+                    return Err(CliError::Validation(format!(
+                        "Unknown provider type '{}'; pass an endpoint or use one of: {}",
+                        provider_type,
+                        names.join(", ")
+                    )));
+                }
+            };
+            let api_key = api_key
+                .or_else(|| known.and_then(|info| info.api_key_env).map(str::to_string))
+                .unwrap_or_default();
             config.providers.push(ProviderConfig {
                 id: id.clone(),
-                provider_type,
+                provider_type: normalize_provider_type(&provider_type),
                 endpoint,
-                api_key: api_key.unwrap_or_default(),
+                api_key,
                 models: vec![],
             });
 
@@ -183,30 +218,48 @@ pub fn execute_config_cli(command: ConfigCommand) -> CliResult<()> {
             Ok(())
         }
 
-        ConfigCommand::Export { output } => {
+        ConfigCommand::Export { output, format } => {
             let config = load_app_config(None)?;
-            let json = serde_json::to_string_pretty(&config)?;
+            let text = config_to_text(&config, format)?;
 
             match output {
                 Some(path) => {
-                    std::fs::write(&path, &json)?;
+                    std::fs::write(&path, &text)?;
                     cli_print!("✓ Exported to: {}", path);
                 }
-                None => cli_print!("{}", json),
+                None => cli_print!("{}", text),
             }
             Ok(())
         }
 
-        ConfigCommand::Import { input } => {
-            let json = std::fs::read_to_string(&input)?;
+        ConfigCommand::Import { input, format } => {
+            let text = std::fs::read_to_string(&input)?;
+            let format = format.unwrap_or_else(|| ConfigFormat::from_path(Path::new(&input)));
 
-            let config: AppConfig = serde_json::from_str(&json)?;
+            let config = config_from_text(&text, format)?;
 
             save_app_config(&config, None)?;
             cli_print!("✓ Imported from: {}", input);
             Ok(())
         }
 
+        ConfigCommand::History => {
+            let versions = config_versions(None)?;
+            if versions.is_empty() {
+                cli_print!("No saved versions yet.");
+            }
+            for version in versions {
+                cli_print!("{}  {}", version.id, version.path.display());
+            }
+            Ok(())
+        }
+
+        ConfigCommand::Rollback { version } => {
+            rollback_config(&version, None)?;
+            cli_print!("✓ Config restored to version {}", version);
+            Ok(())
+        }
+
         ConfigCommand::Reset => {
             init_default_config()?;
             cli_print!("✓ Configuration reset to defaults");
@@ -282,11 +335,17 @@ pub fn execute_config_cli(command: ConfigCommand) -> CliResult<()> {
     }
 }
 
+const HISTORY_DIR_KEY: &str = ConfigHistoryConfig::DIR_KEY;
+const HISTORY_KEEP_KEY: &str = ConfigHistoryConfig::KEEP_KEY;
+
 fn get_field(config: &AppConfig, field: &str) -> CliResult<String> {
     match field {
         "default_provider" => Ok(config.model.default_provider.clone()),
         "model" => Ok(config.model.model.clone()),
         "server.bind" => Ok(config.server.bind.clone()),
+        HISTORY_DIR_KEY | HISTORY_KEEP_KEY => {
+            Ok(config.custom.get(field).cloned().unwrap_or_default())
+        }
         "providers" => Ok(serde_json::to_string(&config.providers)?),
         _ => Err(CliError::Validation(format!("Unknown field: {}", field))),
     }
@@ -306,6 +365,10 @@ fn set_field(config: &mut AppConfig, field: &str, value: &str) -> CliResult<()>
             config.server.bind = value.to_string();
             Ok(())
         }
+        HISTORY_DIR_KEY | HISTORY_KEEP_KEY => {
+            config.custom.insert(field.to_string(), value.to_string());
+            Ok(())
+        }
         _ => Err(CliError::Validation(format!("Unknown field: {}", field))),
     }
 }
@@ -314,6 +377,6 @@ fn main() {
     let args = ConfigCli::parse();
     if let Err(e) = execute_config_cli(args.command) {
         cli_eprint!("Error: {}", e);
-        std::process::exit(1);
+        std::process::exit(AppError::from(e).exit_code().into());
     }
 }
@@ -10,14 +10,27 @@
 //! | `multi-agent` | Multi-agent orchestrator harness |
 //! | `wasm-harness` | Host-FFI WASM probe for runtime/session/tool validation |
 //!
+//! `--list-providers` and `--list-models` print from `app.pc` and
+//! `--emit-schema` prints the config JSON Schema, and `--check-servers`
+//! reports which configured MCP servers start, all exiting without
+//! entering any mode; `--tail-logs` follows the tool audit log until
+//! stopped. `--warmup` preloads every provider's model in
+//! the background before the first request.
+//!
+//! Failures print a one-line message to stderr and exit with the code of
+//! their [`AppError`] variant (config 2, provider 3, I/O 4, failed task 5,
+//! anything else 1).
+//!
 //! All provider resolution, session management, and protocol handling live in
 //! `antikythera-core`; this binary only handles argument-to-run-mode wiring.
 
 use std::path::Path;
+use std::process::ExitCode;
 use std::sync::Arc;
 
+use antikythera_cli::AppError;
 use antikythera_cli::cli::{Cli, RunMode};
-use antikythera_cli::config::load_app_config;
+use antikythera_cli::config::{ConfigPaths, load_app_config};
 
 /// Load the `.env` file from the CLI module directory so that
 /// environment variables (e.g. `GEMINI_API_KEY`) are available
@@ -34,10 +47,17 @@ fn load_cli_env() {
 }
 use antikythera_cli::domain::use_cases::{render_wasm_stream_report, run_wasm_stream_probe};
 use antikythera_cli::infrastructure::llm::install_terminal_stream_sink;
-use antikythera_cli::infrastructure::llm::providers_from_postcard;
+use antikythera_cli::infrastructure::llm::{apply_provider_settings, providers_from_postcard};
+use antikythera_cli::presentation::listing::{model_lines, provider_lines, server_check_lines};
+use antikythera_cli::presentation::log_tail::tail_log;
 use antikythera_cli::presentation::tui;
-use antikythera_cli::runtime::{build_runtime_client, materialize_runtime_config};
+use antikythera_cli::runtime::{
+    SERVER_CHECK_TIMEOUT, WARMUP_KEY, build_runtime_client, check_servers,
+    materialize_runtime_config, warm_up_providers, warmup_targets,
+};
 use antikythera_core::application::agent::multi_agent::task::AgentTask;
+use antikythera_core::application::locale::{Locale, set_locale};
+use antikythera_core::config::config_schema;
 use antikythera_core::infrastructure::model::DynamicModelProvider;
 use antikythera_core::{AppConfig, McpClient};
 use antikythera_log::{cli_eprint, cli_print};
@@ -57,16 +77,63 @@ use antikythera_core::application::agent::multi_agent::guardrails::{
 };
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() -> ExitCode {
+    match run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            cli_eprint!("error: {err}");
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+async fn run() -> Result<(), AppError> {
     load_cli_env();
 
     let cli = Cli::parse();
 
-    let config_path = cli.config.as_deref().map(Path::new);
-    let config = AppConfig::load(config_path)?;
+    if cli.emit_schema {
+        cli_print!("{}", serde_json::to_string_pretty(&config_schema())?);
+        return Ok(());
+    }
+
+    let paths = ConfigPaths::resolve(cli.config.as_deref(), cli.config_dir.as_deref());
+    if cli.list_providers || cli.list_models {
+        print_config_listing(&cli, paths.postcard.as_deref());
+        return Ok(());
+    }
+    if let Some(Some(path)) = &cli.tail_logs {
+        tail_log(path).await?;
+        return Ok(());
+    }
+    // No setup wizard is launched from here, so a missing config must fail
+    // fast rather than block unattended runs.
+    let config = AppConfig::load(paths.runtime.as_deref())?;
+    if cli.check_servers {
+        let checks = check_servers(&config.servers, SERVER_CHECK_TIMEOUT).await;
+        for line in server_check_lines(&checks) {
+            cli_print!("{line}");
+        }
+        return Ok(());
+    }
+    if cli.tail_logs.is_some() {
+        let audit_log = config.audit_log.as_ref().ok_or_else(|| {
+            AppError::Config(
+                "no audit log configured; set [audit_log] path in client.toml or pass --tail-logs <PATH>"
+                    .to_string(),
+            )
+        })?;
+        tail_log(&audit_log.path).await?;
+        return Ok(());
+    }
     // Load provider definitions and last-saved routing choices from app.pc.
-    let pc_config = load_app_config(config_path).unwrap_or_default();
-    let initial_providers = providers_from_postcard(&pc_config.providers);
+    let pc_config = load_app_config(paths.postcard.as_deref()).unwrap_or_default();
+    let mut initial_providers = providers_from_postcard(&pc_config.providers);
+    // Headers, proxy, TLS, and pool settings live in custom; bad values stop startup.
+    apply_provider_settings(&mut initial_providers, &pc_config.custom)?;
+    // UI language for STDIO text and error messages (`locale` = id | en).
+    set_locale(Locale::from_custom(&pc_config.custom));
+    tui::theme::set_theme(tui::theme::Theme::from_custom(&pc_config.custom));
 
     // Resolve provider/model: CLI flags > saved app.pc > TOML defaults.
     let provider_override = cli.provider.clone().or_else(|| {
@@ -84,7 +151,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .or_else(|| pc_config.custom.get("system_prompt").cloned())
         .or_else(|| config.system_prompt.clone());
 
-    let (runtime_config, providers) = materialize_runtime_config(
+    let mode = cli.mode.unwrap_or(RunMode::Stdio);
+    let (runtime_config, providers) = match materialize_runtime_config(
         &config,
         &initial_providers,
         provider_override.as_deref(),
@@ -92,17 +160,48 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         cli.provider_endpoint.as_deref(),
         Some(cli.ollama_url.as_str()),
         system_override.as_deref(),
-    )?;
+    ) {
+        Ok(resolved) => resolved,
+        // Nothing configured yet: the TUI still starts, on its provider setup screen.
+        Err(_) if initial_providers.is_empty() && matches!(mode, RunMode::Stdio) => {
+            (config.clone(), Vec::new())
+        }
+        Err(err) => return Err(err.into()),
+    };
 
     if cli.stream {
         install_terminal_stream_sink();
     }
 
-    let mode = cli.mode.unwrap_or(RunMode::Stdio);
+    // Warmup runs alongside the chosen mode and never stops it.
+    let warmup = cli.warmup
+        || pc_config
+            .custom
+            .get(WARMUP_KEY)
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"));
+    if warmup && !providers.is_empty() && mode != RunMode::WasmHarness {
+        match build_runtime_client(
+            &runtime_config,
+            &providers,
+            std::collections::HashMap::new(),
+        ) {
+            Ok(client) => {
+                let targets = warmup_targets(&runtime_config, &providers);
+                tokio::spawn(async move {
+                    warm_up_providers(&client, targets).await;
+                });
+            }
+            Err(err) => cli_eprint!("warmup skipped: {err}"),
+        }
+    }
 
     match mode {
         RunMode::Stdio => {
-            tui::run_chat_app(runtime_config, providers).await?;
+            let options = tui::TuiOptions {
+                agent_mode: runtime_config.stdio_default_agent_mode && !cli.no_agent,
+                ..tui::TuiOptions::from_custom(&pc_config.custom)
+            };
+            tui::run_chat_app(runtime_config, providers, options).await?;
         }
         RunMode::MultiAgent => {
             let client = build_runtime_client(
@@ -120,7 +219,38 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-async fn run_wasm_harness(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+/// `--list-providers` / `--list-models`: print from `app.pc` and return
+/// without starting a mode. A missing config lists nothing and says so on
+/// stderr, keeping stdout clean for scripts.
+fn print_config_listing(cli: &Cli, config_path: Option<&Path>) {
+    let config = match load_app_config(config_path) {
+        Ok(config) => config,
+        Err(err) => {
+            cli_eprint!("{err}; nothing to list");
+            return;
+        }
+    };
+    let providers = providers_from_postcard(&config.providers);
+    if providers.is_empty() {
+        cli_eprint!("no providers configured");
+    }
+    if cli.list_providers {
+        for line in provider_lines(&providers) {
+            cli_print!("{line}");
+        }
+    }
+    if cli.list_models {
+        for line in model_lines(
+            &providers,
+            &config.model.default_provider,
+            &config.model.model,
+        ) {
+            cli_print!("{line}");
+        }
+    }
+}
+
+async fn run_wasm_harness(cli: Cli) -> Result<(), AppError> {
     let wasm_path = cli
         .wasm
         .unwrap_or_else(|| "target/wasm32-wasip1/release/antikythera_sdk.wasm".to_string());
@@ -166,7 +296,7 @@ async fn run_wasm_harness(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
 async fn run_multi_agent(
     cli: Cli,
     client: Arc<McpClient<DynamicModelProvider>>,
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<(), AppError> {
     // ----------------------------------------------------------------
     // Parse execution mode
     // ----------------------------------------------------------------
@@ -176,9 +306,11 @@ async fn run_multi_agent(
     // Load agent profiles
     // ----------------------------------------------------------------
     let profiles: Vec<AgentProfile> = if let Some(agents_path) = cli.agents.as_deref() {
-        let raw = std::fs::read_to_string(agents_path)
-            .map_err(|e| format!("Failed to read agents file '{}': {e}", agents_path))?;
-        serde_json::from_str(&raw).map_err(|e| format!("Failed to parse agents JSON: {e}"))?
+        let raw = std::fs::read_to_string(agents_path).map_err(|e| {
+            AppError::Config(format!("Failed to read agents file '{}': {e}", agents_path))
+        })?;
+        serde_json::from_str(&raw)
+            .map_err(|e| AppError::Config(format!("Failed to parse agents JSON: {e}")))?
     } else {
         // Default: one general-purpose agent
         vec![AgentProfile {
@@ -251,7 +383,9 @@ async fn run_multi_agent(
     };
 
     if task_input.is_empty() {
-        return Err("No task input provided. Use --task <text> or pipe to stdin.".into());
+        return Err(AppError::Config(
+            "No task input provided. Use --task <text> or pipe to stdin.".to_string(),
+        ));
     }
 
     // ----------------------------------------------------------------
@@ -266,7 +400,11 @@ async fn run_multi_agent(
     cli_print!("{}", serde_json::to_string_pretty(&result)?);
 
     if !result.success {
-        std::process::exit(1);
+        return Err(AppError::Task(
+            result
+                .error
+                .unwrap_or_else(|| "multi-agent task failed".to_string()),
+        ));
     }
 
     Ok(())
@@ -277,8 +415,10 @@ async fn run_multi_agent(
 async fn run_multi_agent(
     _cli: Cli,
     _client: Arc<McpClient<DynamicModelProvider>>,
-) -> Result<(), Box<dyn std::error::Error>> {
-    Err("multi-agent feature is not enabled in this build.\n\
+) -> Result<(), AppError> {
+    Err(AppError::Other(
+        "multi-agent feature is not enabled in this build.\n\
          Rebuild with: cargo build --features multi-agent"
-        .into())
+            .to_string(),
+    ))
 }
@@ -1,4 +1,5 @@
 use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -20,6 +21,27 @@ pub struct Cli {
     pub provider_endpoint: Option<String>,
     #[arg(long)]
     pub config: Option<String>,
+    /// Directory holding the config files (`client.toml` or `config.toml`,
+    /// else `app.pc`), as written by the setup wizard. `--config` wins.
+    #[arg(long)]
+    pub config_dir: Option<PathBuf>,
+    /// Print configured provider IDs, types, and endpoints, then exit.
+    #[arg(long)]
+    pub list_providers: bool,
+    /// Print the models of every provider, marking the default, then exit.
+    #[arg(long)]
+    pub list_models: bool,
+    /// Start every configured MCP server at once, print whether each is
+    /// reachable with its tool count or error, then exit.
+    #[arg(long)]
+    pub check_servers: bool,
+    /// Print the JSON Schema of the TOML config files, then exit.
+    #[arg(long)]
+    pub emit_schema: bool,
+    /// Follow the tool audit log and print each new entry until stopped.
+    /// Without a path, the `[audit_log]` path from the config is used.
+    #[arg(long, value_name = "PATH", num_args = 0..=1)]
+    pub tail_logs: Option<Option<PathBuf>>,
     #[arg(long)]
     pub system: Option<String>,
     #[arg(long, short, value_enum)]
@@ -57,6 +79,17 @@ pub struct Cli {
     #[arg(long)]
     pub stream: bool,
 
+    /// Start the chat in direct chat mode instead of agent mode, overriding
+    /// `stdio_default_agent_mode`. `/agent on` still switches back.
+    #[arg(long)]
+    pub no_agent: bool,
+
+    /// Send a tiny request to each configured provider at startup so the
+    /// first real request does not pay the model-load delay. Also enabled by
+    /// `providers.warmup = true` in `app.pc`.
+    #[arg(long)]
+    pub warmup: bool,
+
     /// Path to WASM module used by `--mode wasm-harness`.
     #[arg(long)]
     pub wasm: Option<String>,
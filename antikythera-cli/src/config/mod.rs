@@ -21,32 +21,23 @@ pub use antikythera_core::config::postcard_config::{
 
 // ── Thin serialization wrappers ────────────────────────────────────────────────
 
-use std::path::Path;
+use crate::domain::entities::{PROVIDER_TYPES, ProviderType};
+use antikythera_core::config::wizard::generators::WizardPaths;
+use antikythera_core::config::write_atomic;
+pub use antikythera_core::config::{ConfigHistoryConfig, ConfigVersion};
+use std::path::{Path, PathBuf};
 
 fn default_provider_catalog() -> Vec<ProviderConfig> {
-    vec![
-        ProviderConfig {
-            id: "ollama".to_string(),
-            provider_type: "ollama".to_string(),
-            endpoint: "http://127.0.0.1:11434".to_string(),
-            api_key: String::new(),
+    PROVIDER_TYPES
+        .iter()
+        .map(|info| ProviderConfig {
+            id: info.name.to_string(),
+            provider_type: info.name.to_string(),
+            endpoint: info.default_endpoint.to_string(),
+            api_key: info.api_key_env.unwrap_or_default().to_string(),
             models: vec![],
-        },
-        ProviderConfig {
-            id: "gemini".to_string(),
-            provider_type: "gemini".to_string(),
-            endpoint: "https://generativelanguage.googleapis.com".to_string(),
-            api_key: "GEMINI_API_KEY".to_string(),
-            models: vec![],
-        },
-        ProviderConfig {
-            id: "openai".to_string(),
-            provider_type: "openai".to_string(),
-            endpoint: "https://api.openai.com".to_string(),
-            api_key: "OPENAI_API_KEY".to_string(),
-            models: vec![],
-        },
-    ]
+        })
+        .collect()
 }
 
 pub fn recommended_default_config() -> AppConfig {
@@ -60,11 +51,12 @@ pub fn recommended_default_config() -> AppConfig {
     }
 }
 
+/// Canonical name of a built-in type or alias; any other type is returned
+/// trimmed and lowercased.
 pub fn normalize_provider_type(provider_type: &str) -> String {
-    match provider_type.trim().to_ascii_lowercase().as_str() {
-        "google" | "google-ai" => "gemini".to_string(),
-        "localai" => "ollama".to_string(),
-        other => other.to_string(),
+    match ProviderType::parse(provider_type) {
+        Some(known) => known.info().name.to_string(),
+        None => provider_type.trim().to_ascii_lowercase(),
     }
 }
 
@@ -78,6 +70,44 @@ pub fn config_from_postcard(data: &[u8]) -> CliResult<AppConfig> {
     antikythera_core::config::postcard_config::config_from_postcard(data).map_err(CliError::Config)
 }
 
+/// Text format of a config shown, exported, or imported by
+/// `antikythera-config`. JSON is the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConfigFormat {
+    #[default]
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Format of a file by its extension: `.toml` is TOML, anything else JSON.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+}
+
+/// Render `AppConfig` as pretty JSON or TOML.
+pub fn config_to_text(config: &AppConfig, format: ConfigFormat) -> CliResult<String> {
+    match format {
+        ConfigFormat::Json => Ok(serde_json::to_string_pretty(config)?),
+        ConfigFormat::Toml => toml::to_string_pretty(config)
+            .map_err(|e| CliError::Config(format!("TOML serialization failed: {e}"))),
+    }
+}
+
+/// Parse `AppConfig` from JSON or TOML text.
+pub fn config_from_text(text: &str, format: ConfigFormat) -> CliResult<AppConfig> {
+    match format {
+        ConfigFormat::Json => Ok(serde_json::from_str(text)?),
+        ConfigFormat::Toml => {
+            toml::from_str(text).map_err(|e| CliError::Config(format!("invalid TOML: {e}")))
+        }
+    }
+}
+
 /// Load `AppConfig` from `path` (defaults to [`CONFIG_PATH`] = `app.pc`).
 pub fn load_app_config(path: Option<&Path>) -> CliResult<AppConfig> {
     let config_path = path.unwrap_or(Path::new(CONFIG_PATH));
@@ -100,17 +130,82 @@ pub fn load_config(path: Option<&Path>) -> CliResult<AppConfig> {
     load_app_config(path)
 }
 
-/// Save `AppConfig` to `path` (defaults to [`CONFIG_PATH`] = `app.pc`).
+/// Save `AppConfig` to `path` (defaults to [`CONFIG_PATH`] = `app.pc`),
+/// replacing the file atomically. When `config_history.dir` is set in
+/// `custom`, the replaced file is kept as a version.
 pub fn save_app_config(config: &AppConfig, path: Option<&Path>) -> CliResult<()> {
     let config_path = path.unwrap_or(Path::new(CONFIG_PATH));
     if let Some(parent) = config_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
     let data = config_to_postcard(config)?;
-    std::fs::write(config_path, &data)?;
+    match ConfigHistoryConfig::from_custom(&config.custom) {
+        Some(history) => history
+            .save(config_path, &data)
+            .map_err(|e| CliError::Config(e.to_string()))?,
+        None => write_atomic(config_path, &data)?,
+    }
     Ok(())
 }
 
+/// Saved versions of `path` (defaults to `app.pc`), newest first. Fails when
+/// `config_history.dir` is not set.
+pub fn config_versions(path: Option<&Path>) -> CliResult<Vec<ConfigVersion>> {
+    let config_path = path.unwrap_or(Path::new(CONFIG_PATH));
+    config_history(path)?
+        .versions(config_path)
+        .map_err(|e| CliError::Config(e.to_string()))
+}
+
+/// Restore `path` (defaults to `app.pc`) to `version`, keeping the current
+/// content as a new version.
+pub fn rollback_config(version: &str, path: Option<&Path>) -> CliResult<()> {
+    let config_path = path.unwrap_or(Path::new(CONFIG_PATH));
+    config_history(path)?
+        .rollback(config_path, version)
+        .map_err(|e| CliError::Config(e.to_string()))
+}
+
+fn config_history(path: Option<&Path>) -> CliResult<ConfigHistoryConfig> {
+    ConfigHistoryConfig::from_custom(&load_app_config(path)?.custom).ok_or_else(|| {
+        CliError::Config(format!(
+            "config history is off; set {} first",
+            ConfigHistoryConfig::DIR_KEY
+        ))
+    })
+}
+
+/// Files one run reads: the runtime config given to core's
+/// `AppConfig::load`, and the `app.pc` holding providers and saved choices.
+/// `None` means the working-directory default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigPaths {
+    pub runtime: Option<PathBuf>,
+    pub postcard: Option<PathBuf>,
+}
+
+impl ConfigPaths {
+    /// `--config` names one file for both loads and wins. `--config-dir`
+    /// reads the layout the setup wizard writes there: its TOML files when
+    /// present, else its `app.pc`.
+    pub fn resolve(config: Option<&str>, config_dir: Option<&Path>) -> Self {
+        match (config, config_dir) {
+            (Some(path), _) => Self {
+                runtime: Some(PathBuf::from(path)),
+                postcard: Some(PathBuf::from(path)),
+            },
+            (None, Some(dir)) => {
+                let paths = WizardPaths::in_dir(dir);
+                Self {
+                    runtime: Some(paths.config_source().path().to_path_buf()),
+                    postcard: Some(paths.postcard()),
+                }
+            }
+            (None, None) => Self::default(),
+        }
+    }
+}
+
 /// Returns `true` if the config file already exists at the default path.
 pub fn config_exists() -> bool {
     Path::new(CONFIG_PATH).exists()
@@ -1,8 +1,8 @@
 //! CLI domain entities.
 //!
 //! Generic message/action types are re-exported from `antikythera-core`.
-//! Provider-routing types (`ProviderType`, `ProviderConfig`, `ChatSession`)
-//! are owned here because they represent CLI-level wiring concerns, not
+//! Provider-routing types (`ProviderType` and its [`PROVIDER_TYPES`]
+//! registry, `ProviderConfig`, `ChatSession`) are owned here because they represent CLI-level wiring concerns, not
 //! core protocol semantics.
 
 pub use antikythera_core::domain::entities::{
@@ -27,21 +27,76 @@ impl ProviderType {
     pub fn parse(value: &str) -> Option<Self> {
         value.parse::<Self>().ok()
     }
+
+    /// Registry entry for this type.
+    pub fn info(self) -> &'static ProviderTypeInfo {
+        PROVIDER_TYPES
+            .iter()
+            .find(|info| info.provider_type == self)
+            .expect("every ProviderType has a PROVIDER_TYPES entry")
+    }
 }
 
 impl std::str::FromStr for ProviderType {
     type Err = String;
 
+    /// Accepts a registry name or alias, ignoring case and surrounding spaces.
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        match value.to_lowercase().as_str() {
-            "gemini" => Ok(ProviderType::Gemini),
-            "ollama" => Ok(ProviderType::Ollama),
-            "openai" => Ok(ProviderType::OpenAi),
-            _ => Err(format!("Unknown provider type: {value}")),
-        }
+        let wanted = value.trim().to_ascii_lowercase();
+        PROVIDER_TYPES
+            .iter()
+            .find(|info| info.name == wanted || info.aliases.contains(&wanted.as_str()))
+            .map(|info| info.provider_type)
+            .ok_or_else(|| format!("Unknown provider type: {value}"))
     }
 }
 
+/// Defaults for one provider type, shared by the config templates, the
+/// `antikythera-config` binary, and the client factory.
+#[derive(Debug)]
+pub struct ProviderTypeInfo {
+    pub provider_type: ProviderType,
+    /// Canonical `type` value stored in config.
+    pub name: &'static str,
+    /// Other accepted spellings of `name`.
+    pub aliases: &'static [&'static str],
+    pub default_endpoint: &'static str,
+    /// Environment variable holding the API key; `None` when no key is needed.
+    pub api_key_env: Option<&'static str>,
+    /// Request path used when the provider sets no `api_path`; `None` when
+    /// the client does not read one.
+    pub default_api_path: Option<&'static str>,
+}
+
+/// Every provider type the CLI has a client for, in the order they are
+/// offered. A `type` not listed here is treated as OpenAI-compatible.
+pub const PROVIDER_TYPES: &[ProviderTypeInfo] = &[
+    ProviderTypeInfo {
+        provider_type: ProviderType::Ollama,
+        name: "ollama",
+        aliases: &["localai"],
+        default_endpoint: "http://127.0.0.1:11434",
+        api_key_env: None,
+        default_api_path: None,
+    },
+    ProviderTypeInfo {
+        provider_type: ProviderType::Gemini,
+        name: "gemini",
+        aliases: &["google", "google-ai"],
+        default_endpoint: "https://generativelanguage.googleapis.com",
+        api_key_env: Some("GEMINI_API_KEY"),
+        default_api_path: Some(antikythera_core::constants::DEFAULT_GEMINI_API_PATH),
+    },
+    ProviderTypeInfo {
+        provider_type: ProviderType::OpenAi,
+        name: "openai",
+        aliases: &[],
+        default_endpoint: "https://api.openai.com",
+        api_key_env: Some("OPENAI_API_KEY"),
+        default_api_path: Some("/v1/chat/completions"),
+    },
+];
+
 /// Resolved provider connection config used by CLI infrastructure.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ProviderConfig {
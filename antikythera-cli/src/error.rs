@@ -1,3 +1,5 @@
+use antikythera_core::config::ConfigError;
+use antikythera_core::infrastructure::model::ModelError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -10,8 +12,92 @@ pub enum CliError {
     Serialization(#[from] serde_json::Error),
     #[error("validation error: {0}")]
     Validation(String),
+    #[error("provider error: {0}")]
+    Provider(String),
     #[error("unsupported operation: {0}")]
     Unsupported(String),
 }
 
 pub type CliResult<T> = Result<T, CliError>;
+
+/// Why a binary stopped, mapped to a distinct process exit code so scripts
+/// and supervisors can tell failures apart. Success exits with 0.
+///
+/// | Code | Variant |
+/// |:-----|:--------|
+/// | 1 | [`Other`](Self::Other) |
+/// | 2 | [`Config`](Self::Config) |
+/// | 3 | [`Provider`](Self::Provider) |
+/// | 4 | [`Io`](Self::Io) |
+/// | 5 | [`Task`](Self::Task) |
+#[derive(Debug, Error)]
+pub enum AppError {
+    /// Missing or invalid configuration, including bad flag values.
+    #[error("{0}")]
+    Config(String),
+    /// A model provider could not be built or answered with an error.
+    #[error("{0}")]
+    Provider(String),
+    /// Reading or writing a file, the terminal, or stdin failed.
+    #[error("{0}")]
+    Io(String),
+    /// A multi-agent task ran but did not succeed.
+    #[error("{0}")]
+    Task(String),
+    /// Anything else.
+    #[error("{0}")]
+    Other(String),
+}
+
+impl AppError {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::Other(_) => 1,
+            Self::Config(_) => 2,
+            Self::Provider(_) => 3,
+            Self::Io(_) => 4,
+            Self::Task(_) => 5,
+        }
+    }
+}
+
+impl From<CliError> for AppError {
+    fn from(error: CliError) -> Self {
+        match error {
+            CliError::Config(_) | CliError::Validation(_) => Self::Config(error.to_string()),
+            CliError::Provider(message) => Self::Provider(message),
+            CliError::Io(_) => Self::Io(error.to_string()),
+            CliError::Serialization(_) | CliError::Unsupported(_) => Self::Other(error.to_string()),
+        }
+    }
+}
+
+impl From<ConfigError> for AppError {
+    fn from(error: ConfigError) -> Self {
+        match error {
+            ConfigError::NotFound { path } => Self::Config(format!(
+                "no config found at {}; run `antikythera-config init` or provide --config",
+                path.display()
+            )),
+            other => Self::Config(other.to_string()),
+        }
+    }
+}
+
+impl From<ModelError> for AppError {
+    fn from(error: ModelError) -> Self {
+        Self::Provider(error.user_message())
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Other(error.to_string())
+    }
+}
@@ -5,8 +5,6 @@
 //! `antikythera-core` so that the core crate compiles cleanly as a WASM
 //! component without any HTTP dependencies.
 
-use antikythera_core::constants::DEFAULT_GEMINI_API_PATH;
-
 use super::super::types::ModelProviderConfig;
 use antikythera_core::ProviderLogger;
 use antikythera_core::infrastructure::model::traits::ModelClient;
@@ -16,8 +14,9 @@ use serde::Deserialize;
 use serde_json::json;
 
 use super::super::adapter::MessageAdapter;
-use super::super::factory::resolve_api_key;
+use super::super::factory::{default_api_path, resolve_api_key, resolve_headers};
 use super::super::http_client::HttpClientBase;
+use crate::domain::entities::ProviderType;
 
 /// Gemini client for Google AI.
 #[derive(Clone)]
@@ -31,11 +30,13 @@ impl GeminiClient {
     pub fn from_config(config: &ModelProviderConfig) -> Self {
         let api_key = resolve_api_key(&config.id, config.api_key.as_deref());
         Self {
-            base: HttpClientBase::new(config.id.clone(), config.endpoint.clone(), api_key),
+            base: HttpClientBase::new(config.id.clone(), config.endpoint.clone(), api_key)
+                .with_headers(resolve_headers(&config.id, &config.headers))
+                .with_transport(config),
             api_path: config
                 .api_path
                 .clone()
-                .unwrap_or_else(|| DEFAULT_GEMINI_API_PATH.to_string()),
+                .unwrap_or_else(|| default_api_path(ProviderType::Gemini)),
         }
     }
 
@@ -10,6 +10,7 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use super::super::adapter::MessageAdapter;
+use super::super::factory::resolve_headers;
 use super::super::http_client::HttpClientBase;
 use super::super::streaming::{StreamAction, extract_stream_content};
 use super::super::types::ModelProviderConfig;
@@ -25,7 +26,9 @@ impl OllamaClient {
     pub fn from_config(config: &ModelProviderConfig) -> Self {
         Self {
             // Ollama does not use an API key.
-            base: HttpClientBase::new(config.id.clone(), config.endpoint.clone(), None),
+            base: HttpClientBase::new(config.id.clone(), config.endpoint.clone(), None)
+                .with_headers(resolve_headers(&config.id, &config.headers))
+                .with_transport(config),
         }
     }
 }
@@ -1,27 +1,31 @@
 //! OpenAI-compatible client — CLI-side implementation
 //!
 //! Implements `ModelClient` for any provider that exposes an OpenAI-compatible
-//! chat completions API (OpenAI, Anthropic via proxy, Mistral, Groq, etc.).
+//! chat completions API (OpenAI, Anthropic via proxy, Mistral, Groq, etc.),
+//! including Azure OpenAI through [`OpenAiFlavor::Azure`].
 //! This client is the CLI-owned version; the core crate is free of HTTP deps.
 
 use antikythera_core::infrastructure::model::traits::ModelClient;
 
-use super::super::types::ModelProviderConfig;
+use super::super::types::{AZURE_DEFAULT_API_VERSION, ModelProviderConfig, OpenAiFlavor};
 use antikythera_core::ProviderLogger;
 use antikythera_core::infrastructure::model::types::{ModelError, ModelRequest, ModelResponse};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use super::super::adapter::MessageAdapter;
-use super::super::factory::resolve_api_key;
+use super::super::factory::{default_api_path, resolve_api_key, resolve_headers};
 use super::super::http_client::HttpClientBase;
 use super::super::streaming::{StreamAction, extract_stream_content};
+use crate::domain::entities::ProviderType;
 
 /// OpenAI-compatible client.
 #[derive(Clone)]
 pub struct OpenAIClient {
     base: HttpClientBase,
     api_path: String,
+    flavor: OpenAiFlavor,
+    api_version: String,
 }
 
 impl OpenAIClient {
@@ -29,11 +33,32 @@ impl OpenAIClient {
     pub fn from_config(config: &ModelProviderConfig) -> Self {
         let api_key = resolve_api_key(&config.id, config.api_key.as_deref());
         Self {
-            base: HttpClientBase::new(config.id.clone(), config.endpoint.clone(), api_key),
+            base: HttpClientBase::new(config.id.clone(), config.endpoint.clone(), api_key)
+                .with_headers(resolve_headers(&config.id, &config.headers))
+                .with_transport(config),
             api_path: config
                 .api_path
                 .clone()
-                .unwrap_or_else(|| "/v1/chat/completions".to_string()),
+                .unwrap_or_else(|| default_api_path(ProviderType::OpenAi)),
+            flavor: config.openai_flavor,
+            api_version: config
+                .api_version
+                .clone()
+                .unwrap_or_else(|| AZURE_DEFAULT_API_VERSION.to_string()),
+        }
+    }
+
+    /// Chat completions URL for `model`. Azure addresses the deployment
+    /// named after the model instead of a fixed path.
+    fn chat_url(&self, model: &str) -> String {
+        match self.flavor {
+            OpenAiFlavor::OpenAi => self.base.build_url(&self.api_path),
+            OpenAiFlavor::Azure => format!(
+                "{}?api-version={}",
+                self.base
+                    .build_url(&format!("openai/deployments/{model}/chat/completions")),
+                self.api_version
+            ),
         }
     }
 }
@@ -45,7 +70,7 @@ impl ModelClient for OpenAIClient {
     }
 
     async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
-        let url = self.base.build_url(&self.api_path);
+        let url = self.chat_url(&request.model);
 
         let force_json = request
             .params
@@ -82,7 +107,14 @@ impl ModelClient for OpenAIClient {
             request.messages.len()
         ));
 
-        let raw = self.base.post_with_bearer_text(&url, &payload).await?;
+        let raw = match self.flavor {
+            OpenAiFlavor::OpenAi => self.base.post_with_bearer_text(&url, &payload).await?,
+            OpenAiFlavor::Azure => {
+                self.base
+                    .post_with_key_header_text(&url, "api-key", &payload)
+                    .await?
+            }
+        };
         log.debug("Received response from OpenAI-compatible provider");
 
         let content = extract_stream_content(
@@ -8,13 +8,16 @@
 //! [`ProviderFactory::create`] is the primary entry point, dispatching on
 //! `provider_type` to instantiate the appropriate concrete client.
 
+use std::collections::HashMap;
 use std::env;
 use std::path::Path;
 use std::sync::Once;
 
+use antikythera_core::application::tooling::Placeholders;
 use antikythera_core::infrastructure::model::traits::ModelClient;
 
 use super::types::ModelProviderConfig;
+use crate::domain::entities::ProviderType;
 use antikythera_core::ProviderLogger;
 
 use super::clients::{GeminiClient, OllamaClient, OpenAIClient};
@@ -72,22 +75,66 @@ pub fn resolve_api_key(provider: &str, spec: Option<&str>) -> Option<String> {
     None
 }
 
+/// Resolve a provider's extra request headers.
+///
+/// `${NAME}` in a value is replaced by the environment variable `NAME` (with
+/// `${NAME:-default}` as a fallback form), so secrets stay out of the config.
+/// A header whose value references an unset variable is dropped with a
+/// warning rather than sent half-filled.
+pub fn resolve_headers(provider: &str, headers: &HashMap<String, String>) -> Vec<(String, String)> {
+    if headers.is_empty() {
+        return Vec::new();
+    }
+    ensure_cli_env_loaded();
+
+    let placeholders = Placeholders::new();
+    let mut resolved: Vec<(String, String)> = headers
+        .iter()
+        .filter_map(|(name, value)| match placeholders.expand(value) {
+            Ok(value) => Some((name.clone(), value)),
+            Err(error) => {
+                ProviderLogger::new(&antikythera_core::get_active_session()).warn(format!(
+                    "Skipping provider header | provider={} header={} error={}",
+                    provider, name, error
+                ));
+                None
+            }
+        })
+        .collect();
+    resolved.sort();
+    resolved
+}
+
+/// Request path for `provider_type` when a provider sets no `api_path`.
+pub(crate) fn default_api_path(provider_type: ProviderType) -> String {
+    provider_type
+        .info()
+        .default_api_path
+        .unwrap_or_default()
+        .to_string()
+}
+
 /// Factory for creating `ModelClient` instances from provider configuration.
 pub struct ProviderFactory;
 
 impl ProviderFactory {
     /// Create the correct client for the given provider configuration.
     ///
-    /// | `provider_type`          | Client               |
+    /// `provider_type` is resolved through [`PROVIDER_TYPES`], so aliases
+    /// such as `"localai"` or `"google"` pick the same client as their type.
+    ///
+    /// | Type                     | Client               |
     /// |--------------------------|----------------------|
-    /// | `"ollama"`, `"localai"`  | [`OllamaClient`]     |
-    /// | `"gemini"`, `"google"`   | [`GeminiClient`]     |
+    /// | [`ProviderType::Ollama`] | [`OllamaClient`]     |
+    /// | [`ProviderType::Gemini`] | [`GeminiClient`]     |
     /// | anything else            | [`OpenAIClient`]     |
+    ///
+    /// [`PROVIDER_TYPES`]: crate::domain::entities::PROVIDER_TYPES
     pub fn create(config: &ModelProviderConfig) -> Box<dyn ModelClient> {
-        match config.provider_type.to_lowercase().as_str() {
-            "ollama" | "localai" => Box::new(OllamaClient::from_config(config)),
-            "gemini" | "google" | "google-ai" => Box::new(GeminiClient::from_config(config)),
-            _ => Box::new(OpenAIClient::from_config(config)),
+        match ProviderType::parse(&config.provider_type) {
+            Some(ProviderType::Ollama) => Box::new(OllamaClient::from_config(config)),
+            Some(ProviderType::Gemini) => Box::new(GeminiClient::from_config(config)),
+            Some(ProviderType::OpenAi) | None => Box::new(OpenAIClient::from_config(config)),
         }
     }
 }
@@ -8,10 +8,23 @@
 //! wrapped in `ModelError::network`, keeping `ModelError` free of
 //! `reqwest`-specific types.
 
+use antikythera_core::ProviderLogger;
 use antikythera_core::infrastructure::model::types::ModelError;
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder, StatusCode};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::Duration;
+
+use super::types::{ModelProviderConfig, RateLimitRetry};
+
+/// Clients keyed by their transport settings, so providers configured alike
+/// reuse one connection pool instead of each opening their own.
+static SHARED_CLIENTS: LazyLock<Mutex<HashMap<String, Client>>> = LazyLock::new(Default::default);
+
+/// Wait before the first retry without `Retry-After`; doubles each retry.
+const BACKOFF_BASE: Duration = Duration::from_secs(1);
 
 /// Base HTTP client with shared functionality for LLM provider calls.
 #[derive(Clone)]
@@ -19,7 +32,11 @@ pub struct HttpClientBase {
     pub id: String,
     pub endpoint: String,
     pub api_key: Option<String>,
+    /// Extra headers added to every request, already interpolated.
+    pub headers: Vec<(String, String)>,
     pub http: Client,
+    /// Retries after HTTP 429.
+    pub rate_limit: RateLimitRetry,
 }
 
 impl HttpClientBase {
@@ -28,7 +45,136 @@ impl HttpClientBase {
             id,
             endpoint,
             api_key,
+            headers: Vec::new(),
             http: Client::new(),
+            rate_limit: RateLimitRetry::default(),
+        }
+    }
+
+    /// Send `headers` with every request in addition to the auth headers.
+    pub fn with_headers(mut self, headers: Vec<(String, String)>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Use an HTTP client matching the provider's proxy, TLS, and pool
+    /// settings, shared with every other provider that has the same ones.
+    ///
+    /// The settings are validated at load time; should building the client
+    /// still fail, the default client (direct, full verification) is kept.
+    pub fn with_transport(mut self, config: &ModelProviderConfig) -> Self {
+        self.rate_limit = config.rate_limit.clone();
+        let log = ProviderLogger::new(&antikythera_core::get_active_session());
+        if config.tls.danger_accept_invalid_certs {
+            log.warn(format!(
+                "TLS CERTIFICATE VERIFICATION IS DISABLED | provider={} endpoint={} \
+                 (danger_accept_invalid_certs=true; prefer ca_cert)",
+                self.id, self.endpoint
+            ));
+        }
+
+        let key =
+            serde_json::to_string(&(&config.proxy, &config.tls, &config.pool)).unwrap_or_default();
+        let mut shared = SHARED_CLIENTS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(http) = shared.get(&key) {
+            self.http = http.clone();
+            return self;
+        }
+        match self.build_client(config) {
+            Ok(http) => {
+                shared.insert(key, http.clone());
+                self.http = http;
+            }
+            Err(error) => log.warn(format!(
+                "Using default HTTP client | provider={} error={}",
+                self.id, error
+            )),
+        }
+        self
+    }
+
+    fn build_client(&self, config: &ModelProviderConfig) -> reqwest::Result<Client> {
+        let log = ProviderLogger::new(&antikythera_core::get_active_session());
+        let mut builder = Client::builder();
+        if let Some(proxy) = &config.proxy {
+            match proxy.to_reqwest() {
+                Ok(proxy) => builder = builder.proxy(proxy),
+                Err(error) => log.warn(format!(
+                    "Ignoring provider proxy | provider={} proxy={} error={}",
+                    self.id, proxy.url, error
+                )),
+            }
+        }
+        match config.tls.load_ca_cert() {
+            Ok(Some(cert)) => builder = builder.add_root_certificate(cert),
+            Ok(None) => {}
+            Err(error) => log.warn(format!(
+                "Ignoring provider CA certificate | provider={} error={}",
+                self.id, error
+            )),
+        }
+        if config.tls.danger_accept_invalid_certs {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        if let Some(max_idle) = config.pool.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(secs) = config.pool.pool_idle_timeout_secs {
+            builder = builder.pool_idle_timeout(Duration::from_secs(secs));
+        }
+        builder.build()
+    }
+
+    /// Start a POST carrying the configured extra headers. Names listed in
+    /// `reserved` are skipped so they never clash with the provider's auth.
+    fn post(&self, url: &str, reserved: &[&str]) -> RequestBuilder {
+        self.headers
+            .iter()
+            .filter(|(name, _)| !reserved.iter().any(|r| name.eq_ignore_ascii_case(r)))
+            .fold(self.http.post(url), |request, (name, value)| {
+                request.header(name.as_str(), value.as_str())
+            })
+    }
+
+    /// Send the request built by `build`, retrying on HTTP 429 as configured
+    /// in [`RateLimitRetry`]. Other error statuses fail at once.
+    async fn send(
+        &self,
+        build: impl Fn() -> RequestBuilder,
+    ) -> Result<reqwest::Response, ModelError> {
+        let mut retries = 0;
+        loop {
+            let response = build()
+                .send()
+                .await
+                .map_err(|e| ModelError::network(&self.id, e.to_string()))?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return response
+                    .error_for_status()
+                    .map_err(|e| ModelError::network(&self.id, e.to_string()));
+            }
+
+            let retry_after = retry_after(&response);
+            let max_wait = Duration::from_secs(self.rate_limit.max_wait_secs);
+            let wait = retry_after.unwrap_or_else(|| backoff(retries).min(max_wait));
+            if retries >= self.rate_limit.max_retries || wait > max_wait {
+                return Err(ModelError::rate_limited(
+                    &self.id,
+                    retries,
+                    retry_after.map(|wait| wait.as_secs()),
+                ));
+            }
+            retries += 1;
+            ProviderLogger::new(&antikythera_core::get_active_session()).warn(format!(
+                "Rate limited, retrying | provider={} retry={}/{} wait_ms={}",
+                self.id,
+                retries,
+                self.rate_limit.max_retries,
+                wait.as_millis()
+            ));
+            tokio::time::sleep(wait).await;
         }
     }
 
@@ -50,19 +196,41 @@ impl HttpClientBase {
     {
         let api_key = self.require_api_key()?;
 
-        self.http
-            .post(url)
-            .header("Authorization", format!("Bearer {}", api_key))
-            .header("Content-Type", "application/json")
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| ModelError::network(&self.id, e.to_string()))?
-            .error_for_status()
-            .map_err(|e| ModelError::network(&self.id, e.to_string()))?
-            .text()
-            .await
-            .map_err(|e| ModelError::network(&self.id, e.to_string()))
+        self.send(|| {
+            self.post(url, &["Authorization"])
+                .header("Authorization", format!("Bearer {}", api_key))
+                .header("Content-Type", "application/json")
+                .json(body)
+        })
+        .await?
+        .text()
+        .await
+        .map_err(|e| ModelError::network(&self.id, e.to_string()))
+    }
+
+    /// POST JSON with the API key as the value of `header` (Azure's
+    /// `api-key`) and return raw response body as text.
+    pub async fn post_with_key_header_text<Req>(
+        &self,
+        url: &str,
+        header: &str,
+        body: &Req,
+    ) -> Result<String, ModelError>
+    where
+        Req: Serialize,
+    {
+        let api_key = self.require_api_key()?;
+
+        self.send(|| {
+            self.post(url, &[header])
+                .header(header, api_key)
+                .header("Content-Type", "application/json")
+                .json(body)
+        })
+        .await?
+        .text()
+        .await
+        .map_err(|e| ModelError::network(&self.id, e.to_string()))
     }
 
     /// POST JSON with `?key=<api_key>` query parameter auth (Gemini style).
@@ -78,14 +246,8 @@ impl HttpClientBase {
         let api_key = self.require_api_key()?;
         let url_with_key = format!("{}?key={}", url, api_key);
 
-        self.http
-            .post(&url_with_key)
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| ModelError::network(&self.id, e.to_string()))?
-            .error_for_status()
-            .map_err(|e| ModelError::network(&self.id, e.to_string()))?
+        self.send(|| self.post(&url_with_key, &[]).json(body))
+            .await?
             .json()
             .await
             .map_err(|e| ModelError::network(&self.id, e.to_string()))
@@ -96,14 +258,8 @@ impl HttpClientBase {
     where
         Req: Serialize,
     {
-        self.http
-            .post(url)
-            .json(body)
-            .send()
-            .await
-            .map_err(|e| ModelError::network(&self.id, e.to_string()))?
-            .error_for_status()
-            .map_err(|e| ModelError::network(&self.id, e.to_string()))?
+        self.send(|| self.post(url, &[]).json(body))
+            .await?
             .text()
             .await
             .map_err(|e| ModelError::network(&self.id, e.to_string()))
@@ -116,3 +272,28 @@ impl HttpClientBase {
             .ok_or_else(|| ModelError::missing_api_key(&self.id))
     }
 }
+
+/// `Retry-After` as seconds or as an HTTP date; `None` when absent or
+/// unreadable. A date in the past means no wait.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let value = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (at.with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// Exponential backoff before retry number `retries + 1`.
+fn backoff(retries: u32) -> Duration {
+    BACKOFF_BASE.saturating_mul(2u32.saturating_pow(retries))
+}
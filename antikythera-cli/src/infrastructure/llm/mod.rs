@@ -13,4 +13,14 @@ pub use provider_builder::build_provider_from_configs;
 pub use streaming::{
     StreamEvent, clear_stream_event_sink, install_terminal_stream_sink, set_stream_event_sink,
 };
-pub use types::{ModelInfo, ModelProviderConfig, providers_from_postcard, providers_to_postcard};
+pub use types::{
+    AZURE_DEFAULT_API_VERSION, HttpPoolConfig, ModelInfo, ModelProviderConfig, OpenAiFlavor,
+    POOL_IDLE_TIMEOUT_SECS_KEY, POOL_MAX_IDLE_PER_HOST_KEY, PROVIDER_API_VERSION_KEY_PREFIX,
+    PROVIDER_DEFAULT_MODEL_KEY_PREFIX, PROVIDER_HEADERS_KEY_PREFIX,
+    PROVIDER_OPENAI_FLAVOR_KEY_PREFIX, PROVIDER_PROXY_KEY_PREFIX, PROVIDER_TLS_KEY_PREFIX,
+    PROXY_NO_PROXY_KEY, PROXY_URL_KEY, ProxyConfig, RATE_LIMIT_MAX_RETRIES_KEY,
+    RATE_LIMIT_MAX_WAIT_SECS_KEY, RateLimitRetry, TlsConfig, apply_default_model_settings,
+    apply_http_pool_settings, apply_openai_flavor_settings, apply_provider_headers,
+    apply_provider_settings, apply_proxy_settings, apply_rate_limit_settings, apply_tls_settings,
+    providers_from_postcard, providers_to_postcard, write_provider_headers,
+};
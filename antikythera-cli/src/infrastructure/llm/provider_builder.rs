@@ -12,7 +12,7 @@ pub fn build_provider_from_configs(
     configs: &[ModelProviderConfig],
 ) -> Result<DynamicModelProvider, ModelError> {
     if configs.is_empty() {
-        return Err(ModelError::provider_not_found("<empty-provider-config>"));
+        return Err(ModelError::NoProviders);
     }
 
     let provider = configs
@@ -20,7 +20,11 @@ pub fn build_provider_from_configs(
         .fold(DynamicModelProvider::new(), |provider, config| {
             let client = ProviderFactory::create(config);
             let models = config.models.iter().map(|m| m.name.clone()).collect();
-            provider.register(config.id.clone(), models, client)
+            let provider = provider.register(config.id.clone(), models, client);
+            match &config.default_model {
+                Some(model) => provider.with_default_model(&config.id, model.clone()),
+                None => provider,
+            }
         });
 
     Ok(provider)
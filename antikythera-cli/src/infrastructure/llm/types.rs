@@ -6,9 +6,13 @@
 //! concern — `antikythera-core` is completely agnostic about which LLM is
 //! speaking to it.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
 use crate::config::{ModelInfo as PostcardModelInfo, ProviderConfig};
+use crate::error::{CliError, CliResult};
 
 /// Runtime connection parameters for a single LLM provider backend.
 ///
@@ -28,6 +32,37 @@ pub struct ModelProviderConfig {
     pub api_key: Option<String>,
     /// Optional provider-specific API path override (used by Gemini).
     pub api_path: Option<String>,
+    /// Extra headers sent with every request (tenant ids, gateway routing
+    /// keys). Values may reference environment variables as `${NAME}`.
+    /// Sent alongside the provider's own auth headers, never instead of them.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Outbound proxy for this provider's requests; `None` connects directly
+    /// (reqwest still honours the usual proxy environment variables).
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+    /// Certificate trust for self-hosted gateways. Verification stays on
+    /// unless explicitly disabled.
+    #[serde(default)]
+    pub tls: TlsConfig,
+    /// Connection pool tuning; unset values keep reqwest's defaults.
+    #[serde(default)]
+    pub pool: HttpPoolConfig,
+    /// Automatic retries when the provider answers HTTP 429.
+    #[serde(default)]
+    pub rate_limit: RateLimitRetry,
+    /// API shape of an OpenAI-compatible provider; ignored by the Ollama
+    /// and Gemini clients.
+    #[serde(default)]
+    pub openai_flavor: OpenAiFlavor,
+    /// `api-version` query parameter sent by the Azure flavor; `None` uses
+    /// [`AZURE_DEFAULT_API_VERSION`].
+    #[serde(default)]
+    pub api_version: Option<String>,
+    /// Model used when a request names one this provider does not offer,
+    /// e.g. after switching to it from another provider.
+    #[serde(default)]
+    pub default_model: Option<String>,
     /// Models offered by this provider.
     pub models: Vec<ModelInfo>,
 }
@@ -63,6 +98,171 @@ impl ModelProviderConfig {
     }
 }
 
+/// `api-version` used by Azure OpenAI providers that set none.
+pub const AZURE_DEFAULT_API_VERSION: &str = "2024-10-21";
+
+/// URL scheme and auth header of an OpenAI-compatible provider.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenAiFlavor {
+    /// `{endpoint}/{api_path}` with `Authorization: Bearer <key>`.
+    #[default]
+    OpenAi,
+    /// Azure OpenAI: `{endpoint}/openai/deployments/{model}/chat/completions`
+    /// with `?api-version=` and an `api-key` header. The model name is the
+    /// deployment name, and `api_path` is ignored.
+    Azure,
+}
+
+impl OpenAiFlavor {
+    /// Parse `openai` or `azure`, ignoring case and surrounding spaces.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "openai" => Some(Self::OpenAi),
+            "azure" => Some(Self::Azure),
+            _ => None,
+        }
+    }
+}
+
+/// Proxy used for outbound model requests.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProxyConfig {
+    /// Proxy URL (`http://` or `https://`), e.g. `http://proxy.corp:3128`.
+    pub url: String,
+    /// Hosts reached without the proxy. Defaults to loopback so a local
+    /// Ollama keeps working behind a corporate proxy.
+    #[serde(default = "ProxyConfig::default_no_proxy")]
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    /// Validate `url` and build a config with the default `no_proxy` list.
+    pub fn new(url: impl Into<String>) -> CliResult<Self> {
+        let config = Self {
+            url: url.into().trim().to_string(),
+            no_proxy: Self::default_no_proxy(),
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn default_no_proxy() -> Vec<String> {
+        ["localhost", "127.0.0.1", "::1"]
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Replace the hosts that bypass the proxy.
+    pub fn with_no_proxy(mut self, hosts: Vec<String>) -> Self {
+        self.no_proxy = hosts;
+        self
+    }
+
+    /// Reject URLs reqwest cannot use as an HTTP proxy.
+    pub fn validate(&self) -> CliResult<()> {
+        let scheme_ok = ["http://", "https://"]
+            .iter()
+            .any(|scheme| self.url.to_ascii_lowercase().starts_with(scheme));
+        if !scheme_ok {
+            return Err(CliError::Config(format!(
+                "invalid proxy URL '{}': expected an http:// or https:// URL",
+                self.url
+            )));
+        }
+        self.to_reqwest().map(|_| ()).map_err(|error| {
+            CliError::Config(format!("invalid proxy URL '{}': {}", self.url, error))
+        })
+    }
+
+    pub(crate) fn to_reqwest(&self) -> reqwest::Result<reqwest::Proxy> {
+        let proxy = reqwest::Proxy::all(&self.url)?;
+        Ok(proxy.no_proxy(reqwest::NoProxy::from_string(&self.no_proxy.join(","))))
+    }
+}
+
+/// TLS trust settings for a provider.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TlsConfig {
+    /// Extra root certificate (PEM or DER) trusted for this provider. Prefer
+    /// this over `danger_accept_invalid_certs` for a self-signed gateway.
+    #[serde(default)]
+    pub ca_cert: Option<PathBuf>,
+    /// DANGER: accept any certificate, including expired or forged ones.
+    /// Only for a gateway you control on a network you trust.
+    #[serde(default)]
+    pub danger_accept_invalid_certs: bool,
+}
+
+impl TlsConfig {
+    /// Read and parse `ca_cert`, if set.
+    pub fn load_ca_cert(&self) -> CliResult<Option<reqwest::Certificate>> {
+        let Some(path) = &self.ca_cert else {
+            return Ok(None);
+        };
+        let bytes = std::fs::read(path).map_err(|error| {
+            CliError::Config(format!(
+                "cannot read CA certificate '{}': {}",
+                path.display(),
+                error
+            ))
+        })?;
+        reqwest::Certificate::from_pem(&bytes)
+            .or_else(|_| reqwest::Certificate::from_der(&bytes))
+            .map(Some)
+            .map_err(|error| {
+                CliError::Config(format!(
+                    "invalid CA certificate '{}': {}",
+                    path.display(),
+                    error
+                ))
+            })
+    }
+}
+
+/// Connection pool settings for a provider's HTTP client.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct HttpPoolConfig {
+    /// Idle connections kept open per host (reqwest default: unlimited).
+    #[serde(default)]
+    pub pool_max_idle_per_host: Option<usize>,
+    /// Seconds an idle connection stays open (reqwest default: 90).
+    #[serde(default)]
+    pub pool_idle_timeout_secs: Option<u64>,
+}
+
+/// Retries for requests the provider rejects with HTTP 429. The wait is the
+/// response's `Retry-After` when present, otherwise an exponential backoff
+/// starting at one second.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RateLimitRetry {
+    /// Retries after the first attempt; `0` fails on the first 429.
+    #[serde(default = "default_rate_limit_retries")]
+    pub max_retries: u32,
+    /// Longest single wait in seconds. A `Retry-After` above it fails at
+    /// once instead of blocking the request; backoff is capped to it.
+    #[serde(default = "default_rate_limit_max_wait_secs")]
+    pub max_wait_secs: u64,
+}
+
+fn default_rate_limit_retries() -> u32 {
+    3
+}
+
+fn default_rate_limit_max_wait_secs() -> u64 {
+    30
+}
+
+impl Default for RateLimitRetry {
+    fn default() -> Self {
+        Self {
+            max_retries: default_rate_limit_retries(),
+            max_wait_secs: default_rate_limit_max_wait_secs(),
+        }
+    }
+}
+
 /// A single model entry within a [`ModelProviderConfig`].
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ModelInfo {
@@ -84,6 +284,14 @@ impl From<&ProviderConfig> for ModelProviderConfig {
                 Some(pc.api_key.clone())
             },
             api_path: None,
+            headers: HashMap::new(),
+            proxy: None,
+            tls: TlsConfig::default(),
+            pool: HttpPoolConfig::default(),
+            rate_limit: RateLimitRetry::default(),
+            openai_flavor: OpenAiFlavor::default(),
+            api_version: None,
+            default_model: None,
             models: pc.models.iter().map(ModelInfo::from).collect(),
         }
     }
@@ -123,6 +331,269 @@ impl From<ModelInfo> for PostcardModelInfo {
     }
 }
 
+/// Prefix of the `custom` keys in `app.pc` that hold a provider's extra
+/// headers as a JSON object (e.g. `provider_headers.gateway`).
+/// [`ProviderConfig`] is positional, so the headers cannot live there.
+pub const PROVIDER_HEADERS_KEY_PREFIX: &str = "provider_headers.";
+
+/// Fill each provider's `headers` from the `custom` map of `app.pc`.
+/// Entries that are not a JSON object of strings are ignored.
+pub fn apply_provider_headers(
+    providers: &mut [ModelProviderConfig],
+    custom: &HashMap<String, String>,
+) {
+    for provider in providers.iter_mut() {
+        let key = format!("{PROVIDER_HEADERS_KEY_PREFIX}{}", provider.id);
+        if let Some(headers) = custom
+            .get(&key)
+            .and_then(|raw| serde_json::from_str::<HashMap<String, String>>(raw).ok())
+        {
+            provider.headers = headers;
+        }
+    }
+}
+
+/// Store every provider's non-empty `headers` in the `custom` map of `app.pc`.
+pub fn write_provider_headers(
+    providers: &[ModelProviderConfig],
+    custom: &mut HashMap<String, String>,
+) {
+    for provider in providers.iter().filter(|p| !p.headers.is_empty()) {
+        if let Ok(raw) = serde_json::to_string(&provider.headers) {
+            custom.insert(format!("{PROVIDER_HEADERS_KEY_PREFIX}{}", provider.id), raw);
+        }
+    }
+}
+
+/// `custom` key in `app.pc` holding the proxy URL used by every provider.
+pub const PROXY_URL_KEY: &str = "proxy.url";
+/// `custom` key holding the comma-separated hosts that bypass the proxy.
+pub const PROXY_NO_PROXY_KEY: &str = "proxy.no_proxy";
+/// Prefix of the `custom` keys holding a per-provider proxy URL
+/// (e.g. `provider_proxy.openai`), which overrides `proxy.url`.
+pub const PROVIDER_PROXY_KEY_PREFIX: &str = "provider_proxy.";
+
+/// Set each provider's `proxy` from the `custom` map of `app.pc`.
+///
+/// A per-provider URL wins over the global one, and both share the
+/// `proxy.no_proxy` list. Invalid URLs fail here, at load time, instead of
+/// on the first model request.
+pub fn apply_proxy_settings(
+    providers: &mut [ModelProviderConfig],
+    custom: &HashMap<String, String>,
+) -> CliResult<()> {
+    let no_proxy = custom.get(PROXY_NO_PROXY_KEY).map(|raw| {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|host| !host.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    });
+    let build = |url: &str, provider: &str| {
+        let proxy = ProxyConfig::new(url)
+            .map_err(|error| CliError::Config(format!("provider '{provider}': {error}")))?;
+        Ok::<_, CliError>(match &no_proxy {
+            Some(hosts) => proxy.with_no_proxy(hosts.clone()),
+            None => proxy,
+        })
+    };
+
+    let global = custom
+        .get(PROXY_URL_KEY)
+        .map(|url| url.trim())
+        .filter(|url| !url.is_empty());
+    for provider in providers.iter_mut() {
+        let key = format!("{PROVIDER_PROXY_KEY_PREFIX}{}", provider.id);
+        let url = custom
+            .get(&key)
+            .map(|url| url.trim())
+            .filter(|url| !url.is_empty())
+            .or(global);
+        if let Some(url) = url {
+            provider.proxy = Some(build(url, &provider.id)?);
+        }
+    }
+    Ok(())
+}
+
+/// Prefix of the `custom` keys in `app.pc` holding a provider's
+/// [`TlsConfig`] as JSON (e.g. `provider_tls.gateway`).
+pub const PROVIDER_TLS_KEY_PREFIX: &str = "provider_tls.";
+
+/// Set each provider's `tls` from the `custom` map of `app.pc`.
+///
+/// Malformed entries and unreadable or invalid CA certificates fail here,
+/// at load time, with an error naming the provider.
+pub fn apply_tls_settings(
+    providers: &mut [ModelProviderConfig],
+    custom: &HashMap<String, String>,
+) -> CliResult<()> {
+    for provider in providers.iter_mut() {
+        let key = format!("{PROVIDER_TLS_KEY_PREFIX}{}", provider.id);
+        let Some(raw) = custom.get(&key) else {
+            continue;
+        };
+        let tls: TlsConfig = serde_json::from_str(raw).map_err(|error| {
+            CliError::Config(format!(
+                "provider '{}': invalid {key}: {error}",
+                provider.id
+            ))
+        })?;
+        tls.load_ca_cert()
+            .map_err(|error| CliError::Config(format!("provider '{}': {error}", provider.id)))?;
+        provider.tls = tls;
+    }
+    Ok(())
+}
+
+/// `custom` key in `app.pc` for the idle connections kept per host.
+pub const POOL_MAX_IDLE_PER_HOST_KEY: &str = "http.pool_max_idle_per_host";
+/// `custom` key in `app.pc` for the idle connection timeout in seconds.
+pub const POOL_IDLE_TIMEOUT_SECS_KEY: &str = "http.pool_idle_timeout_secs";
+
+/// Set every provider's `pool` from the `custom` map of `app.pc`.
+/// A value that is not a non-negative integer is a configuration error.
+pub fn apply_http_pool_settings(
+    providers: &mut [ModelProviderConfig],
+    custom: &HashMap<String, String>,
+) -> CliResult<()> {
+    fn parse<T: std::str::FromStr>(
+        custom: &HashMap<String, String>,
+        key: &str,
+    ) -> CliResult<Option<T>> {
+        custom
+            .get(key)
+            .map(|raw| {
+                raw.trim().parse().map_err(|_| {
+                    CliError::Config(format!(
+                        "invalid {key} '{raw}': expected a non-negative integer"
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    let pool = HttpPoolConfig {
+        pool_max_idle_per_host: parse(custom, POOL_MAX_IDLE_PER_HOST_KEY)?,
+        pool_idle_timeout_secs: parse(custom, POOL_IDLE_TIMEOUT_SECS_KEY)?,
+    };
+    for provider in providers.iter_mut() {
+        provider.pool = pool.clone();
+    }
+    Ok(())
+}
+
+/// `custom` key in `app.pc` for the retries after an HTTP 429.
+pub const RATE_LIMIT_MAX_RETRIES_KEY: &str = "http.rate_limit_max_retries";
+/// `custom` key in `app.pc` for the longest wait, in seconds, before a retry.
+pub const RATE_LIMIT_MAX_WAIT_SECS_KEY: &str = "http.rate_limit_max_wait_secs";
+
+/// Set every provider's `rate_limit` from the `custom` map of `app.pc`.
+/// A value that is not a non-negative integer is a configuration error.
+pub fn apply_rate_limit_settings(
+    providers: &mut [ModelProviderConfig],
+    custom: &HashMap<String, String>,
+) -> CliResult<()> {
+    fn parse<T: std::str::FromStr>(
+        custom: &HashMap<String, String>,
+        key: &str,
+        default: T,
+    ) -> CliResult<T> {
+        match custom.get(key) {
+            Some(raw) => raw.trim().parse().map_err(|_| {
+                CliError::Config(format!(
+                    "invalid {key} '{raw}': expected a non-negative integer"
+                ))
+            }),
+            None => Ok(default),
+        }
+    }
+
+    let defaults = RateLimitRetry::default();
+    let rate_limit = RateLimitRetry {
+        max_retries: parse(custom, RATE_LIMIT_MAX_RETRIES_KEY, defaults.max_retries)?,
+        max_wait_secs: parse(custom, RATE_LIMIT_MAX_WAIT_SECS_KEY, defaults.max_wait_secs)?,
+    };
+    for provider in providers.iter_mut() {
+        provider.rate_limit = rate_limit.clone();
+    }
+    Ok(())
+}
+
+/// Prefix of the `custom` keys in `app.pc` holding a provider's
+/// [`OpenAiFlavor`] (e.g. `provider_openai_flavor.azure-prod`).
+pub const PROVIDER_OPENAI_FLAVOR_KEY_PREFIX: &str = "provider_openai_flavor.";
+/// Prefix of the `custom` keys in `app.pc` holding a provider's Azure
+/// `api-version` (e.g. `provider_api_version.azure-prod`).
+pub const PROVIDER_API_VERSION_KEY_PREFIX: &str = "provider_api_version.";
+
+/// Set each provider's `openai_flavor` and `api_version` from the `custom`
+/// map of `app.pc`. An unknown flavor is a configuration error naming the
+/// provider.
+pub fn apply_openai_flavor_settings(
+    providers: &mut [ModelProviderConfig],
+    custom: &HashMap<String, String>,
+) -> CliResult<()> {
+    for provider in providers.iter_mut() {
+        let key = format!("{PROVIDER_OPENAI_FLAVOR_KEY_PREFIX}{}", provider.id);
+        if let Some(raw) = custom.get(&key) {
+            provider.openai_flavor = OpenAiFlavor::parse(raw).ok_or_else(|| {
+                CliError::Config(format!(
+                    "provider '{}': invalid {key} '{raw}': expected openai or azure",
+                    provider.id
+                ))
+            })?;
+        }
+        let key = format!("{PROVIDER_API_VERSION_KEY_PREFIX}{}", provider.id);
+        if let Some(version) = custom
+            .get(&key)
+            .map(|raw| raw.trim())
+            .filter(|raw| !raw.is_empty())
+        {
+            provider.api_version = Some(version.to_string());
+        }
+    }
+    Ok(())
+}
+
+/// Prefix of the `custom` keys in `app.pc` holding a provider's default
+/// model (e.g. `provider_default_model.ollama`).
+pub const PROVIDER_DEFAULT_MODEL_KEY_PREFIX: &str = "provider_default_model.";
+
+/// Set each provider's `default_model` from the `custom` map of `app.pc`.
+pub fn apply_default_model_settings(
+    providers: &mut [ModelProviderConfig],
+    custom: &HashMap<String, String>,
+) {
+    for provider in providers.iter_mut() {
+        let key = format!("{PROVIDER_DEFAULT_MODEL_KEY_PREFIX}{}", provider.id);
+        if let Some(model) = custom
+            .get(&key)
+            .map(|raw| raw.trim())
+            .filter(|raw| !raw.is_empty())
+        {
+            provider.default_model = Some(model.to_string());
+        }
+    }
+}
+
+/// Apply every provider setting stored in the `custom` map of `app.pc`:
+/// headers, proxy, TLS, connection pool, rate-limit retries, the OpenAI
+/// flavor, and the default model.
+pub fn apply_provider_settings(
+    providers: &mut [ModelProviderConfig],
+    custom: &HashMap<String, String>,
+) -> CliResult<()> {
+    apply_provider_headers(providers, custom);
+    apply_proxy_settings(providers, custom)?;
+    apply_tls_settings(providers, custom)?;
+    apply_http_pool_settings(providers, custom)?;
+    apply_rate_limit_settings(providers, custom)?;
+    apply_openai_flavor_settings(providers, custom)?;
+    apply_default_model_settings(providers, custom);
+    Ok(())
+}
+
 /// Convert a slice of postcard [`ProviderConfig`]s to runtime
 /// [`ModelProviderConfig`]s.
 pub fn providers_from_postcard(configs: &[ProviderConfig]) -> Vec<ModelProviderConfig> {
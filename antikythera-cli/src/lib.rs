@@ -42,4 +42,4 @@ pub mod runtime;
 pub mod cli;
 
 // Re-exports for convenience
-pub use error::{CliError, CliResult};
+pub use error::{AppError, CliError, CliResult};
@@ -0,0 +1,59 @@
+//! Plain-text output of `--list-providers`, `--list-models`, and
+//! `--check-servers`.
+//!
+//! One line per entry with tab-separated columns, so the output can be piped
+//! into `cut` or `awk` without parsing the config file.
+
+use crate::infrastructure::llm::ModelProviderConfig;
+use crate::runtime::ServerCheck;
+
+/// `id<TAB>type<TAB>endpoint` for each provider, in config order.
+pub fn provider_lines(providers: &[ModelProviderConfig]) -> Vec<String> {
+    providers
+        .iter()
+        .map(|provider| {
+            format!(
+                "{}\t{}\t{}",
+                provider.id, provider.provider_type, provider.endpoint
+            )
+        })
+        .collect()
+}
+
+/// `provider<TAB>model` for each model, prefixed with `* ` for the default
+/// provider/model pair and two spaces otherwise.
+pub fn model_lines(
+    providers: &[ModelProviderConfig],
+    default_provider: &str,
+    default_model: &str,
+) -> Vec<String> {
+    providers
+        .iter()
+        .flat_map(|provider| {
+            provider.models.iter().map(move |model| {
+                let marker = if provider.id == default_provider && model.name == default_model {
+                    '*'
+                } else {
+                    ' '
+                };
+                format!("{marker} {}\t{}", provider.id, model.name)
+            })
+        })
+        .collect()
+}
+
+/// `name<TAB>yes|no<TAB>tools<TAB>error` for each checked server, with `-`
+/// for a tool count or error that does not apply.
+pub fn server_check_lines(checks: &[ServerCheck]) -> Vec<String> {
+    checks
+        .iter()
+        .map(|check| {
+            let reachable = if check.reachable() { "yes" } else { "no" };
+            let tools = check
+                .tools
+                .map_or_else(|| "-".to_string(), |tools| tools.to_string());
+            let error = check.error.as_deref().unwrap_or("-");
+            format!("{}\t{reachable}\t{tools}\t{error}", check.name)
+        })
+        .collect()
+}
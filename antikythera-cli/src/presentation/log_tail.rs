@@ -0,0 +1,113 @@
+//! `--tail-logs`: follow the tool audit log like `tail -f`.
+//!
+//! [`LogFollower`] re-opens the file on every poll and reads from where it
+//! stopped, so a log that is truncated or rotated (replaced by a shorter
+//! file) is read again from the start instead of going silent. Only entries
+//! appended after the follower starts are shown.
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use antikythera_log::cli_print;
+use serde_json::Value;
+
+/// How often the file is checked for new lines.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Reads the lines appended to a file since the last poll.
+pub struct LogFollower {
+    path: PathBuf,
+    offset: u64,
+    partial: String,
+}
+
+impl LogFollower {
+    /// Follow `path` from its current end. A missing file is followed from
+    /// the start once it appears.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let offset = std::fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+        Self {
+            path,
+            offset,
+            partial: String::new(),
+        }
+    }
+
+    /// Complete lines appended since the last call. A trailing line without
+    /// its newline is kept until the rest is written.
+    pub fn poll(&mut self) -> io::Result<Vec<String>> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // Truncated or rotated: start over on the new content.
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut bytes = Vec::new();
+        file.take(len - self.offset).read_to_end(&mut bytes)?;
+        self.offset += bytes.len() as u64;
+        self.partial.push_str(&String::from_utf8_lossy(&bytes));
+
+        let Some(end) = self.partial.rfind('\n') else {
+            return Ok(Vec::new());
+        };
+        let rest = self.partial.split_off(end + 1);
+        let complete = std::mem::replace(&mut self.partial, rest);
+        Ok(complete
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+/// One audit log line for the terminal:
+/// `timestamp  session  tool  ok|failed  input -> output`. Lines that are not
+/// audit JSON are returned unchanged.
+pub fn render_entry(line: &str) -> String {
+    let Ok(Value::Object(entry)) = serde_json::from_str::<Value>(line) else {
+        return line.to_string();
+    };
+    let text = |key: &str| entry.get(key).and_then(Value::as_str).unwrap_or("-");
+    let status = match entry.get("success").and_then(Value::as_bool) {
+        Some(true) => "ok",
+        Some(false) => "failed",
+        None => "-",
+    };
+    let input = entry
+        .get("input")
+        .map(Value::to_string)
+        .unwrap_or_else(|| "{}".to_string());
+    format!(
+        "{}  {}  {}  {}  {} -> {}",
+        text("timestamp"),
+        text("session_id"),
+        text("tool"),
+        status,
+        input,
+        text("output")
+    )
+}
+
+/// Print every entry appended to `path` until the process is stopped.
+pub async fn tail_log(path: &Path) -> io::Result<()> {
+    cli_print!("Mengikuti {} (Ctrl+C untuk berhenti)", path.display());
+    let mut follower = LogFollower::new(path);
+    loop {
+        for line in follower.poll()? {
+            cli_print!("{}", render_entry(&line));
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -3,5 +3,10 @@
 //! Contains the full-screen ratatui TUI (`tui`) that forms the
 //! interactive front-end for the Chat feature slice.
 //! The Settings Panel (F2) covers all WASM-accessible config fields.
+//! `listing` formats the `--list-providers` / `--list-models` /
+//! `--check-servers` output.
+//! `log_tail` follows the tool audit log for `--tail-logs`.
 
+pub mod listing;
+pub mod log_tail;
 pub mod tui;
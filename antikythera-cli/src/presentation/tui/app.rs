@@ -11,7 +11,8 @@ use crate::infrastructure::history::{ChatHistorySession, ChatHistoryStore};
 use crate::infrastructure::llm::ModelProviderConfig;
 
 use super::types::{
-    HistoryBrowser, PendingResponse, SettingsPanel, UiMessage, UiTone, slash_command_suggestions,
+    HistoryBrowser, ModelPicker, PendingResponse, SettingsPanel, TuiOptions, TurnLogViewer,
+    UiMessage, UiTone, slash_command_suggestions,
 };
 
 pub(crate) struct ChatApp {
@@ -19,9 +20,15 @@ pub(crate) struct ChatApp {
     pub(super) provider: String,
     pub(super) model: String,
     pub(super) session_id: Option<String>,
+    /// Provider and model reported by the latest chat reply.
+    pub(super) answered_by: Option<(String, String)>,
     pub(super) input: String,
     pub(super) settings: SettingsPanel,
     pub(super) agent_mode: bool,
+    /// Render assistant replies as Markdown (`/markdown` toggles it).
+    pub(super) render_markdown: bool,
+    /// Show the input and output of each agent tool step (`/steps` toggles it).
+    pub(super) expand_tool_steps: bool,
     pub(super) status: String,
     pub(super) tools: usize,
     pub(super) providers: Vec<ModelProviderConfig>,
@@ -31,6 +38,10 @@ pub(crate) struct ChatApp {
     pub(super) log_lines: Vec<String>,
     pub(super) loading: bool,
     pub(super) should_quit: bool,
+    /// Ask before quitting when work would be lost (`tui.confirm_exit`).
+    pub(super) confirm_exit: bool,
+    /// Quit confirmation popup is showing.
+    pub(super) quit_prompt: bool,
     /// In-flight request receiver. Set when a chat/agent task has been spawned;
     /// cleared when the result arrives or the channel is closed.
     pub(super) pending_rx: Option<oneshot::Receiver<PendingResponse>>,
@@ -39,13 +50,21 @@ pub(crate) struct ChatApp {
     pub(super) history_store: ChatHistoryStore,
     /// The history session that is currently being built (in-flight).
     pub(super) current_history_session: Option<ChatHistorySession>,
+    /// The history session has turns that are not on disk yet.
+    pub(super) history_unsaved: bool,
     /// Overlay for browsing and managing saved debug sessions.
     pub(super) history: HistoryBrowser,
+    /// Popup with the interaction logs of the last turn (Ctrl+L).
+    pub(super) turn_logs: TurnLogViewer,
+    /// Popup for switching the model of the active provider (F4).
+    pub(super) model_picker: ModelPicker,
     // ── Live streaming ───────────────────────────────────────────────────────
     /// Tokens received so far from the in-flight streaming request.
     pub(super) streaming_content: String,
     /// Channel receiver for streaming token chunks from the background task.
     pub(super) stream_rx: Option<mpsc::UnboundedReceiver<String>>,
+    /// Status lines for the progress of the running agent's tool calls.
+    pub(super) progress_rx: Option<mpsc::UnboundedReceiver<String>>,
     // ── Provider health ──────────────────────────────────────────────────────
     /// Aggregated health metrics for the active LLM provider.
     pub(super) health: Arc<Mutex<HealthTracker>>,
@@ -67,15 +86,20 @@ impl ChatApp {
         snapshot: ClientConfigSnapshot,
         tools: usize,
         builtin_transports: HashMap<String, Arc<BuiltinTransport>>,
+        options: TuiOptions,
     ) -> Self {
         let mut app = Self {
             provider: runtime_config.default_provider.clone(),
             model: runtime_config.model.clone(),
             session_id: None,
+            answered_by: None,
             input: String::new(),
             settings: SettingsPanel::new(),
-            agent_mode: true,
-            status: "Siap. Ketik pesan atau /help. F2 = Settings | F3 = Riwayat.".to_string(),
+            agent_mode: options.agent_mode,
+            render_markdown: options.render_markdown,
+            expand_tool_steps: false,
+            status: "Siap. Ketik pesan atau /help. F2 = Settings | F3 = Riwayat | F4 = Model | Ctrl+L = Log."
+                .to_string(),
             tools,
             providers,
             runtime_config,
@@ -84,25 +108,66 @@ impl ChatApp {
             log_lines: Vec::new(),
             loading: false,
             should_quit: false,
+            confirm_exit: options.confirm_exit,
+            quit_prompt: false,
             pending_rx: None,
             history_store: ChatHistoryStore::new(),
             current_history_session: None,
+            history_unsaved: false,
             history: HistoryBrowser::new(),
+            turn_logs: TurnLogViewer::new(),
+            model_picker: ModelPicker::new(),
             streaming_content: String::new(),
             stream_rx: None,
+            progress_rx: None,
             health: Arc::new(Mutex::new(HealthTracker::new())),
             conversation_scroll: 0,
             log_scroll: 0,
             builtin_transports,
         };
+        let mode_hint = if app.agent_mode {
+            "Mode agent aktif; /agent off untuk chat langsung."
+        } else {
+            "Mode chat langsung aktif; /agent on bila tugas butuh tools."
+        };
         app.messages.push(UiMessage::new(
             "Welcome",
-            "Interactive mode siap. Gunakan /use <provider> [model] atau /model <nama-model> untuk mengganti backend langsung dari TUI.",
+            format!(
+                "Interactive mode siap. {mode_hint} Gunakan /use <provider> [model] atau /model <nama-model> untuk mengganti backend langsung dari TUI."
+            ),
             UiTone::System,
         ));
         app
     }
 
+    /// Model names configured for the active provider.
+    pub(super) fn provider_models(&self) -> Vec<String> {
+        self.providers
+            .iter()
+            .find(|provider| provider.id == self.provider)
+            .map(|provider| provider.models.iter().map(|m| m.name.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Whether quitting now would lose work: a reply is still pending, or the
+    /// history session has turns that were never saved.
+    pub(super) fn has_unsaved_work(&self) -> bool {
+        self.pending_rx.is_some()
+            || (self.history_unsaved && self.current_history_session.is_some())
+    }
+
+    /// Quit, or open the confirmation popup first when work would be lost.
+    pub(super) fn request_quit(&mut self) {
+        if self.confirm_exit && self.has_unsaved_work() {
+            self.quit_prompt = true;
+            self.status =
+                "Keluar? s=simpan & keluar | y=keluar tanpa simpan | n/Esc=batal".to_string();
+        } else {
+            self.status = "Menutup TUI...".to_string();
+            self.should_quit = true;
+        }
+    }
+
     pub(super) fn push_message(&mut self, message: UiMessage) {
         self.messages.push(message);
         if self.messages.len() > 64 {
@@ -111,12 +176,28 @@ impl ChatApp {
         }
     }
 
+    /// Start on the Provider settings tab with setup guidance, for a launch
+    /// with no model providers configured.
+    pub(super) fn open_provider_setup(&mut self) {
+        let config = self.runtime_config.clone();
+        self.settings
+            .open_with("", "", &config, &self.providers, self.agent_mode);
+        self.status =
+            "Belum ada provider. Esc=tutup settings, lalu /use <provider> <model>.".to_string();
+        self.push_message(UiMessage::new(
+            "Setup Provider",
+            "Belum ada penyedia model yang dikonfigurasi. Pilih backend dengan /use <provider> <model> (misalnya /use ollama llama3.2, /use gemini gemini-2.0-flash) atau jalankan antikythera-config add-provider lalu mulai ulang.",
+            UiTone::System,
+        ));
+    }
+
     pub(super) fn suggestions(&self) -> Vec<(&'static str, &'static str)> {
         slash_command_suggestions(&self.input)
     }
 
     pub(super) fn reset_session(&mut self) {
         self.session_id = None;
+        self.answered_by = None;
         // Finalise the in-flight history session — it was already saved on the
         // last assistant turn, so we just drop the in-memory reference.
         self.current_history_session = None;
@@ -23,7 +23,7 @@ use ratatui::backend::CrosstermBackend;
 use crate::CliResult;
 use crate::infrastructure::llm::ModelProviderConfig;
 use crate::infrastructure::llm::clear_stream_event_sink;
-use crate::runtime::build_runtime_client;
+use crate::runtime::{build_runtime_client, build_setup_client};
 
 use key_dispatch::handle_key_event;
 use result_handler::{apply_agent_outcome, apply_chat_result};
@@ -33,14 +33,17 @@ pub(crate) use key_dispatch::KeyAction;
 pub(crate) use result_handler::scroll_to_bottom;
 
 use super::app::ChatApp;
-use super::handlers::commands::{apply_runtime_selection, reconfigure_runtime};
-use super::handlers::submit::submit_input;
+use super::handlers::commands::{
+    apply_runtime_selection, apply_tool_sync_report, reconfigure_runtime,
+};
+use super::handlers::submit::{regenerate_last, submit_input};
 use super::render::draw;
-use super::types::{PendingResponse, UiMessage, UiTone};
+use super::types::{PendingResponse, TuiOptions, UiMessage, UiTone};
 
 pub async fn run_chat_app(
     mut config: AppConfig,
     providers: Vec<ModelProviderConfig>,
+    options: TuiOptions,
 ) -> CliResult<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -59,13 +62,29 @@ pub async fn run_chat_app(
         providers.len(),
     ));
 
-    let client = build_runtime_client(&config, &providers, builtin_transports.clone())?;
+    // With no providers the TUI opens on the provider setup screen instead of failing.
+    let needs_setup = providers.is_empty();
+    let client = if needs_setup {
+        build_setup_client(&config, builtin_transports.clone())?
+    } else {
+        build_runtime_client(&config, &providers, builtin_transports.clone())?
+    };
     let snapshot = client.config_snapshot();
     let tools = client.tools().len();
-    let mut app = ChatApp::new(config, providers, snapshot, tools, builtin_transports);
+    let mut app = ChatApp::new(
+        config,
+        providers,
+        snapshot,
+        tools,
+        builtin_transports,
+        options,
+    );
     if let Some(msg) = discovery_msg {
         app.push_message(UiMessage::new("Server Discovery", msg, UiTone::System));
     }
+    if needs_setup {
+        app.open_provider_setup();
+    }
     let result = run_loop(&mut terminal, client, app).await;
 
     disable_raw_mode()?;
@@ -86,6 +105,12 @@ async fn run_loop(
                 app.streaming_content.push_str(&chunk);
             }
         }
+        // Show the latest tool progress of a running agent in the status bar.
+        if let Some(rx) = &mut app.progress_rx {
+            while let Ok(label) = rx.try_recv() {
+                app.status = label;
+            }
+        }
 
         // Poll for a completed in-flight request spawned in a previous iteration.
         if let Some(mut rx) = app.pending_rx.take() {
@@ -111,6 +136,7 @@ async fn run_loop(
                     app.streaming_content.clear();
                     app.stream_rx = None;
                     clear_stream_event_sink();
+                    app.progress_rx = None;
                     apply_agent_outcome(&mut app, outcome);
                 }
                 Ok(PendingResponse::Agent(Err(msg))) => {
@@ -118,9 +144,14 @@ async fn run_loop(
                     app.streaming_content.clear();
                     app.stream_rx = None;
                     clear_stream_event_sink();
+                    app.progress_rx = None;
                     app.status = "Agent gagal menyelesaikan permintaan.".to_string();
                     app.push_message(UiMessage::new("Agent Error", msg, UiTone::Error));
                 }
+                Ok(PendingResponse::Sync(report)) => {
+                    app.loading = false;
+                    apply_tool_sync_report(&mut app, &mut client, report);
+                }
                 Err(TryRecvError::Empty) => {
                     // Task still running — put the receiver back.
                     app.pending_rx = Some(rx);
@@ -193,6 +224,9 @@ async fn run_loop(
                 KeyAction::Submit => {
                     submit_input(&mut client, &mut app);
                 }
+                KeyAction::Regenerate => {
+                    regenerate_last(&mut client, &mut app);
+                }
                 KeyAction::ApplySettings => {
                     // Extract pending provider / model from the settings panel.
                     let provider_id = app
@@ -233,7 +267,7 @@ async fn run_loop(
                         }
                     }
                 }
-                KeyAction::Quit => app.should_quit = true,
+                KeyAction::Quit => app.request_quit(),
             }
         }
     }
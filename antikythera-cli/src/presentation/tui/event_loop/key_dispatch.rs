@@ -2,17 +2,26 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use super::super::app::ChatApp;
 use super::super::handlers::history_handler::handle_history_key;
+use super::super::handlers::model_picker_handler::handle_model_picker_key;
+use super::super::handlers::quit_handler::handle_quit_key;
 use super::super::handlers::settings_handler::handle_settings_key;
+use super::super::handlers::turn_log_handler::handle_turn_log_key;
 use super::result_handler::scroll_to_bottom;
 
 pub(crate) enum KeyAction {
     None,
     Submit,
+    Regenerate,
     ApplySettings,
     Quit,
 }
 
 pub(super) fn handle_key_event(key: KeyEvent, app: &mut ChatApp) -> KeyAction {
+    // The quit confirmation takes every key, Ctrl+C included.
+    if app.quit_prompt {
+        return handle_quit_key(key, app);
+    }
+
     if key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Char('c')) {
         return KeyAction::Quit;
     }
@@ -27,6 +36,16 @@ pub(super) fn handle_key_event(key: KeyEvent, app: &mut ChatApp) -> KeyAction {
         return handle_history_key(key, app);
     }
 
+    // Route all input to the turn log viewer when it's open.
+    if app.turn_logs.open {
+        return handle_turn_log_key(key, app);
+    }
+
+    // Route all input to the model picker when it's open.
+    if app.model_picker.open {
+        return handle_model_picker_key(key, app);
+    }
+
     // F2 opens the full settings panel.
     if key.code == KeyCode::F(2) {
         let provider = app.provider.clone();
@@ -51,6 +70,32 @@ pub(super) fn handle_key_event(key: KeyEvent, app: &mut ChatApp) -> KeyAction {
         return KeyAction::None;
     }
 
+    // F4 opens the model picker for the active provider.
+    if key.code == KeyCode::F(4) {
+        let models = app.provider_models();
+        if models.is_empty() {
+            app.status = format!("Provider '{}' tidak punya daftar model.", app.provider);
+            return KeyAction::None;
+        }
+        let active = app.model.clone();
+        app.model_picker.open_with(models, &active);
+        app.status = "Pilih model. ↑↓=navigasi | Enter=pakai | Esc=tutup".to_string();
+        return KeyAction::None;
+    }
+
+    // Ctrl+L opens the log viewer for the last turn.
+    if key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Char('l')) {
+        app.turn_logs.open = true;
+        app.turn_logs.scroll = 0;
+        app.status = "Log giliran terakhir. ↑↓/PgUp/PgDn=gulir | Esc/Ctrl+L=tutup".to_string();
+        return KeyAction::None;
+    }
+
+    // Ctrl+R asks again for the last answer.
+    if key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Char('r')) {
+        return KeyAction::Regenerate;
+    }
+
     match key.code {
         KeyCode::Esc => KeyAction::Quit,
         KeyCode::Enter => KeyAction::Submit,
@@ -1,12 +1,13 @@
 use chrono::Utc;
 
 use antikythera_core::ProviderLogger;
-use antikythera_core::application::agent::{AgentOutcome, AgentStep};
+use antikythera_core::application::agent::AgentOutcome;
 use antikythera_core::application::client::ChatResult;
 
-use crate::infrastructure::history::{ChatTurn, TurnRole};
+use crate::infrastructure::history::{ChatHistorySession, ChatTurn, TurnRole};
 
 use super::super::app::ChatApp;
+use super::super::render::tool_steps::format_tool_steps;
 use super::super::types::{UiMessage, UiTone};
 
 pub(super) fn apply_chat_result(app: &mut ChatApp, result: ChatResult) {
@@ -19,6 +20,8 @@ pub(super) fn apply_chat_result(app: &mut ChatApp, result: ChatResult) {
     ));
     app.session_id = Some(result.session_id.clone());
     antikythera_core::set_active_session(&result.session_id);
+    app.turn_logs.record(result.logs.clone());
+    app.answered_by = Some((result.provider.clone(), result.model.clone()));
     app.status = format!(
         "Respons diterima dari {}/{}.",
         result.provider, result.model
@@ -33,18 +36,14 @@ pub(super) fn apply_chat_result(app: &mut ChatApp, result: ChatResult) {
     if let Some(session) = &mut app.current_history_session {
         session.core_session_id = Some(result.session_id.clone());
         session.updated_at = Utc::now().to_rfc3339();
-        if session.title.is_empty()
-            && let Some(first) = session.turns.iter().find(|t| t.role == TurnRole::User)
-        {
-            session.title = first.content.chars().take(60).collect();
-        }
+        adopt_title(session, result.title.clone());
         session.turns.push(ChatTurn {
             timestamp: Utc::now().to_rfc3339(),
             role: TurnRole::Assistant,
             content: result.content.clone(),
             tool_steps: 0,
         });
-        let _ = app.history_store.save_session(session);
+        app.history_unsaved = app.history_store.save_session(session).is_err();
     }
 }
 
@@ -57,7 +56,30 @@ pub(super) fn apply_agent_outcome(app: &mut ChatApp, outcome: AgentOutcome) {
     ));
     app.session_id = Some(outcome.session_id.clone());
     antikythera_core::set_active_session(&outcome.session_id);
-    app.status = format!("Agent selesai dengan {} langkah tool.", outcome.steps.len());
+    app.turn_logs.record(outcome.logs.clone());
+    // Agent outcomes do not report their backend; the run used the selection
+    // sent with it, so drop any backend left over from an earlier chat reply.
+    app.answered_by = None;
+    app.status = if outcome.steps.is_empty() || app.expand_tool_steps {
+        format!("Agent selesai dengan {} langkah tool.", outcome.steps.len())
+    } else {
+        format!(
+            "Agent selesai dengan {} langkah tool. /steps untuk detail.",
+            outcome.steps.len()
+        )
+    };
+    // Tool steps sit between the user's message and the answer.
+    if !outcome.steps.is_empty() {
+        app.push_message(
+            UiMessage::new(
+                "Tools",
+                format_tool_steps(&outcome.steps, false),
+                UiTone::Tool,
+            )
+            .with_detail(format_tool_steps(&outcome.steps, true)),
+        );
+    }
+
     let response_text = format_agent_response(&outcome.response);
     app.push_message(UiMessage::new(
         "Agent",
@@ -67,31 +89,32 @@ pub(super) fn apply_agent_outcome(app: &mut ChatApp, outcome: AgentOutcome) {
     // Scroll conversation to show the response.
     app.conversation_scroll = scroll_to_bottom(&app.messages, app.conversation_scroll);
 
-    if !outcome.steps.is_empty() {
-        app.push_message(UiMessage::new(
-            "Tool Trace",
-            render_steps_summary(&outcome.steps),
-            UiTone::System,
-        ));
-    }
-
     // Append assistant turn and persist the debug history session.
     let tool_step_count = outcome.steps.len();
     if let Some(session) = &mut app.current_history_session {
         session.core_session_id = Some(outcome.session_id.clone());
         session.updated_at = Utc::now().to_rfc3339();
-        if session.title.is_empty()
-            && let Some(first) = session.turns.iter().find(|t| t.role == TurnRole::User)
-        {
-            session.title = first.content.chars().take(60).collect();
-        }
+        adopt_title(session, outcome.title.clone());
         session.turns.push(ChatTurn {
             timestamp: Utc::now().to_rfc3339(),
             role: TurnRole::Assistant,
             content: response_text,
             tool_steps: tool_step_count,
         });
-        let _ = app.history_store.save_session(session);
+        app.history_unsaved = app.history_store.save_session(session).is_err();
+    }
+}
+
+/// Give an untitled history session the title core derived for it, or the
+/// start of its first user turn when core has none.
+fn adopt_title(session: &mut ChatHistorySession, core_title: Option<String>) {
+    if !session.title.is_empty() {
+        return;
+    }
+    if let Some(title) = core_title {
+        session.title = title;
+    } else if let Some(first) = session.turns.iter().find(|t| t.role == TurnRole::User) {
+        session.title = first.content.chars().take(60).collect();
     }
 }
 
@@ -111,23 +134,3 @@ fn format_agent_response(value: &serde_json::Value) -> String {
         serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
     })
 }
-
-fn render_steps_summary(steps: &[AgentStep]) -> String {
-    steps
-        .iter()
-        .enumerate()
-        .map(|(index, step)| {
-            format!(
-                "{}. {} [{}]{}",
-                index + 1,
-                step.tool,
-                if step.success { "ok" } else { "failed" },
-                step.message
-                    .as_deref()
-                    .map(|message| format!(" - {}", message))
-                    .unwrap_or_default()
-            )
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
-}
@@ -12,22 +12,15 @@ pub(super) async fn bootstrap_servers_and_transports(
     // ── Server auto-discovery ─────────────────────────────────────────────────
     let discovery_msg = {
         use antikythera_core::application::discovery::startup::run_startup_discovery;
-        use antikythera_core::config::server::{ServerConfig as CoreServerConfig, TransportType};
+        use antikythera_core::config::server::ServerConfig as CoreServerConfig;
 
         let result = run_startup_discovery(None).await;
         let mut added = 0usize;
         for server in result.loaded_servers() {
             let sc = CoreServerConfig {
                 name: server.name.clone(),
-                transport: TransportType::Stdio,
                 command: Some(server.binary_path.clone()),
-                args: Vec::new(),
-                env: HashMap::new(),
-                workdir: None,
-                url: None,
-                headers: HashMap::new(),
-                default_timezone: None,
-                default_city: None,
+                ..Default::default()
             };
             if !config.servers.iter().any(|s| s.name == sc.name) {
                 config.servers.push(sc);
@@ -57,14 +50,7 @@ pub(super) async fn bootstrap_servers_and_transports(
             config.servers.push(CoreServerConfig {
                 name: builtin_server_name.to_string(),
                 transport: TransportType::Builtin,
-                command: None,
-                args: Vec::new(),
-                env: HashMap::new(),
-                workdir: None,
-                url: None,
-                headers: HashMap::new(),
-                default_timezone: None,
-                default_city: None,
+                ..Default::default()
             });
         }
 
@@ -75,6 +61,8 @@ pub(super) async fn bootstrap_servers_and_transports(
                     name: tool_name.to_string(),
                     description: Some(tool_desc.to_string()),
                     server: Some(builtin_server_name.to_string()),
+                    remote_name: None,
+                    cacheable: false,
                 });
             }
         }
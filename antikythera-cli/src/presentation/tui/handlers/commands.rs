@@ -1,19 +1,26 @@
 //! Slash-command processor and runtime reconfiguration.
 
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use antikythera_core::ConfigLogger;
-use antikythera_core::application::client::{ClientConfigSnapshot, McpClient};
+use antikythera_core::application::client::{
+    ClientConfigSnapshot, McpClient, ProviderSummary, RuntimeSummary,
+};
+use antikythera_core::application::discovery::sync_server_tools;
+use antikythera_core::config::ServerConfig;
 use antikythera_core::infrastructure::model::DynamicModelProvider;
 
 use crate::config::{
-    AppConfig as PostcardAppConfig, ModelConfig as PostcardModelConfig, save_app_config,
+    AppConfig as PostcardAppConfig, ModelConfig as PostcardModelConfig, load_app_config,
+    save_app_config,
+};
+use crate::infrastructure::llm::{
+    ModelProviderConfig, providers_to_postcard, write_provider_headers,
 };
-use crate::infrastructure::llm::{ModelProviderConfig, providers_to_postcard};
 use crate::presentation::tui::app::ChatApp;
 use crate::presentation::tui::types::{
-    SLASH_COMMANDS, UiMessage, UiTone, slash_command_suggestions,
+    PendingResponse, RENDER_MARKDOWN_KEY, SLASH_COMMANDS, ToolSyncReport, UiMessage, UiTone,
+    slash_command_suggestions,
 };
 use crate::runtime::{build_runtime_client, materialize_runtime_config};
 
@@ -111,12 +118,21 @@ pub(crate) fn process_command(
                 UiTone::System,
             ));
         }
+        "whoami" => {
+            let summary = app.snapshot.summary(provider_summaries(&app.providers));
+            app.status = "Ringkasan runtime aktif ditampilkan.".to_string();
+            app.push_message(UiMessage::new(
+                "Who Am I",
+                render_runtime_summary(&summary),
+                UiTone::System,
+            ));
+        }
         "tools" => {
-            let body = if client.tools().is_empty() {
+            let tools = client.public_tools();
+            let body = if tools.is_empty() {
                 "Tidak ada tool yang aktif pada sesi ini.".to_string()
             } else {
-                client
-                    .tools()
+                tools
                     .iter()
                     .map(|tool| format!("- {}", tool.name))
                     .collect::<Vec<_>>()
@@ -125,6 +141,40 @@ pub(crate) fn process_command(
             app.status = "Daftar tools aktif ditampilkan.".to_string();
             app.push_message(UiMessage::new("Tools", body, UiTone::System));
         }
+        "servers" | "server" => match args.as_slice() {
+            [] => {
+                app.status = "Daftar server MCP ditampilkan.".to_string();
+                app.push_message(UiMessage::new(
+                    "Servers",
+                    render_server_list(&app.runtime_config.servers),
+                    UiTone::System,
+                ));
+            }
+            [name, state] => toggle_server(app, client, name, state),
+            _ => app.push_message(UiMessage::new(
+                "Command Error",
+                "Gunakan /servers atau /servers <nama> on|off|toggle.",
+                UiTone::Error,
+            )),
+        },
+        "sync" => {
+            let prune = match args.first().copied() {
+                None => false,
+                Some("prune") => true,
+                Some(other) => {
+                    app.push_message(UiMessage::new(
+                        "Command Error",
+                        format!(
+                            "Argumen /sync '{}' tidak dikenal. Gunakan /sync [prune].",
+                            other
+                        ),
+                        UiTone::Error,
+                    ));
+                    return;
+                }
+            };
+            start_tool_sync(app, prune);
+        }
         "agent" => {
             let next_mode = match args.first().copied() {
                 Some("on") => true,
@@ -158,6 +208,54 @@ pub(crate) fn process_command(
                 UiTone::System,
             ));
         }
+        "markdown" | "md" => {
+            let enabled = match args.first().copied() {
+                Some("on") => true,
+                Some("off") => false,
+                Some("toggle") | None => !app.render_markdown,
+                Some(other) => {
+                    app.push_message(UiMessage::new(
+                        "Command Error",
+                        format!(
+                            "Argumen /markdown '{}' tidak dikenal. Gunakan on, off, atau toggle.",
+                            other
+                        ),
+                        UiTone::Error,
+                    ));
+                    return;
+                }
+            };
+            app.render_markdown = enabled;
+            app.status = if enabled {
+                "Render Markdown aktif.".to_string()
+            } else {
+                "Render Markdown nonaktif, jawaban ditampilkan mentah.".to_string()
+            };
+        }
+        "steps" => {
+            let expanded = match args.first().copied() {
+                Some("on") => true,
+                Some("off") => false,
+                Some("toggle") | None => !app.expand_tool_steps,
+                Some(other) => {
+                    app.push_message(UiMessage::new(
+                        "Command Error",
+                        format!(
+                            "Argumen /steps '{}' tidak dikenal. Gunakan on, off, atau toggle.",
+                            other
+                        ),
+                        UiTone::Error,
+                    ));
+                    return;
+                }
+            };
+            app.expand_tool_steps = expanded;
+            app.status = if expanded {
+                "Detail langkah tool ditampilkan.".to_string()
+            } else {
+                "Detail langkah tool disembunyikan.".to_string()
+            };
+        }
         "reset" | "clear" => app.reset_session(),
         "history" => {
             let sessions = app.history_store.list_sessions();
@@ -166,10 +264,7 @@ pub(crate) fn process_command(
                 "Riwayat Chat. ↑↓=navigasi | Enter=lihat | d=hapus | r=ganti judul | Esc=tutup"
                     .to_string();
         }
-        "exit" | "quit" => {
-            app.status = "Menutup TUI...".to_string();
-            app.should_quit = true;
-        }
+        "exit" | "quit" => app.request_quit(),
         other => {
             app.status = "Command tidak dikenal.".to_string();
             let suggestion_text = slash_command_suggestions(&format!("/{other}"))
@@ -190,6 +285,147 @@ pub(crate) fn process_command(
     }
 }
 
+/// Enable or disable server `name` for this session and rebuild the client,
+/// so its tools join or leave the agent's tool list.
+fn toggle_server(
+    app: &mut ChatApp,
+    client: &mut Arc<McpClient<DynamicModelProvider>>,
+    name: &str,
+    state: &str,
+) {
+    let Some(index) = app
+        .runtime_config
+        .servers
+        .iter()
+        .position(|server| server.name.eq_ignore_ascii_case(name))
+    else {
+        app.push_message(UiMessage::new(
+            "Command Error",
+            format!("Server '{name}' tidak ditemukan. Gunakan /servers untuk daftar server."),
+            UiTone::Error,
+        ));
+        return;
+    };
+    let enabled = match state {
+        "on" => true,
+        "off" => false,
+        "toggle" => !app.runtime_config.servers[index].enabled,
+        other => {
+            app.push_message(UiMessage::new(
+                "Command Error",
+                format!("Argumen /servers '{other}' tidak dikenal. Gunakan on, off, atau toggle."),
+                UiTone::Error,
+            ));
+            return;
+        }
+    };
+
+    app.runtime_config.servers[index].enabled = enabled;
+    if let Err(error) = reconfigure_runtime(app, client) {
+        app.runtime_config.servers[index].enabled = !enabled;
+        app.status = "Gagal menerapkan perubahan server.".to_string();
+        app.push_message(UiMessage::new("Command Error", error, UiTone::Error));
+        return;
+    }
+    let server = &app.runtime_config.servers[index].name;
+    app.status = if enabled {
+        format!("Server '{server}' diaktifkan.")
+    } else {
+        format!("Server '{server}' dinonaktifkan; tools-nya tidak dipakai agent.")
+    };
+}
+
+/// List tools from every enabled non-builtin server in the background and reconcile
+/// the runtime tool list; the result arrives as [`PendingResponse::Sync`].
+fn start_tool_sync(app: &mut ChatApp, prune: bool) {
+    let servers: Vec<_> = app
+        .runtime_config
+        .servers
+        .iter()
+        .filter(|server| server.enabled && !server.is_builtin())
+        .cloned()
+        .collect();
+    if servers.is_empty() {
+        app.status = "Tidak ada server MCP untuk disinkronkan.".to_string();
+        return;
+    }
+
+    let mut tools = app.runtime_config.tools.clone();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.pending_rx = Some(rx);
+    app.loading = true;
+    app.status = format!("Menyinkronkan tools dari {} server...", servers.len());
+
+    tokio::spawn(async move {
+        let mut diffs = Vec::new();
+        let mut failures = Vec::new();
+        for server in &servers {
+            match sync_server_tools(server, &mut tools, prune).await {
+                Ok(diff) => diffs.push(diff),
+                Err(error) => failures.push((server.name.clone(), error.to_string())),
+            }
+        }
+        let _ = tx.send(PendingResponse::Sync(ToolSyncReport {
+            diffs,
+            failures,
+            tools,
+            pruned: prune,
+        }));
+    });
+}
+
+/// Apply a finished `/sync`: adopt the reconciled tool list, rebuild the
+/// client when anything changed, and show the per-server diff.
+pub(crate) fn apply_tool_sync_report(
+    app: &mut ChatApp,
+    client: &mut Arc<McpClient<DynamicModelProvider>>,
+    report: ToolSyncReport,
+) {
+    let body = render_tool_sync_report(&report);
+    let changed = app.runtime_config.tools != report.tools;
+    if changed {
+        app.runtime_config.tools = report.tools;
+        if let Err(error) = reconfigure_runtime(app, client) {
+            app.status = "Gagal menerapkan hasil sinkronisasi tools.".to_string();
+            app.push_message(UiMessage::new("Command Error", error, UiTone::Error));
+            return;
+        }
+    }
+    app.status = if report.failures.is_empty() {
+        format!(
+            "Sinkronisasi tools selesai untuk {} server.",
+            report.diffs.len()
+        )
+    } else {
+        format!(
+            "Sinkronisasi tools selesai, {} server gagal dibaca.",
+            report.failures.len()
+        )
+    };
+    app.push_message(UiMessage::new("Tool Sync", body, UiTone::System));
+}
+
+pub(crate) fn render_tool_sync_report(report: &ToolSyncReport) -> String {
+    let mut sections: Vec<String> = report.diffs.iter().map(|diff| diff.render()).collect();
+    sections.extend(
+        report
+            .failures
+            .iter()
+            .map(|(server, error)| format!("{server}: gagal membaca tools/list ({error})")),
+    );
+    let stale: usize = report.diffs.iter().map(|diff| diff.removed.len()).sum();
+    if stale > 0 {
+        sections.push(if report.pruned {
+            format!("{stale} tool yang sudah tidak ada di server dihapus dari konfigurasi.")
+        } else {
+            format!(
+                "{stale} tool sudah tidak ada di server. Jalankan /sync prune untuk menghapusnya dari konfigurasi."
+            )
+        });
+    }
+    sections.join("\n")
+}
+
 pub fn render_provider_catalog(
     providers: &[ModelProviderConfig],
     active_provider: &str,
@@ -239,6 +475,53 @@ pub fn render_config_snapshot(snapshot: &ClientConfigSnapshot) -> String {
     .join("\n")
 }
 
+/// One line per configured server, marking the disabled ones.
+pub fn render_server_list(servers: &[ServerConfig]) -> String {
+    if servers.is_empty() {
+        return "Tidak ada server MCP yang dikonfigurasi.".to_string();
+    }
+    servers
+        .iter()
+        .map(|server| {
+            let state = if server.enabled { "aktif" } else { "nonaktif" };
+            format!("- {} [{}]", server.name, state)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn provider_summaries(providers: &[ModelProviderConfig]) -> Vec<ProviderSummary> {
+    providers
+        .iter()
+        .map(|provider| ProviderSummary {
+            id: provider.id.clone(),
+            provider_type: provider.provider_type.clone(),
+        })
+        .collect()
+}
+
+pub fn render_runtime_summary(summary: &RuntimeSummary) -> String {
+    let providers = if summary.providers.is_empty() {
+        "<none>".to_string()
+    } else {
+        summary
+            .providers
+            .iter()
+            .map(|provider| format!("{} [{}]", provider.id, provider.provider_type))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    [
+        format!("default provider : {}", summary.default_provider),
+        format!("default model    : {}", summary.default_model),
+        format!("providers        : {providers}"),
+        format!("tools            : {}", summary.tool_count),
+        format!("servers          : {}", summary.server_count),
+        format!("mcp protocol     : {}", summary.protocol_version),
+    ]
+    .join("\n")
+}
+
 fn apply_provider_selection(
     app: &mut ChatApp,
     provider_input: &str,
@@ -383,10 +666,18 @@ pub(crate) fn reconfigure_runtime(
         }
     };
     // Persist system_prompt in the extensible custom map (PostcardAppConfig has no dedicated field).
-    let mut custom = HashMap::new();
+    // Start from the saved map so settings the TUI doesn't edit (proxy, locale, ...) survive.
+    let mut custom = load_app_config(None)
+        .map(|saved| saved.custom)
+        .unwrap_or_default();
     if let Some(sp) = &app.runtime_config.system_prompt {
         custom.insert("system_prompt".to_string(), sp.clone());
     }
+    custom.insert(
+        RENDER_MARKDOWN_KEY.to_string(),
+        app.render_markdown.to_string(),
+    );
+    write_provider_headers(&app.providers, &mut custom);
     let pc = PostcardAppConfig {
         model: PostcardModelConfig {
             default_provider: app.runtime_config.default_provider.clone(),
@@ -4,6 +4,7 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::presentation::tui::app::ChatApp;
 use crate::presentation::tui::event_loop::KeyAction;
+use crate::presentation::tui::navigation::NavAction;
 
 pub(crate) fn handle_history_key(key: KeyEvent, app: &mut ChatApp) -> KeyAction {
     // Rename mode intercepts all printable input.
@@ -40,19 +41,14 @@ pub(crate) fn handle_history_key(key: KeyEvent, app: &mut ChatApp) -> KeyAction
     }
 
     // Detail view — show full conversation, allow scrolling.
-    if app.history.detail.is_some() {
-        match key.code {
-            KeyCode::Esc | KeyCode::Backspace => {
-                app.history.detail = None;
-                app.history.detail_scroll = 0;
-            }
-            KeyCode::Up => {
-                app.history.detail_scroll = app.history.detail_scroll.saturating_sub(1);
-            }
-            KeyCode::Down => {
-                app.history.detail_scroll += 1;
-            }
-            _ => {}
+    if let Some(detail) = &app.history.detail {
+        let last = detail.turns.len().saturating_sub(1);
+        let action = NavAction::from(key);
+        if action == NavAction::Back || key.code == KeyCode::Backspace {
+            app.history.detail = None;
+            app.history.detail_scroll = 0;
+        } else {
+            app.history.detail_scroll = action.apply(app.history.detail_scroll, last);
         }
         return KeyAction::None;
     }
@@ -63,15 +59,6 @@ pub(crate) fn handle_history_key(key: KeyEvent, app: &mut ChatApp) -> KeyAction
             app.history.open = false;
             app.status = "Siap.".to_string();
         }
-        KeyCode::Up => {
-            app.history.cursor = app.history.cursor.saturating_sub(1);
-        }
-        KeyCode::Down => {
-            let max = app.history.sessions.len().saturating_sub(1);
-            if app.history.cursor < max {
-                app.history.cursor += 1;
-            }
-        }
         KeyCode::Enter => {
             if let Some(id) = app
                 .history
@@ -106,7 +93,10 @@ pub(crate) fn handle_history_key(key: KeyEvent, app: &mut ChatApp) -> KeyAction
             app.history.rename_buffer = buf;
             app.history.rename_mode = true;
         }
-        _ => {}
+        _ => {
+            let last = app.history.sessions.len().saturating_sub(1);
+            app.history.cursor = NavAction::from(key).apply(app.history.cursor, last);
+        }
     }
     KeyAction::None
 }
@@ -2,5 +2,8 @@
 
 pub mod commands;
 pub(crate) mod history_handler;
+pub(crate) mod model_picker_handler;
+pub(crate) mod quit_handler;
 pub(crate) mod settings_handler;
 pub(crate) mod submit;
+pub(crate) mod turn_log_handler;
@@ -0,0 +1,43 @@
+//! Model picker keyboard handler.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::presentation::tui::app::ChatApp;
+use crate::presentation::tui::event_loop::KeyAction;
+use crate::presentation::tui::navigation::NavAction;
+
+/// Navigate the model list; Enter switches the model used for the following
+/// turns while keeping the current session.
+pub(crate) fn handle_model_picker_key(key: KeyEvent, app: &mut ChatApp) -> KeyAction {
+    let picker = &mut app.model_picker;
+    if key.code == KeyCode::F(4) {
+        picker.open = false;
+        app.status = "Siap.".to_string();
+        return KeyAction::None;
+    }
+    match NavAction::from(key) {
+        NavAction::Back => {
+            picker.open = false;
+            app.status = "Siap.".to_string();
+        }
+        NavAction::Select => {
+            let selected = picker.selected().map(str::to_string);
+            picker.open = false;
+            match selected {
+                Some(model) => {
+                    app.status = format!(
+                        "Model aktif untuk giliran berikutnya: {}/{}.",
+                        app.provider, model
+                    );
+                    app.model = model;
+                }
+                None => app.status = "Siap.".to_string(),
+            }
+        }
+        action => {
+            let last = picker.models.len().saturating_sub(1);
+            picker.cursor = action.apply(picker.cursor, last);
+        }
+    }
+    KeyAction::None
+}
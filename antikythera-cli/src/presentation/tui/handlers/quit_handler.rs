@@ -0,0 +1,47 @@
+//! Quit confirmation keyboard handler.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::presentation::tui::app::ChatApp;
+use crate::presentation::tui::event_loop::KeyAction;
+
+/// `s`/Enter saves the history session and quits, `y` quits without saving,
+/// `n`/Esc goes back to the chat. Ctrl+C again quits at once.
+pub(crate) fn handle_quit_key(key: KeyEvent, app: &mut ChatApp) -> KeyAction {
+    if key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Char('c')) {
+        app.should_quit = true;
+        return KeyAction::None;
+    }
+    match key.code {
+        KeyCode::Char('s') | KeyCode::Char('S') | KeyCode::Enter => {
+            let saved = match &app.current_history_session {
+                Some(session) => app.history_store.save_session(session),
+                None => Ok(()),
+            };
+            match saved {
+                Ok(()) => {
+                    app.history_unsaved = false;
+                    app.quit_prompt = false;
+                    app.status = "Riwayat disimpan. Menutup TUI...".to_string();
+                    app.should_quit = true;
+                }
+                Err(error) => {
+                    app.status = format!(
+                        "Gagal menyimpan riwayat: {error}. y=keluar tanpa simpan | n/Esc=batal"
+                    );
+                }
+            }
+        }
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.quit_prompt = false;
+            app.status = "Menutup TUI...".to_string();
+            app.should_quit = true;
+        }
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+            app.quit_prompt = false;
+            app.status = "Siap.".to_string();
+        }
+        _ => {}
+    }
+    KeyAction::None
+}
@@ -1,5 +1,6 @@
 //! Chat input submission handler with agent/chat dispatch.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use chrono::Utc;
@@ -13,6 +14,7 @@ use antikythera_core::ProviderLogger;
 use antikythera_core::application::agent::{Agent, AgentOptions};
 use antikythera_core::application::client::{ChatRequest, McpClient};
 use antikythera_core::application::resilience::{ContextWindowPolicy, RetryPolicy, with_retry_if};
+use antikythera_core::application::streaming::AgentEvent;
 use antikythera_core::infrastructure::model::DynamicModelProvider;
 use tokio::sync::{mpsc, oneshot};
 
@@ -38,8 +40,59 @@ pub(crate) fn submit_input(client: &mut Arc<McpClient<DynamicModelProvider>>, ap
         return;
     }
 
-    app.push_message(UiMessage::new("You", &input, UiTone::User));
     app.status = format!("Mengirim ke {}/{}...", app.provider, app.model);
+    dispatch_prompt(client, app, input, false);
+}
+
+/// Send the last user message again for a new answer (Ctrl+R).
+///
+/// The old exchange leaves the conversation panel, the history session, and
+/// the core session, so the new answer is generated from the same context.
+pub(crate) fn regenerate_last(
+    client: &mut Arc<McpClient<DynamicModelProvider>>,
+    app: &mut ChatApp,
+) {
+    if app.pending_rx.is_some() {
+        app.status = "Menunggu respons...".to_string();
+        return;
+    }
+    let Some(start) = app
+        .messages
+        .iter()
+        .rposition(|message| message.tone == UiTone::User)
+    else {
+        app.status = "Belum ada pesan untuk dibuat ulang.".to_string();
+        return;
+    };
+    let input = app.messages[start].body.clone();
+    app.messages.truncate(start);
+    if let Some(session) = &mut app.current_history_session
+        && let Some(last_user) = session
+            .turns
+            .iter()
+            .rposition(|turn| turn.role == TurnRole::User)
+    {
+        session.turns.truncate(last_user);
+        app.history_unsaved = true;
+    }
+
+    app.status = format!(
+        "Membuat ulang respons dari {}/{}...",
+        app.provider, app.model
+    );
+    dispatch_prompt(client, app, input, true);
+}
+
+/// Show `input` as the user's message and run it as a chat or agent turn.
+/// With `regenerate`, the session's last exchange is removed from the core
+/// history before the request is sent.
+fn dispatch_prompt(
+    client: &mut Arc<McpClient<DynamicModelProvider>>,
+    app: &mut ChatApp,
+    input: String,
+    regenerate: bool,
+) {
+    app.push_message(UiMessage::new("You", &input, UiTone::User));
     app.loading = true;
     // Scroll to show latest messages (count lines from message body lengths).
     app.conversation_scroll = scroll_to_bottom(&app.messages, app.conversation_scroll);
@@ -63,6 +116,7 @@ pub(crate) fn submit_input(client: &mut Arc<McpClient<DynamicModelProvider>>, ap
             content: input.clone(),
             tool_steps: 0,
         });
+        app.history_unsaved = true;
     }
 
     let (tx, rx) = oneshot::channel();
@@ -96,12 +150,24 @@ pub(crate) fn submit_input(client: &mut Arc<McpClient<DynamicModelProvider>>, ap
     if app.agent_mode {
         let options = AgentOptions {
             session_id: app.session_id.clone(),
+            model: Some(model_id),
             ..AgentOptions::default()
         };
+        let (progress_tx, progress_rx) = mpsc::unbounded_channel::<String>();
+        app.progress_rx = Some(progress_rx);
         let client_arc = Arc::clone(client);
+        let session_id = app.session_id.clone();
         tokio::spawn(async move {
+            if regenerate && let Some(ref sid) = session_id {
+                client_arc.pop_last_exchange(sid).await;
+            }
             let start = std::time::Instant::now();
             let result = Agent::new(client_arc)
+                .with_event_sink(Arc::new(move |event: &AgentEvent| {
+                    if let Some(label) = event.progress_label() {
+                        let _ = progress_tx.send(label);
+                    }
+                }))
                 .run(input, options)
                 .await
                 .map_err(|e| e.user_message());
@@ -117,9 +183,13 @@ pub(crate) fn submit_input(client: &mut Arc<McpClient<DynamicModelProvider>>, ap
     } else {
         let client_arc = Arc::clone(client);
         let session_id = app.session_id.clone();
+        let model = model_id;
         let cw_policy = ContextWindowPolicy::default();
         let retry_policy = RetryPolicy::default();
         tokio::spawn(async move {
+            if regenerate && let Some(ref sid) = session_id {
+                client_arc.pop_last_exchange(sid).await;
+            }
             // Auto-prune context window before sending if the session is long.
             if let Some(ref sid) = session_id {
                 let removed = client_arc.prune_session(sid, &cw_policy).await;
@@ -142,6 +212,7 @@ pub(crate) fn submit_input(client: &mut Arc<McpClient<DynamicModelProvider>>, ap
                     let c = Arc::clone(&client_arc);
                     let prompt = input.clone();
                     let sid = session_id.clone();
+                    let model = model.clone();
                     async move {
                         c.chat(ChatRequest {
                             prompt,
@@ -151,6 +222,9 @@ pub(crate) fn submit_input(client: &mut Arc<McpClient<DynamicModelProvider>>, ap
                             raw_mode: false,
                             bypass_template: false,
                             force_json: false,
+                            provider: None,
+                            model: Some(model),
+                            metadata: HashMap::new(),
                         })
                         .await
                     }
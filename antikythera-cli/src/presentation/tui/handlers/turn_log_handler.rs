@@ -0,0 +1,31 @@
+//! Turn log viewer keyboard handler.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::presentation::tui::app::ChatApp;
+use crate::presentation::tui::event_loop::KeyAction;
+use crate::presentation::tui::navigation::NavAction;
+
+pub(crate) fn handle_turn_log_key(key: KeyEvent, app: &mut ChatApp) -> KeyAction {
+    let viewer = &mut app.turn_logs;
+    match key.code {
+        KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            viewer.open = false;
+        }
+        KeyCode::Char('q') => {
+            viewer.open = false;
+        }
+        _ => match NavAction::from(key) {
+            NavAction::Back => viewer.open = false,
+            action => {
+                // Header and blank line come before the numbered log lines.
+                let last = viewer.lines.len() + 1;
+                viewer.scroll = action.apply(usize::from(viewer.scroll), last) as u16;
+            }
+        },
+    }
+    if !app.turn_logs.open {
+        app.status = "Siap.".to_string();
+    }
+    KeyAction::None
+}
@@ -1,7 +1,10 @@
 pub mod app;
 pub mod event_loop;
 pub mod handlers;
+pub mod navigation;
 pub mod render;
+pub mod theme;
 pub mod types;
 
 pub use event_loop::run_chat_app;
+pub use types::TuiOptions;
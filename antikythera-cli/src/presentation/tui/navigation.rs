@@ -0,0 +1,60 @@
+//! Navigation keys shared by the TUI's lists and scrollable overlays.
+//!
+//! Handlers map a [`KeyEvent`] to a [`NavAction`] after checking their own
+//! shortcuts, so every screen scrolls and pages the same way. Besides the
+//! arrow and paging keys, vi-style `j`/`k` move down/up and `g`/`G` jump to
+//! the top/bottom, so text-entry modes must handle characters before
+//! converting a key.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Lines or items moved by [`NavAction::PageUp`] and [`NavAction::PageDown`].
+pub const PAGE_SIZE: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavAction {
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Select,
+    Back,
+    ForceQuit,
+    None,
+}
+
+impl From<KeyEvent> for NavAction {
+    fn from(key: KeyEvent) -> Self {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Self::ForceQuit,
+            KeyCode::Char(_) if key.modifiers.contains(KeyModifiers::CONTROL) => Self::None,
+            KeyCode::Up | KeyCode::Char('k') => Self::Up,
+            KeyCode::Down | KeyCode::Char('j') => Self::Down,
+            KeyCode::PageUp => Self::PageUp,
+            KeyCode::PageDown => Self::PageDown,
+            KeyCode::Home | KeyCode::Char('g') => Self::Home,
+            KeyCode::End | KeyCode::Char('G') => Self::End,
+            KeyCode::Enter => Self::Select,
+            KeyCode::Esc => Self::Back,
+            _ => Self::None,
+        }
+    }
+}
+
+impl NavAction {
+    /// Move `position` within `0..=last`. Actions that do not move return it
+    /// unchanged.
+    pub fn apply(self, position: usize, last: usize) -> usize {
+        match self {
+            Self::Up => position.saturating_sub(1),
+            Self::Down => (position + 1).min(last),
+            Self::PageUp => position.saturating_sub(PAGE_SIZE),
+            Self::PageDown => (position + PAGE_SIZE).min(last),
+            Self::Home => 0,
+            Self::End => last,
+            Self::Select | Self::Back | Self::ForceQuit | Self::None => position,
+        }
+    }
+}
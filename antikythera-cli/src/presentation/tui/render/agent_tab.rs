@@ -1,5 +1,6 @@
+use super::super::theme::theme;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
 use super::super::app::ChatApp;
@@ -28,7 +29,7 @@ pub(super) fn draw_settings_tab_agent(frame: &mut ratatui::Frame<'_>, app: &Chat
             Block::default()
                 .borders(Borders::ALL)
                 .title("Agent Settings  [Enter=toggle | Ctrl+S=simpan semua]")
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(theme().accent)),
         )
         .wrap(Wrap { trim: false });
     frame.render_widget(widget, rows[0]);
@@ -61,7 +62,7 @@ pub(super) fn draw_settings_tab_agent(frame: &mut ratatui::Frame<'_>, app: &Chat
             Block::default()
                 .borders(Borders::ALL)
                 .title("Ringkasan Perubahan Pending  [Ctrl+S=terapkan semua]")
-                .border_style(Style::default().fg(Color::Yellow)),
+                .border_style(Style::default().fg(theme().highlight)),
         )
         .wrap(Wrap { trim: false });
     frame.render_widget(summary_widget, rows[1]);
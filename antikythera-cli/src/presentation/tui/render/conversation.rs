@@ -1,12 +1,14 @@
 //! Conversation panel — message list with streaming preview.
 
+use super::super::theme::theme;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
 use super::super::app::ChatApp;
 use super::super::types::{UiMessage, UiTone};
+use super::markdown::render_markdown;
 
 pub(super) fn draw_conversation(frame: &mut ratatui::Frame<'_>, app: &ChatApp, area: Rect) {
     let conv_title = if app.loading {
@@ -18,7 +20,11 @@ pub(super) fn draw_conversation(frame: &mut ratatui::Frame<'_>, app: &ChatApp, a
     } else {
         "Conversation  [↑↓/PgUp/PgDn/Home/End = scroll]"
     };
-    let mut conv_text = render_messages(app.messages.iter());
+    let mut conv_text = render_messages(
+        app.messages.iter(),
+        app.render_markdown,
+        app.expand_tool_steps,
+    );
     if app.loading && !app.streaming_content.is_empty() {
         conv_text.extend(render_streaming_preview(&app.streaming_content));
     }
@@ -35,8 +41,8 @@ pub(super) fn render_streaming_preview(content: &str) -> Text<'static> {
         Span::styled(
             " Streaming ",
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::Cyan)
+                .fg(theme().on_color)
+                .bg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" "),
@@ -44,43 +50,64 @@ pub(super) fn render_streaming_preview(content: &str) -> Text<'static> {
     for line in content.lines() {
         lines.push(Line::from(Span::styled(
             line.to_string(),
-            Style::default().fg(Color::LightCyan),
+            Style::default().fg(theme().accent_alt),
         )));
     }
     lines.push(Line::from(Span::styled(
         "...",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme().muted),
     )));
     Text::from(lines)
 }
 
-pub(super) fn render_messages<'a>(messages: impl Iterator<Item = &'a UiMessage>) -> Text<'static> {
+pub(super) fn render_messages<'a>(
+    messages: impl Iterator<Item = &'a UiMessage>,
+    markdown: bool,
+    expand_steps: bool,
+) -> Text<'static> {
     let mut lines = Vec::new();
     for message in messages {
         let tone_style = match message.tone {
             UiTone::User => Style::default()
-                .fg(Color::Black)
-                .bg(Color::Green)
+                .fg(theme().on_color)
+                .bg(theme().success)
                 .add_modifier(Modifier::BOLD),
             UiTone::Assistant => Style::default()
-                .fg(Color::Black)
-                .bg(Color::Blue)
+                .fg(theme().on_color)
+                .bg(theme().info)
                 .add_modifier(Modifier::BOLD),
             UiTone::System => Style::default()
-                .fg(Color::Black)
-                .bg(Color::Yellow)
+                .fg(theme().on_color)
+                .bg(theme().highlight)
                 .add_modifier(Modifier::BOLD),
             UiTone::Error => Style::default()
-                .fg(Color::White)
-                .bg(Color::Red)
+                .fg(theme().on_error)
+                .bg(theme().error)
+                .add_modifier(Modifier::BOLD),
+            UiTone::Tool => Style::default()
+                .fg(theme().on_color)
+                .bg(theme().secondary)
                 .add_modifier(Modifier::BOLD),
         };
         lines.push(Line::from(vec![
             Span::styled(format!(" {} ", message.title), tone_style),
             Span::raw(" "),
         ]));
-        for body_line in message.body.lines() {
-            lines.push(Line::from(Span::raw(body_line.to_string())));
+        if markdown && message.tone == UiTone::Assistant {
+            lines.extend(render_markdown(&message.body));
+        } else if message.tone == UiTone::Tool {
+            for body_line in message.text(expand_steps).lines() {
+                let style = if body_line.starts_with(' ') {
+                    Style::default().fg(theme().muted)
+                } else {
+                    Style::default().fg(theme().secondary)
+                };
+                lines.push(Line::from(Span::styled(body_line.to_string(), style)));
+            }
+        } else {
+            for body_line in message.text(expand_steps).lines() {
+                lines.push(Line::from(Span::raw(body_line.to_string())));
+            }
         }
         lines.push(Line::default());
     }
@@ -1,7 +1,8 @@
 //! Header bar showing app title, provider/model, and mode.
 
+use super::super::theme::theme;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
@@ -12,15 +13,20 @@ pub(super) fn draw_header(frame: &mut ratatui::Frame<'_>, app: &ChatApp, area: R
         Span::styled(
             " Antikythera CLI ",
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::Cyan)
+                .fg(theme().on_color)
+                .bg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("  "),
         Span::styled(
-            format!("{} / {}", app.provider, app.model),
+            backend_label(
+                (&app.provider, &app.model),
+                app.answered_by
+                    .as_ref()
+                    .map(|(provider, model)| (provider.as_str(), model.as_str())),
+            ),
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("  "),
@@ -30,9 +36,39 @@ pub(super) fn draw_header(frame: &mut ratatui::Frame<'_>, app: &ChatApp, area: R
             } else {
                 "Direct Chat"
             },
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme().highlight),
         ),
     ]))
-    .block(Block::default().borders(Borders::ALL).title("Session"));
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(session_title(app)),
+    );
     frame.render_widget(header, area);
 }
+
+/// `provider / model` for the header. Once a reply has arrived it names the
+/// backend that actually answered; the selection for the next turn is added
+/// when it differs (after `/use`, `/model`, or F4, or a core-side override).
+pub fn backend_label(selected: (&str, &str), answered_by: Option<(&str, &str)>) -> String {
+    match answered_by {
+        Some(answered) if answered != selected => format!(
+            "dijawab {} / {} · dipilih {} / {}",
+            answered.0, answered.1, selected.0, selected.1
+        ),
+        _ => format!("{} / {}", selected.0, selected.1),
+    }
+}
+
+/// Border title: the current session's title when it has one.
+fn session_title(app: &ChatApp) -> String {
+    match app
+        .current_history_session
+        .as_ref()
+        .map(|session| session.title.as_str())
+        .filter(|title| !title.is_empty())
+    {
+        Some(title) => format!("Session: {title}"),
+        None => "Session".to_string(),
+    }
+}
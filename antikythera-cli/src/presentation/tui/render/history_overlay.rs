@@ -1,7 +1,8 @@
 //! History browser overlay rendering.
 
+use super::super::theme::theme;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
 
@@ -21,15 +22,15 @@ pub(super) fn render_history_detail(session: &ChatHistorySession, scroll: usize)
             TurnRole::User => (
                 format!(" Anda [{ts}] "),
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Green)
+                    .fg(theme().on_color)
+                    .bg(theme().success)
                     .add_modifier(Modifier::BOLD),
             ),
             TurnRole::Assistant => (
                 format!(" AI   [{ts}] "),
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Blue)
+                    .fg(theme().on_color)
+                    .bg(theme().info)
                     .add_modifier(Modifier::BOLD),
             ),
         };
@@ -40,7 +41,7 @@ pub(super) fn render_history_detail(session: &ChatHistorySession, scroll: usize)
         if turn.tool_steps > 0 {
             lines.push(Line::from(Span::styled(
                 format!("  [{} langkah tool]", turn.tool_steps),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme().highlight),
             )));
         }
         lines.push(Line::default());
@@ -58,7 +59,7 @@ pub(super) fn draw_history_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp
     let outer_block = Block::default()
         .borders(Borders::ALL)
         .title(" Riwayat Chat  [\u{2191}\u{2193} = navigasi  |  Enter = lihat  |  d = hapus  |  r = ganti judul  |  Esc = tutup] ")
-        .border_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        .border_style(Style::default().fg(theme().highlight).add_modifier(Modifier::BOLD));
     let inner = outer_block.inner(area);
     frame.render_widget(outer_block, area);
 
@@ -98,7 +99,7 @@ pub(super) fn draw_history_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp
             };
             let style = if cursor {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme().highlight)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
@@ -121,7 +122,7 @@ pub(super) fn draw_history_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp
                     "Sesi ({})  [Enter=lihat  d=hapus  r=ganti judul]",
                     app.history.sessions.len()
                 ))
-                .border_style(Style::default().fg(Color::Yellow)),
+                .border_style(Style::default().fg(theme().highlight)),
         ),
         split[0],
     );
@@ -142,7 +143,7 @@ pub(super) fn draw_history_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp
                             "Percakapan: {}  [\u{2191}\u{2193}=gulir  Esc=kembali]",
                             detail_title
                         ))
-                        .border_style(Style::default().fg(Color::Cyan)),
+                        .border_style(Style::default().fg(theme().accent)),
                 )
                 .wrap(Wrap { trim: false }),
             split[1],
@@ -168,7 +169,7 @@ pub(super) fn draw_history_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp
                     Block::default()
                         .borders(Borders::ALL)
                         .title("Info Sesi")
-                        .border_style(Style::default().fg(Color::Yellow)),
+                        .border_style(Style::default().fg(theme().highlight)),
                 )
                 .wrap(Wrap { trim: false }),
             split[1],
@@ -190,9 +191,9 @@ pub(super) fn draw_history_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp
                     Block::default()
                         .borders(Borders::ALL)
                         .title("Ganti Judul  [Enter=simpan  Esc=batal]")
-                        .border_style(Style::default().fg(Color::Magenta)),
+                        .border_style(Style::default().fg(theme().secondary)),
                 )
-                .style(Style::default().fg(Color::Magenta)),
+                .style(Style::default().fg(theme().secondary)),
             bottom,
         );
     }
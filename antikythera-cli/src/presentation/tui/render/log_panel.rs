@@ -1,57 +1,58 @@
 //! Log panel color resolution by source and level.
 
-use ratatui::style::{Color, Style};
+use super::super::theme::theme;
+use ratatui::style::Style;
 
 pub fn resolve_log_line_style(line: &str) -> Style {
     if line.contains("[WARN]") {
-        return Style::default().fg(Color::Yellow);
+        return Style::default().fg(theme().highlight);
     }
     if line.contains("[ERROR]") {
-        return Style::default().fg(Color::Red);
+        return Style::default().fg(theme().error);
     }
     let is_debug = line.contains("[DEBUG]");
     // SDK/FFI entries have a colon-prefixed source (e.g. "sdk:ConfigFfiLogger")
     if line.contains("][sdk:") || line.contains("][ffi:") {
         return if is_debug {
-            Style::default().fg(Color::Magenta)
+            Style::default().fg(theme().secondary)
         } else {
-            Style::default().fg(Color::LightMagenta)
+            Style::default().fg(theme().secondary_alt)
         };
     }
     if line.contains("][cli:") {
         return if is_debug {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme().highlight)
         } else {
-            Style::default().fg(Color::LightYellow)
+            Style::default().fg(theme().highlight_alt)
         };
     }
     if line.contains("][stream:") {
         return if is_debug {
-            Style::default().fg(Color::Cyan)
+            Style::default().fg(theme().accent)
         } else {
-            Style::default().fg(Color::LightCyan)
+            Style::default().fg(theme().accent_alt)
         };
     }
     // Module loggers from core — bare source names (no colon).
     if line.contains("][agent]") {
         return if is_debug {
-            Style::default().fg(Color::Green)
+            Style::default().fg(theme().success)
         } else {
-            Style::default().fg(Color::LightGreen)
+            Style::default().fg(theme().success_alt)
         };
     }
     if line.contains("][provider]") {
         return if is_debug {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme().highlight)
         } else {
-            Style::default().fg(Color::LightYellow)
+            Style::default().fg(theme().highlight_alt)
         };
     }
     if line.contains("][transport]") || line.contains("][tool]") {
         return if is_debug {
-            Style::default().fg(Color::Blue)
+            Style::default().fg(theme().info)
         } else {
-            Style::default().fg(Color::LightBlue)
+            Style::default().fg(theme().info_alt)
         };
     }
     if line.contains("][config]")
@@ -65,8 +66,8 @@ pub fn resolve_log_line_style(line: &str) -> Style {
         || line.contains("][wasm]")
         || line.contains("][security]")
     {
-        return Style::default().fg(Color::Gray);
+        return Style::default().fg(theme().subtle);
     }
     // core:* or unknown
-    Style::default().fg(Color::Gray)
+    Style::default().fg(theme().subtle)
 }
@@ -0,0 +1,134 @@
+//! Lightweight Markdown → ratatui conversion for assistant messages.
+//!
+//! Covers what LLM replies use most: ATX headings, bullet and numbered
+//! lists, fenced code blocks, inline code, and `**bold**` / `*italic*`.
+//! Anything else is passed through as plain text.
+
+use super::super::theme::theme;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+pub fn render_markdown(body: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+
+    for raw in body.lines() {
+        let trimmed = raw.trim_start();
+        if let Some(fence) = trimmed.strip_prefix("```") {
+            in_code_block = !in_code_block;
+            if in_code_block && !fence.trim().is_empty() {
+                lines.push(Line::from(Span::styled(
+                    format!(" {} ", fence.trim()),
+                    Style::default().fg(theme().muted).bg(theme().code_bg),
+                )));
+            }
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(Line::from(Span::styled(
+                format!(" {raw} "),
+                Style::default().fg(theme().success_alt).bg(theme().code_bg),
+            )));
+            continue;
+        }
+
+        if let Some((level, text)) = heading(trimmed) {
+            let color = if level == 1 {
+                theme().accent_alt
+            } else {
+                theme().accent
+            };
+            lines.push(Line::from(Span::styled(
+                text.to_string(),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )));
+            continue;
+        }
+
+        let indent = &raw[..raw.len() - trimmed.len()];
+        if let Some((marker, item)) = list_item(trimmed) {
+            let mut spans = vec![Span::styled(
+                format!("{indent}{marker} "),
+                Style::default().fg(theme().highlight),
+            )];
+            spans.extend(inline_spans(item));
+            lines.push(Line::from(spans));
+            continue;
+        }
+
+        lines.push(Line::from(inline_spans(raw)));
+    }
+
+    lines
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|ch| *ch == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..]
+        .strip_prefix(' ')
+        .map(|text| (level, text.trim()))
+}
+
+fn list_item(line: &str) -> Option<(String, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(bullet) {
+            return Some(("•".to_string(), item));
+        }
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0
+        && let Some(item) = line[digits..].strip_prefix(". ")
+    {
+        return Some((line[..=digits].to_string(), item));
+    }
+    None
+}
+
+/// Split a line into styled spans for inline code, bold, and italic.
+fn inline_spans(text: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let marker = if rest.starts_with('`') {
+            Some((
+                "`",
+                Style::default().fg(theme().success_alt).bg(theme().code_bg),
+            ))
+        } else if rest.starts_with("**") {
+            Some(("**", Style::default().add_modifier(Modifier::BOLD)))
+        } else if rest.starts_with('*') {
+            Some(("*", Style::default().add_modifier(Modifier::ITALIC)))
+        } else {
+            None
+        };
+
+        if let Some((delimiter, style)) = marker {
+            let inner = &rest[delimiter.len()..];
+            if let Some(end) = inner.find(delimiter)
+                && end > 0
+            {
+                if !plain.is_empty() {
+                    spans.push(Span::raw(std::mem::take(&mut plain)));
+                }
+                spans.push(Span::styled(inner[..end].to_string(), style));
+                rest = &inner[end + delimiter.len()..];
+                continue;
+            }
+        }
+
+        let ch = rest.chars().next().expect("rest is not empty");
+        plain.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+
+    if !plain.is_empty() || spans.is_empty() {
+        spans.push(Span::raw(plain));
+    }
+    spans
+}
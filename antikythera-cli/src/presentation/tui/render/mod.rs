@@ -1,7 +1,8 @@
-//! TUI render orchestrator — composes chat, log, settings, and history panels.
+//! TUI render orchestrator — composes chat, log, settings, history, turn log, model picker, and quit confirmation panels.
 
+use super::theme::theme;
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
@@ -10,17 +11,22 @@ use super::app::ChatApp;
 pub(crate) mod agent_tab;
 mod autocomplete;
 mod conversation;
-mod header;
+pub mod header;
 pub(crate) mod history_overlay;
 pub mod log_panel;
+pub mod markdown;
+pub mod model_picker_overlay;
 pub(crate) mod model_tab;
 mod prompt_bar;
 pub(crate) mod prompts_tab;
 pub(crate) mod provider_tab;
+pub mod quit_overlay;
 pub(crate) mod settings_overlay;
 mod sidebar;
 mod status_bar;
 pub(crate) mod system_tab;
+pub mod tool_steps;
+pub mod turn_log_overlay;
 
 pub(super) fn draw(frame: &mut ratatui::Frame<'_>, app: &ChatApp) {
     let layout = Layout::default()
@@ -70,7 +76,7 @@ pub(super) fn draw(frame: &mut ratatui::Frame<'_>, app: &ChatApp) {
                 .title("Logs [yellow=CLI/prov | magenta=SDK/FFI | cyan=stream | green=agent | blue=tool/transport] [Ctrl+↑↓/PgUp/PgDn/Home/End = scroll]")
                 .title_style(
                     Style::default()
-                        .fg(Color::Magenta)
+                        .fg(theme().secondary)
                         .add_modifier(Modifier::BOLD),
                 ),
         )
@@ -97,6 +103,21 @@ pub(super) fn draw(frame: &mut ratatui::Frame<'_>, app: &ChatApp) {
     if app.history.open {
         history_overlay::draw_history_overlay(frame, app);
     }
+
+    // Last-turn log viewer (drawn on top of everything else)
+    if app.turn_logs.open {
+        turn_log_overlay::draw_turn_log_overlay(frame, app);
+    }
+
+    // Model picker (drawn on top of everything else)
+    if app.model_picker.open {
+        model_picker_overlay::draw_model_picker_overlay(frame, app);
+    }
+
+    // Quit confirmation (drawn on top of everything else)
+    if app.quit_prompt {
+        quit_overlay::draw_quit_overlay(frame, app);
+    }
 }
 
 fn centered_rect(
@@ -0,0 +1,60 @@
+//! Popup for switching the model of the active provider (F4).
+
+use super::super::theme::theme;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use super::super::app::ChatApp;
+
+/// One line per model: `▶` marks the cursor and `◉` the active model.
+pub fn render_model_picker(models: &[String], cursor: usize, active: &str) -> Vec<Line<'static>> {
+    models
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let is_active = name == active;
+            let arrow = if i == cursor { "\u{25b6}" } else { " " };
+            let radio = if is_active { "\u{25c9}" } else { "\u{25cb}" };
+            let style = if i == cursor {
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD)
+            } else if is_active {
+                Style::default().fg(theme().success)
+            } else {
+                Style::default()
+            };
+            Line::from(Span::styled(format!("{arrow} {radio} {name}"), style))
+        })
+        .collect()
+}
+
+pub(super) fn draw_model_picker_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp) {
+    let area = super::centered_rect(50, 40, frame.area());
+    frame.render_widget(Clear, area);
+
+    let picker = &app.model_picker;
+    let body = render_model_picker(&picker.models, picker.cursor, &app.model);
+    // Keep the cursor visible in long lists.
+    let visible = area.height.saturating_sub(2) as usize;
+    let scroll = picker.cursor.saturating_sub(visible.saturating_sub(1)) as u16;
+    frame.render_widget(
+        Paragraph::new(body)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        " Model '{}'  [\u{2191}\u{2193} = navigasi  |  Enter = pakai  |  Esc = tutup] ",
+                        app.provider
+                    ))
+                    .border_style(
+                        Style::default()
+                            .fg(theme().accent)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+            )
+            .scroll((scroll, 0)),
+        area,
+    );
+}
@@ -1,5 +1,6 @@
+use super::super::theme::theme;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
 
 use super::super::app::ChatApp;
@@ -38,10 +39,10 @@ pub(super) fn draw_settings_tab_model(frame: &mut ratatui::Frame<'_>, app: &Chat
             let arrow = if cursor { "\u{25b6}" } else { " " };
             let style = if cursor {
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme().accent)
                     .add_modifier(Modifier::BOLD)
             } else if selected {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme().success)
             } else {
                 Style::default()
             };
@@ -58,7 +59,7 @@ pub(super) fn draw_settings_tab_model(frame: &mut ratatui::Frame<'_>, app: &Chat
         Block::default()
             .borders(Borders::ALL)
             .title(list_title)
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(Style::default().fg(theme().accent)),
     );
     frame.render_widget(list, cols[0]);
 
@@ -94,9 +95,9 @@ pub(super) fn draw_settings_tab_model(frame: &mut ratatui::Frame<'_>, app: &Chat
                 Block::default()
                     .borders(Borders::ALL)
                     .title("Tambah Model  [Enter=simpan | Esc=batal]")
-                    .border_style(Style::default().fg(Color::Yellow)),
+                    .border_style(Style::default().fg(theme().highlight)),
             )
-            .style(Style::default().fg(Color::Yellow));
+            .style(Style::default().fg(theme().highlight));
         frame.render_widget(input_widget, input_rect);
     }
 }
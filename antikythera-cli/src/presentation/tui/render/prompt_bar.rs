@@ -1,7 +1,8 @@
 //! Prompt input bar at the bottom of the chat area.
 
+use super::super::theme::theme;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use super::super::app::ChatApp;
@@ -15,7 +16,7 @@ pub(super) fn draw_prompt_bar(frame: &mut ratatui::Frame<'_>, app: &ChatApp, are
     let input_widget = Paragraph::new(app.input.as_str())
         .block(Block::default().borders(Borders::ALL).title(prompt_title))
         .style(if app.loading {
-            Style::default().fg(Color::Yellow)
+            Style::default().fg(theme().highlight)
         } else {
             Style::default()
         });
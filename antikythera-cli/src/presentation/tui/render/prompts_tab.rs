@@ -1,5 +1,6 @@
+use super::super::theme::theme;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
 
 use super::super::app::ChatApp;
@@ -27,7 +28,7 @@ pub(super) fn draw_settings_tab_prompts(frame: &mut ratatui::Frame<'_>, app: &Ch
                 .collect::<String>();
             let style = if cursor {
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme().accent)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
@@ -42,7 +43,7 @@ pub(super) fn draw_settings_tab_prompts(frame: &mut ratatui::Frame<'_>, app: &Ch
             .title(
                 "Prompt Fields  [↑↓=pilih | Enter=edit | Ctrl+Enter=simpan field | Esc=batal edit]",
             )
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(Style::default().fg(theme().accent)),
     );
     frame.render_widget(list, rows[0]);
 
@@ -52,7 +53,7 @@ pub(super) fn draw_settings_tab_prompts(frame: &mut ratatui::Frame<'_>, app: &Ch
                 Block::default()
                     .borders(Borders::ALL)
                     .title("Edit Field  [Ctrl+Enter=simpan | Esc=batal | Enter=baris baru]")
-                    .border_style(Style::default().fg(Color::Magenta)),
+                    .border_style(Style::default().fg(theme().secondary)),
             )
             .wrap(Wrap { trim: false });
         frame.render_widget(edit_widget, rows[1]);
@@ -63,7 +64,7 @@ pub(super) fn draw_settings_tab_prompts(frame: &mut ratatui::Frame<'_>, app: &Ch
                 Block::default()
                     .borders(Borders::ALL)
                     .title(format!("Preview: {}  [Enter=edit]", field.label()))
-                    .border_style(Style::default().fg(Color::Yellow)),
+                    .border_style(Style::default().fg(theme().highlight)),
             )
             .wrap(Wrap { trim: false });
         frame.render_widget(preview_widget, rows[1]);
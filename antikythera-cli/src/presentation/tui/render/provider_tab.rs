@@ -1,5 +1,6 @@
+use super::super::theme::theme;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
 
 use super::super::app::ChatApp;
@@ -25,10 +26,10 @@ pub(super) fn draw_settings_tab_provider(
             let arrow = if cursor { "▶" } else { " " };
             let style = if cursor {
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme().accent)
                     .add_modifier(Modifier::BOLD)
             } else if selected {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme().success)
             } else {
                 Style::default()
             };
@@ -44,7 +45,7 @@ pub(super) fn draw_settings_tab_provider(
         Block::default()
             .borders(Borders::ALL)
             .title("Provider  [↑↓=navigasi | Enter=pilih & ke tab Model]")
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(Style::default().fg(theme().accent)),
     );
     frame.render_widget(list, cols[0]);
 
@@ -80,5 +81,18 @@ pub(super) fn draw_settings_tab_provider(
             )
             .wrap(Wrap { trim: false });
         frame.render_widget(widget, cols[1]);
+    } else if app.providers.is_empty() {
+        let hint = "Belum ada provider yang dikonfigurasi.\n\n\
+                    Tutup panel ini (Esc) lalu ketik:\n  /use <provider> <model>\n\
+                    contoh: /use ollama llama3.2\n\n\
+                    Atau tambahkan provider dari terminal:\n  antikythera-config add-provider";
+        let widget = Paragraph::new(hint)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Setup Provider"),
+            )
+            .wrap(Wrap { trim: false });
+        frame.render_widget(widget, cols[1]);
     }
 }
@@ -0,0 +1,59 @@
+//! Quit confirmation popup, shown when quitting would lose work.
+
+use super::super::theme::theme;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use super::super::app::ChatApp;
+
+/// Popup body: what would be lost, then the choices.
+pub fn render_quit_prompt(reply_pending: bool) -> Vec<Line<'static>> {
+    let warning = if reply_pending {
+        "Jawaban masih diproses dan akan hilang jika Anda keluar sekarang."
+    } else {
+        "Ada giliran chat yang belum tersimpan ke riwayat."
+    };
+    let key = Style::default()
+        .fg(theme().accent)
+        .add_modifier(Modifier::BOLD);
+    vec![
+        Line::from(Span::styled(
+            warning,
+            Style::default().fg(theme().highlight),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("s", key),
+            Span::raw("     simpan riwayat lalu keluar"),
+        ]),
+        Line::from(vec![
+            Span::styled("y", key),
+            Span::raw("     keluar tanpa menyimpan"),
+        ]),
+        Line::from(vec![
+            Span::styled("n/Esc", key),
+            Span::raw(" kembali ke chat"),
+        ]),
+    ]
+}
+
+pub(super) fn draw_quit_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp) {
+    let area = super::centered_rect(50, 30, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(render_quit_prompt(app.pending_rx.is_some()))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Keluar dari TUI? ")
+                    .border_style(
+                        Style::default()
+                            .fg(theme().highlight)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+            )
+            .wrap(Wrap { trim: true }),
+        area,
+    );
+}
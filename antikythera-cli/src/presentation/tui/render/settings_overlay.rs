@@ -1,7 +1,8 @@
 //! Settings overlay with Provider, Model, Prompts, System, Agent tabs.
 
+use super::super::theme::theme;
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
@@ -17,7 +18,7 @@ pub(super) fn draw_settings_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatAp
         .title(" \u{2699}  Settings  [Tab/BackTab=ganti tab | \u{2191}\u{2193}=nav | Enter=pilih | Ctrl+S=simpan | Esc=tutup] ")
         .border_style(
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         );
     let inner = outer_block.inner(area);
@@ -35,12 +36,12 @@ pub(super) fn draw_settings_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatAp
             tab_spans.push(Span::styled(
                 label,
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
+                    .fg(theme().on_color)
+                    .bg(theme().accent)
                     .add_modifier(Modifier::BOLD),
             ));
         } else {
-            tab_spans.push(Span::styled(label, Style::default().fg(Color::Cyan)));
+            tab_spans.push(Span::styled(label, Style::default().fg(theme().accent)));
         }
         tab_spans.push(Span::raw("  "));
     }
@@ -1,8 +1,9 @@
 //! Footer status bar showing health indicator and keyboard shortcut hints.
 
+use super::super::theme::theme;
 use antikythera_core::application::resilience::HealthStatus;
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
@@ -11,11 +12,11 @@ use super::super::app::ChatApp;
 pub(super) fn draw_status_bar(frame: &mut ratatui::Frame<'_>, app: &ChatApp, area: Rect) {
     let (health_dot, health_color) = match app.health.try_lock() {
         Ok(h) => match h.overall_status() {
-            HealthStatus::Healthy => ("\u{25cf} ", Color::Green),
-            HealthStatus::Degraded => ("\u{25cf} ", Color::Yellow),
-            HealthStatus::Unhealthy => ("\u{25cf} ", Color::Red),
+            HealthStatus::Healthy => ("\u{25cf} ", theme().success),
+            HealthStatus::Degraded => ("\u{25cf} ", theme().highlight),
+            HealthStatus::Unhealthy => ("\u{25cf} ", theme().error),
         },
-        Err(_) => ("\u{25cb} ", Color::Gray),
+        Err(_) => ("\u{25cb} ", theme().subtle),
     };
     let footer = Paragraph::new(Line::from(vec![
         Span::styled(
@@ -27,53 +28,74 @@ pub(super) fn draw_status_bar(frame: &mut ratatui::Frame<'_>, app: &ChatApp, are
         Span::styled(
             "Tab",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" autocomplete  "),
         Span::styled(
             "Enter",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" submit  "),
         Span::styled(
             "F2",
             Style::default()
-                .fg(Color::Magenta)
+                .fg(theme().secondary)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" settings  "),
         Span::styled(
             "F3",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme().highlight)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" riwayat  "),
+        Span::styled(
+            "F4",
+            Style::default()
+                .fg(theme().success)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" model  "),
+        Span::styled(
+            "Ctrl+L",
+            Style::default()
+                .fg(theme().highlight)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" log  "),
+        Span::styled(
+            "Ctrl+R",
+            Style::default()
+                .fg(theme().success)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" ulang  "),
         Span::styled(
             "Esc",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" quit  "),
         Span::styled(
             "↑↓",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" scroll chat  "),
         Span::styled(
             "Ctrl+↑↓",
             Style::default()
-                .fg(Color::Magenta)
+                .fg(theme().secondary)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" scroll log  "),
-        Span::styled(app.status.as_str(), Style::default().fg(Color::Gray)),
+        Span::styled(app.status.as_str(), Style::default().fg(theme().subtle)),
     ]))
     .block(Block::default().borders(Borders::ALL).title("Status"));
     frame.render_widget(footer, area);
@@ -1,5 +1,6 @@
+use super::super::theme::theme;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
 use super::super::app::ChatApp;
@@ -25,7 +26,7 @@ pub(super) fn draw_settings_tab_system(frame: &mut ratatui::Frame<'_>, app: &Cha
                 Block::default()
                     .borders(Borders::ALL)
                     .title("Edit System Prompt  [Ctrl+Enter=simpan | Esc=batal | Enter=baris baru]")
-                    .border_style(Style::default().fg(Color::Magenta)),
+                    .border_style(Style::default().fg(theme().secondary)),
             )
             .wrap(Wrap { trim: false });
         frame.render_widget(edit, rows[1]);
@@ -40,7 +41,7 @@ pub(super) fn draw_settings_tab_system(frame: &mut ratatui::Frame<'_>, app: &Cha
                 Block::default()
                     .borders(Borders::ALL)
                     .title("System Prompt Aktif  [Enter=edit]")
-                    .border_style(Style::default().fg(Color::Yellow)),
+                    .border_style(Style::default().fg(theme().highlight)),
             )
             .wrap(Wrap { trim: false });
         frame.render_widget(preview, rows[1]);
@@ -0,0 +1,46 @@
+//! Text of the tool steps an agent turn ran, shown in the conversation
+//! between the user's message and the agent's answer.
+
+use antikythera_core::application::agent::AgentStep;
+use serde_json::Value;
+
+/// Longest input or output shown for one step when expanded.
+const DETAIL_MAX_CHARS: usize = 1_000;
+
+/// One `🔧 called <tool> → success|failed` line per step, marked `(cached)`
+/// when the result came from the tool result cache. When `expanded`, each
+/// line is followed by the step's note, input, and output.
+pub fn format_tool_steps(steps: &[AgentStep], expanded: bool) -> String {
+    let mut lines = Vec::new();
+    for step in steps {
+        let status = if step.success { "success" } else { "failed" };
+        let cached = if step.cached { " (cached)" } else { "" };
+        lines.push(format!(
+            "\u{1f527} called {} \u{2192} {status}{cached}",
+            step.tool
+        ));
+        if !expanded {
+            continue;
+        }
+        if let Some(message) = step.message.as_deref() {
+            lines.push(format!("   catatan: {message}"));
+        }
+        push_value(&mut lines, "in ", &step.input);
+        push_value(&mut lines, "out", &step.output);
+    }
+    lines.join("\n")
+}
+
+fn push_value(lines: &mut Vec<String>, label: &str, value: &Value) {
+    if value.is_null() {
+        return;
+    }
+    let mut text = serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+    if text.chars().count() > DETAIL_MAX_CHARS {
+        text = text.chars().take(DETAIL_MAX_CHARS).collect();
+        text.push('…');
+    }
+    for line in text.lines() {
+        lines.push(format!("   {label}: {line}"));
+    }
+}
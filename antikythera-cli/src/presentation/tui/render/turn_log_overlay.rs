@@ -0,0 +1,72 @@
+//! Popup with the interaction logs collected for the last chat/agent turn.
+
+use super::super::theme::theme;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use super::super::app::ChatApp;
+
+/// Build the popup body: a timestamp header followed by numbered log lines.
+pub fn render_turn_logs(lines: &[String], received_at: Option<&str>) -> Vec<Line<'static>> {
+    let Some(received_at) = received_at else {
+        return vec![Line::from(Span::styled(
+            "Belum ada giliran yang selesai. Kirim pesan terlebih dahulu.",
+            Style::default().fg(theme().subtle),
+        ))];
+    };
+
+    let timestamp = received_at
+        .get(..19)
+        .unwrap_or(received_at)
+        .replace('T', " ");
+    let mut rendered = vec![
+        Line::from(Span::styled(
+            format!("Diterima {timestamp} UTC · {} baris", lines.len()),
+            Style::default()
+                .fg(theme().highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::default(),
+    ];
+    if lines.is_empty() {
+        rendered.push(Line::from(Span::styled(
+            "(tidak ada log untuk giliran ini)",
+            Style::default().fg(theme().subtle),
+        )));
+    }
+    let width = lines.len().to_string().len();
+    for (index, line) in lines.iter().enumerate() {
+        rendered.push(Line::from(vec![
+            Span::styled(
+                format!("{:>width$} │ ", index + 1),
+                Style::default().fg(theme().muted),
+            ),
+            Span::raw(line.clone()),
+        ]));
+    }
+    rendered
+}
+
+pub(super) fn draw_turn_log_overlay(frame: &mut ratatui::Frame<'_>, app: &ChatApp) {
+    let area = super::centered_rect(80, 70, frame.area());
+    frame.render_widget(Clear, area);
+
+    let body = render_turn_logs(&app.turn_logs.lines, app.turn_logs.received_at.as_deref());
+    frame.render_widget(
+        Paragraph::new(body)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Log Giliran Terakhir  [\u{2191}\u{2193}/PgUp/PgDn = gulir  |  Esc/Ctrl+L = tutup] ")
+                    .border_style(
+                        Style::default()
+                            .fg(theme().accent)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((app.turn_logs.scroll, 0)),
+        area,
+    );
+}
@@ -0,0 +1,187 @@
+//! Colors of the chat TUI.
+//!
+//! Render code takes every color from the active [`Theme`] instead of naming
+//! one. The theme is read from the `theme.*` keys in the `custom` map of
+//! `app.pc`: `theme.preset` picks `dark` (the default), `light`, or
+//! `high_contrast`, and `theme.<slot>` replaces one slot of the preset with a
+//! color name (`cyan`, `light-blue`, …), an ANSI index, or `#rrggbb`.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use ratatui::style::Color;
+
+/// `custom` key holding the preset name in `app.pc`.
+pub const THEME_PRESET_KEY: &str = "theme.preset";
+/// Prefix of the `custom` keys overriding one slot, e.g. `theme.accent`.
+pub const THEME_KEY_PREFIX: &str = "theme.";
+
+static ACTIVE: RwLock<Theme> = RwLock::new(Theme::dark());
+
+/// Named colors used by the TUI. Each `*_alt` slot is the brighter variant
+/// of its base slot, used where one screen needs two shades of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Headers, titles, and the borders of the main panels.
+    pub accent: Color,
+    pub accent_alt: Color,
+    /// Markers, focused borders, and warnings.
+    pub highlight: Color,
+    pub highlight_alt: Color,
+    /// Selected items and healthy status.
+    pub success: Color,
+    pub success_alt: Color,
+    /// Agent and prompt panels.
+    pub secondary: Color,
+    pub secondary_alt: Color,
+    /// Tool and transport entries.
+    pub info: Color,
+    pub info_alt: Color,
+    pub error: Color,
+    /// Hints and secondary text.
+    pub muted: Color,
+    /// Status text and plain log lines.
+    pub subtle: Color,
+    /// Text on a colored background.
+    pub on_color: Color,
+    /// Text on an `error` background.
+    pub on_error: Color,
+    /// Background of code blocks.
+    pub code_bg: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// The original palette, for dark terminal backgrounds.
+    pub const fn dark() -> Self {
+        Self {
+            accent: Color::Cyan,
+            accent_alt: Color::LightCyan,
+            highlight: Color::Yellow,
+            highlight_alt: Color::LightYellow,
+            success: Color::Green,
+            success_alt: Color::LightGreen,
+            secondary: Color::Magenta,
+            secondary_alt: Color::LightMagenta,
+            info: Color::Blue,
+            info_alt: Color::LightBlue,
+            error: Color::Red,
+            muted: Color::DarkGray,
+            subtle: Color::Gray,
+            on_color: Color::Black,
+            on_error: Color::White,
+            code_bg: Color::Rgb(40, 44, 52),
+        }
+    }
+
+    /// Darker shades that stay readable on light terminal backgrounds.
+    pub const fn light() -> Self {
+        Self {
+            accent: Color::Rgb(0, 95, 175),
+            accent_alt: Color::Rgb(0, 70, 135),
+            highlight: Color::Rgb(175, 95, 0),
+            highlight_alt: Color::Rgb(135, 70, 0),
+            success: Color::Rgb(0, 125, 0),
+            success_alt: Color::Rgb(0, 95, 0),
+            secondary: Color::Rgb(135, 0, 135),
+            secondary_alt: Color::Rgb(175, 0, 175),
+            info: Color::Rgb(0, 0, 175),
+            info_alt: Color::Rgb(0, 70, 215),
+            error: Color::Rgb(175, 0, 0),
+            muted: Color::Rgb(128, 128, 128),
+            subtle: Color::Rgb(78, 78, 78),
+            on_color: Color::White,
+            on_error: Color::White,
+            code_bg: Color::Rgb(230, 230, 230),
+        }
+    }
+
+    /// Bright colors only, with black text on colored backgrounds, for low
+    /// vision or washed-out displays.
+    pub const fn high_contrast() -> Self {
+        Self {
+            accent: Color::LightCyan,
+            accent_alt: Color::White,
+            highlight: Color::LightYellow,
+            highlight_alt: Color::White,
+            success: Color::LightGreen,
+            success_alt: Color::White,
+            secondary: Color::LightMagenta,
+            secondary_alt: Color::White,
+            info: Color::LightBlue,
+            info_alt: Color::White,
+            error: Color::LightRed,
+            muted: Color::Gray,
+            subtle: Color::White,
+            on_color: Color::Black,
+            on_error: Color::Black,
+            code_bg: Color::Black,
+        }
+    }
+
+    /// The preset called `name` (`dark`, `light`, `high_contrast`), ignoring
+    /// case and `-`/`_`.
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high_contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Read the theme stored in a Postcard `custom` map. An unknown preset
+    /// keeps `dark`; unknown slots and unreadable colors are ignored.
+    pub fn from_custom(custom: &HashMap<String, String>) -> Self {
+        let mut theme = custom
+            .get(THEME_PRESET_KEY)
+            .and_then(|name| Self::preset(name))
+            .unwrap_or_default();
+        for (key, value) in custom {
+            let Some(slot) = key.strip_prefix(THEME_KEY_PREFIX) else {
+                continue;
+            };
+            if let (Some(target), Ok(color)) = (theme.slot_mut(slot), value.trim().parse()) {
+                *target = color;
+            }
+        }
+        theme
+    }
+
+    fn slot_mut(&mut self, name: &str) -> Option<&mut Color> {
+        Some(match name {
+            "accent" => &mut self.accent,
+            "accent_alt" => &mut self.accent_alt,
+            "highlight" => &mut self.highlight,
+            "highlight_alt" => &mut self.highlight_alt,
+            "success" => &mut self.success,
+            "success_alt" => &mut self.success_alt,
+            "secondary" => &mut self.secondary,
+            "secondary_alt" => &mut self.secondary_alt,
+            "info" => &mut self.info,
+            "info_alt" => &mut self.info_alt,
+            "error" => &mut self.error,
+            "muted" => &mut self.muted,
+            "subtle" => &mut self.subtle,
+            "on_color" => &mut self.on_color,
+            "on_error" => &mut self.on_error,
+            "code_bg" => &mut self.code_bg,
+            _ => return None,
+        })
+    }
+}
+
+/// Use `theme` for everything the TUI draws from now on.
+pub fn set_theme(theme: Theme) {
+    *ACTIVE.write().unwrap_or_else(|e| e.into_inner()) = theme;
+}
+
+/// The active theme; [`Theme::dark`] until [`set_theme`] is called.
+pub fn theme() -> Theme {
+    *ACTIVE.read().unwrap_or_else(|e| e.into_inner())
+}
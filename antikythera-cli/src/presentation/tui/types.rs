@@ -1,14 +1,27 @@
+use std::collections::HashMap;
+
 use antikythera_core::application::agent::AgentOutcome;
 use antikythera_core::application::client::ChatResult;
-use antikythera_core::config::{AppConfig, PromptsConfig};
+use antikythera_core::application::discovery::ToolCatalogDiff;
+use antikythera_core::config::{AppConfig, PromptsConfig, ToolConfig};
 
 use crate::infrastructure::history::ChatHistorySession;
 use crate::infrastructure::llm::ModelProviderConfig;
 
-/// Result received from a spawned chat or agent task via a oneshot channel.
+/// Result received from a spawned chat, agent, or tool sync task via a oneshot channel.
 pub(super) enum PendingResponse {
     Chat(Result<ChatResult, String>),
     Agent(Result<AgentOutcome, String>),
+    Sync(ToolSyncReport),
+}
+
+/// Outcome of `/sync`: per-server diffs plus the reconciled tool list.
+pub(crate) struct ToolSyncReport {
+    pub(super) diffs: Vec<ToolCatalogDiff>,
+    /// Servers whose `tools/list` failed, as (server, error)
+    pub(super) failures: Vec<(String, String)>,
+    pub(super) tools: Vec<ToolConfig>,
+    pub(super) pruned: bool,
 }
 
 // ── Settings Panel types ─────────────────────────────────────────────────────
@@ -121,6 +134,72 @@ impl PromptField {
     }
 }
 
+// ── Turn Log Viewer ─────────────────────────────────────────────────────────
+
+/// Overlay showing the interaction logs (`ChatResult.logs` /
+/// `AgentOutcome.logs`) collected for the last turn.
+pub(super) struct TurnLogViewer {
+    pub(super) open: bool,
+    /// Log lines of the most recent completed turn.
+    pub(super) lines: Vec<String>,
+    /// RFC 3339 time the turn's response arrived.
+    pub(super) received_at: Option<String>,
+    /// Scroll offset (in lines) inside the popup.
+    pub(super) scroll: u16,
+}
+
+impl TurnLogViewer {
+    pub(super) fn new() -> Self {
+        Self {
+            open: false,
+            lines: Vec::new(),
+            received_at: None,
+            scroll: 0,
+        }
+    }
+
+    /// Replace the stored logs with those of a newly completed turn.
+    pub(super) fn record(&mut self, lines: Vec<String>) {
+        self.lines = lines;
+        self.received_at = Some(chrono::Utc::now().to_rfc3339());
+        self.scroll = 0;
+    }
+}
+
+// ── Model Picker ────────────────────────────────────────────────────────────
+
+/// Popup for switching the model of the current provider (F4) without
+/// resetting the session.
+pub(super) struct ModelPicker {
+    pub(super) open: bool,
+    /// Cursor position in the model list.
+    pub(super) cursor: usize,
+    /// Model names offered by the active provider.
+    pub(super) models: Vec<String>,
+}
+
+impl ModelPicker {
+    pub(super) fn new() -> Self {
+        Self {
+            open: false,
+            cursor: 0,
+            models: Vec::new(),
+        }
+    }
+
+    /// Open the popup on `models` with the cursor on `active` when listed.
+    pub(super) fn open_with(&mut self, models: Vec<String>, active: &str) {
+        self.cursor = models.iter().position(|m| m == active).unwrap_or(0);
+        self.models = models;
+        self.open = true;
+    }
+
+    /// Model under the cursor, if any.
+    pub(super) fn selected(&self) -> Option<&str> {
+        self.models.get(self.cursor).map(String::as_str)
+    }
+}
+
 // ── History Browser ─────────────────────────────────────────────────────────
 
 /// Overlay for browsing and managing saved debug chat sessions.
@@ -236,14 +315,78 @@ impl SettingsPanel {
     }
 }
 
-pub(super) const SLASH_COMMANDS: [(&str, &str); 11] = [
+/// Key in the postcard config's `custom` map that stores the Markdown toggle.
+pub const RENDER_MARKDOWN_KEY: &str = "tui.render_markdown";
+
+/// Key in the postcard config's `custom` map that turns the quit confirmation
+/// on or off.
+pub const CONFIRM_EXIT_KEY: &str = "tui.confirm_exit";
+
+/// Display preferences for the chat TUI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TuiOptions {
+    /// Render assistant replies as Markdown instead of raw text.
+    pub render_markdown: bool,
+    /// Ask before quitting while a reply is pending or turns are unsaved.
+    pub confirm_exit: bool,
+    /// Start in agent mode (`/agent` still switches). Not stored in
+    /// `custom`: it comes from `stdio_default_agent_mode` and `--no-agent`.
+    pub agent_mode: bool,
+}
+
+impl Default for TuiOptions {
+    fn default() -> Self {
+        Self {
+            render_markdown: true,
+            confirm_exit: true,
+            agent_mode: true,
+        }
+    }
+}
+
+impl TuiOptions {
+    /// Read options persisted in the postcard config's `custom` map.
+    pub fn from_custom(custom: &HashMap<String, String>) -> Self {
+        let defaults = Self::default();
+        Self {
+            render_markdown: custom
+                .get(RENDER_MARKDOWN_KEY)
+                .and_then(|value| value.trim().parse().ok())
+                .unwrap_or(defaults.render_markdown),
+            confirm_exit: custom
+                .get(CONFIRM_EXIT_KEY)
+                .and_then(|value| value.trim().parse().ok())
+                .unwrap_or(defaults.confirm_exit),
+            agent_mode: defaults.agent_mode,
+        }
+    }
+}
+
+pub(super) const SLASH_COMMANDS: [(&str, &str); 16] = [
     ("help", "Tampilkan perintah yang tersedia"),
     ("providers", "Tampilkan provider dan model yang tersedia"),
     ("use", "Pilih provider aktif: /use <provider> [model]"),
     ("model", "Ganti model provider aktif: /model <nama-model>"),
     ("config", "Ringkasan provider, prompt, tools, dan server"),
+    (
+        "whoami",
+        "Ringkasan runtime efektif tanpa rahasia (provider, tools, protokol)",
+    ),
     ("tools", "Daftar tools aktif pada sesi ini"),
+    (
+        "servers",
+        "Daftar server MCP, aktifkan/nonaktifkan: /servers [<nama> on|off]",
+    ),
+    ("sync", "Sinkronkan tools dengan server MCP: /sync [prune]"),
     ("agent", "Toggle atau set mode agent: /agent on|off|toggle"),
+    (
+        "markdown",
+        "Render Markdown pada jawaban: /markdown on|off|toggle",
+    ),
+    (
+        "steps",
+        "Tampilkan input/output langkah tool: /steps on|off|toggle",
+    ),
     ("reset", "Mulai sesi baru dan hapus riwayat UI"),
     ("clear", "Alias untuk /reset"),
     ("history", "Buka browser riwayat sesi chat (F3)"),
@@ -256,6 +399,8 @@ pub(crate) enum UiTone {
     Assistant,
     System,
     Error,
+    /// Tool steps of an agent turn.
+    Tool,
 }
 
 #[derive(Debug, Clone)]
@@ -263,6 +408,9 @@ pub(crate) struct UiMessage {
     pub(super) title: String,
     pub(super) body: String,
     pub(super) tone: UiTone,
+    /// Longer form of `body`, shown instead of it while tool step details
+    /// are expanded (`/steps`).
+    pub(super) detail: Option<String>,
 }
 
 impl UiMessage {
@@ -271,6 +419,20 @@ impl UiMessage {
             title: title.into(),
             body: body.into(),
             tone,
+            detail: None,
+        }
+    }
+
+    pub(super) fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// The text to show, given whether details are expanded.
+    pub(super) fn text(&self, expanded: bool) -> &str {
+        match &self.detail {
+            Some(detail) if expanded => detail,
+            _ => &self.body,
         }
     }
 }
@@ -1,13 +1,19 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::CliError;
 use crate::CliResult;
+use crate::domain::entities::PROVIDER_TYPES;
 use crate::infrastructure::llm::ModelProviderConfig;
 use crate::infrastructure::llm::build_provider_from_configs;
-use antikythera_core::application::tooling::BuiltinTransport;
-use antikythera_core::infrastructure::model::DynamicModelProvider;
-use antikythera_core::{AppConfig, ClientConfig, McpClient};
+use antikythera_core::application::tooling::{
+    BuiltinTransport, ToolInvokeError, spawn_and_list_tools,
+};
+use antikythera_core::config::ServerConfig;
+use antikythera_core::domain::types::{ChatMessage, MessageRole};
+use antikythera_core::infrastructure::model::{DynamicModelProvider, ModelProvider};
+use antikythera_core::{AppConfig, ClientConfig, McpClient, ProviderLogger};
 
 pub fn build_runtime_client(
     config: &AppConfig,
@@ -15,18 +21,74 @@ pub fn build_runtime_client(
     builtin_transports: HashMap<String, Arc<BuiltinTransport>>,
 ) -> CliResult<Arc<McpClient<DynamicModelProvider>>> {
     let provider = build_provider_from_configs(providers)
-        .map_err(|error| CliError::Validation(error.user_message()))?;
+        .map_err(|error| CliError::Provider(error.user_message()))?;
+    client_with_provider(config, provider, builtin_transports)
+}
+
+/// Client with no model providers, for starting the TUI on its provider
+/// setup screen. Chats fail with [`ModelError::NoProviders`] until a
+/// provider is selected and the client is rebuilt.
+///
+/// [`ModelError::NoProviders`]: antikythera_core::infrastructure::model::ModelError::NoProviders
+pub fn build_setup_client(
+    config: &AppConfig,
+    builtin_transports: HashMap<String, Arc<BuiltinTransport>>,
+) -> CliResult<Arc<McpClient<DynamicModelProvider>>> {
+    client_with_provider(config, DynamicModelProvider::new(), builtin_transports)
+}
 
+/// Fails when the `seed_history` file cannot be read or parsed.
+fn client_with_provider(
+    config: &AppConfig,
+    provider: DynamicModelProvider,
+    builtin_transports: HashMap<String, Arc<BuiltinTransport>>,
+) -> CliResult<Arc<McpClient<DynamicModelProvider>>> {
+    let seed_history = config
+        .load_seed_history()
+        .map_err(|error| CliError::Config(error.to_string()))?;
     let mut client_config =
         ClientConfig::new(config.default_provider.clone(), config.model.clone())
             .with_tools(config.tools.clone())
             .with_servers(config.servers.clone())
-            .with_prompts(config.prompts.clone());
+            .with_prompts(config.prompts.clone())
+            .with_case_sensitive_tools(config.case_sensitive_tools)
+            .with_terminal_tools(config.terminal_tools.clone())
+            .with_session_title(config.session_title.clone())
+            .with_seed_history(seed_history)
+            .with_reasoning(config.reasoning.clone());
 
     if let Some(system) = config.system_prompt.clone() {
         client_config = client_config.with_system_prompt(system);
     }
 
+    if let Some(public_tools) = config.public_tools.clone() {
+        client_config = client_config.with_public_tools(public_tools);
+    }
+
+    if let Some(audit_log) = config.audit_log.clone() {
+        client_config = client_config.with_audit_log(audit_log);
+    }
+
+    if let Some(ttl) = config.session_ttl_secs {
+        client_config = client_config.with_session_ttl(Duration::from_secs(ttl));
+    }
+
+    if let Some(ttl) = config.tool_cache_ttl_secs {
+        client_config = client_config.with_tool_cache_ttl(Duration::from_secs(ttl));
+    }
+
+    if let Some(max) = config.max_prompt_chars {
+        client_config = client_config.with_max_prompt_chars(max);
+    }
+
+    if let Some(steps) = config.agent_max_steps {
+        client_config = client_config.with_max_steps(steps);
+    }
+
+    for (provider, prompts) in config.provider_prompts.clone() {
+        client_config = client_config.with_provider_prompts(provider, prompts);
+    }
+
     for (name, transport) in builtin_transports {
         client_config = client_config.with_builtin_transport(name, transport);
     }
@@ -34,6 +96,135 @@ pub fn build_runtime_client(
     Ok(Arc::new(McpClient::new(provider, client_config)))
 }
 
+/// Key in the postcard config's `custom` map that turns on provider warmup
+/// at startup (same as passing `--warmup`).
+pub const WARMUP_KEY: &str = "providers.warmup";
+
+/// Prompt of the warmup request; the reply is discarded.
+const WARMUP_PROMPT: &str = "ping";
+
+/// Outcome of preloading one provider's model.
+#[derive(Debug)]
+pub struct WarmupReport {
+    pub provider: String,
+    pub model: String,
+    pub elapsed: Duration,
+    /// The provider's error, if the request failed.
+    pub error: Option<String>,
+}
+
+/// Provider/model pairs to preload: the selected model of the active
+/// provider and the first configured model of every other provider.
+/// Providers without models are skipped.
+pub fn warmup_targets(
+    config: &AppConfig,
+    providers: &[ModelProviderConfig],
+) -> Vec<(String, String)> {
+    providers
+        .iter()
+        .filter_map(|provider| {
+            let model = if provider.id == config.default_provider {
+                Some(config.model.clone())
+            } else {
+                provider.models.first().map(|model| model.name.clone())
+            };
+            model.map(|model| (provider.id.clone(), model))
+        })
+        .collect()
+}
+
+/// Send a one-word request to every target at once so local backends such
+/// as Ollama load their model before the first real request. Failures are
+/// logged and reported, never returned as errors.
+pub async fn warm_up_providers<P: ModelProvider>(
+    client: &McpClient<P>,
+    targets: Vec<(String, String)>,
+) -> Vec<WarmupReport> {
+    let requests = targets.into_iter().map(|(provider, model)| async move {
+        let started = Instant::now();
+        let result = client
+            .complete_once(
+                Some(provider.clone()),
+                Some(model.clone()),
+                vec![ChatMessage::new(MessageRole::User, WARMUP_PROMPT)],
+            )
+            .await;
+        let report = WarmupReport {
+            provider,
+            model,
+            elapsed: started.elapsed(),
+            error: result.err().map(|error| error.to_string()),
+        };
+        let log = ProviderLogger::new(&antikythera_core::get_active_session());
+        match &report.error {
+            None => log.info(format!(
+                "Warmup finished | provider={} model={} latency_ms={}",
+                report.provider,
+                report.model,
+                report.elapsed.as_millis()
+            )),
+            Some(error) => log.warn(format!(
+                "Warmup failed | provider={} model={} error={}",
+                report.provider, report.model, error
+            )),
+        }
+        report
+    });
+    futures::future::join_all(requests).await
+}
+
+/// Longest `--check-servers` waits for one server to start and list its tools.
+pub const SERVER_CHECK_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Outcome of starting one configured MCP server for `--check-servers`.
+#[derive(Debug)]
+pub struct ServerCheck {
+    pub name: String,
+    /// Tools the server offers after its allow/block lists, when it answered.
+    pub tools: Option<usize>,
+    /// Why the server could not be reached.
+    pub error: Option<String>,
+}
+
+impl ServerCheck {
+    pub fn reachable(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Start and initialize every server at once and list its tools, giving
+/// each at most `timeout`. Disabled servers are reported, not started.
+/// Results keep config order.
+pub async fn check_servers(servers: &[ServerConfig], timeout: Duration) -> Vec<ServerCheck> {
+    let checks = servers.iter().map(|server| async move {
+        let result = if !server.enabled {
+            Err(ToolInvokeError::Disabled {
+                server: server.name.clone(),
+            }
+            .to_string())
+        } else {
+            match tokio::time::timeout(timeout, spawn_and_list_tools(server)).await {
+                Ok(Ok(tools)) => Ok(tools.len()),
+                Ok(Err(error)) => Err(error.to_string()),
+                Err(_) => Err(format!("timed out after {}s", timeout.as_secs())),
+            }
+        };
+        match result {
+            Ok(tools) => ServerCheck {
+                name: server.name.clone(),
+                tools: Some(tools),
+                error: None,
+            },
+            Err(error) => ServerCheck {
+                name: server.name.clone(),
+                tools: None,
+                error: Some(error),
+            },
+        }
+    });
+    futures::future::join_all(checks).await
+}
+
 pub fn materialize_runtime_config(
     base: &AppConfig,
     initial_providers: &[ModelProviderConfig],
@@ -200,34 +391,27 @@ fn default_model_for_provider(_provider_id: &str) -> &'static str {
     ""
 }
 
+/// Provider entry for a built-in provider type named `provider_id`, filled
+/// from [`PROVIDER_TYPES`]; `None` for any other id.
 #[doc(hidden)]
 pub fn default_provider_template(provider_id: &str) -> Option<ModelProviderConfig> {
-    match provider_id.to_ascii_lowercase().as_str() {
-        "gemini" => Some(ModelProviderConfig {
-            id: "gemini".to_string(),
-            provider_type: "gemini".to_string(),
-            endpoint: "https://generativelanguage.googleapis.com".to_string(),
-            // Store the env-var *name* — resolve_api_key will look it up.
-            api_key: Some("GEMINI_API_KEY".to_string()),
-            api_path: None,
-            models: vec![],
-        }),
-        "openai" => Some(ModelProviderConfig {
-            id: "openai".to_string(),
-            provider_type: "openai".to_string(),
-            endpoint: "https://api.openai.com".to_string(),
-            api_key: Some("OPENAI_API_KEY".to_string()),
-            api_path: None,
-            models: vec![],
-        }),
-        "ollama" => Some(ModelProviderConfig {
-            id: "ollama".to_string(),
-            provider_type: "ollama".to_string(),
-            endpoint: "http://127.0.0.1:11434".to_string(),
-            api_key: None,
-            api_path: None,
-            models: vec![],
-        }),
-        _ => None,
-    }
+    let id = provider_id.to_ascii_lowercase();
+    let info = PROVIDER_TYPES.iter().find(|info| info.name == id)?;
+    Some(ModelProviderConfig {
+        id: info.name.to_string(),
+        provider_type: info.name.to_string(),
+        endpoint: info.default_endpoint.to_string(),
+        // Store the env-var *name* — resolve_api_key will look it up.
+        api_key: info.api_key_env.map(str::to_string),
+        api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
+        models: vec![],
+    })
 }
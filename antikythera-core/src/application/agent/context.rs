@@ -2,6 +2,7 @@ use serde::Serialize;
 use serde_json::Value;
 
 use crate::application::tooling::{ToolAnnotations, ToolExecution, ToolIcon};
+use crate::config::ToolSchemaDetail;
 
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct ToolContext {
@@ -36,6 +37,55 @@ pub struct ToolDescriptor {
     pub annotations: Option<ToolAnnotations>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub execution: Option<ToolExecution>,
+    /// Why the tool can't be called right now, e.g. its server could not be
+    /// reached when the context was built.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unavailable: Option<String>,
+}
+
+impl ToolDescriptor {
+    /// The input schema as shown in the agent's system instructions, at
+    /// `detail`. `None` when the tool has no schema or `detail` omits it.
+    pub fn schema_hint(&self, detail: ToolSchemaDetail) -> Option<String> {
+        let schema = self.input_schema.as_ref()?;
+        let shown = match detail {
+            ToolSchemaDetail::Full => schema.clone(),
+            ToolSchemaDetail::Summary => summarize_schema(schema),
+            ToolSchemaDetail::Omit => return None,
+        };
+        serde_json::to_string(&shown).ok()
+    }
+}
+
+/// Keep only the structure of a JSON schema: `type`, `required`, and the
+/// nested `properties`, `items`, and `anyOf`/`oneOf`/`allOf` branches.
+fn summarize_schema(schema: &Value) -> Value {
+    let Some(object) = schema.as_object() else {
+        return schema.clone();
+    };
+    let mut summary = serde_json::Map::new();
+    for (key, value) in object {
+        let kept = match key.as_str() {
+            "type" | "required" => value.clone(),
+            "properties" => match value.as_object() {
+                Some(properties) => Value::Object(
+                    properties
+                        .iter()
+                        .map(|(name, property)| (name.clone(), summarize_schema(property)))
+                        .collect(),
+                ),
+                None => continue,
+            },
+            "items" => summarize_schema(value),
+            "anyOf" | "oneOf" | "allOf" => match value.as_array() {
+                Some(branches) => Value::Array(branches.iter().map(summarize_schema).collect()),
+                None => continue,
+            },
+            _ => continue,
+        };
+        summary.insert(key.clone(), kept);
+    }
+    Value::Object(summary)
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1,7 +1,7 @@
 //! Agent directive types for parsing LLM responses.
 
 use serde::Deserialize;
-use serde_json::Value;
+use serde_json::{Value, json};
 
 /// Directive extracted from LLM response.
 ///
@@ -23,3 +23,22 @@ pub enum AgentDirective {
     /// Call multiple tools in parallel.
     CallTools(Vec<(String, Value)>),
 }
+
+impl AgentDirective {
+    /// The directive in the action format the model is asked to answer in.
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Final { response } => json!({ "action": "final", "response": response }),
+            Self::CallTool { tool, input } => {
+                json!({ "action": "call_tool", "tool": tool, "input": input })
+            }
+            Self::CallTools(tools) => json!({
+                "action": "call_tools",
+                "tools": tools
+                    .iter()
+                    .map(|(name, input)| json!({ "name": name, "input": input }))
+                    .collect::<Vec<_>>(),
+            }),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use super::memory::MemoryError;
+use super::models::AgentStep;
 use crate::application::client::McpError;
+use crate::application::locale::{Locale, current_locale, fill};
 use crate::application::tooling::ToolInvokeError;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -15,28 +18,67 @@ pub enum AgentError {
     MaxStepsExceeded,
     #[error("operation timed out")]
     Timeout,
+    #[error("tool '{tool}' was called {repeats} times in a row with the same input")]
+    ToolLoop { tool: String, repeats: usize },
     #[error("memory error: {0}")]
     MemoryError(#[from] MemoryError),
+    #[error("too many agent runs in progress")]
+    Busy,
+    /// A tool call ran past the client's tool timeout and the run was
+    /// aborted (`abort_on_tool_error`). `steps` are the calls completed
+    /// before it.
+    #[error("tool '{tool}' timed out after {after:?}")]
+    ToolTimeout {
+        tool: String,
+        after: Duration,
+        steps: Vec<AgentStep>,
+    },
 }
 
 impl AgentError {
+    /// User-facing message in the process-wide locale.
     pub fn user_message(&self) -> String {
+        self.user_message_in(current_locale())
+    }
+
+    /// User-facing message in `locale`.
+    pub fn user_message_in(&self, locale: Locale) -> String {
+        let text = &locale.strings().errors;
+        match self {
+            AgentError::Client(err) => err.user_message_in(locale),
+            AgentError::Tool(err) => err.user_message_in(locale),
+            AgentError::InvalidResponse(msg) => fill(text.invalid_response, &[("error", msg)]),
+            AgentError::MaxStepsExceeded => text.max_steps_exceeded.to_string(),
+            AgentError::Timeout => text.timeout.to_string(),
+            AgentError::ToolLoop { tool, repeats } => fill(
+                text.tool_loop,
+                &[("tool", tool), ("repeats", &repeats.to_string())],
+            ),
+            AgentError::MemoryError(err) => fill(text.memory, &[("error", &err.to_string())]),
+            AgentError::Busy => text.agent_busy.to_string(),
+            AgentError::ToolTimeout { tool, after, .. } => timeout_message(locale, tool, *after),
+        }
+    }
+
+    /// Tool steps completed before the run failed; empty unless the error
+    /// carries them.
+    pub fn partial_steps(&self) -> &[AgentStep] {
         match self {
-            AgentError::Client(err) => err.user_message(),
-            AgentError::Tool(err) => err.user_message(),
-            AgentError::InvalidResponse(msg) => {
-                format!(
-                    "AI memberikan respons yang tidak dapat dipahami. Coba ulangi instruksi Anda. Error: {}",
-                    msg
-                )
-            }
-            AgentError::MaxStepsExceeded => {
-                "Langkah maksimum terlampaui. Proses dihentikan.".to_string()
-            }
-            AgentError::Timeout => "Operasi timeout. Silakan coba lagi.".to_string(),
-            AgentError::MemoryError(err) => {
-                format!("Error penyimpanan state: {}", err)
-            }
+            AgentError::ToolTimeout { steps, .. } => steps,
+            _ => &[],
+        }
+    }
+
+    /// Error for a tool failure that aborts the run, keeping the steps
+    /// completed so far when the failure is a timeout.
+    pub(super) fn aborted_by(err: ToolError, steps: &mut Vec<AgentStep>) -> Self {
+        match err {
+            ToolError::Timeout { tool, after } => AgentError::ToolTimeout {
+                tool,
+                after,
+                steps: std::mem::take(steps),
+            },
+            other => other.into(),
         }
     }
 }
@@ -53,25 +95,45 @@ pub enum ToolError {
         #[source]
         source: ToolInvokeError,
     },
+    #[error("tool '{tool}' did not finish within {after:?}")]
+    Timeout { tool: String, after: Duration },
 }
 
 impl ToolError {
+    /// Name of the tool the failed call was addressed to.
+    pub fn tool_name(&self) -> &str {
+        match self {
+            ToolError::UnknownTool(name) | ToolError::UnboundTool(name) => name,
+            ToolError::Execution { tool, .. } | ToolError::Timeout { tool, .. } => tool,
+        }
+    }
+
+    /// User-facing message in the process-wide locale.
     pub fn user_message(&self) -> String {
+        self.user_message_in(current_locale())
+    }
+
+    /// User-facing message in `locale`.
+    pub fn user_message_in(&self, locale: Locale) -> String {
+        let text = &locale.strings().errors;
         match self {
-            ToolError::UnknownTool(name) => {
-                format!("Tool \"{name}\" belum tersedia di server.")
-            }
-            ToolError::UnboundTool(name) => {
-                format!(
-                    "Tool \"{name}\" belum terhubung ke MCP server apa pun. Mohon periksa konfigurasi client."
-                )
-            }
-            ToolError::Execution { tool, source } => {
-                format!(
-                    "Eksekusi tool \"{tool}\" gagal: {message}",
-                    message = source
-                )
-            }
+            ToolError::UnknownTool(name) => fill(text.unknown_tool, &[("tool", name)]),
+            ToolError::UnboundTool(name) => fill(text.unbound_tool, &[("tool", name)]),
+            ToolError::Execution { tool, source } => fill(
+                text.tool_execution,
+                &[("tool", tool), ("error", &source.to_string())],
+            ),
+            ToolError::Timeout { tool, after } => timeout_message(locale, tool, *after),
         }
     }
 }
+
+fn timeout_message(locale: Locale, tool: &str, after: Duration) -> String {
+    fill(
+        locale.strings().errors.tool_timeout,
+        &[
+            ("tool", tool),
+            ("seconds", &after.as_secs_f64().to_string()),
+        ],
+    )
+}
@@ -13,9 +13,11 @@ use super::directive::AgentDirective;
 use super::errors::AgentError;
 use super::memory::MemoryProvider;
 use super::models::{AgentOptions, AgentOutcome, AgentStep};
-use super::runtime::ToolRuntime;
 use super::runtime::json_retry::MAX_JSON_RETRIES;
+use super::runtime::{ToolRuntime, normalize_final_response};
+use super::scratchpad::Scratchpad;
 use super::state::{AgentState, Event, TerminationReason};
+use super::tool_summary::ToolResultSummarizer;
 use crate::application::client::{ChatRequest, McpClient};
 use crate::application::model_provider::ModelProvider;
 use crate::logging::AgentLogger;
@@ -39,15 +41,40 @@ impl<P: ModelProvider> FsmAgent<P> {
     pub fn new(client: Arc<McpClient<P>>, memory: Arc<dyn MemoryProvider>) -> Self {
         let tools = client.tools().to_vec();
         let bridge = client.server_bridge();
+        let audit_log = client.audit_log().cloned();
+        let case_sensitive_tools = client.case_sensitive_tools();
+        let tool_timeout = client.tool_timeout();
+        let tool_cache = client.tool_cache();
+        let output_guard = client
+            .prompts()
+            .guard_tool_output()
+            .then(|| client.prompts().untrusted_tool_output_notice().to_string());
         Self {
             client,
-            runtime: ToolRuntime::new(tools, bridge),
+            runtime: ToolRuntime::new(tools, bridge)
+                .with_case_sensitive_names(case_sensitive_tools)
+                .with_audit_log(audit_log)
+                .with_output_guard(output_guard)
+                .with_timeout(tool_timeout)
+                .with_result_cache(tool_cache),
             memory,
         }
     }
 
     /// Run agent with automatic state persistence
     pub async fn run(
+        &self,
+        prompt: String,
+        options: AgentOptions,
+    ) -> Result<AgentOutcome, AgentError> {
+        self.client.check_prompt(&prompt)?;
+        let key = options.idempotency_key.clone();
+        self.client
+            .run_idempotent(key.as_deref(), self.run_fresh(prompt, options))
+            .await
+    }
+
+    async fn run_fresh(
         &self,
         prompt: String,
         mut options: AgentOptions,
@@ -57,6 +84,7 @@ impl<P: ModelProvider> FsmAgent<P> {
             .clone()
             .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
 
+        let _slot = self.client.acquire_agent_slot().await?;
         let log = AgentLogger::new(&context_id);
         log.info(format!(
             "Starting FSM-driven agent execution | context_id={}",
@@ -116,7 +144,10 @@ impl<P: ModelProvider> FsmAgent<P> {
         );
         let mut steps = Vec::new();
         let mut logs = Vec::new();
-        let mut remaining_steps = options.max_steps as u32;
+        let mut remaining_steps = options
+            .max_steps
+            .unwrap_or_else(|| self.client.default_max_steps())
+            as u32;
         let mut transient_retries = 0u32;
 
         // Prepare initial context.  When resuming with an empty prompt the
@@ -130,9 +161,10 @@ impl<P: ModelProvider> FsmAgent<P> {
                 Some(&prompt)
             })
             .await;
-        let instructions = self
-            .runtime
-            .compose_system_instructions(&context, self.client.prompts());
+        let instructions = self.runtime.compose_system_instructions(
+            &context,
+            &self.client.prompts_for(options.provider.as_deref()),
+        );
         let system_prompt = match options.system_prompt.take() {
             Some(existing) if !existing.trim().is_empty() => {
                 format!("{existing}\n\n{instructions}")
@@ -145,7 +177,8 @@ impl<P: ModelProvider> FsmAgent<P> {
         let mut next_prompt = if prompt.is_empty() {
             String::new()
         } else {
-            self.runtime.initial_user_prompt(prompt.clone(), &context)
+            self.runtime
+                .initial_user_prompt(prompt.clone(), &context, &options.metadata)
         };
         if !prompt.is_empty() {
             logs.push(format!(
@@ -157,6 +190,8 @@ impl<P: ModelProvider> FsmAgent<P> {
         let mut system_prompt_to_send = Some(system_prompt);
         let mut first_call = true;
         let initial_attachments = std::mem::take(&mut options.attachments);
+        let mut scratchpad = Scratchpad::from_options(&options);
+        let summarizer = ToolResultSummarizer::from_options(&options);
         #[cfg(feature = "native-transport")]
         let mut system = System::new();
 
@@ -206,6 +241,9 @@ impl<P: ModelProvider> FsmAgent<P> {
                         raw_mode: false,
                         bypass_template: true,
                         force_json: true,
+                        provider: options.provider.clone(),
+                        model: options.model.clone(),
+                        metadata: options.metadata.clone(),
                     };
 
                     match self.client.chat(request).await {
@@ -222,6 +260,8 @@ impl<P: ModelProvider> FsmAgent<P> {
                                     &self.client,
                                     &mut logs,
                                     &session_id,
+                                    &options.provider,
+                                    &options.model,
                                 )
                                 .await
                             {
@@ -266,7 +306,7 @@ impl<P: ModelProvider> FsmAgent<P> {
                     log.info(format!("Executing tool | tool={}", tool_id));
 
                     match self.runtime.execute(tool_id, input.clone()).await {
-                        Ok(execution) => {
+                        Ok(mut execution) => {
                             logs.push(format!(
                                 "Tool '{}' executed (success: {})",
                                 execution.tool, execution.success
@@ -285,7 +325,11 @@ impl<P: ModelProvider> FsmAgent<P> {
                                 success: execution.success,
                                 output: execution.output.clone(),
                                 message: execution.message.clone(),
+                                cached: execution.cached,
                             });
+                            if let Some(scratchpad) = scratchpad.as_mut() {
+                                scratchpad.record(&steps[steps.len() - 1]);
+                            }
 
                             // Save state after tool execution (pause point)
                             self.save_intermediate_state(
@@ -296,24 +340,30 @@ impl<P: ModelProvider> FsmAgent<P> {
                             )
                             .await?;
 
+                            if let Some(summarizer) = &summarizer {
+                                summarizer
+                                    .apply(&self.client, &mut execution, &mut logs)
+                                    .await;
+                            }
+
                             // Prepare tool result prompt
-                            let tool_result_instruction =
-                                self.client.prompts().tool_result_instruction();
-                            next_prompt = json!({
-                                "tool_result": {
-                                    "tool": execution.tool,
-                                    "input": execution.input,
-                                    "success": execution.success,
-                                    "output": execution.output,
-                                    "message": execution.message,
-                                },
+                            let tool_result_instruction = self
+                                .client
+                                .prompts_for(options.provider.as_deref())
+                                .tool_result_instruction()
+                                .to_string();
+                            let mut payload = json!({
+                                "tool_result": self.runtime.tool_result_payload(&execution),
                                 "instruction": tool_result_instruction,
-                            })
-                            .to_string();
+                            });
+                            if let Some(scratchpad) = &scratchpad {
+                                payload["scratchpad"] = scratchpad.to_value();
+                            }
+                            next_prompt = payload.to_string();
 
                             state = state.transition(Event::ToolCompleted {
                                 tool: execution.tool.clone(),
-                                output: execution.output.clone(),
+                                output: steps[steps.len() - 1].output.clone(),
                             });
                         }
                         Err(e) => {
@@ -390,6 +440,7 @@ impl<P: ModelProvider> FsmAgent<P> {
 
                 // response is already a serde_json::Value — work with it directly
                 // rather than round-tripping through a string.
+                let response = normalize_final_response(response);
                 let (content, data, metadata) = match response {
                     Value::String(s) => (s, None, None),
                     Value::Object(ref obj) => {
@@ -0,0 +1,83 @@
+//! Detection of an agent repeating the same tool call, bounded by
+//! [`AgentOptions::max_identical_tool_calls`].
+//!
+//! Once the limit of identical consecutive calls has run, the next identical
+//! request is not executed: the model gets a nudge instead. Repeating the call
+//! after that nudge ends the run with [`AgentError::ToolLoop`].
+//!
+//! [`AgentError::ToolLoop`]: super::errors::AgentError::ToolLoop
+
+use super::models::AgentOptions;
+use serde_json::{Value, json};
+
+/// What the runner should do with a requested call.
+#[derive(Debug, PartialEq)]
+pub(super) enum LoopCheck {
+    /// Execute the call.
+    Run,
+    /// Skip the call and send [`nudge_payload`] back to the model.
+    Nudge,
+    /// The model repeated the call after being nudged.
+    Abort,
+}
+
+pub(super) struct LoopGuard {
+    limit: usize,
+    last: Option<Value>,
+    repeats: usize,
+    nudged: bool,
+}
+
+impl LoopGuard {
+    /// A guard for this run, or `None` when the option is 0.
+    pub(super) fn from_options(options: &AgentOptions) -> Option<Self> {
+        (options.max_identical_tool_calls > 0).then_some(Self {
+            limit: options.max_identical_tool_calls,
+            last: None,
+            repeats: 0,
+            nudged: false,
+        })
+    }
+
+    /// Check the next requested batch of `(tool, input)` calls; a single call
+    /// is a batch of one. Tool names are compared ignoring case.
+    pub(super) fn check(&mut self, calls: &[(String, Value)]) -> LoopCheck {
+        let signature = Value::from(
+            calls
+                .iter()
+                .map(|(tool, input)| json!([tool.to_lowercase(), input]))
+                .collect::<Vec<_>>(),
+        );
+        if self.last.as_ref() != Some(&signature) {
+            self.last = Some(signature);
+            self.repeats = 1;
+            self.nudged = false;
+            return LoopCheck::Run;
+        }
+        if self.repeats < self.limit {
+            self.repeats += 1;
+            return LoopCheck::Run;
+        }
+        if self.nudged {
+            return LoopCheck::Abort;
+        }
+        self.nudged = true;
+        LoopCheck::Nudge
+    }
+
+    /// Identical consecutive calls executed so far.
+    pub(super) fn repeats(&self) -> usize {
+        self.repeats
+    }
+}
+
+/// Prompt sent instead of running a repeated call.
+pub(super) fn nudge_payload(tool: &str, repeats: usize, instruction: &str) -> Value {
+    json!({
+        "loop_warning": format!(
+            "'{tool}' was already called {repeats} times in a row with the same input and was not run again. \
+             Use the results you already have to give the final response, or call a different tool or input."
+        ),
+        "instruction": instruction,
+    })
+}
@@ -24,13 +24,16 @@ mod context;
 mod directive;
 mod errors;
 mod fsm_runner;
+mod loop_guard;
 mod memory;
 mod models;
 mod runner;
 mod runtime;
+mod scratchpad;
 mod state;
 mod state_persistence;
 mod terminal_handler;
+mod tool_summary;
 
 #[cfg(feature = "multi-agent")]
 pub mod multi_agent;
@@ -41,6 +44,7 @@ pub use fsm_runner::FsmAgent;
 pub use memory::{
     AgentStateSnapshot, MemoryError, MemoryProvider, STATE_SCHEMA_VERSION, StateMetadata,
 };
+pub(crate) use models::DEFAULT_MAX_STEPS;
 pub use models::{AgentOptions, AgentOutcome, AgentStep};
 pub use runner::Agent;
 
@@ -1,8 +1,13 @@
 use crate::domain::types::MessagePart;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 
-const DEFAULT_MAX_STEPS: usize = 8;
+/// Step budget of a run when neither its options nor the client set one.
+pub(crate) const DEFAULT_MAX_STEPS: usize = 8;
+const DEFAULT_SCRATCHPAD_MAX_CHARS: usize = 2000;
+const DEFAULT_SUMMARIZE_THRESHOLD_CHARS: usize = 4000;
+const DEFAULT_MAX_IDENTICAL_TOOL_CALLS: usize = 3;
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct AgentStep {
@@ -11,6 +16,8 @@ pub struct AgentStep {
     pub success: bool,
     pub output: Value,
     pub message: Option<String>,
+    /// The result came from the tool result cache; the server was not called.
+    pub cached: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -19,15 +26,89 @@ pub struct AgentOutcome {
     pub session_id: String,
     pub response: Value,
     pub steps: Vec<AgentStep>,
+    /// Title of the run's session; `None` when the run did not title it.
+    pub title: Option<String>,
+    /// The model repeated a tool call past
+    /// [`AgentOptions::max_identical_tool_calls`] and was told to stop.
+    pub loop_detected: bool,
+    /// The step budget ran out and the response comes from the forced final
+    /// turn of [`AgentOptions::force_final_on_step_limit`].
+    pub hit_step_limit: bool,
+    /// The action the model chose on each turn, in order, when
+    /// [`AgentOptions::trace`] is set; `None` otherwise.
+    pub reasoning_trace: Option<Vec<Value>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct AgentOptions {
     pub system_prompt: Option<String>,
     pub session_id: Option<String>,
-    #[serde(default = "default_max_steps")]
-    pub max_steps: usize,
+    /// Step budget of the run; `None` uses the client's
+    /// [`McpClient::default_max_steps`](crate::application::client::McpClient::default_max_steps).
+    #[serde(default)]
+    pub max_steps: Option<usize>,
     pub attachments: Vec<MessagePart>,
+    /// Abort the whole run on the first tool failure instead of reporting the
+    /// failure back to the model as an unsuccessful `tool_result`.
+    #[serde(default)]
+    pub abort_on_tool_error: bool,
+    /// Provider ID used for every model call in the run; `None` uses the
+    /// client's default provider.
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Model used for every model call in the run; `None` uses the client's
+    /// default model.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Opaque, caller-supplied context sent with every model call of the run
+    /// (see [`ChatRequest::metadata`](crate::application::client::ChatRequest::metadata))
+    /// and added to the initial `user_request` payload as `metadata`.
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    /// Send a running summary of earlier tool steps with every tool-result
+    /// prompt, not only the latest result.
+    #[serde(default)]
+    pub scratchpad: bool,
+    /// Size bound of the scratchpad in characters; the oldest steps are
+    /// dropped first.
+    #[serde(default = "default_scratchpad_max_chars")]
+    pub scratchpad_max_chars: usize,
+    /// Replace tool outputs longer than `summarize_threshold_chars` with a
+    /// model-written summary before sending them back to the model. Costs
+    /// one extra model call per oversized result; `AgentStep.output` keeps
+    /// the full output.
+    #[serde(default)]
+    pub summarize_tool_results: bool,
+    /// Model for those summaries; `None` uses the run's model.
+    #[serde(default)]
+    pub summarizer_model: Option<String>,
+    /// Serialized output length, in characters, above which a result is
+    /// summarized.
+    #[serde(default = "default_summarize_threshold_chars")]
+    pub summarize_threshold_chars: usize,
+    /// Identical consecutive tool calls (same tool and input) that are run.
+    /// The next one is skipped and the model is told it is looping; a
+    /// further repeat fails the run with `AgentError::ToolLoop`. 0 turns
+    /// detection off.
+    #[serde(default = "default_max_identical_tool_calls")]
+    pub max_identical_tool_calls: usize,
+    /// Caller-chosen key identifying this run across retries. A run with a
+    /// key already used on the same client returns that run's outcome
+    /// instead of calling the model and tools again, for as long as the
+    /// client's `idempotency_window`. Failed runs are not remembered.
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+    /// When `max_steps` runs out, make one more model call asking for a
+    /// final answer from what was gathered so far, instead of failing the
+    /// run. The outcome then has `hit_step_limit` set.
+    #[serde(default)]
+    pub force_final_on_step_limit: bool,
+    /// Record the action the model chose on every turn, including turns
+    /// whose tool calls were skipped, in [`AgentOutcome::reasoning_trace`].
+    /// Nothing is redacted: tool inputs and answers appear as the model
+    /// wrote them, so a trace can hold whatever the conversation held.
+    #[serde(default)]
+    pub trace: bool,
 }
 
 impl Default for AgentOptions {
@@ -35,12 +116,33 @@ impl Default for AgentOptions {
         Self {
             system_prompt: None,
             session_id: None,
-            max_steps: default_max_steps(),
+            max_steps: None,
             attachments: Vec::new(),
+            abort_on_tool_error: false,
+            provider: None,
+            model: None,
+            metadata: HashMap::new(),
+            scratchpad: false,
+            scratchpad_max_chars: default_scratchpad_max_chars(),
+            summarize_tool_results: false,
+            summarizer_model: None,
+            summarize_threshold_chars: default_summarize_threshold_chars(),
+            max_identical_tool_calls: default_max_identical_tool_calls(),
+            idempotency_key: None,
+            force_final_on_step_limit: false,
+            trace: false,
         }
     }
 }
 
-fn default_max_steps() -> usize {
-    DEFAULT_MAX_STEPS
+fn default_scratchpad_max_chars() -> usize {
+    DEFAULT_SCRATCHPAD_MAX_CHARS
+}
+
+fn default_summarize_threshold_chars() -> usize {
+    DEFAULT_SUMMARIZE_THRESHOLD_CHARS
+}
+
+fn default_max_identical_tool_calls() -> usize {
+    DEFAULT_MAX_IDENTICAL_TOOL_CALLS
 }
@@ -55,7 +55,10 @@ pub(super) async fn execute_task<P: ModelProvider>(
         guardrails,
         concurrency_wait_ms,
     } = runtime;
-    let max_steps = task.max_steps.or(profile.max_steps).unwrap_or(8);
+    let max_steps = task
+        .max_steps
+        .or(profile.max_steps)
+        .unwrap_or_else(|| client.default_max_steps());
     let budgeted_max_steps = task
         .budget_steps
         .map(|b| b.min(max_steps))
@@ -183,8 +186,9 @@ pub(super) async fn execute_task<P: ModelProvider>(
         let options = AgentOptions {
             system_prompt: profile.system_prompt.clone(),
             session_id: task.session_id.clone(),
-            max_steps: budgeted_max_steps,
+            max_steps: Some(budgeted_max_steps),
             attachments: Vec::new(),
+            ..AgentOptions::default()
         };
 
         log.info(format!(
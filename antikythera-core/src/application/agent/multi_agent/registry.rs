@@ -36,8 +36,8 @@ pub struct AgentProfile {
     pub system_prompt: Option<String>,
     /// Maximum reasoning steps for this agent.
     ///
-    /// Per-task `max_steps` overrides this value.  Falls back to 8 when both
-    /// are `None`.
+    /// Per-task `max_steps` overrides this value.  Falls back to the client's
+    /// default step budget when both are `None`.
     #[serde(default)]
     pub max_steps: Option<usize>,
 }
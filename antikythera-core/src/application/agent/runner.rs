@@ -1,24 +1,45 @@
 use super::directive::AgentDirective;
 use super::errors::AgentError;
+use super::loop_guard::{LoopCheck, LoopGuard, nudge_payload};
 use super::models::{AgentOptions, AgentOutcome, AgentStep};
-use super::runtime::ToolRuntime;
+use super::runtime::{ToolExecution, ToolRuntime, normalize_final_response};
+use super::scratchpad::Scratchpad;
+use super::tool_summary::ToolResultSummarizer;
 use crate::application::client::{ChatRequest, McpClient};
 use crate::application::model_provider::ModelProvider;
+use crate::application::streaming::{AgentEvent, AgentEventSink, ToolEventPhase};
+use crate::application::tooling::{ToolProgress, with_call_progress};
+use crate::config::PromptsConfig;
 use crate::logging::AgentLogger;
 use serde_json::{Value, json};
+use std::future::Future;
 use std::sync::Arc;
 #[cfg(feature = "native-transport")]
 use sysinfo::System;
 
+/// Prompt of the extra turn made by [`AgentOptions::force_final_on_step_limit`].
+const STEP_LIMIT_INSTRUCTION: &str = "The tool call budget is used up. Do not call any more tools; \
+    reply with a final answer based on what you have gathered so far.";
+
 pub struct Agent<P: ModelProvider> {
     client: Arc<McpClient<P>>,
     runtime: ToolRuntime,
+    events: Option<AgentEventSink>,
 }
 
 impl<P: ModelProvider> Agent<P> {
     pub fn new(client: Arc<McpClient<P>>) -> Self {
         let tools = client.tools().to_vec();
         let bridge = client.server_bridge();
+        let audit_log = client.audit_log().cloned();
+        let case_sensitive_tools = client.case_sensitive_tools();
+        let terminal_tools = client.terminal_tools().to_vec();
+        let tool_timeout = client.tool_timeout();
+        let tool_cache = client.tool_cache();
+        let output_guard = client
+            .prompts()
+            .guard_tool_output()
+            .then(|| client.prompts().untrusted_tool_output_notice().to_string());
         let fallback_keys: Vec<String> = client
             .prompts()
             .fallback_response_keys()
@@ -27,11 +48,67 @@ impl<P: ModelProvider> Agent<P> {
             .collect();
         Self {
             client,
-            runtime: ToolRuntime::new(tools, bridge).with_fallback_keys(fallback_keys),
+            runtime: ToolRuntime::new(tools, bridge)
+                .with_case_sensitive_names(case_sensitive_tools)
+                .with_fallback_keys(fallback_keys)
+                .with_audit_log(audit_log)
+                .with_output_guard(output_guard)
+                .with_timeout(tool_timeout)
+                .with_terminal_tools(terminal_tools)
+                .with_result_cache(tool_cache),
+            events: None,
         }
     }
 
+    /// Report tool starts, progress and finishes and the final answer to
+    /// `sink` while [`run`](Self::run) is in progress.
+    pub fn with_event_sink(mut self, sink: AgentEventSink) -> Self {
+        self.events = Some(sink);
+        self
+    }
+
+    fn emit(&self, event: AgentEvent) {
+        if let Some(sink) = &self.events {
+            sink(&event);
+        }
+    }
+
+    fn emit_tool(&self, tool: &str, phase: ToolEventPhase) {
+        self.emit(AgentEvent::Tool {
+            tool_name: tool.to_string(),
+            phase,
+        });
+    }
+
+    /// Run tool execution `future`, forwarding the progress its calls report
+    /// as [`AgentEvent::ToolProgress`] when an event sink is set.
+    async fn with_tool_progress<F: Future>(&self, future: F) -> F::Output {
+        let Some(events) = self.events.clone() else {
+            return future.await;
+        };
+        let sink = Arc::new(move |progress: &ToolProgress| {
+            events(&AgentEvent::ToolProgress {
+                tool_name: progress.tool.clone().unwrap_or_default(),
+                percent: progress.percent(),
+                message: progress.message.clone(),
+            });
+        });
+        with_call_progress(sink, future).await
+    }
+
     pub async fn run(
+        &self,
+        prompt: String,
+        options: AgentOptions,
+    ) -> Result<AgentOutcome, AgentError> {
+        self.client.check_prompt(&prompt)?;
+        let key = options.idempotency_key.clone();
+        self.client
+            .run_idempotent(key.as_deref(), self.run_fresh(prompt, options))
+            .await
+    }
+
+    async fn run_fresh(
         &self,
         prompt: String,
         mut options: AgentOptions,
@@ -42,15 +119,15 @@ impl<P: ModelProvider> Agent<P> {
                 .as_deref()
                 .unwrap_or(&crate::logging::get_active_session()),
         );
+        let _slot = self.client.acquire_agent_slot().await?;
         log.info("Agent run started");
         let mut session_id = options.session_id.clone();
         let mut steps = Vec::new();
         let mut logs = Vec::new();
 
+        let prompts = self.client.prompts_for(options.provider.as_deref());
         let context = self.runtime.build_context(Some(&prompt)).await;
-        let instructions = self
-            .runtime
-            .compose_system_instructions(&context, self.client.prompts());
+        let instructions = self.runtime.compose_system_instructions(&context, &prompts);
         let system_prompt = match options.system_prompt.take() {
             Some(existing) if !existing.trim().is_empty() => {
                 format!("{existing}\n\n{instructions}")
@@ -65,21 +142,37 @@ impl<P: ModelProvider> Agent<P> {
         ));
 
         let prompt_preview = McpClient::<P>::summarise(&prompt);
-        let mut next_prompt = self.runtime.initial_user_prompt(prompt, &context);
+        let title_source = prompt.clone();
+        let mut next_prompt = self
+            .runtime
+            .initial_user_prompt(prompt, &context, &options.metadata);
         logs.push(format!("Initial agent request: {prompt_preview}"));
 
-        let effective_provider = self.client.default_provider().to_string();
-        let effective_model = self.client.default_model().to_string();
+        let effective_provider = options
+            .provider
+            .clone()
+            .unwrap_or_else(|| self.client.default_provider().to_string());
+        let effective_model = options
+            .model
+            .clone()
+            .unwrap_or_else(|| self.client.default_model().to_string());
         logs.push(format!(
             "Active provider: '{effective_provider}' | Model: '{effective_model}'"
         ));
 
-        let mut remaining_steps = options.max_steps;
+        let mut remaining_steps = options
+            .max_steps
+            .unwrap_or_else(|| self.client.default_max_steps());
         let mut system_prompt_to_send = Some(system_prompt);
         #[cfg(feature = "native-transport")]
         let mut system = System::new();
         let mut first_call = true;
         let initial_attachments = std::mem::take(&mut options.attachments);
+        let mut scratchpad = Scratchpad::from_options(&options);
+        let summarizer = ToolResultSummarizer::from_options(&options);
+        let mut loop_guard = LoopGuard::from_options(&options);
+        let mut loop_detected = false;
+        let mut trace = options.trace.then(Vec::new);
 
         loop {
             #[cfg(feature = "native-transport")]
@@ -123,6 +216,9 @@ impl<P: ModelProvider> Agent<P> {
                 raw_mode: false,
                 bypass_template: true, // Agent composes its own complete system prompt
                 force_json: true,
+                provider: options.provider.clone(),
+                model: options.model.clone(),
+                metadata: options.metadata.clone(),
             };
 
             let result = self.client.chat(request).await?;
@@ -141,8 +237,18 @@ impl<P: ModelProvider> Agent<P> {
             // Parse agent action with retry logic for malformed JSON
             let directive = self
                 .runtime
-                .parse_with_retry(&result.content, &self.client, &mut logs, &session_id)
+                .parse_with_retry(
+                    &result.content,
+                    &self.client,
+                    &mut logs,
+                    &session_id,
+                    &options.provider,
+                    &options.model,
+                )
                 .await?;
+            if let Some(trace) = trace.as_mut() {
+                trace.push(directive.to_value());
+            }
 
             match directive {
                 AgentDirective::Final { response } => {
@@ -150,23 +256,80 @@ impl<P: ModelProvider> Agent<P> {
                         "Agent returned final response | session_id={}",
                         result.session_id.as_str()
                     ));
-                    return Ok(AgentOutcome {
-                        logs,
-                        session_id: result.session_id,
-                        response,
-                        steps,
-                    });
+                    let mut outcome = self
+                        .finish(
+                            result.session_id,
+                            &title_source,
+                            normalize_final_response(response),
+                            logs,
+                            steps,
+                            loop_detected,
+                        )
+                        .await;
+                    outcome.reasoning_trace = trace;
+                    return Ok(outcome);
                 }
                 AgentDirective::CallTool { tool, input } => {
                     if remaining_steps == 0 {
                         log.warn("Agent exceeded max tool interactions");
-                        return Err(AgentError::InvalidResponse(
-                            self.client.prompts().agent_max_steps_error().into(),
-                        ));
+                        if !options.force_final_on_step_limit {
+                            return Err(AgentError::InvalidResponse(
+                                prompts.agent_max_steps_error().into(),
+                            ));
+                        }
+                        let response = self
+                            .forced_final(
+                                &result.session_id,
+                                &options,
+                                &prompts,
+                                &mut logs,
+                                &mut trace,
+                            )
+                            .await?;
+                        let mut outcome = self
+                            .finish(
+                                result.session_id,
+                                &title_source,
+                                response,
+                                logs,
+                                steps,
+                                loop_detected,
+                            )
+                            .await;
+                        outcome.hit_step_limit = true;
+                        outcome.reasoning_trace = trace;
+                        return Ok(outcome);
+                    }
+                    if let Some(guard) = loop_guard.as_mut() {
+                        let call = [(tool.clone(), input.clone())];
+                        if let Some(nudge) =
+                            self.check_loop(guard, &call, &prompts, &mut logs, &log)?
+                        {
+                            loop_detected = true;
+                            next_prompt = nudge;
+                            continue;
+                        }
                     }
                     remaining_steps -= 1;
                     log.info(format!("Agent requested tool execution | tool={}", tool));
-                    let execution = self.runtime.execute(&tool, input).await?;
+                    self.emit_tool(&tool, ToolEventPhase::Started);
+                    let execution = self
+                        .with_tool_progress(self.runtime.execute(&tool, input.clone()))
+                        .await;
+                    let mut execution = match execution {
+                        Ok(execution) => execution,
+                        Err(err) if options.abort_on_tool_error => {
+                            return Err(AgentError::aborted_by(err, &mut steps));
+                        }
+                        Err(err) => {
+                            log.warn(format!(
+                                "Tool execution failed, reporting to model | tool={} error={}",
+                                tool, err
+                            ));
+                            ToolExecution::failed(tool, input, &err)
+                        }
+                    };
+                    self.emit_tool(&execution.tool, ToolEventPhase::Finished);
                     logs.push(format!(
                         "Tool '{}' executed (success: {})",
                         execution.tool, execution.success
@@ -184,29 +347,85 @@ impl<P: ModelProvider> Agent<P> {
                         success: execution.success,
                         output: execution.output.clone(),
                         message: execution.message.clone(),
+                        cached: execution.cached,
                     });
+                    if let Some(scratchpad) = scratchpad.as_mut() {
+                        scratchpad.record(&steps[steps.len() - 1]);
+                    }
+                    if execution.success && self.runtime.is_terminal(&execution.tool) {
+                        log.info(format!(
+                            "Terminal tool succeeded, ending the run | tool={}",
+                            execution.tool
+                        ));
+                        let response = execution.final_response();
+                        let mut outcome = self
+                            .finish(
+                                result.session_id,
+                                &title_source,
+                                response,
+                                logs,
+                                steps,
+                                loop_detected,
+                            )
+                            .await;
+                        outcome.reasoning_trace = trace;
+                        return Ok(outcome);
+                    }
+                    if let Some(summarizer) = &summarizer {
+                        summarizer
+                            .apply(&self.client, &mut execution, &mut logs)
+                            .await;
+                    }
 
                     // Use configurable tool result instruction
-                    // Use configurable tool result instruction
-                    let tool_result_instruction = self.client.prompts().tool_result_instruction();
-                    next_prompt = json!({
-                        "tool_result": {
-                            "tool": execution.tool,
-                            "input": execution.input,
-                            "success": execution.success,
-                            "output": execution.output,
-                            "message": execution.message,
-                        },
+                    let tool_result_instruction = prompts.tool_result_instruction();
+                    let mut payload = json!({
+                        "tool_result": self.runtime.tool_result_payload(&execution),
                         "instruction": tool_result_instruction,
-                    })
-                    .to_string();
+                    });
+                    if let Some(scratchpad) = &scratchpad {
+                        payload["scratchpad"] = scratchpad.to_value();
+                    }
+                    next_prompt = payload.to_string();
                 }
                 AgentDirective::CallTools(tools) => {
                     if remaining_steps == 0 {
                         log.warn("Agent exceeded max tool interactions");
-                        return Err(AgentError::InvalidResponse(
-                            self.client.prompts().agent_max_steps_error().into(),
-                        ));
+                        if !options.force_final_on_step_limit {
+                            return Err(AgentError::InvalidResponse(
+                                prompts.agent_max_steps_error().into(),
+                            ));
+                        }
+                        let response = self
+                            .forced_final(
+                                &result.session_id,
+                                &options,
+                                &prompts,
+                                &mut logs,
+                                &mut trace,
+                            )
+                            .await?;
+                        let mut outcome = self
+                            .finish(
+                                result.session_id,
+                                &title_source,
+                                response,
+                                logs,
+                                steps,
+                                loop_detected,
+                            )
+                            .await;
+                        outcome.hit_step_limit = true;
+                        outcome.reasoning_trace = trace;
+                        return Ok(outcome);
+                    }
+                    if let Some(guard) = loop_guard.as_mut()
+                        && let Some(nudge) =
+                            self.check_loop(guard, &tools, &prompts, &mut logs, &log)?
+                    {
+                        loop_detected = true;
+                        next_prompt = nudge;
+                        continue;
                     }
                     remaining_steps -= 1;
                     log.info(format!(
@@ -214,57 +433,218 @@ impl<P: ModelProvider> Agent<P> {
                         tools.len()
                     ));
 
-                    let executions = self.runtime.clone().execute_parallel(tools).await?;
+                    for (tool, _) in &tools {
+                        self.emit_tool(tool, ToolEventPhase::Started);
+                    }
+                    let requested = tools.clone();
+                    let executions = self
+                        .with_tool_progress(self.runtime.clone().execute_parallel(tools))
+                        .await?;
                     let mut aggregated_results = Vec::new();
+                    let mut terminal = None;
 
-                    for exec_result in executions {
-                        match exec_result {
-                            Ok(execution) => {
-                                logs.push(format!(
-                                    "Tool '{}' executed (success: {})",
-                                    execution.tool, execution.success
-                                ));
-                                if let Some(message) = execution.message.as_deref() {
-                                    logs.push(format!(
-                                        "Tool message: {}",
-                                        McpClient::<P>::summarise(message)
-                                    ));
-                                }
-
-                                steps.push(AgentStep {
-                                    tool: execution.tool.clone(),
-                                    input: execution.input.clone(),
-                                    success: execution.success,
-                                    output: execution.output.clone(),
-                                    message: execution.message.clone(),
-                                });
-
-                                aggregated_results.push(json!({
-                                    "tool": execution.tool,
-                                    "input": execution.input,
-                                    "success": execution.success,
-                                    "output": execution.output,
-                                    "message": execution.message,
-                                }));
+                    for (exec_result, (_, input)) in executions.into_iter().zip(requested) {
+                        let mut execution = match exec_result {
+                            Ok(execution) => execution,
+                            Err(err) if options.abort_on_tool_error => {
+                                return Err(AgentError::aborted_by(err, &mut steps));
                             }
-                            Err(e) => {
-                                log.warn(format!("One of the parallel tools failed: {}", e));
-                                logs.push(format!("Parallel tool failure: {}", e));
+                            Err(err) => {
+                                log.warn(format!("One of the parallel tools failed: {}", err));
+                                logs.push(format!("Parallel tool failure: {}", err));
+                                let tool = err.tool_name().to_string();
+                                ToolExecution::failed(tool, input, &err)
                             }
+                        };
+                        self.emit_tool(&execution.tool, ToolEventPhase::Finished);
+                        logs.push(format!(
+                            "Tool '{}' executed (success: {})",
+                            execution.tool, execution.success
+                        ));
+                        if let Some(message) = execution.message.as_deref() {
+                            logs.push(format!(
+                                "Tool message: {}",
+                                McpClient::<P>::summarise(message)
+                            ));
+                        }
+
+                        steps.push(AgentStep {
+                            tool: execution.tool.clone(),
+                            input: execution.input.clone(),
+                            success: execution.success,
+                            output: execution.output.clone(),
+                            message: execution.message.clone(),
+                            cached: execution.cached,
+                        });
+                        if let Some(scratchpad) = scratchpad.as_mut() {
+                            scratchpad.record(&steps[steps.len() - 1]);
                         }
+                        if terminal.is_none()
+                            && execution.success
+                            && self.runtime.is_terminal(&execution.tool)
+                        {
+                            terminal = Some(execution.final_response());
+                        }
+                        if let Some(summarizer) = &summarizer {
+                            summarizer
+                                .apply(&self.client, &mut execution, &mut logs)
+                                .await;
+                        }
+
+                        aggregated_results.push(self.runtime.tool_result_payload(&execution));
+                    }
+                    if let Some(response) = terminal {
+                        log.info("Terminal tool succeeded in a parallel batch, ending the run");
+                        let mut outcome = self
+                            .finish(
+                                result.session_id,
+                                &title_source,
+                                response,
+                                logs,
+                                steps,
+                                loop_detected,
+                            )
+                            .await;
+                        outcome.reasoning_trace = trace;
+                        return Ok(outcome);
                     }
 
-                    let tool_result_instruction = self.client.prompts().tool_result_instruction();
-                    next_prompt = json!({
+                    let tool_result_instruction = prompts.tool_result_instruction();
+                    let mut payload = json!({
                         "tool_results": aggregated_results,
                         "instruction": tool_result_instruction,
-                    })
-                    .to_string();
+                    });
+                    if let Some(scratchpad) = &scratchpad {
+                        payload["scratchpad"] = scratchpad.to_value();
+                    }
+                    next_prompt = payload.to_string();
                 }
             }
         }
     }
 
+    /// The prompt to send instead of running `calls` when they repeat past
+    /// the limit; an error when the model already ignored that prompt.
+    fn check_loop(
+        &self,
+        guard: &mut LoopGuard,
+        calls: &[(String, Value)],
+        prompts: &PromptsConfig,
+        logs: &mut Vec<String>,
+        log: &AgentLogger,
+    ) -> Result<Option<String>, AgentError> {
+        let tool = calls
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match guard.check(calls) {
+            LoopCheck::Run => Ok(None),
+            LoopCheck::Nudge => {
+                log.warn(format!(
+                    "Agent repeated an identical tool call, asking it to stop | tool={} repeats={}",
+                    tool,
+                    guard.repeats()
+                ));
+                logs.push(format!(
+                    "Repeated call to '{tool}' skipped after {} identical calls",
+                    guard.repeats()
+                ));
+                let instruction = prompts.tool_result_instruction();
+                Ok(Some(
+                    nudge_payload(&tool, guard.repeats(), instruction).to_string(),
+                ))
+            }
+            LoopCheck::Abort => {
+                log.warn(format!(
+                    "Agent kept repeating an identical tool call, stopping | tool={tool}"
+                ));
+                Err(AgentError::ToolLoop {
+                    tool,
+                    repeats: guard.repeats(),
+                })
+            }
+        }
+    }
+
+    /// Ask the model for a final answer once the step budget is spent; an
+    /// error when it still asks for a tool.
+    async fn forced_final(
+        &self,
+        session_id: &str,
+        options: &AgentOptions,
+        prompts: &PromptsConfig,
+        logs: &mut Vec<String>,
+        trace: &mut Option<Vec<Value>>,
+    ) -> Result<Value, AgentError> {
+        logs.push("Step limit reached, asking the model for a final answer".to_string());
+        let request = ChatRequest {
+            prompt: json!({
+                "step_limit_reached": true,
+                "instruction": STEP_LIMIT_INSTRUCTION,
+            })
+            .to_string(),
+            attachments: Vec::new(),
+            system_prompt: None,
+            session_id: Some(session_id.to_string()),
+            raw_mode: false,
+            bypass_template: true,
+            force_json: true,
+            provider: options.provider.clone(),
+            model: options.model.clone(),
+            metadata: options.metadata.clone(),
+        };
+        let result = self.client.chat(request).await?;
+        logs.extend(result.logs.clone());
+        let directive = self
+            .runtime
+            .parse_with_retry(
+                &result.content,
+                &self.client,
+                logs,
+                &Some(result.session_id.clone()),
+                &options.provider,
+                &options.model,
+            )
+            .await?;
+        if let Some(trace) = trace.as_mut() {
+            trace.push(directive.to_value());
+        }
+        match directive {
+            AgentDirective::Final { response } => Ok(normalize_final_response(response)),
+            _ => Err(AgentError::InvalidResponse(
+                prompts.agent_max_steps_error().into(),
+            )),
+        }
+    }
+
+    /// Complete the run with `response` as its final answer.
+    async fn finish(
+        &self,
+        session_id: String,
+        title_source: &str,
+        response: Value,
+        logs: Vec<String>,
+        steps: Vec<AgentStep>,
+        loop_detected: bool,
+    ) -> AgentOutcome {
+        self.emit(AgentEvent::Completed);
+        let title = self
+            .client
+            .ensure_session_title(&session_id, title_source)
+            .await;
+        AgentOutcome {
+            logs,
+            session_id,
+            response,
+            steps,
+            title,
+            loop_detected,
+            hit_step_limit: false,
+            reasoning_trace: None,
+        }
+    }
+
     /// Run agent and return response with embedded tool results.
     pub async fn run_ui_layout(
         &self,
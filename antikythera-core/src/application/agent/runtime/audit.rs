@@ -0,0 +1,110 @@
+//! Append-only audit trail of tool calls.
+//!
+//! Unlike the tracing logs, every line here is a self-contained JSON record
+//! meant to be kept: timestamp, session, tool, (redacted) input, success,
+//! whether the result came from the cache, and a short output summary. Writes run on the blocking pool so a slow disk
+//! never stalls the agent, and a failed write is logged rather than surfaced.
+
+use super::{ToolError, ToolExecution, Value, json};
+use crate::config::AuditLogConfig;
+use crate::logging::AgentLogger;
+use std::io::Write;
+use std::sync::Arc;
+
+const REDACTED: &str = "[REDACTED]";
+const SUMMARY_LIMIT: usize = 200;
+
+pub(crate) struct ToolAuditLog {
+    config: AuditLogConfig,
+    redact_keys: Vec<String>,
+}
+
+impl ToolAuditLog {
+    pub(crate) fn new(config: AuditLogConfig) -> Arc<Self> {
+        let redact_keys = config
+            .redact_keys
+            .iter()
+            .map(|key| key.to_ascii_lowercase())
+            .collect();
+        Arc::new(Self {
+            config,
+            redact_keys,
+        })
+    }
+
+    pub(crate) fn record(
+        self: &Arc<Self>,
+        session_id: &str,
+        tool: &str,
+        input: &Value,
+        result: &Result<ToolExecution, ToolError>,
+    ) {
+        let (success, cached, output) = match result {
+            Ok(execution) => (execution.success, execution.cached, summarize(execution)),
+            Err(error) => (false, false, error.to_string()),
+        };
+        let entry = json!({
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+            "session_id": session_id,
+            "tool": tool,
+            "input": self.redact(input),
+            "success": success,
+            "cached": cached,
+            "output": output,
+        });
+
+        let audit = Arc::clone(self);
+        let session_id = session_id.to_string();
+        tokio::task::spawn_blocking(move || {
+            if let Err(error) = audit.append(&entry) {
+                AgentLogger::new(&session_id).warn(format!(
+                    "Failed to write tool audit log | path={} error={}",
+                    audit.config.path.display(),
+                    error
+                ));
+            }
+        });
+    }
+
+    fn append(&self, entry: &Value) -> std::io::Result<()> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.config.path)?
+            .write_all(&line)
+    }
+
+    fn redact(&self, value: &Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(key, value)| {
+                        let value = if self.redact_keys.contains(&key.to_ascii_lowercase()) {
+                            Value::String(REDACTED.to_string())
+                        } else {
+                            self.redact(value)
+                        };
+                        (key.clone(), value)
+                    })
+                    .collect(),
+            ),
+            Value::Array(items) => Value::Array(items.iter().map(|v| self.redact(v)).collect()),
+            other => other.clone(),
+        }
+    }
+}
+
+fn summarize(execution: &ToolExecution) -> String {
+    let text = match &execution.message {
+        Some(message) => message.clone(),
+        None => execution.output.to_string(),
+    };
+    if text.chars().count() <= SUMMARY_LIMIT {
+        return text;
+    }
+    let mut summary: String = text.chars().take(SUMMARY_LIMIT).collect();
+    summary.push_str("...");
+    summary
+}
@@ -1,11 +1,36 @@
 use crate::logging::AgentLogger;
+use futures::future::join_all;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use super::{ServerGuidance, ToolContext, ToolDescriptor, ToolRuntime};
 use crate::domain::sanitize::sanitize_for_toml;
 
+/// Longest wait for one server to answer the startup probe.
+const SERVER_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl ToolRuntime {
+    /// Probe every server the tools are bound to, all at once, and return
+    /// the ones that could not be reached with the reason.
+    async fn unreachable_servers(&self) -> HashMap<String, String> {
+        let servers: HashSet<&str> = self
+            .configs
+            .iter()
+            .filter_map(|tool| tool.server.as_deref())
+            .collect();
+        let probes = servers.into_iter().map(|server| async move {
+            let outcome =
+                tokio::time::timeout(SERVER_PROBE_TIMEOUT, self.bridge.probe_server(server)).await;
+            let reason = match outcome {
+                Ok(Ok(())) => return None,
+                Ok(Err(err)) => err.to_string(),
+                Err(_) => format!("no answer within {}s", SERVER_PROBE_TIMEOUT.as_secs()),
+            };
+            Some((server.to_string(), reason))
+        });
+        join_all(probes).await.into_iter().flatten().collect()
+    }
+
     pub async fn build_context(&self, input: Option<&str>) -> ToolContext {
         let log = AgentLogger::new(&crate::logging::get_active_session());
         let start_time = Instant::now();
@@ -15,9 +40,22 @@ impl ToolRuntime {
 
         let mut context = ToolContext::default();
         let mut seen_servers = HashSet::new();
+        let unreachable = self.unreachable_servers().await;
+        for (server, reason) in &unreachable {
+            log.warn(format!(
+                "MCP server unreachable, its tools are marked unavailable | server={} error={}",
+                server, reason
+            ));
+        }
 
         for tool in &self.configs {
+            let unavailable = tool.server.as_deref().and_then(|server| {
+                unreachable
+                    .get(server)
+                    .map(|reason| format!("server '{server}' is unreachable: {reason}"))
+            });
             if let Some(server_name) = tool.server.as_deref()
+                && unavailable.is_none()
                 && seen_servers.insert(server_name.to_string())
                 && let Some(instruction) = self.bridge.server_instructions(server_name).await
             {
@@ -37,9 +75,11 @@ impl ToolRuntime {
                 output_schema: None,
                 annotations: None,
                 execution: None,
+                unavailable,
             };
 
             if let Some(server_name) = tool.server.as_deref()
+                && descriptor.unavailable.is_none()
                 && let Some(metadata) = self.bridge.tool_metadata(server_name, &tool.name).await
             {
                 if !metadata.name.is_empty() {
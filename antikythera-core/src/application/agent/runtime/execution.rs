@@ -1,6 +1,7 @@
-use super::{ToolError, ToolInvokeError, ToolRuntime, Value};
+use super::{ToolError, ToolInvokeError, ToolRuntime, Value, index_key, json};
+use crate::application::tool_cache::CachedResult;
 use crate::logging::AgentLogger;
-use futures::stream::{FuturesUnordered, StreamExt};
+use futures::future::join_all;
 use std::time::Instant;
 
 pub(crate) struct ToolExecution {
@@ -9,14 +10,186 @@ pub(crate) struct ToolExecution {
     pub input: Value,
     pub output: Value,
     pub message: Option<String>,
+    /// Served from the tool result cache instead of calling the server.
+    pub cached: bool,
 }
 
+impl ToolExecution {
+    /// The result as an agent's final response: the tool's
+    /// `structuredContent` when it has one, otherwise its text message,
+    /// otherwise the raw output.
+    pub(crate) fn final_response(&self) -> Value {
+        if let Some(structured) = self.output.get("structuredContent") {
+            return structured.clone();
+        }
+        match &self.message {
+            Some(message) => Value::String(message.clone()),
+            None => self.output.clone(),
+        }
+    }
+
+    /// Describe a tool call that never produced a result so the failure can be
+    /// reported to the model like any other tool outcome.
+    pub(crate) fn failed(tool: impl Into<String>, input: Value, error: &ToolError) -> Self {
+        Self {
+            tool: tool.into(),
+            success: false,
+            input,
+            output: Value::Null,
+            message: Some(error.to_string()),
+            cached: false,
+        }
+    }
+}
+
+/// Delimiters around guarded tool output. Occurrences inside the output are
+/// defanged so a tool cannot close the block early.
+const UNTRUSTED_BEGIN: &str = "<<<BEGIN UNTRUSTED TOOL OUTPUT>>>";
+const UNTRUSTED_END: &str = "<<<END UNTRUSTED TOOL OUTPUT>>>";
+
 impl ToolRuntime {
+    /// Describe `execution` for the `tool_result` message sent to the model.
+    ///
+    /// With an output guard configured, `output` and `message` are replaced
+    /// by one labeled, delimited text block. This is a mitigation against
+    /// prompt injection through tool output, not a guarantee: a model may
+    /// still follow instructions found inside the block.
+    pub(crate) fn tool_result_payload(&self, execution: &ToolExecution) -> Value {
+        let Some(notice) = &self.output_guard else {
+            return json!({
+                "tool": execution.tool,
+                "input": execution.input,
+                "success": execution.success,
+                "output": execution.output,
+                "message": execution.message,
+            });
+        };
+
+        let mut body = match &execution.output {
+            Value::Null => String::new(),
+            Value::String(text) => text.clone(),
+            other => serde_json::to_string_pretty(other).unwrap_or_else(|_| other.to_string()),
+        };
+        if let Some(message) = &execution.message
+            && !body.contains(message.as_str())
+        {
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            body.push_str(message);
+        }
+        let body = body
+            .replace(UNTRUSTED_BEGIN, "[delimiter removed]")
+            .replace(UNTRUSTED_END, "[delimiter removed]");
+
+        json!({
+            "tool": execution.tool,
+            "input": execution.input,
+            "success": execution.success,
+            "output": format!("{notice}\n{UNTRUSTED_BEGIN}\n{body}\n{UNTRUSTED_END}"),
+        })
+    }
+
+    /// Run `tool_name`, answering from the result cache when the tool is
+    /// cacheable and was called with the same input before. Cache hits are
+    /// audited too, marked `cached`.
     pub(crate) async fn execute(
         &self,
         tool_name: &str,
         input: Value,
     ) -> Result<ToolExecution, ToolError> {
+        let cache = self.result_cache_for(tool_name);
+        if let Some((cache, tool)) = &cache
+            && let Some(hit) = cache.get(tool, &input)
+        {
+            AgentLogger::new(&crate::logging::get_active_session())
+                .info(format!("Tool result served from cache | tool={tool}"));
+            let result = Ok(ToolExecution {
+                tool: tool.clone(),
+                success: true,
+                input,
+                output: hit.output,
+                message: hit.message,
+                cached: true,
+            });
+            if let (Some(audit), Ok(execution)) = (&self.audit, &result) {
+                audit.record(
+                    &crate::logging::get_active_session(),
+                    tool_name,
+                    &execution.input,
+                    &result,
+                );
+            }
+            return result;
+        }
+        let result = self.execute_audited(tool_name, input).await;
+        if let (Some((cache, tool)), Ok(execution)) = (&cache, &result)
+            && execution.success
+        {
+            cache.insert(
+                tool,
+                &execution.input,
+                CachedResult {
+                    output: execution.output.clone(),
+                    message: execution.message.clone(),
+                },
+            );
+        }
+        result
+    }
+
+    async fn execute_audited(
+        &self,
+        tool_name: &str,
+        input: Value,
+    ) -> Result<ToolExecution, ToolError> {
+        let Some(audit) = &self.audit else {
+            return self.dispatch_within_timeout(tool_name, input).await;
+        };
+        let audited_input = input.clone();
+        let result = self.dispatch_within_timeout(tool_name, input).await;
+        audit.record(
+            &crate::logging::get_active_session(),
+            tool_name,
+            &audited_input,
+            &result,
+        );
+        result
+    }
+
+    async fn dispatch_within_timeout(
+        &self,
+        tool_name: &str,
+        input: Value,
+    ) -> Result<ToolExecution, ToolError> {
+        let Some(after) = self.timeout else {
+            return self.dispatch(tool_name, input).await;
+        };
+        match tokio::time::timeout(after, self.dispatch(tool_name, input)).await {
+            Ok(result) => result,
+            Err(_) => {
+                AgentLogger::new(&crate::logging::get_active_session()).warn(format!(
+                    "Tool execution timed out | tool={} timeout_ms={}",
+                    tool_name,
+                    after.as_millis()
+                ));
+                Err(ToolError::Timeout {
+                    tool: self.canonical_name(tool_name),
+                    after,
+                })
+            }
+        }
+    }
+
+    /// Configured name of the tool `tool_name` resolves to, or `tool_name`
+    /// itself when it is unknown.
+    fn canonical_name(&self, tool_name: &str) -> String {
+        self.index
+            .get(&index_key(tool_name, self.case_sensitive))
+            .map_or_else(|| tool_name.to_string(), |tool| tool.name.clone())
+    }
+
+    async fn dispatch(&self, tool_name: &str, input: Value) -> Result<ToolExecution, ToolError> {
         let log = AgentLogger::new(&crate::logging::get_active_session());
         if tool_name.eq_ignore_ascii_case("list_tools") {
             let manifest = self.build_context(None).await;
@@ -31,6 +204,7 @@ impl ToolRuntime {
                     "Configured tools available: {} item(s).",
                     manifest.tools.len()
                 )),
+                cached: false,
             };
             log.info(format!(
                 "Tool executed | tool={} success={}",
@@ -39,8 +213,11 @@ impl ToolRuntime {
             return Ok(execution);
         }
 
-        let key = tool_name.to_lowercase();
-        let Some(tool) = self.index.get(&key).cloned() else {
+        let Some(tool) = self
+            .index
+            .get(&index_key(tool_name, self.case_sensitive))
+            .cloned()
+        else {
             log.warn(format!(
                 "Unknown tool requested by agent | requested_tool={}",
                 tool_name
@@ -66,14 +243,15 @@ impl ToolRuntime {
             other => other,
         };
 
+        let remote_name = tool.remote_name();
         log.debug(format!(
-            "Dispatching tool via MCP | tool={} server={}",
-            tool_name, server_name
+            "Dispatching tool via MCP | tool={} remote={} server={}",
+            tool_name, remote_name, server_name
         ));
         let start_time = Instant::now();
         match self
             .bridge
-            .invoke_tool(server_name, &tool_name, arguments)
+            .invoke_tool(server_name, remote_name, arguments)
             .await
         {
             Ok(result) => {
@@ -94,6 +272,7 @@ impl ToolRuntime {
                     input,
                     output: result,
                     message,
+                    cached: false,
                 };
                 log.info(format!(
                     "Tool executed | tool={} success={}",
@@ -115,16 +294,15 @@ impl ToolRuntime {
         }
     }
 
+    /// Run `tools` concurrently, bounded by the execution semaphore. The
+    /// results are in the order of `tools`, not of completion.
     pub(crate) async fn execute_parallel(
         &self,
         tools: Vec<(String, Value)>,
     ) -> Result<Vec<Result<ToolExecution, ToolError>>, ToolError> {
-        let mut futures = FuturesUnordered::new();
-
-        for (tool_name, input) in tools {
+        let calls = tools.into_iter().map(|(tool_name, input)| {
             let runtime = self.clone();
-
-            futures.push(async move {
+            async move {
                 // Apply bounded concurrency backpressure using semaphore
                 let _permit = runtime.execution_semaphore.acquire().await.map_err(|_| {
                     ToolError::Execution {
@@ -135,37 +313,37 @@ impl ToolRuntime {
                     }
                 })?;
 
-                // Track execution wait and process times individually
-
                 runtime.execute(&tool_name, input).await
-            });
-        }
-
-        let mut results = Vec::new();
-        while let Some(res) = futures.next().await {
-            results.push(res);
-        }
+            }
+        });
 
-        Ok(results)
+        Ok(join_all(calls).await)
     }
 }
 
+/// Short message for a tool result: its first non-empty text block, followed
+/// by one `[image: <mime>, N bytes]` note per image or audio block so binary
+/// content is not silently dropped. The blocks themselves stay in the output.
 fn extract_tool_message(result: &Value) -> Option<String> {
     if let Some(array) = result.get("content").and_then(Value::as_array) {
+        let mut text = None;
+        let mut notes = Vec::new();
         for block in array {
-            if block
-                .get("type")
-                .and_then(Value::as_str)
-                .map(|value| value.eq_ignore_ascii_case("text"))
-                .unwrap_or(false)
-                && let Some(text) = block.get("text").and_then(Value::as_str)
-            {
-                let trimmed = text.trim();
-                if !trimmed.is_empty() {
-                    return Some(trimmed.to_string());
+            let kind = block.get("type").and_then(Value::as_str).unwrap_or("");
+            if kind.eq_ignore_ascii_case("text") {
+                if text.is_none()
+                    && let Some(value) = block.get("text").and_then(Value::as_str)
+                    && !value.trim().is_empty()
+                {
+                    text = Some(value.trim().to_string());
                 }
+            } else if kind.eq_ignore_ascii_case("image") || kind.eq_ignore_ascii_case("audio") {
+                notes.push(binary_block_note(kind, block));
             }
         }
+        if text.is_some() || !notes.is_empty() {
+            return Some(text.into_iter().chain(notes).collect::<Vec<_>>().join("\n"));
+        }
     }
 
     if let Some(structured) = result.get("structuredContent").and_then(Value::as_object)
@@ -180,3 +358,26 @@ fn extract_tool_message(result: &Value) -> Option<String> {
 
     None
 }
+
+/// `[image: image/png, 1024 bytes]` for a base64 `data` content block.
+fn binary_block_note(kind: &str, block: &Value) -> String {
+    let mime = block
+        .get("mimeType")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown");
+    let data = block.get("data").and_then(Value::as_str).unwrap_or("");
+    format!(
+        "[{}: {mime}, {} bytes]",
+        kind.to_ascii_lowercase(),
+        base64_decoded_len(data)
+    )
+}
+
+/// Decoded size of base64 `data` without decoding it.
+fn base64_decoded_len(data: &str) -> usize {
+    let digits = data
+        .bytes()
+        .filter(|byte| !byte.is_ascii_whitespace() && *byte != b'=')
+        .count();
+    digits * 3 / 4
+}
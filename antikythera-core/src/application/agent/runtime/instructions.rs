@@ -1,5 +1,6 @@
 use super::{ToolContext, ToolRuntime, json};
 use crate::config::PromptsConfig;
+use std::collections::HashMap;
 
 impl ToolRuntime {
     pub fn compose_system_instructions(
@@ -26,6 +27,7 @@ impl ToolRuntime {
         }
 
         if !context.tools.is_empty() {
+            let schema_detail = prompts.tool_schema_detail();
             lines.push("Configured tools:".to_string());
             for descriptor in &context.tools {
                 let mut line = format!("- {}", descriptor.name);
@@ -35,9 +37,14 @@ impl ToolRuntime {
                 if let Some(description) = &descriptor.description {
                     line.push_str(&format!(": {}", description));
                 }
-                if let Some(schema) = &descriptor.input_schema {
-                    let compact = serde_json::to_string(schema).unwrap_or_default();
-                    line.push_str(&format!(". Input schema: {}", compact));
+                if let Some(schema) = descriptor.schema_hint(schema_detail) {
+                    line.push_str(&format!(". Input schema: {}", schema));
+                }
+                if let Some(reason) = &descriptor.unavailable {
+                    line.push_str(&format!(
+                        ". UNAVAILABLE ({}); do not call this tool",
+                        reason
+                    ));
                 }
                 lines.push(line);
             }
@@ -46,12 +53,25 @@ impl ToolRuntime {
         lines.join(" ")
     }
 
-    pub fn initial_user_prompt(&self, prompt: String, context: &ToolContext) -> String {
+    /// The run's first message: the prompt, the tool context, and the
+    /// caller's `metadata` when there is any.
+    pub fn initial_user_prompt(
+        &self,
+        prompt: String,
+        context: &ToolContext,
+        metadata: &HashMap<String, String>,
+    ) -> String {
         let mut payload = json!({
             "action": "user_request",
             "prompt": prompt,
         });
 
+        if !metadata.is_empty()
+            && let Some(map) = payload.as_object_mut()
+        {
+            map.insert("metadata".to_string(), json!(metadata));
+        }
+
         if !context.is_empty()
             && let Some(map) = payload.as_object_mut()
             && let Ok(value) = serde_json::to_value(context)
@@ -8,6 +8,7 @@ use super::{AgentDirective, AgentError, ToolRuntime};
 use crate::application::client::{ChatRequest, McpClient};
 use crate::application::model_provider::ModelProvider;
 use crate::logging::AgentLogger;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Maximum retry attempts for JSON parsing failures.
@@ -24,12 +25,15 @@ impl ToolRuntime {
     /// * `client`     — The [`McpClient`] used to send correction requests.
     /// * `logs`       — Mutable log accumulator; retry attempts are appended.
     /// * `session_id` — Current session identifier forwarded to correction requests.
+    /// * `provider`, `model` — Per-run overrides forwarded to correction requests.
     pub(crate) async fn parse_with_retry<P: ModelProvider>(
         &self,
         content: &str,
         client: &Arc<McpClient<P>>,
         logs: &mut Vec<String>,
         session_id: &Option<String>,
+        provider: &Option<String>,
+        model: &Option<String>,
     ) -> Result<AgentDirective, AgentError> {
         let log = AgentLogger::new(
             session_id
@@ -55,7 +59,7 @@ impl ToolRuntime {
 
                     let retry_message = format!(
                         "{}\n\nError details: {}",
-                        client.prompts().json_retry_message(),
+                        client.prompts_for(provider.as_deref()).json_retry_message(),
                         e
                     );
 
@@ -67,6 +71,9 @@ impl ToolRuntime {
                         raw_mode: false,
                         bypass_template: true,
                         force_json: true,
+                        provider: provider.clone(),
+                        model: model.clone(),
+                        metadata: HashMap::new(),
                     };
 
                     match client.chat(retry_request).await {
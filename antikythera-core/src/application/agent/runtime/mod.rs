@@ -1,3 +1,4 @@
+mod audit;
 mod context;
 mod execution;
 mod instructions;
@@ -6,41 +7,62 @@ mod parser;
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Semaphore;
 
-use crate::config::ToolConfig;
+use crate::application::tool_cache::ToolResultCache;
+use crate::config::{AuditLogConfig, ToolConfig};
+
+use audit::ToolAuditLog;
 
 pub(super) use super::context::{ServerGuidance, ToolContext, ToolDescriptor};
 pub(super) use super::directive::AgentDirective;
 pub(super) use super::errors::{AgentError, ToolError};
 pub(super) use crate::application::tooling::{ToolInvokeError, ToolServerInterface};
+pub(super) use execution::ToolExecution;
+pub(super) use parser::normalize_final_response;
 pub(super) use serde_json::{Value, json};
+
 #[derive(Clone)]
 pub struct ToolRuntime {
     configs: Vec<ToolConfig>,
     index: HashMap<String, ToolConfig>,
+    case_sensitive: bool,
     bridge: Arc<dyn ToolServerInterface>,
     execution_semaphore: Arc<Semaphore>,
+    audit: Option<Arc<ToolAuditLog>>,
+    output_guard: Option<String>,
+    timeout: Option<Duration>,
+    terminal: Vec<String>,
+    result_cache: Option<Arc<ToolResultCache>>,
     pub(super) fallback_response_keys: Vec<String>,
 }
 
 impl ToolRuntime {
     pub fn new(configs: Vec<ToolConfig>, bridge: Arc<dyn ToolServerInterface>) -> Self {
-        let index = configs
-            .iter()
-            .cloned()
-            .map(|cfg| (cfg.name.to_lowercase(), cfg))
-            .collect();
-
         Self {
+            index: tool_index(&configs, false),
             configs,
-            index,
+            case_sensitive: false,
             bridge,
             execution_semaphore: Arc::new(Semaphore::new(10)), // Default limit to 10 concurrent tools
+            audit: None,
+            output_guard: None,
+            timeout: None,
+            terminal: Vec::new(),
+            result_cache: None,
             fallback_response_keys: vec!["response".into(), "content".into(), "message".into()],
         }
     }
 
+    /// Match requested tool names with exact case, so tools that differ only
+    /// in case stay distinct. By default case is ignored.
+    pub fn with_case_sensitive_names(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self.index = tool_index(&self.configs, case_sensitive);
+        self
+    }
+
     /// Override the fallback response keys used when parsing unknown action values.
     pub fn with_fallback_keys(mut self, keys: Vec<String>) -> Self {
         if !keys.is_empty() {
@@ -48,4 +70,73 @@ impl ToolRuntime {
         }
         self
     }
+
+    /// Wrap tool output reported to the model in a delimited block headed by
+    /// `notice`, marking it as untrusted data. `None` reports it verbatim.
+    pub fn with_output_guard(mut self, notice: Option<String>) -> Self {
+        self.output_guard = notice;
+        self
+    }
+
+    /// Append a JSON line per tool call to the configured audit log.
+    pub fn with_audit_log(mut self, config: Option<AuditLogConfig>) -> Self {
+        self.audit = config.map(ToolAuditLog::new);
+        self
+    }
+
+    /// Fail a tool call with [`ToolError::Timeout`] once it has run for
+    /// `timeout`. `None` waits for the server however long it takes.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Tools whose successful result ends the run, see
+    /// [`is_terminal`](Self::is_terminal).
+    pub fn with_terminal_tools(mut self, names: Vec<String>) -> Self {
+        self.terminal = names;
+        self
+    }
+
+    /// Reuse results of tools configured as `cacheable` from `cache`.
+    pub(crate) fn with_result_cache(mut self, cache: Arc<ToolResultCache>) -> Self {
+        self.result_cache = Some(cache);
+        self
+    }
+
+    /// The result cache and configured name of `tool`, when the tool is
+    /// cacheable and a cache is set.
+    fn result_cache_for(&self, tool: &str) -> Option<(&ToolResultCache, String)> {
+        let cache = self.result_cache.as_deref()?;
+        let config = self.index.get(&index_key(tool, self.case_sensitive))?;
+        config.cacheable.then(|| (cache, config.name.clone()))
+    }
+
+    /// Whether a successful call to `tool` ends the run with its result.
+    pub fn is_terminal(&self, tool: &str) -> bool {
+        self.terminal.iter().any(|name| self.same_tool(name, tool))
+    }
+
+    /// Whether `a` and `b` name the same tool, compared the way tool names
+    /// are resolved: exactly when names are case-sensitive, else ignoring
+    /// case.
+    pub(crate) fn same_tool(&self, a: &str, b: &str) -> bool {
+        index_key(a, self.case_sensitive) == index_key(b, self.case_sensitive)
+    }
+}
+
+fn tool_index(configs: &[ToolConfig], case_sensitive: bool) -> HashMap<String, ToolConfig> {
+    configs
+        .iter()
+        .map(|cfg| (index_key(&cfg.name, case_sensitive), cfg.clone()))
+        .collect()
+}
+
+/// Key under which the tool `name` is stored in and looked up from the index.
+fn index_key(name: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        name.to_string()
+    } else {
+        name.to_lowercase()
+    }
 }
@@ -96,6 +96,68 @@ impl ToolRuntime {
     }
 }
 
+/// How many wrapping layers [`normalize_final_response`] peels at most.
+const MAX_FINAL_UNWRAP_DEPTH: usize = 3;
+
+/// Fence languages treated as wrappers rather than as a code answer.
+const WRAPPER_FENCE_LANGUAGES: &[&str] = &["", "json", "text", "markdown", "md"];
+
+/// Clean up a final `response` the model wrapped one level too many.
+///
+/// Unwraps a nested `{"action": "final", "response": ...}` or a bare
+/// `{"response": ...}` object, whether given as JSON or as a string holding
+/// JSON, and strips a code fence around the whole answer when it is a plain,
+/// JSON, or Markdown fence. Anything else, including prose and code answers,
+/// is returned unchanged.
+pub(crate) fn normalize_final_response(response: Value) -> Value {
+    let mut current = response;
+    for _ in 0..MAX_FINAL_UNWRAP_DEPTH {
+        match unwrap_final_layer(&current) {
+            Some(inner) => current = inner,
+            None => break,
+        }
+    }
+    current
+}
+
+fn unwrap_final_layer(value: &Value) -> Option<Value> {
+    match value {
+        Value::Object(map) => {
+            let response = map.get("response")?;
+            let is_final = match map.get("action").and_then(Value::as_str) {
+                Some(action) => action == "final",
+                None => map.len() == 1,
+            };
+            is_final.then(|| response.clone())
+        }
+        Value::String(text) => {
+            let trimmed = text.trim();
+            let unfenced = strip_wrapper_fence(trimmed);
+            let candidate = unfenced.unwrap_or(trimmed);
+            if candidate.starts_with('{')
+                && let Ok(parsed) = serde_json::from_str::<Value>(candidate)
+                && let Some(inner) = unwrap_final_layer(&parsed)
+            {
+                return Some(inner);
+            }
+            unfenced.map(|inner| Value::String(inner.to_string()))
+        }
+        _ => None,
+    }
+}
+
+fn strip_wrapper_fence(text: &str) -> Option<&str> {
+    let body = text.strip_prefix("```")?.strip_suffix("```")?;
+    let (language, inner) = body.split_once('\n')?;
+    if inner.contains("```") {
+        return None;
+    }
+    let language = language.trim().to_ascii_lowercase();
+    WRAPPER_FENCE_LANGUAGES
+        .contains(&language.as_str())
+        .then(|| inner.trim())
+}
+
 fn extract_json(content: &str) -> Option<Value> {
     let trimmed = content.trim();
 
@@ -0,0 +1,71 @@
+//! Running summary of earlier tool steps, sent with every tool-result prompt
+//! when [`AgentOptions::scratchpad`] is on.
+//!
+//! Each step becomes one short line. Once the lines exceed
+//! [`AgentOptions::scratchpad_max_chars`], the oldest are dropped first.
+
+use super::models::{AgentOptions, AgentStep};
+use serde_json::Value;
+use std::collections::VecDeque;
+
+/// Longest input or result snippet kept for a single step.
+const SNIPPET_CHARS: usize = 120;
+
+pub(super) struct Scratchpad {
+    entries: VecDeque<String>,
+    chars: usize,
+    max_chars: usize,
+    recorded: usize,
+}
+
+impl Scratchpad {
+    /// A scratchpad for this run, or `None` when the option is off.
+    pub(super) fn from_options(options: &AgentOptions) -> Option<Self> {
+        options.scratchpad.then(|| Self {
+            entries: VecDeque::new(),
+            chars: 0,
+            max_chars: options.scratchpad_max_chars,
+            recorded: 0,
+        })
+    }
+
+    pub(super) fn record(&mut self, step: &AgentStep) {
+        self.recorded += 1;
+        let status = if step.success { "ok" } else { "failed" };
+        let result = match step.message.as_deref() {
+            Some(message) if !message.trim().is_empty() => message.to_string(),
+            _ => step.output.to_string(),
+        };
+        let entry = format!(
+            "{}. {} [{status}] input={} -> {}",
+            self.recorded,
+            step.tool,
+            snippet(&step.input.to_string()),
+            snippet(&result)
+        );
+        self.chars += entry.chars().count();
+        self.entries.push_back(entry);
+        while self.chars > self.max_chars {
+            match self.entries.pop_front() {
+                Some(dropped) => self.chars -= dropped.chars().count(),
+                None => break,
+            }
+        }
+    }
+
+    /// The retained lines, oldest first, as a JSON array.
+    pub(super) fn to_value(&self) -> Value {
+        Value::from(self.entries.iter().cloned().collect::<Vec<_>>())
+    }
+}
+
+/// `text` on one line, cut to [`SNIPPET_CHARS`].
+fn snippet(text: &str) -> String {
+    let single_line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single_line.chars().count() <= SNIPPET_CHARS {
+        return single_line;
+    }
+    let mut cut: String = single_line.chars().take(SNIPPET_CHARS).collect();
+    cut.push('…');
+    cut
+}
@@ -49,6 +49,10 @@ impl<P: ModelProvider> FsmAgent<P> {
                     session_id: session_id.unwrap_or_default(),
                     response: structured_response,
                     steps,
+                    title: None,
+                    loop_detected: false,
+                    hit_step_limit: false,
+                    reasoning_trace: None,
                 })
             }
             AgentState::Terminated { reason } => match reason {
@@ -61,6 +65,10 @@ impl<P: ModelProvider> FsmAgent<P> {
                         session_id: session_id.unwrap_or_default(),
                         response: Value::String(last_step.message.clone().unwrap_or_default()),
                         steps,
+                        title: None,
+                        loop_detected: false,
+                        hit_step_limit: false,
+                        reasoning_trace: None,
                     })
                 }
                 TerminationReason::Error { message } => Err(AgentError::InvalidResponse(message)),
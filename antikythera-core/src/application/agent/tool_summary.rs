@@ -0,0 +1,94 @@
+//! Model-written summaries of oversized tool results, sent in place of the
+//! raw output when [`AgentOptions::summarize_tool_results`] is on.
+//!
+//! Only the `tool_result` message changes: the step recorded in
+//! [`AgentOutcome::steps`](super::AgentOutcome::steps) keeps the full output.
+
+use super::models::AgentOptions;
+use super::runtime::ToolExecution;
+use crate::application::client::McpClient;
+use crate::application::model_provider::ModelProvider;
+use crate::domain::types::{ChatMessage, MessageRole};
+use crate::logging::AgentLogger;
+use serde_json::Value;
+
+/// Longest output, in characters, sent to the summarizer.
+const MAX_INPUT_CHARS: usize = 50_000;
+
+const INSTRUCTION: &str = "Summarize the output of the tool below for an assistant that \
+called it. Keep every fact, number, name, identifier, and error the assistant may need; \
+drop repetition and formatting. Reply with the summary only.";
+
+pub(super) struct ToolResultSummarizer {
+    provider: Option<String>,
+    model: Option<String>,
+    threshold_chars: usize,
+}
+
+impl ToolResultSummarizer {
+    /// A summarizer for this run, or `None` when the option is off.
+    pub(super) fn from_options(options: &AgentOptions) -> Option<Self> {
+        options.summarize_tool_results.then(|| Self {
+            provider: options.provider.clone(),
+            model: options
+                .summarizer_model
+                .clone()
+                .or_else(|| options.model.clone()),
+            threshold_chars: options.summarize_threshold_chars,
+        })
+    }
+
+    /// Replace `execution`'s output and message with a summary when the
+    /// output is over the threshold. A failed or empty summary leaves the
+    /// execution unchanged.
+    pub(super) async fn apply<P: ModelProvider>(
+        &self,
+        client: &McpClient<P>,
+        execution: &mut ToolExecution,
+        logs: &mut Vec<String>,
+    ) {
+        let text = match &execution.output {
+            Value::Null => return,
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        };
+        let chars = text.chars().count();
+        if chars <= self.threshold_chars {
+            return;
+        }
+
+        let input: String = text.chars().take(MAX_INPUT_CHARS).collect();
+        let messages = vec![
+            ChatMessage::new(MessageRole::System, INSTRUCTION),
+            ChatMessage::new(
+                MessageRole::User,
+                format!("Tool: {}\n\n{input}", execution.tool),
+            ),
+        ];
+        let log = AgentLogger::new(&crate::logging::get_active_session());
+        match client
+            .complete_once(self.provider.clone(), self.model.clone(), messages)
+            .await
+        {
+            Ok(summary) if !summary.trim().is_empty() => {
+                logs.push(format!(
+                    "Tool '{}' output summarized ({chars} chars)",
+                    execution.tool
+                ));
+                execution.output = Value::String(format!(
+                    "[Summary of {chars} characters of tool output]\n{}",
+                    summary.trim()
+                ));
+                execution.message = None;
+            }
+            Ok(_) => log.warn(format!(
+                "Tool result summary was empty, sending full output | tool={}",
+                execution.tool
+            )),
+            Err(error) => log.warn(format!(
+                "Tool result summary failed, sending full output | tool={} error={error}",
+                execution.tool
+            )),
+        }
+    }
+}
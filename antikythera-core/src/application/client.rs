@@ -21,22 +21,43 @@
 //! }
 //! ```
 
-use super::session_store::{DEFAULT_MAX_SESSIONS, SessionStore};
-use super::tooling::{BuiltinTransport, ServerManager, ToolServerInterface};
-use crate::config::{AppConfig, PromptsConfig, ServerConfig, ToolConfig};
+use super::agent::{AgentError, AgentOutcome, DEFAULT_MAX_STEPS};
+use super::idempotency::{DEFAULT_IDEMPOTENCY_WINDOW, IdempotentRuns};
+use super::locale::{Locale, current_locale, fill};
+use super::reasoning;
+use super::request_metadata;
+use super::session_store::{DEFAULT_MAX_SESSIONS, SessionLocks, SessionStore};
+use super::session_title;
+use super::tool_cache::{DEFAULT_TOOL_CACHE_TTL, ToolResultCache};
+use super::tooling::{BuiltinTransport, PROTOCOL_VERSION, ServerManager, ToolServerInterface};
+use crate::config::{
+    AppConfig, AuditLogConfig, PromptsConfig, ReasoningConfig, ServerConfig, SessionTitleConfig,
+    SessionTitleStrategy, ToolConfig,
+};
+use crate::constants::SNIPPET_MAX_CHARS;
+use crate::domain::text::preview;
 use crate::domain::types::MessagePart;
 use crate::domain::types::{ChatMessage, MessageRole};
 use crate::infrastructure::model::{
     ModelError, ModelParams, ModelProvider, ModelRequest, ModelResponse,
 };
 use crate::logging::ChatLogger;
+use futures::{StreamExt, stream};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 use thiserror::Error;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Semaphore, SemaphorePermit};
 use uuid::Uuid;
 
+/// Requests of one [`McpClient::chat_batch`] run at once when
+/// `max_concurrent_agents` is unlimited.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
 /// Client configuration for the MCP client.
 ///
 /// This struct holds all settings needed to initialize and run the client,
@@ -64,8 +85,47 @@ pub struct ClientConfig {
     pub servers: Vec<ServerConfig>,
     /// Configurable prompts for agent behavior
     pub prompts: PromptsConfig,
+    /// Prompt overrides for chats with one provider, keyed by provider ID
+    pub provider_prompts: HashMap<String, PromptsConfig>,
     /// Pre-built builtin transports keyed by server name (registered after ServerManager init)
     pub builtin_transports: HashMap<String, Arc<BuiltinTransport>>,
+    /// Tool-call audit log written by agents built on this client
+    pub audit_log: Option<AuditLogConfig>,
+    /// Match tool names from the model with exact case
+    pub case_sensitive_tools: bool,
+    /// Tools shown by [`McpClient::public_tools`]; `None` shows all
+    pub public_tools: Option<Vec<String>>,
+    /// Tools whose successful result ends an agent run as its final response
+    pub terminal_tools: Vec<String>,
+    /// How new sessions get their title
+    pub session_title: SessionTitleConfig,
+    /// Messages every new session's history starts with
+    pub seed_history: Vec<ChatMessage>,
+    /// Idle time after which a session is dropped from memory; `None`
+    /// keeps sessions until reset or evicted by the session limit
+    pub session_ttl: Option<Duration>,
+    /// Reasoning blocks stripped from model replies
+    pub reasoning: ReasoningConfig,
+    /// Agent runs allowed at once on this client (0 = unlimited); further
+    /// runs wait for a free slot. Direct chat is not limited, but the
+    /// requests of one [`McpClient::chat_batch`] never run more at once.
+    pub max_concurrent_agents: usize,
+    /// Longest wait for an agent slot before the run fails with
+    /// `AgentError::Busy`; `None` waits as long as it takes.
+    pub agent_queue_timeout: Option<Duration>,
+    /// Longest an agent waits for one tool call before failing it with
+    /// `ToolError::Timeout`; `None` waits as long as the server takes.
+    pub tool_timeout: Option<Duration>,
+    /// How long an agent outcome is replayed for a run retried with the
+    /// same `AgentOptions::idempotency_key`
+    pub idempotency_window: Duration,
+    /// How long agents reuse the result of a `cacheable` tool for a call
+    /// with the same input
+    pub tool_cache_ttl: Duration,
+    /// Longest user prompt accepted, in characters; `None` accepts any
+    pub max_prompt_chars: Option<usize>,
+    /// Step budget of an agent run whose options set none; `None` uses 8
+    pub max_steps: Option<usize>,
 }
 
 impl ClientConfig {
@@ -78,7 +138,23 @@ impl ClientConfig {
             tools: Vec::new(),
             servers: Vec::new(),
             prompts: PromptsConfig::default(),
+            provider_prompts: HashMap::new(),
             builtin_transports: HashMap::new(),
+            audit_log: None,
+            case_sensitive_tools: false,
+            public_tools: None,
+            terminal_tools: Vec::new(),
+            session_title: SessionTitleConfig::default(),
+            seed_history: Vec::new(),
+            session_ttl: None,
+            reasoning: ReasoningConfig::default(),
+            max_concurrent_agents: 0,
+            agent_queue_timeout: None,
+            tool_timeout: None,
+            idempotency_window: DEFAULT_IDEMPOTENCY_WINDOW,
+            tool_cache_ttl: DEFAULT_TOOL_CACHE_TTL,
+            max_prompt_chars: None,
+            max_steps: None,
         }
     }
 
@@ -106,6 +182,115 @@ impl ClientConfig {
         self
     }
 
+    /// Use `prompts` instead of the global prompts when chatting with
+    /// `provider`; fields it leaves unset still come from the global prompts.
+    pub fn with_provider_prompts(
+        mut self,
+        provider: impl Into<String>,
+        prompts: PromptsConfig,
+    ) -> Self {
+        self.provider_prompts.insert(provider.into(), prompts);
+        self
+    }
+
+    /// Record every agent tool call to an append-only audit log.
+    pub fn with_audit_log(mut self, audit_log: AuditLogConfig) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Match tool names requested by the model with exact case instead of
+    /// ignoring case.
+    pub fn with_case_sensitive_tools(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive_tools = case_sensitive;
+        self
+    }
+
+    /// Show only the tools named in `names` in tool listings. The agent can
+    /// still call the others.
+    pub fn with_public_tools(mut self, names: Vec<String>) -> Self {
+        self.public_tools = Some(names);
+        self
+    }
+
+    /// End an agent run as soon as one of these tools succeeds, with the
+    /// tool's result as the final response.
+    pub fn with_terminal_tools(mut self, names: Vec<String>) -> Self {
+        self.terminal_tools = names;
+        self
+    }
+
+    /// Choose how new sessions get their title.
+    pub fn with_session_title(mut self, session_title: SessionTitleConfig) -> Self {
+        self.session_title = session_title;
+        self
+    }
+
+    /// Start every new session's history with `messages`, e.g. few-shot
+    /// examples. The model sees them as earlier turns of the conversation.
+    pub fn with_seed_history(mut self, messages: Vec<ChatMessage>) -> Self {
+        self.seed_history = messages;
+        self
+    }
+
+    /// Drop sessions idle for longer than `ttl`. A request that reuses the
+    /// id of a dropped session starts a new one.
+    pub fn with_session_ttl(mut self, ttl: Duration) -> Self {
+        self.session_ttl = Some(ttl);
+        self
+    }
+
+    /// Choose which reasoning blocks are stripped from model replies.
+    pub fn with_reasoning(mut self, reasoning: ReasoningConfig) -> Self {
+        self.reasoning = reasoning;
+        self
+    }
+
+    /// Allow at most `max` agent runs at once (0 = unlimited). A run that
+    /// finds no free slot within `queue_timeout` fails instead of waiting.
+    pub fn with_max_concurrent_agents(
+        mut self,
+        max: usize,
+        queue_timeout: Option<Duration>,
+    ) -> Self {
+        self.max_concurrent_agents = max;
+        self.agent_queue_timeout = queue_timeout;
+        self
+    }
+
+    /// Fail agent tool calls that run longer than `timeout`.
+    pub fn with_tool_timeout(mut self, timeout: Duration) -> Self {
+        self.tool_timeout = Some(timeout);
+        self
+    }
+
+    /// Replay an agent outcome for `window` to runs retried with the same
+    /// idempotency key (default 10 minutes).
+    pub fn with_idempotency_window(mut self, window: Duration) -> Self {
+        self.idempotency_window = window;
+        self
+    }
+
+    /// Reuse results of `cacheable` tools for `ttl` (default 5 minutes).
+    pub fn with_tool_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.tool_cache_ttl = ttl;
+        self
+    }
+
+    /// Reject user prompts longer than `max` characters with
+    /// [`McpError::PromptTooLong`] before any provider work.
+    pub fn with_max_prompt_chars(mut self, max: usize) -> Self {
+        self.max_prompt_chars = Some(max);
+        self
+    }
+
+    /// Give agent runs that set no `max_steps` a budget of `steps`
+    /// (default 8).
+    pub fn with_max_steps(mut self, steps: usize) -> Self {
+        self.max_steps = Some(steps);
+        self
+    }
+
     /// Register a pre-built builtin transport for the given server name.
     pub fn with_builtin_transport(
         mut self,
@@ -117,11 +302,33 @@ impl ClientConfig {
         self
     }
 
+    /// Check whether a tool's server is enabled and passes the tool through
+    /// its allow/block lists.
+    ///
+    /// The lists name tools as the server does, so a tool with a
+    /// `remote_name` is checked under that name. Tools without a server
+    /// binding, or bound to an unknown server, are always permitted.
+    pub fn permits_tool(&self, tool: &ToolConfig) -> bool {
+        tool.server
+            .as_deref()
+            .and_then(|name| self.servers.iter().find(|server| server.name == name))
+            .is_none_or(|server| server.enabled && server.allows_tool(tool.remote_name()))
+    }
+
     /// Get the prompt template from prompts config.
     pub fn prompt_template(&self) -> &str {
         self.prompts.template()
     }
 
+    /// The prompts used when chatting with `provider`: its override layered
+    /// over the global prompts, or the global prompts when it has none.
+    pub fn prompts_for(&self, provider: &str) -> Cow<'_, PromptsConfig> {
+        match self.provider_prompts.get(provider) {
+            Some(overrides) => Cow::Owned(overrides.layered_over(&self.prompts)),
+            None => Cow::Borrowed(&self.prompts),
+        }
+    }
+
     /// Convert to AppConfig for compatibility.
     pub fn to_app_config(&self) -> AppConfig {
         AppConfig {
@@ -132,6 +339,25 @@ impl ClientConfig {
             servers: self.servers.clone(),
             rest_server: Default::default(),
             prompts: self.prompts.clone(),
+            provider_prompts: self.provider_prompts.clone(),
+            audit_log: self.audit_log.clone(),
+            // Where saves are versioned is a host file setting the client
+            // does not carry either.
+            config_history: None,
+            case_sensitive_tools: self.case_sensitive_tools,
+            public_tools: self.public_tools.clone(),
+            terminal_tools: self.terminal_tools.clone(),
+            session_title: self.session_title.clone(),
+            reasoning: self.reasoning.clone(),
+            // A STDIO front-end setting the client does not carry.
+            stdio_default_agent_mode: true,
+            // The client holds the loaded messages, not the file they came from.
+            seed_history: None,
+            session_ttl_secs: self.session_ttl.map(|ttl| ttl.as_secs()),
+            tool_cache_ttl_secs: (self.tool_cache_ttl != DEFAULT_TOOL_CACHE_TTL)
+                .then_some(self.tool_cache_ttl.as_secs()),
+            max_prompt_chars: self.max_prompt_chars,
+            agent_max_steps: self.max_steps,
         }
     }
 }
@@ -155,6 +381,16 @@ pub struct ChatRequest {
     pub bypass_template: bool,
     /// Force JSON mode - requests the LLM to output valid JSON
     pub force_json: bool,
+    /// Provider ID for this turn; `None` uses the configured default.
+    /// Unknown IDs fail with [`ModelError::ProviderNotFound`].
+    pub provider: Option<String>,
+    /// Model for this turn; `None` uses the configured default.
+    pub model: Option<String>,
+    /// Opaque, caller-supplied context such as a user id or locale. Each
+    /// `{{metadata.<key>}}` in the system prompt is replaced with its value
+    /// (a missing key with nothing). The client does not check the values:
+    /// treat them as user-controlled input and keep secrets out.
+    pub metadata: HashMap<String, String>,
 }
 
 /// Result from a chat interaction.
@@ -173,6 +409,8 @@ pub struct ChatResult {
     pub model: String,
     /// Debug/execution logs
     pub logs: Vec<String>,
+    /// Session title, once one has been derived
+    pub title: Option<String>,
 }
 
 /// Prepared host-facing model request.
@@ -192,19 +430,38 @@ pub struct PreparedChatTurn {
 
 /// Error returned by [`McpClient`] operations.
 ///
-/// Wraps [`ModelError`] — the only error path today is a model provider
-/// failure. Use [`McpError::user_message`] to get a human-readable string
-/// suitable for display in the TUI or CLI output.
+/// A model provider failure, or a prompt rejected before any provider work.
+/// Use [`McpError::user_message`] to get a human-readable string suitable
+/// for display in the TUI or CLI output.
 #[derive(Debug, Error)]
 pub enum McpError {
     #[error(transparent)]
     Model(#[from] ModelError),
+    /// The prompt is longer than [`ClientConfig::max_prompt_chars`].
+    #[error("prompt is {chars} characters, over the limit of {max}")]
+    PromptTooLong { chars: usize, max: usize },
 }
 
 impl McpError {
     pub fn user_message(&self) -> String {
+        self.user_message_in(current_locale())
+    }
+
+    pub fn user_message_in(&self, locale: Locale) -> String {
+        match self {
+            McpError::Model(err) => err.user_message_in(locale),
+            McpError::PromptTooLong { chars, max } => fill(
+                locale.strings().errors.prompt_too_long,
+                &[("chars", &chars.to_string()), ("max", &max.to_string())],
+            ),
+        }
+    }
+
+    /// Stable machine-readable code, e.g. for API error bodies.
+    pub fn code(&self) -> &'static str {
         match self {
-            McpError::Model(err) => err.user_message(),
+            McpError::Model(_) => "model_error",
+            McpError::PromptTooLong { .. } => "prompt_too_long",
         }
     }
 }
@@ -226,6 +483,45 @@ pub struct ClientConfigSnapshot {
     pub raw: String,
 }
 
+impl ClientConfigSnapshot {
+    /// Read-only summary of the effective configuration.
+    ///
+    /// The core holds no provider catalogue, so the caller lists the
+    /// configured providers; only their ids and types are kept.
+    pub fn summary(&self, providers: Vec<ProviderSummary>) -> RuntimeSummary {
+        RuntimeSummary {
+            default_provider: self.default_provider.clone(),
+            default_model: self.model.clone(),
+            providers,
+            tool_count: self.tools.len(),
+            server_count: self.servers.len(),
+            protocol_version: PROTOCOL_VERSION.to_string(),
+        }
+    }
+}
+
+/// A provider as listed in a [`RuntimeSummary`]: never endpoints or keys.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderSummary {
+    pub id: String,
+    pub provider_type: String,
+}
+
+/// "Who am I" view of the running client for dashboards and status output.
+///
+/// Unlike [`ClientConfigSnapshot`] it carries no prompts, server commands,
+/// headers, or raw TOML, so it is safe to show or serve as-is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimeSummary {
+    pub default_provider: String,
+    pub default_model: String,
+    pub providers: Vec<ProviderSummary>,
+    pub tool_count: usize,
+    pub server_count: usize,
+    /// MCP protocol version this client negotiates with servers
+    pub protocol_version: String,
+}
+
 /// Core MCP client that owns session history, provider dispatch, and server connectivity.
 ///
 /// `McpClient<P>` is generic over any [`ModelProvider`] implementation, making
@@ -242,11 +538,22 @@ pub struct ClientConfigSnapshot {
 /// `max_sessions` is exceeded (default: [`DEFAULT_MAX_SESSIONS`]).
 /// Use [`McpClient::prune_session`] to trim old messages before a request
 /// when the conversation grows long.
+///
+/// [`McpClient::chat`] serializes turns per session: concurrent requests
+/// sharing a `session_id` are processed in arrival order, while different
+/// sessions run in parallel.
 pub struct McpClient<P: ModelProvider> {
     provider: P,
     config: ClientConfig,
     sessions: Mutex<SessionStore>,
+    session_locks: SessionLocks,
     server_bridge: Arc<dyn ToolServerInterface>,
+    /// Slots for concurrent agent runs; `None` when unlimited.
+    agent_slots: Option<Semaphore>,
+    /// Outcomes of agent runs that carried an idempotency key.
+    idempotent_runs: IdempotentRuns,
+    /// Results of `cacheable` tools, shared by every agent on this client.
+    tool_cache: Arc<ToolResultCache>,
 }
 
 impl<P: ModelProvider> McpClient<P> {
@@ -254,21 +561,100 @@ impl<P: ModelProvider> McpClient<P> {
     ///
     /// A [`ServerManager`] is created from `config.servers` and stored as the
     /// active [`ToolServerInterface`].  Session history starts empty with a
-    /// default LRU capacity of [`DEFAULT_MAX_SESSIONS`].
+    /// default LRU capacity of [`DEFAULT_MAX_SESSIONS`].  Tools of disabled
+    /// servers, or filtered out by their server's `allowed_tools`/
+    /// `blocked_tools`, are dropped here, so they never reach the system
+    /// prompt or the agent's tool index.
     pub fn new(provider: P, config: ClientConfig) -> Self {
         let server_manager = Arc::new(ServerManager::new(config.servers.clone()));
         for (name, transport) in &config.builtin_transports {
             server_manager.register_builtin_transport(name, transport.clone());
         }
-        let bridge: Arc<dyn ToolServerInterface> = server_manager;
+        Self::with_bridge(provider, config, server_manager)
+    }
+
+    /// Construct a client that invokes tools through `bridge` instead of a
+    /// [`ServerManager`] built from `config.servers`.
+    ///
+    /// Test-focused: inject a stub [`ToolServerInterface`] to drive client
+    /// and agent tool flows deterministically without spawning servers.
+    /// `config.builtin_transports` is ignored since no manager is created;
+    /// tool filtering is the same as in [`McpClient::new`].
+    pub fn with_bridge(
+        provider: P,
+        mut config: ClientConfig,
+        bridge: Arc<dyn ToolServerInterface>,
+    ) -> Self {
+        let permitted: Vec<ToolConfig> = config
+            .tools
+            .iter()
+            .filter(|tool| config.permits_tool(tool))
+            .cloned()
+            .collect();
+        config.tools = permitted;
+        let agent_slots = (config.max_concurrent_agents > 0)
+            .then(|| Semaphore::new(config.max_concurrent_agents));
+        let sessions = SessionStore::new(
+            DEFAULT_MAX_SESSIONS,
+            config.seed_history.clone(),
+            config.session_ttl,
+        );
         Self {
             provider,
+            agent_slots,
+            idempotent_runs: IdempotentRuns::new(config.idempotency_window),
+            tool_cache: Arc::new(ToolResultCache::new(config.tool_cache_ttl)),
             config,
-            sessions: Mutex::new(SessionStore::new(DEFAULT_MAX_SESSIONS)),
+            sessions: Mutex::new(sessions),
+            session_locks: SessionLocks::default(),
             server_bridge: bridge,
         }
     }
 
+    /// Wait for a free agent slot when `max_concurrent_agents` is set.
+    /// `Ok(None)` when runs are unlimited; [`AgentError::Busy`] when
+    /// `agent_queue_timeout` ran out first.
+    pub(crate) async fn acquire_agent_slot(
+        &self,
+    ) -> Result<Option<SemaphorePermit<'_>>, AgentError> {
+        let Some(slots) = &self.agent_slots else {
+            return Ok(None);
+        };
+        let permit = match self.config.agent_queue_timeout {
+            Some(limit) => tokio::time::timeout(limit, slots.acquire())
+                .await
+                .map_err(|_| AgentError::Busy)?,
+            None => slots.acquire().await,
+        };
+        permit.map(Some).map_err(|_| AgentError::Busy)
+    }
+
+    /// Fail with [`McpError::PromptTooLong`] when `prompt` is longer than
+    /// [`ClientConfig::max_prompt_chars`].
+    pub fn check_prompt(&self, prompt: &str) -> Result<(), McpError> {
+        let Some(max) = self.config.max_prompt_chars else {
+            return Ok(());
+        };
+        let chars = prompt.chars().count();
+        if chars > max {
+            ChatLogger::new(&crate::logging::get_active_session()).warn(format!(
+                "Prompt rejected as too long | chars={chars} max={max}"
+            ));
+            return Err(McpError::PromptTooLong { chars, max });
+        }
+        Ok(())
+    }
+
+    /// Await the agent run `run`, or replay the outcome of an earlier run
+    /// with the same idempotency `key` (see [`ClientConfig::idempotency_window`]).
+    pub(crate) async fn run_idempotent(
+        &self,
+        key: Option<&str>,
+        run: impl Future<Output = Result<AgentOutcome, AgentError>>,
+    ) -> Result<AgentOutcome, AgentError> {
+        self.idempotent_runs.run(key, run).await
+    }
+
     /// Return the list of registered tool configurations.
     pub fn tools(&self) -> &[ToolConfig] {
         &self.config.tools
@@ -284,6 +670,11 @@ impl<P: ModelProvider> McpClient<P> {
         &self.config.default_model
     }
 
+    /// Return the step budget of an agent run whose options set none.
+    pub fn default_max_steps(&self) -> usize {
+        self.config.max_steps.unwrap_or(DEFAULT_MAX_STEPS)
+    }
+
     /// Build a [`ClientConfigSnapshot`] from the current config for display layers.
     ///
     /// The snapshot includes the raw TOML representation used by the Settings overlay.
@@ -307,6 +698,59 @@ impl<P: ModelProvider> McpClient<P> {
         &self.config.prompts
     }
 
+    /// The prompts used when chatting with `provider` (the default provider
+    /// when `None`), see [`ClientConfig::prompts_for`].
+    pub fn prompts_for(&self, provider: Option<&str>) -> Cow<'_, PromptsConfig> {
+        self.config
+            .prompts_for(provider.unwrap_or(&self.config.default_provider))
+    }
+
+    pub fn audit_log(&self) -> Option<&AuditLogConfig> {
+        self.config.audit_log.as_ref()
+    }
+
+    /// Whether agents match tool names with exact case.
+    pub fn case_sensitive_tools(&self) -> bool {
+        self.config.case_sensitive_tools
+    }
+
+    /// Tools whose successful result ends an agent run.
+    pub fn terminal_tools(&self) -> &[String] {
+        &self.config.terminal_tools
+    }
+
+    /// The tools to list to users: [`tools`](Self::tools) narrowed to
+    /// `public_tools` when it is set. Names are compared ignoring case
+    /// unless tool names are case-sensitive.
+    pub fn public_tools(&self) -> Vec<&ToolConfig> {
+        let Some(names) = &self.config.public_tools else {
+            return self.config.tools.iter().collect();
+        };
+        self.config
+            .tools
+            .iter()
+            .filter(|tool| {
+                names.iter().any(|name| {
+                    if self.config.case_sensitive_tools {
+                        *name == tool.name
+                    } else {
+                        name.eq_ignore_ascii_case(&tool.name)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Limit on a single agent tool call, if any.
+    pub fn tool_timeout(&self) -> Option<Duration> {
+        self.config.tool_timeout
+    }
+
+    /// The result cache of `cacheable` tools shared by this client's agents.
+    pub(crate) fn tool_cache(&self) -> Arc<ToolResultCache> {
+        self.tool_cache.clone()
+    }
+
     /// Return a clone of the active [`ToolServerInterface`] arc (the `ServerManager`).
     pub fn server_bridge(&self) -> Arc<dyn ToolServerInterface> {
         self.server_bridge.clone()
@@ -319,9 +763,19 @@ impl<P: ModelProvider> McpClient<P> {
     /// constructs the outgoing [`ModelRequest`].  The result can be
     /// inspected or handed to [`complete_chat_from_host`] when the host
     /// owns the LLM API call.
+    ///
+    /// Nothing is written to the session store, so calling it on its own
+    /// previews exactly what the provider would receive; `model_request`
+    /// serializes to JSON for debugging.
     pub async fn prepare_chat(&self, request: ChatRequest) -> PreparedChatTurn {
-        let provider = self.config.default_provider.clone();
-        let model = self.config.default_model.clone();
+        let provider = request
+            .provider
+            .clone()
+            .unwrap_or_else(|| self.config.default_provider.clone());
+        let model = request
+            .model
+            .clone()
+            .unwrap_or_else(|| self.config.default_model.clone());
         let session_id = request.session_id.clone().unwrap_or_else(new_session_id);
         let raw_mode = request.raw_mode;
 
@@ -345,7 +799,7 @@ impl<P: ModelProvider> McpClient<P> {
                     "Acquired session lock for reading history | lock_wait_us={:?}",
                     elapsed.as_micros()
                 ));
-                sessions.get(session_id.as_str()).unwrap_or_default()
+                sessions.history_or_seed(session_id.as_str())
             };
             ChatLogger::new(&session_id).debug(format!(
                 "Preparing chat request with prior history | session_id={} history_count={}",
@@ -371,9 +825,15 @@ impl<P: ModelProvider> McpClient<P> {
                 let system = request
                     .system_prompt
                     .or_else(|| self.config.default_system_prompt.clone());
-                self.compose_system_prompt(system)
+                let provider = request
+                    .provider
+                    .as_deref()
+                    .unwrap_or(&self.config.default_provider);
+                self.compose_system_prompt(system, provider)
             };
 
+            let system_prompt = request_metadata::substitute(&system_prompt, &request.metadata);
+
             if !system_prompt.is_empty() {
                 logs.push(format!(
                     "System prompt active: {}",
@@ -430,7 +890,8 @@ impl<P: ModelProvider> McpClient<P> {
     ///
     /// Both the user message and the model's assistant message are appended to
     /// the in-memory session store under `prepared.session_id` via
-    /// [`persist_exchange`].
+    /// [`persist_exchange`]. Reasoning blocks matching the configured
+    /// [`ReasoningConfig`] delimiters are removed from the reply first.
     pub async fn complete_chat(
         &self,
         prepared: PreparedChatTurn,
@@ -440,10 +901,17 @@ impl<P: ModelProvider> McpClient<P> {
             .session_id
             .clone()
             .unwrap_or_else(|| prepared.session_id.clone());
-        let assistant_message = response.message.clone();
+        let mut assistant_message = response.message;
+        let stripped =
+            reasoning::strip_message(&mut assistant_message, &self.config.reasoning.delimiters);
         let response_preview = Self::summarise(&assistant_message.content());
 
         let mut logs = prepared.logs;
+        if self.config.reasoning.log {
+            for block in &stripped {
+                logs.push(format!("Reasoning: {block}"));
+            }
+        }
         logs.push(format!("Model: {response_preview}"));
 
         let log = ChatLogger::new(&final_session);
@@ -461,6 +929,7 @@ impl<P: ModelProvider> McpClient<P> {
             ));
         }
 
+        let content = assistant_message.content();
         self.persist_exchange(&final_session, prepared.user_message, assistant_message)
             .await;
 
@@ -472,18 +941,62 @@ impl<P: ModelProvider> McpClient<P> {
                 .record_tokens(&final_session, response.tokens);
         }
 
+        let title = self.session_title(&final_session).await;
         Ok(ChatResult {
-            content: response.message.content(),
+            content,
             session_id: final_session,
             provider: prepared.provider,
             model: prepared.model,
             logs,
+            title,
         })
     }
 
     /// Single-method convenience: [`prepare_chat`] → provider dispatch → [`complete_chat`].
+    ///
+    /// The whole turn holds the session's lock, so a second request on the
+    /// same `session_id` sees the history this one persists. A session
+    /// without a title is titled from the prompt, except for
+    /// `bypass_template` turns whose prompt an agent composed.
     pub async fn chat(&self, request: ChatRequest) -> Result<ChatResult, McpError> {
-        let prepared = self.prepare_chat(request).await;
+        self.run_chat(request, None).await
+    }
+
+    /// [`chat`](Self::chat), passing the reply to `on_token` chunk by chunk
+    /// as the provider streams it.
+    ///
+    /// History is persisted once the reply is complete, and the returned
+    /// [`ChatResult`] is the same as from `chat`: `content` is the whole
+    /// reply, with reasoning blocks stripped. Chunks are passed on as
+    /// received, reasoning included. A provider that does not stream
+    /// delivers the reply as one chunk.
+    pub async fn chat_streaming(
+        &self,
+        request: ChatRequest,
+        on_token: impl Fn(&str) + Send + Sync,
+    ) -> Result<ChatResult, McpError> {
+        self.run_chat(request, Some(&on_token)).await
+    }
+
+    async fn run_chat(
+        &self,
+        request: ChatRequest,
+        on_token: Option<&(dyn Fn(&str) + Send + Sync)>,
+    ) -> Result<ChatResult, McpError> {
+        // Agent turns carry composed prompts; agents check the user's prompt
+        // when the run starts.
+        if !request.bypass_template {
+            self.check_prompt(&request.prompt)?;
+        }
+        let session_id = request.session_id.clone().unwrap_or_else(new_session_id);
+        let _turn = self.session_locks.acquire(&session_id).await;
+        let title_source = (!request.bypass_template).then(|| request.prompt.clone());
+        let mut prepared = self
+            .prepare_chat(ChatRequest {
+                session_id: Some(session_id),
+                ..request
+            })
+            .await;
 
         ChatLogger::new(&prepared.session_id).info(format!(
             "Dispatching prepared request to model host | session_id={} provider={} model={}",
@@ -492,25 +1005,169 @@ impl<P: ModelProvider> McpClient<P> {
             prepared.model.as_str()
         ));
 
-        let response = self.provider.chat(prepared.model_request.clone()).await?;
-        self.complete_chat(prepared, response).await
+        let response = match on_token {
+            Some(on_token) => {
+                let chunks = AtomicUsize::new(0);
+                let counted = |chunk: &str| {
+                    chunks.fetch_add(1, Ordering::Relaxed);
+                    on_token(chunk);
+                };
+                let response = self
+                    .provider
+                    .chat_stream(prepared.model_request.clone(), &counted)
+                    .await?;
+                prepared.logs.push(format!(
+                    "Streamed reply in {} chunks",
+                    chunks.load(Ordering::Relaxed)
+                ));
+                response
+            }
+            None => self.provider.chat(prepared.model_request.clone()).await?,
+        };
+        let mut result = self.complete_chat(prepared, response).await?;
+        if let Some(prompt) = title_source {
+            result.title = self.ensure_session_title(&result.session_id, &prompt).await;
+        }
+        Ok(result)
     }
 
-    fn compose_system_prompt(&self, override_prompt: Option<String>) -> String {
-        let template = self.config.prompt_template().to_string();
+    /// Run independent [`chat`](Self::chat) requests concurrently and return
+    /// their results in the order of `requests`, whichever finishes first.
+    ///
+    /// At most `max_concurrent_agents` requests run at once, or
+    /// [`DEFAULT_BATCH_CONCURRENCY`] when that is unlimited. Each request
+    /// keeps its own session handling: one without a `session_id` starts a
+    /// new session, and requests sharing one run in turn. A failed request
+    /// does not affect the others.
+    pub async fn chat_batch(
+        &self,
+        requests: Vec<ChatRequest>,
+    ) -> Vec<Result<ChatResult, McpError>> {
+        let limit = match self.config.max_concurrent_agents {
+            0 => DEFAULT_BATCH_CONCURRENCY,
+            max => max,
+        };
+        stream::iter(requests)
+            .map(|request| self.chat(request))
+            .buffered(limit)
+            .collect()
+            .await
+    }
+
+    /// Title of `session_id`, or `None` when it has none or does not exist.
+    pub async fn session_title(&self, session_id: &str) -> Option<String> {
+        self.sessions.lock().await.title(session_id)
+    }
+
+    /// Title of `session_id`, first deriving one from `first_message` with
+    /// the configured [`SessionTitleStrategy`] when the session has none.
+    ///
+    /// Returns `None` for an unknown session or a blank message.
+    pub async fn ensure_session_title(
+        &self,
+        session_id: &str,
+        first_message: &str,
+    ) -> Option<String> {
+        {
+            let sessions = self.sessions.lock().await;
+            if !sessions.manager().has_session(session_id).unwrap_or(false) {
+                return None;
+            }
+            if let Some(title) = sessions.title(session_id) {
+                return Some(title);
+            }
+        }
+        let title = self.derive_session_title(session_id, first_message).await?;
+        self.sessions.lock().await.set_title(session_id, &title);
+        ChatLogger::new(session_id).debug(format!("Session titled | title={title}"));
+        Some(title)
+    }
+
+    async fn derive_session_title(&self, session_id: &str, first_message: &str) -> Option<String> {
+        let settings = &self.config.session_title;
+        let truncated = session_title::truncate(first_message, settings.max_chars);
+        if truncated.is_empty() {
+            return None;
+        }
+        if settings.strategy == SessionTitleStrategy::Summarize {
+            let request = ModelRequest {
+                provider: self.config.default_provider.clone(),
+                model: settings
+                    .model
+                    .clone()
+                    .unwrap_or_else(|| self.config.default_model.clone()),
+                messages: session_title::summary_messages(first_message),
+                session_id: None,
+                params: ModelParams::new(),
+            };
+            match self.provider.chat(request).await {
+                Ok(response) => {
+                    if let Some(title) = session_title::clean_summary(
+                        &response.message.content(),
+                        settings.max_chars,
+                    ) {
+                        return Some(title);
+                    }
+                }
+                Err(err) => ChatLogger::new(session_id).warn(format!(
+                    "Session title summary failed, truncating instead | error={err}"
+                )),
+            }
+        }
+        Some(truncated)
+    }
+
+    /// One model call outside any session, for helper work such as
+    /// summaries. `provider` and `model` default to the client's defaults.
+    /// Returns the reply text without reasoning blocks.
+    pub async fn complete_once(
+        &self,
+        provider: Option<String>,
+        model: Option<String>,
+        messages: Vec<ChatMessage>,
+    ) -> Result<String, McpError> {
+        let request = ModelRequest {
+            provider: provider.unwrap_or_else(|| self.config.default_provider.clone()),
+            model: model.unwrap_or_else(|| self.config.default_model.clone()),
+            messages,
+            session_id: None,
+            params: ModelParams::new(),
+        };
+        let response = self.provider.chat(request).await?;
+        let (text, _) = reasoning::strip(
+            &response.message.content(),
+            &self.config.reasoning.delimiters,
+        );
+        Ok(text)
+    }
+
+    fn compose_system_prompt(&self, override_prompt: Option<String>, provider: &str) -> String {
+        let prompts = self.config.prompts_for(provider);
+        let template = prompts.template().to_string();
         let custom_instruction = override_prompt.unwrap_or_default();
+        // The fallback guidance normally rides in {{tool_guidance}}; a
+        // template without that placeholder only gets it when always asked.
+        let appended_fallback = (prompts.always_fallback_guidance()
+            && !template.contains("{{tool_guidance}}"))
+        .then(|| prompts.fallback_guidance().trim().to_string())
+        .filter(|guidance| !guidance.is_empty());
         if template.is_empty() {
-            return custom_instruction.trim().to_string();
+            return match appended_fallback {
+                Some(guidance) => format!("{}\n\n{guidance}", custom_instruction.trim())
+                    .trim()
+                    .to_string(),
+                None => custom_instruction.trim().to_string(),
+            };
         }
 
         let tool_guidance = if self.config.tools.is_empty() {
             // No MCP tools registered: emit only the fallback guidance so the model
             // knows it must rely on its own knowledge rather than tool invocations.
-            self.config.prompts.fallback_guidance().to_string()
+            prompts.fallback_guidance().to_string()
         } else {
             // MCP tools are registered: list each tool name + description so the
             // model can reason about which tool to invoke for the current request.
-            let mut text = format!("{}\n", self.config.prompts.tool_guidance());
+            let mut text = format!("{}\n", prompts.tool_guidance());
             for tool in &self.config.tools {
                 let description = tool
                     .description
@@ -518,7 +1175,7 @@ impl<P: ModelProvider> McpClient<P> {
                     .unwrap_or("No description available.");
                 text.push_str(&format!("- {}: {}\n", tool.name, description));
             }
-            text.push_str(self.config.prompts.fallback_guidance());
+            text.push_str(prompts.fallback_guidance());
             text
         };
 
@@ -530,6 +1187,10 @@ impl<P: ModelProvider> McpClient<P> {
             .replace("{{language_guidance}}", "")
             .replace("{{tool_guidance}}", "")
             .replace("{{custom_instruction}}", "");
+        if let Some(guidance) = appended_fallback {
+            prompt.push_str("\n\n");
+            prompt.push_str(&guidance);
+        }
         let mut cleaned = Vec::new();
         let mut previous_blank = false;
         for line in prompt.lines().map(|line| line.trim_end()) {
@@ -579,6 +1240,54 @@ impl<P: ModelProvider> McpClient<P> {
         ));
     }
 
+    /// Fork `session_id` into a new session with a copy of its history.
+    ///
+    /// Returns the new session ID, or `None` when `session_id` does not
+    /// exist. Later turns on either session leave the other untouched, so
+    /// alternative follow-ups can be explored from the same point. A turn in
+    /// progress on `session_id` finishes before the copy is taken.
+    pub async fn fork_session(&self, session_id: &str) -> Option<String> {
+        let _turn = self.session_locks.acquire(session_id).await;
+        let forked = new_session_id();
+        let mut sessions = self.sessions.lock().await;
+        if !sessions.fork(session_id, &forked) {
+            return None;
+        }
+        ChatLogger::new(session_id).info(format!(
+            "Session forked | session_id={} forked_session_id={}",
+            session_id, forked
+        ));
+        Some(forked)
+    }
+
+    /// Remove the last exchange of `session_id`: its last user message and
+    /// every message after it, such as the reply.
+    ///
+    /// Returns the number of messages removed, or `0` when the session does
+    /// not exist or has no user message. Used to regenerate an answer: the
+    /// prompt is sent again without the old exchange left in the context.
+    pub async fn pop_last_exchange(&self, session_id: &str) -> usize {
+        let _turn = self.session_locks.acquire(session_id).await;
+        let mut sessions = self.sessions.lock().await;
+        let Some(mut history) = sessions.get(session_id) else {
+            return 0;
+        };
+        let Some(start) = history
+            .iter()
+            .rposition(|message| message.role == MessageRole::User)
+        else {
+            return 0;
+        };
+        let removed = history.len() - start;
+        history.truncate(start);
+        sessions.replace_history(session_id, history);
+        ChatLogger::new(session_id).info(format!(
+            "Last exchange removed | session_id={} removed={}",
+            session_id, removed
+        ));
+        removed
+    }
+
     /// Prune old non-system messages from `session_id` to fit within `policy`.
     ///
     /// Returns the number of messages removed, or `0` when the session does
@@ -615,25 +1324,12 @@ impl<P: ModelProvider> McpClient<P> {
     }
 
     pub(crate) fn summarise(text: &str) -> String {
-        const SNIPPET_LIMIT: usize = 160;
         let trimmed = text.trim();
         if trimmed.is_empty() {
             return "(empty)".to_string();
         }
         let single_line = trimmed.split_whitespace().collect::<Vec<_>>().join(" ");
-        let mut result = String::new();
-        let mut chars = single_line.chars();
-        for _ in 0..SNIPPET_LIMIT {
-            if let Some(ch) = chars.next() {
-                result.push(ch);
-            } else {
-                return result;
-            }
-        }
-        if chars.next().is_some() {
-            result.push('…');
-        }
-        result
+        preview(&single_line, SNIPPET_MAX_CHARS, "…")
     }
 
     /// Update session stats based on agent execution outcome.
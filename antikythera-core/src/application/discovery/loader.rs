@@ -34,7 +34,6 @@ use super::types::{DiscoveredServer, DiscoverySummary, LoadStatus};
 use crate::application::tooling::spawn_and_list_tools;
 use crate::config::ServerConfig;
 use crate::logging::DiscoveryLogger;
-use std::collections::HashMap;
 use std::path::Path;
 
 /// Load all discovered servers and fetch their tools.
@@ -157,8 +156,6 @@ pub async fn load_server(server: &mut DiscoveredServer) {
     }
 }
 
-use crate::config::TransportType;
-
 /// Create a `ServerConfig` from a binary path.
 ///
 /// This function creates the configuration needed to spawn an MCP server
@@ -175,15 +172,8 @@ use crate::config::TransportType;
 pub fn create_server_config(name: &str, binary_path: &Path) -> ServerConfig {
     ServerConfig {
         name: name.to_string(),
-        transport: TransportType::Stdio,
         command: Some(binary_path.to_path_buf()),
-        args: Vec::new(),
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     }
 }
 
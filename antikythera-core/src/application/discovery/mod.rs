@@ -7,10 +7,11 @@
 //!
 //! # Architecture
 //!
-//! The discovery system consists of three main components:
+//! The discovery system consists of four main components:
 //!
 //! - **Scanner** (`scanner.rs`): Scans a folder for executable files
 //! - **Loader** (`loader.rs`): Spawns servers and fetches tools via MCP
+//! - **Sync** (`sync.rs`): Reconciles configured tools with a server's `tools/list`
 //! - **Types** (`types.rs`): Core data structures for discovery results
 //!
 //! # Usage
@@ -88,6 +89,8 @@ pub mod loader;
 pub mod scanner;
 #[cfg(feature = "native-transport")]
 pub mod startup;
+#[cfg(feature = "native-transport")]
+pub mod sync;
 pub mod types;
 
 // Re-export commonly used items
@@ -96,6 +99,8 @@ pub use loader::{load_all, load_server, scan_and_load};
 pub use scanner::scan_folder;
 #[cfg(feature = "native-transport")]
 pub use startup::{StartupDiscoveryResult, print_discovery_summary, run_startup_discovery};
+#[cfg(feature = "native-transport")]
+pub use sync::{ToolCatalogDiff, ToolDescriptionChange, sync_server_tools};
 pub use types::{DiscoveredServer, DiscoveryError, DiscoverySummary, LoadStatus};
 
 /// Default folder name for MCP server binaries.
@@ -13,6 +13,8 @@
 
 use super::types::{DiscoveredServer, DiscoverySummary, LoadStatus};
 use super::{DEFAULT_SERVERS_FOLDER, load_all, scan_folder};
+use crate::constants::TOOL_DESCRIPTION_PREVIEW_CHARS;
+use crate::domain::text;
 use crate::logging::DiscoveryLogger;
 use std::path::Path;
 
@@ -161,7 +163,8 @@ pub async fn run_startup_discovery(servers_folder: Option<&Path>) -> StartupDisc
                 ));
                 // Log each tool
                 for (tool_name, description) in &server.tools {
-                    let desc_preview: String = description.chars().take(50).collect();
+                    let desc_preview =
+                        text::preview(description, TOOL_DESCRIPTION_PREVIEW_CHARS, "");
                     log.info(format!(
                         "   🔧 Tool available | server={} tool={} desc={}",
                         server.name, tool_name, desc_preview
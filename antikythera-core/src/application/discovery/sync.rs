@@ -0,0 +1,169 @@
+//! Tool catalogue sync.
+//!
+//! Compares the tools configured for a server with what its `tools/list`
+//! currently returns, so tools the server dropped don't linger in config and
+//! get called by the agent. The resulting [`ToolCatalogDiff`] reports added,
+//! removed, and description-changed tools and can be applied back onto the
+//! configured tool list, optionally pruning the removed ones.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use antikythera_core::application::discovery::sync_server_tools;
+//!
+//! let diff = sync_server_tools(&server, &mut config.tools, false).await?;
+//! println!("{}", diff.render());
+//! ```
+
+use crate::application::tooling::{ToolInvokeError, spawn_and_list_tools};
+use crate::config::{ServerConfig, ToolConfig};
+use crate::logging::DiscoveryLogger;
+use std::collections::HashMap;
+
+/// A tool whose description differs between config and the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolDescriptionChange {
+    pub name: String,
+    /// Description currently in config
+    pub before: Option<String>,
+    /// Description reported by the server
+    pub after: String,
+}
+
+/// Difference between a server's configured tools and its live `tools/list`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolCatalogDiff {
+    /// Server the diff was computed for
+    pub server: String,
+    /// Tools the server offers that are not configured, as (name, description)
+    pub added: Vec<(String, String)>,
+    /// Configured tools the server no longer offers
+    pub removed: Vec<String>,
+    /// Tools whose description changed
+    pub changed: Vec<ToolDescriptionChange>,
+}
+
+impl ToolCatalogDiff {
+    /// Compare the tools configured for `server` with the `listed` (name,
+    /// description) pairs. Tools bound to other servers are ignored.
+    pub fn between(server: &str, configured: &[ToolConfig], listed: &[(String, String)]) -> Self {
+        let configured: HashMap<&str, &ToolConfig> = configured
+            .iter()
+            .filter(|tool| tool.server.as_deref() == Some(server))
+            .map(|tool| (tool.name.as_str(), tool))
+            .collect();
+        let listed_names: HashMap<&str, &str> = listed
+            .iter()
+            .map(|(name, description)| (name.as_str(), description.as_str()))
+            .collect();
+
+        let mut diff = Self {
+            server: server.to_string(),
+            ..Self::default()
+        };
+        for (name, description) in listed {
+            match configured.get(name.as_str()) {
+                None => diff.added.push((name.clone(), description.clone())),
+                Some(tool) if tool.description.as_deref().unwrap_or_default() != description => {
+                    diff.changed.push(ToolDescriptionChange {
+                        name: name.clone(),
+                        before: tool.description.clone(),
+                        after: description.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        diff.removed = configured
+            .keys()
+            .filter(|name| !listed_names.contains_key(*name))
+            .map(|name| name.to_string())
+            .collect();
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort_by(|a, b| a.name.cmp(&b.name));
+        diff
+    }
+
+    /// Whether config already matches the server.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Add new tools and update changed descriptions in `tools`. Removed tools
+    /// are only dropped when `prune_removed` is set.
+    pub fn apply(&self, tools: &mut Vec<ToolConfig>, prune_removed: bool) {
+        let owned_by_server = |tool: &ToolConfig| tool.server.as_deref() == Some(&self.server);
+
+        for change in &self.changed {
+            if let Some(tool) = tools
+                .iter_mut()
+                .find(|tool| owned_by_server(tool) && tool.name == change.name)
+            {
+                tool.description = non_empty(&change.after);
+            }
+        }
+        if prune_removed {
+            tools.retain(|tool| !(owned_by_server(tool) && self.removed.contains(&tool.name)));
+        }
+        tools.extend(self.added.iter().map(|(name, description)| ToolConfig {
+            name: name.clone(),
+            description: non_empty(description),
+            server: Some(self.server.clone()),
+            remote_name: None,
+            cacheable: false,
+        }));
+    }
+
+    /// One line per difference: `+` added, `-` removed, `~` description changed.
+    pub fn render(&self) -> String {
+        if self.is_empty() {
+            return format!("{}: up to date", self.server);
+        }
+        let mut lines = vec![format!(
+            "{}: +{} -{} ~{}",
+            self.server,
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )];
+        lines.extend(self.added.iter().map(|(name, _)| format!("  + {name}")));
+        lines.extend(self.removed.iter().map(|name| format!("  - {name}")));
+        lines.extend(
+            self.changed
+                .iter()
+                .map(|change| format!("  ~ {} (description)", change.name)),
+        );
+        lines.join("\n")
+    }
+}
+
+/// List `server`'s tools and reconcile `tools` with them.
+///
+/// New tools and description changes are always applied; tools the server
+/// dropped are only removed when `prune_removed` is set. The returned diff
+/// describes what differed before the update.
+pub async fn sync_server_tools(
+    server: &ServerConfig,
+    tools: &mut Vec<ToolConfig>,
+    prune_removed: bool,
+) -> Result<ToolCatalogDiff, ToolInvokeError> {
+    let listed = spawn_and_list_tools(server).await?;
+    let diff = ToolCatalogDiff::between(&server.name, tools, &listed);
+    diff.apply(tools, prune_removed);
+
+    DiscoveryLogger::new("discovery").info(format!(
+        "Tool catalogue synced | server={} added={} removed={} changed={} pruned={}",
+        server.name,
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len(),
+        prune_removed
+    ));
+    Ok(diff)
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    (!text.is_empty()).then(|| text.to_string())
+}
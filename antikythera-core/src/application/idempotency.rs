@@ -0,0 +1,65 @@
+//! Replay of agent outcomes for retried runs.
+//!
+//! A run started with an [`AgentOptions::idempotency_key`] stores its outcome
+//! under that key. A later run with the same key gets the stored outcome
+//! instead of running again, until the entry is older than the client's
+//! window; a run that arrives while the first is still going waits for it.
+//! Failed runs are not stored, so a retry after an error runs again.
+//!
+//! [`AgentOptions::idempotency_key`]: super::agent::AgentOptions::idempotency_key
+
+use super::agent::{AgentError, AgentOutcome};
+use super::session_store::SessionLocks;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex as StdMutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// How long an outcome is replayed when the client config sets no window.
+pub(super) const DEFAULT_IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(600);
+
+pub(super) struct IdempotentRuns {
+    window: Duration,
+    outcomes: StdMutex<HashMap<String, (Instant, AgentOutcome)>>,
+    locks: SessionLocks,
+}
+
+impl IdempotentRuns {
+    pub(super) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            outcomes: StdMutex::new(HashMap::new()),
+            locks: SessionLocks::default(),
+        }
+    }
+
+    /// Await `run`, or return the outcome stored for `key` without polling
+    /// it. Without a key `run` is always awaited.
+    pub(super) async fn run(
+        &self,
+        key: Option<&str>,
+        run: impl Future<Output = Result<AgentOutcome, AgentError>>,
+    ) -> Result<AgentOutcome, AgentError> {
+        let Some(key) = key else {
+            return run.await;
+        };
+        let _turn = self.locks.acquire(key).await;
+        if let Some(outcome) = self.replay(key) {
+            return Ok(outcome);
+        }
+        let outcome = run.await?;
+        self.outcomes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key.to_string(), (Instant::now(), outcome.clone()));
+        Ok(outcome)
+    }
+
+    /// The outcome stored for `key`, after dropping every expired entry.
+    fn replay(&self, key: &str) -> Option<AgentOutcome> {
+        let mut outcomes = self.outcomes.lock().unwrap_or_else(|e| e.into_inner());
+        outcomes.retain(|_, (stored, _)| stored.elapsed() < self.window);
+        outcomes.get(key).map(|(_, outcome)| outcome.clone())
+    }
+}
@@ -0,0 +1,110 @@
+use super::{ErrorStrings, StdioStrings, Strings};
+
+pub(super) const STRINGS: Strings = Strings {
+    stdio: StdioStrings {
+        banner: &[
+            "Interactive STDIO mode is ready.",
+            "Type a message and press Enter to send it.",
+            "Use /help to list the commands.",
+        ],
+        banner_agent_mode: "Agent mode is on so every answer is final; /agent off for direct chat.",
+        banner_chat_mode: "Direct chat mode is on; /agent on when a task needs tools.",
+        banner_tip: "Tip: type '/' and part of a command name (e.g. /co) for suggestions.",
+        help_title: "Available commands:",
+        help: &[
+            "  /help               Show this help",
+            "  /config             Show the active MCP configuration",
+            "  /config edit        Open the interactive configuration editor",
+            "  /config validate    Check that the config file's settings resolve",
+            "  /log                Show the logs of the last interaction",
+            "  /steps              Show the tool steps of the last interaction",
+            "  /agent [on|off]     Turn agent mode on or off",
+            "  /provider [id]      Pick the model provider (no id: back to the default)",
+            "  /reset, /clear      Clear the session and start a new conversation",
+            "  /cls                Clear the screen and scrollback; the session is kept",
+            "  /fork               Copy the active session into a new one to try alternatives",
+            "  /reload             Apply provider, model, and system prompt from file",
+            "  /exit               Leave STDIO mode",
+            "Type a message without a leading / to send it to the model.",
+        ],
+        help_tip: "Quick suggestions: '/', '/he', '/co', '/ag'.",
+        stdin_closed: "\nSTDIN closed. Leaving STDIO mode.",
+        interrupted: "\nCancelled (Ctrl+C). Leaving STDIO mode.",
+        exiting: "Closing STDIO mode.",
+        session_reset: "Session history cleared. Starting a new session.",
+        reload_start: "\nReloading configuration...",
+        reload_ok: "Configuration reloaded; this session now uses:",
+        reload_note: &[
+            "NOTE: Servers, tools, and prompt templates change only after a restart.",
+            "To apply those, use /exit and start again.",
+        ],
+        reload_failed: "Failed to load configuration: {error}",
+        reload_rejected: "{count} check(s) failed; nothing was applied.",
+        agent_unknown_value: "Unknown agent value '{value}'. Use on/off/toggle.",
+        agent_on: "Agent mode on. The next message runs the agent flow.",
+        agent_off: "Direct chat mode on. The next message goes straight to the model.",
+        provider_set: "Provider '{provider}' is used for the next messages.",
+        provider_default: "Back to the default provider.",
+        fork_no_session: "No active session to fork yet.",
+        fork_done: "Session {source} forked. Active session is now: {session}",
+        fork_missing: "Session {session} was not found; nothing was forked.",
+        config_edit_done: "\nBack to STDIO mode.",
+        config_edit_failed: "Editor error: {error}",
+        config_validate_title: "\n=== Config Checks ===",
+        config_validate_ok: "All checks passed.",
+        config_validate_failed: "{count} check(s) failed.",
+        wizard_unavailable: "The wizard is not enabled in this build. Use --features wizard to edit the config interactively.",
+        no_logs: "No logs from the last interaction yet.",
+        no_steps: "No tool executions in the last interaction yet.",
+        unknown_command: "Unknown command '{command}'. Use /help for help.",
+        no_recommendations: "No command suggestions for '/{prefix}'.",
+        recommendations: "Command suggestions:",
+        tool_call_redirect: "\nThe model response needs a tool call. Switching to agent mode automatically.",
+        session_active: "\nActive session: {session}",
+        session_updated: "\nActive session updated: {session}",
+        assistant_label: "Assistant:",
+        agent_label: "Agent:",
+        logs_hint: "(Use /log to see the latest logs.)",
+        request_failed: "\nRequest failed:",
+        agent_failed: "\nThe agent failed:",
+        tool_running: "{tool}: running...",
+        config_title: "\n=== Active Configuration ===",
+        not_set: "(not set)",
+        none: "(none)",
+        empty: "(empty)",
+        providers_managed: "(managed by the CLI)",
+        server_disabled: "(disabled)",
+        config_file_title: "\n=== File {path} ===",
+        config_file_empty: "(Empty file)",
+        config_file_missing: "(File not available yet. Showing the active configuration as TOML.)",
+        config_file_unreadable: "Failed to read the configuration file: {error}",
+        config_unchanged: "The active configuration is as shown above.",
+        steps_title: "\nTool steps:",
+        step_ok: "ok",
+        step_failed: "failed",
+        step_note: "note",
+        logs_title: "Logs:",
+    },
+    errors: ErrorStrings {
+        invalid_response: "The AI returned a response that could not be understood. Please repeat your instruction. Error: {error}",
+        max_steps_exceeded: "Maximum number of steps exceeded. Processing stopped.",
+        timeout: "The operation timed out. Please try again.",
+        tool_loop: "The AI kept calling tool \"{tool}\" with the same input ({repeats} times in a row). Processing stopped.",
+        memory: "State storage error: {error}",
+        agent_busy: "Too many requests are being processed right now. Please try again shortly.",
+        prompt_too_long: "The message is {chars} characters long, over the limit of {max}. Please shorten it and try again.",
+        unknown_tool: "Tool \"{tool}\" is not available on the server.",
+        unbound_tool: "Tool \"{tool}\" is not connected to any MCP server. Please check the client configuration.",
+        tool_execution: "Tool \"{tool}\" failed: {error}",
+        tool_timeout: "Tool \"{tool}\" did not finish within {seconds} seconds.",
+        no_providers: "No model providers are configured. Run setup to add one.",
+        provider_not_found: "Model provider '{provider}' was not found. Check the client.toml settings.",
+        model_not_found: "Model '{model}' is not available on provider '{provider}'.",
+        missing_api_key: "Provider '{provider}' requires an API key.",
+        network: "Network error on '{provider}': {error}",
+        rate_limited: "'{provider}' is rate limiting requests; still limited after {retries} automatic retries.",
+        rate_limited_wait: "The provider asks to wait {secs} seconds before trying again.",
+        invalid_model_response: "The response from '{provider}' is not valid.",
+        host_delegate: "The host failed to process the model request for '{provider}': {error}",
+    },
+};
@@ -0,0 +1,110 @@
+use super::{ErrorStrings, StdioStrings, Strings};
+
+pub(super) const STRINGS: Strings = Strings {
+    stdio: StdioStrings {
+        banner: &[
+            "Mode STDIO interaktif siap digunakan.",
+            "Ketik pesan lalu tekan Enter untuk mengirim.",
+            "Gunakan /help untuk daftar perintah.",
+        ],
+        banner_agent_mode: "Mode agent aktif untuk memastikan jawaban final; /agent off untuk chat langsung.",
+        banner_chat_mode: "Mode chat langsung aktif; /agent on bila tugas butuh tools.",
+        banner_tip: "Tip: ketik '/' lalu nama perintah sebagian (contoh: /co) untuk rekomendasi.",
+        help_title: "Perintah yang tersedia:",
+        help: &[
+            "  /help               Tampilkan bantuan ini",
+            "  /config             Lihat konfigurasi MCP aktif",
+            "  /config edit        Buka editor konfigurasi interaktif",
+            "  /config validate    Periksa apakah pengaturan file konfigurasi valid",
+            "  /log                Tampilkan log interaksi terakhir",
+            "  /steps              Tampilkan langkah tool terakhir",
+            "  /agent [on|off]     Aktifkan atau nonaktifkan mode agent",
+            "  /provider [id]      Pilih provider model (tanpa id: kembali ke default)",
+            "  /reset, /clear      Hapus session dan mulai percakapan baru",
+            "  /cls                Bersihkan layar dan scrollback; session tetap",
+            "  /fork               Salin session aktif ke session baru untuk mencoba alternatif",
+            "  /reload             Terapkan provider, model, dan system prompt dari file",
+            "  /exit               Keluar dari mode STDIO",
+            "Ketik pesan tanpa awalan / untuk mengirim ke model.",
+        ],
+        help_tip: "Rekomendasi cepat: '/', '/he', '/co', '/ag'.",
+        stdin_closed: "\nInput STDIN ditutup. Keluar dari mode STDIO.",
+        interrupted: "\nDibatalkan (Ctrl+C). Keluar dari mode STDIO.",
+        exiting: "Menutup mode STDIO.",
+        session_reset: "Riwayat sesi dihapus. Mulai sesi baru.",
+        reload_start: "\nMemuat ulang konfigurasi...",
+        reload_ok: "Konfigurasi dimuat ulang; session ini sekarang memakai:",
+        reload_note: &[
+            "CATATAN: Server, tool, dan template prompt baru berubah setelah restart.",
+            "Untuk menerapkannya, gunakan /exit lalu jalankan ulang.",
+        ],
+        reload_failed: "Gagal memuat konfigurasi: {error}",
+        reload_rejected: "{count} pemeriksaan gagal; tidak ada yang diterapkan.",
+        agent_unknown_value: "Nilai agent '{value}' tidak dikenal. Gunakan on/off/toggle.",
+        agent_on: "Mode agent aktif. Pesan berikutnya akan menjalankan alur agent.",
+        agent_off: "Mode chat langsung aktif. Pesan berikutnya dikirim langsung ke model.",
+        provider_set: "Provider '{provider}' dipakai untuk pesan berikutnya.",
+        provider_default: "Kembali ke provider default.",
+        fork_no_session: "Belum ada session aktif untuk disalin.",
+        fork_done: "Session {source} disalin. Session aktif sekarang: {session}",
+        fork_missing: "Session {session} tidak ditemukan; tidak ada yang disalin.",
+        config_edit_done: "\nKembali ke mode STDIO.",
+        config_edit_failed: "Error dalam editor: {error}",
+        config_validate_title: "\n=== Pemeriksaan Konfigurasi ===",
+        config_validate_ok: "Semua pemeriksaan lolos.",
+        config_validate_failed: "{count} pemeriksaan gagal.",
+        wizard_unavailable: "Fitur wizard tidak aktif pada build ini. Gunakan --features wizard untuk mengedit config interaktif.",
+        no_logs: "Belum ada log dari interaksi terakhir.",
+        no_steps: "Belum ada eksekusi tool pada interaksi terakhir.",
+        unknown_command: "Perintah '{command}' tidak dikenal. Gunakan /help untuk bantuan.",
+        no_recommendations: "Tidak ada rekomendasi perintah untuk '/{prefix}'.",
+        recommendations: "Rekomendasi perintah:",
+        tool_call_redirect: "\nRespons model memerlukan eksekusi tool. Mengalihkan ke mode agent otomatis.",
+        session_active: "\nSession aktif: {session}",
+        session_updated: "\nSession aktif diperbarui: {session}",
+        assistant_label: "Assistant:",
+        agent_label: "Agent:",
+        logs_hint: "(Gunakan /log untuk melihat log terbaru.)",
+        request_failed: "\nPermintaan gagal:",
+        agent_failed: "\nAgent mengalami kegagalan:",
+        tool_running: "{tool}: berjalan...",
+        config_title: "\n=== Konfigurasi Aktif ===",
+        not_set: "(tidak disetel)",
+        none: "(tidak ada)",
+        empty: "(kosong)",
+        providers_managed: "(dikelola oleh CLI)",
+        server_disabled: "(nonaktif)",
+        config_file_title: "\n=== Berkas {path} ===",
+        config_file_empty: "(Berkas kosong)",
+        config_file_missing: "(Berkas belum tersedia. Menampilkan konfigurasi aktif dalam bentuk TOML.)",
+        config_file_unreadable: "Gagal membaca berkas konfigurasi: {error}",
+        config_unchanged: "Konfigurasi aktif tetap seperti yang ditampilkan di atas.",
+        steps_title: "\nLangkah tool:",
+        step_ok: "sukses",
+        step_failed: "gagal",
+        step_note: "catatan",
+        logs_title: "Log:",
+    },
+    errors: ErrorStrings {
+        invalid_response: "AI memberikan respons yang tidak dapat dipahami. Coba ulangi instruksi Anda. Error: {error}",
+        max_steps_exceeded: "Langkah maksimum terlampaui. Proses dihentikan.",
+        timeout: "Operasi timeout. Silakan coba lagi.",
+        tool_loop: "AI terus memanggil tool \"{tool}\" dengan input yang sama ({repeats} kali berturut-turut). Proses dihentikan.",
+        memory: "Error penyimpanan state: {error}",
+        agent_busy: "Terlalu banyak permintaan yang sedang diproses. Silakan coba lagi sebentar lagi.",
+        prompt_too_long: "Pesan sepanjang {chars} karakter melebihi batas {max} karakter. Mohon persingkat lalu coba lagi.",
+        unknown_tool: "Tool \"{tool}\" belum tersedia di server.",
+        unbound_tool: "Tool \"{tool}\" belum terhubung ke MCP server apa pun. Mohon periksa konfigurasi client.",
+        tool_execution: "Eksekusi tool \"{tool}\" gagal: {error}",
+        tool_timeout: "Tool \"{tool}\" tidak selesai dalam {seconds} detik.",
+        no_providers: "Belum ada penyedia model yang dikonfigurasi. Jalankan setup untuk menambahkannya.",
+        provider_not_found: "Penyedia model '{provider}' tidak ditemukan. Periksa pengaturan client.toml.",
+        model_not_found: "Model '{model}' tidak tersedia pada penyedia '{provider}'.",
+        missing_api_key: "Penyedia '{provider}' memerlukan API key.",
+        network: "Kesalahan jaringan pada '{provider}': {error}",
+        rate_limited: "'{provider}' membatasi laju permintaan; masih dibatasi setelah {retries} percobaan ulang otomatis.",
+        rate_limited_wait: "Provider meminta menunggu {secs} detik sebelum mencoba lagi.",
+        invalid_model_response: "Respons dari '{provider}' tidak valid.",
+        host_delegate: "Host gagal memproses permintaan model untuk '{provider}': {error}",
+    },
+};
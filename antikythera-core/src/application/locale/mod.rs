@@ -0,0 +1,223 @@
+//! Localized user-facing strings.
+//!
+//! Each locale is one [`Strings`] table in its own file (`id.rs`, `en.rs`).
+//! Adding a locale means adding a table and a [`Locale`] variant; callers
+//! go through [`strings`] or, to pick a locale per request,
+//! [`Locale::strings`] (as the errors' `user_message_in` does).
+//!
+//! The active locale is process-wide. It defaults to Indonesian and is set
+//! once at startup from the `locale` config key (see [`LOCALE_KEY`]).
+//! Templates use `{name}` placeholders, filled with [`fill`].
+
+mod en;
+mod id;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Key in `PostcardAppConfig::custom` holding the locale code.
+pub const LOCALE_KEY: &str = "locale";
+
+/// Supported UI locales.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    /// Indonesian (`id`).
+    #[default]
+    Id,
+    /// English (`en`).
+    En,
+}
+
+impl Locale {
+    /// Parse a locale code such as `"id"`, `"en"` or `"en-US"`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        match language.as_str() {
+            "id" => Some(Locale::Id),
+            "en" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    /// Read the locale from a config `custom` map, falling back to the default.
+    pub fn from_custom(custom: &HashMap<String, String>) -> Self {
+        custom
+            .get(LOCALE_KEY)
+            .and_then(|code| Self::from_code(code))
+            .unwrap_or_default()
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::Id => "id",
+            Locale::En => "en",
+        }
+    }
+
+    /// The string table for this locale.
+    pub fn strings(self) -> &'static Strings {
+        match self {
+            Locale::Id => &id::STRINGS,
+            Locale::En => &en::STRINGS,
+        }
+    }
+}
+
+static ACTIVE_LOCALE: AtomicU8 = AtomicU8::new(0);
+
+/// Set the process-wide locale.
+pub fn set_locale(locale: Locale) {
+    ACTIVE_LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// The process-wide locale.
+pub fn current_locale() -> Locale {
+    match ACTIVE_LOCALE.load(Ordering::Relaxed) {
+        1 => Locale::En,
+        _ => Locale::Id,
+    }
+}
+
+/// The string table for the process-wide locale.
+pub fn strings() -> &'static Strings {
+    current_locale().strings()
+}
+
+/// Replace `{name}` placeholders in `template`.
+pub fn fill(template: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+/// All localized strings.
+#[derive(Debug)]
+pub struct Strings {
+    pub stdio: StdioStrings,
+    pub errors: ErrorStrings,
+}
+
+/// Text shown by the interactive STDIO loop.
+#[derive(Debug)]
+pub struct StdioStrings {
+    pub banner: &'static [&'static str],
+    /// Banner line when the session starts in agent mode.
+    pub banner_agent_mode: &'static str,
+    /// Banner line when the session starts in direct chat mode.
+    pub banner_chat_mode: &'static str,
+    pub banner_tip: &'static str,
+    pub help_title: &'static str,
+    pub help: &'static [&'static str],
+    pub help_tip: &'static str,
+    pub stdin_closed: &'static str,
+    pub interrupted: &'static str,
+    pub exiting: &'static str,
+    pub session_reset: &'static str,
+    pub reload_start: &'static str,
+    pub reload_ok: &'static str,
+    pub reload_note: &'static [&'static str],
+    /// `{error}`
+    pub reload_failed: &'static str,
+    /// `{count}`
+    pub reload_rejected: &'static str,
+    /// `{value}`
+    pub agent_unknown_value: &'static str,
+    pub agent_on: &'static str,
+    pub agent_off: &'static str,
+    /// `{provider}`
+    pub provider_set: &'static str,
+    pub provider_default: &'static str,
+    pub fork_no_session: &'static str,
+    /// `{source}`, `{session}`
+    pub fork_done: &'static str,
+    /// `{session}`
+    pub fork_missing: &'static str,
+    pub config_edit_done: &'static str,
+    /// `{error}`
+    pub config_edit_failed: &'static str,
+    pub config_validate_title: &'static str,
+    pub config_validate_ok: &'static str,
+    /// `{count}`
+    pub config_validate_failed: &'static str,
+    pub wizard_unavailable: &'static str,
+    pub no_logs: &'static str,
+    pub no_steps: &'static str,
+    /// `{command}`
+    pub unknown_command: &'static str,
+    /// `{prefix}`
+    pub no_recommendations: &'static str,
+    pub recommendations: &'static str,
+    pub tool_call_redirect: &'static str,
+    /// `{session}`
+    pub session_active: &'static str,
+    /// `{session}`
+    pub session_updated: &'static str,
+    pub assistant_label: &'static str,
+    pub agent_label: &'static str,
+    pub logs_hint: &'static str,
+    pub request_failed: &'static str,
+    pub agent_failed: &'static str,
+    /// `{tool}`; progress of a running tool call whose total is unknown.
+    pub tool_running: &'static str,
+    pub config_title: &'static str,
+    pub not_set: &'static str,
+    pub none: &'static str,
+    pub empty: &'static str,
+    pub providers_managed: &'static str,
+    /// Appended to a disabled server in the config listing.
+    pub server_disabled: &'static str,
+    /// `{path}`
+    pub config_file_title: &'static str,
+    pub config_file_empty: &'static str,
+    pub config_file_missing: &'static str,
+    /// `{error}`
+    pub config_file_unreadable: &'static str,
+    pub config_unchanged: &'static str,
+    pub steps_title: &'static str,
+    pub step_ok: &'static str,
+    pub step_failed: &'static str,
+    pub step_note: &'static str,
+    pub logs_title: &'static str,
+}
+
+/// `user_message()` text for agent, tool, and model errors.
+#[derive(Debug)]
+pub struct ErrorStrings {
+    /// `{error}`
+    pub invalid_response: &'static str,
+    pub max_steps_exceeded: &'static str,
+    pub timeout: &'static str,
+    /// `{tool}`, `{repeats}`
+    pub tool_loop: &'static str,
+    /// `{error}`
+    pub memory: &'static str,
+    pub agent_busy: &'static str,
+    /// `{chars}`, `{max}`
+    pub prompt_too_long: &'static str,
+    /// `{tool}`
+    pub unknown_tool: &'static str,
+    /// `{tool}`
+    pub unbound_tool: &'static str,
+    /// `{tool}`, `{error}`
+    pub tool_execution: &'static str,
+    /// `{tool}`, `{seconds}`
+    pub tool_timeout: &'static str,
+    pub no_providers: &'static str,
+    /// `{provider}`
+    pub provider_not_found: &'static str,
+    /// `{provider}`, `{model}`
+    pub model_not_found: &'static str,
+    /// `{provider}`
+    pub missing_api_key: &'static str,
+    /// `{provider}`, `{error}`
+    pub network: &'static str,
+    /// `{provider}`, `{retries}`
+    pub rate_limited: &'static str,
+    /// `{secs}`
+    pub rate_limited_wait: &'static str,
+    /// `{provider}`
+    pub invalid_model_response: &'static str,
+    /// `{provider}`, `{error}`
+    pub host_delegate: &'static str,
+}
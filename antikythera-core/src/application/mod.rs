@@ -8,6 +8,7 @@
 //! - [`agent`] - Autonomous agent that can use tools and execute multi-step tasks
 //! - [`discovery`] - Auto-discovery and loading of MCP servers from a folder
 //! - [`hooks`] - Host authentication, correlation, policy, and telemetry middleware
+//! - [`locale`] - Localized string tables for STDIO text and error messages
 //! - [`stdio`] - Standard input/output interface for command-line interaction
 //! - [`streaming`] - Token/event streaming primitives and host adapters
 //! - [`tooling`] - Tool server management and MCP server integration
@@ -18,13 +19,19 @@ pub mod agent;
 pub mod client;
 pub mod discovery;
 pub mod hooks;
+mod idempotency;
+pub mod locale;
 pub mod model_provider;
 pub mod observability;
+mod reasoning;
+mod request_metadata;
 pub mod resilience;
 pub(super) mod session_store;
+mod session_title;
 #[cfg(feature = "native-transport")]
 pub mod stdio;
 pub mod streaming;
+mod tool_cache;
 pub mod tooling;
 
 pub use hooks::{
@@ -40,7 +47,7 @@ pub use observability::{
     TraceSpanContext, TraceStatus, TracingHook,
 };
 pub use streaming::{
-    AgentEvent, AgentEventStream, BufferPolicy, ClientInputStream, InMemoryStreamingResponse,
-    StreamingBuffer, StreamingMode, StreamingPhase2Options, StreamingRequest, StreamingResponse,
-    StreamingSnapshot, ToolEventPhase,
+    AgentEvent, AgentEventSink, AgentEventStream, BufferPolicy, ClientInputStream,
+    InMemoryStreamingResponse, StreamingBuffer, StreamingMode, StreamingPhase2Options,
+    StreamingRequest, StreamingResponse, StreamingSnapshot, ToolEventPhase,
 };
@@ -0,0 +1,60 @@
+//! Reasoning blocks (`<think>…</think>` and similar) removed from model
+//! replies, as configured by [`ReasoningConfig`](crate::config::ReasoningConfig).
+
+use crate::config::ReasoningDelimiter;
+use crate::domain::types::{ChatMessage, MessagePart};
+
+/// Remove every complete reasoning block from `text`.
+///
+/// Returns the remaining answer and the text of each removed block. A
+/// closing marker with no opening marker before it ends a block that
+/// started at the beginning of the reply; some chat templates send the
+/// opening tag in the prompt, so only the closing one shows up. An opening
+/// marker that is never closed is left alone rather than dropping the rest
+/// of the reply.
+pub(super) fn strip(text: &str, delimiters: &[ReasoningDelimiter]) -> (String, Vec<String>) {
+    let mut answer = text.to_string();
+    let mut reasoning = Vec::new();
+    for delimiter in delimiters {
+        let (open, close) = (delimiter.open.as_str(), delimiter.close.as_str());
+        if open.is_empty() || close.is_empty() {
+            continue;
+        }
+        if let Some(end) = answer.find(close)
+            && answer.find(open).is_none_or(|start| start > end)
+        {
+            reasoning.push(answer[..end].trim().to_string());
+            answer.replace_range(..end + close.len(), "");
+        }
+        while let Some(start) = answer.find(open) {
+            let inner = start + open.len();
+            let Some(length) = answer[inner..].find(close) else {
+                break;
+            };
+            reasoning.push(answer[inner..inner + length].trim().to_string());
+            answer.replace_range(start..inner + length + close.len(), "");
+        }
+    }
+    if reasoning.is_empty() {
+        return (answer, reasoning);
+    }
+    reasoning.retain(|block| !block.is_empty());
+    (answer.trim().to_string(), reasoning)
+}
+
+/// Strip reasoning from each text part of `message`, returning the removed
+/// blocks.
+pub(super) fn strip_message(
+    message: &mut ChatMessage,
+    delimiters: &[ReasoningDelimiter],
+) -> Vec<String> {
+    let mut removed = Vec::new();
+    for part in &mut message.parts {
+        if let MessagePart::Text { text } = part {
+            let (answer, reasoning) = strip(text, delimiters);
+            *text = answer;
+            removed.extend(reasoning);
+        }
+    }
+    removed
+}
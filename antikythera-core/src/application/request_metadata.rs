@@ -0,0 +1,28 @@
+//! `{{metadata.<key>}}` placeholders filled from
+//! [`ChatRequest::metadata`](crate::application::client::ChatRequest::metadata).
+
+use std::collections::HashMap;
+
+const OPEN: &str = "{{metadata.";
+const CLOSE: &str = "}}";
+
+/// Replace every `{{metadata.<key>}}` in `text` with the value of `key`, or
+/// with nothing when the key is missing. Inserted values are not scanned
+/// again, so a value that itself contains a placeholder is kept as is.
+pub(super) fn substitute(text: &str, metadata: &HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(OPEN) {
+        let after_open = &rest[start + OPEN.len()..];
+        let Some(end) = after_open.find(CLOSE) else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        if let Some(value) = metadata.get(&after_open[..end]) {
+            out.push_str(value);
+        }
+        rest = &after_open[end + CLOSE.len()..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -1,4 +1,6 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant};
 
 use crate::domain::types::ChatMessage;
 use crate::domain::types::MessagePart;
@@ -7,6 +9,7 @@ use crate::logging::SessionLogger;
 use antikythera_session::{
     Message, MessagePart as SessionMessagePart, MessageRole as SessionMessageRole, SessionManager,
 };
+use chrono::{DateTime, Utc};
 
 /// Default maximum number of concurrent sessions kept in memory.
 ///
@@ -15,39 +18,93 @@ use antikythera_session::{
 /// long-running deployments with many ephemeral sessions.
 pub(super) const DEFAULT_MAX_SESSIONS: usize = 256;
 
-/// In-memory session store with LRU eviction.
+/// Per-session turn locks.
+///
+/// A full chat turn (read history → provider call → persist) holds its
+/// session's lock, so concurrent requests on one session run one after the
+/// other while different sessions proceed in parallel. Entries nobody holds
+/// or waits on are dropped on the next acquire.
+#[derive(Default)]
+pub(super) struct SessionLocks {
+    locks: StdMutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl SessionLocks {
+    pub(super) async fn acquire(&self, session_id: &str) -> tokio::sync::OwnedMutexGuard<()> {
+        let lock = {
+            let mut locks = self.locks.lock().unwrap_or_else(|e| e.into_inner());
+            locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+            locks.entry(session_id.to_string()).or_default().clone()
+        };
+        lock.lock_owned().await
+    }
+}
+
+/// In-memory session store with LRU eviction and an optional idle TTL.
 pub(super) struct SessionStore {
     manager: SessionManager,
     /// Access order: front = least recently used, back = most recently used.
     pub(super) order: VecDeque<String>,
     /// Maximum number of sessions to retain simultaneously.
     pub(super) max_sessions: usize,
+    /// Messages a session starts with when it is created.
+    seed: Vec<ChatMessage>,
+    /// Idle time after which a session is dropped; `None` keeps it.
+    ttl: Option<Duration>,
+    /// When each session in `order` was last touched.
+    last_access: HashMap<String, Instant>,
 }
 
 impl SessionStore {
-    pub(super) fn new(max_sessions: usize) -> Self {
+    pub(super) fn new(max_sessions: usize, seed: Vec<ChatMessage>, ttl: Option<Duration>) -> Self {
         Self {
             manager: SessionManager::new(),
             order: VecDeque::new(),
             max_sessions,
+            seed,
+            ttl,
+            last_access: HashMap::new(),
         }
     }
 
-    /// Return a reference to the history for `session_id`, or `None`.
+    /// Return the history for `session_id` in creation order, or `None`.
+    /// A session idle beyond the TTL counts as gone.
+    ///
+    /// Messages with the same timestamp keep the order they were stored in.
     pub(super) fn get(&self, session_id: &str) -> Option<Vec<ChatMessage>> {
+        if self.expired(session_id) {
+            return None;
+        }
         self.manager
             .get_chat_history(session_id)
             .ok()
-            .map(|messages| messages.into_iter().map(session_message_to_chat).collect())
+            .map(|messages| {
+                let mut history: Vec<ChatMessage> =
+                    messages.into_iter().map(session_message_to_chat).collect();
+                history.sort_by_key(|message| message.created_at);
+                history
+            })
     }
 
-    /// Ensure a session exists and mark it as most-recently-used.
+    /// History of `session_id`, or the seed messages a new session would
+    /// start with when it does not exist yet.
+    pub(super) fn history_or_seed(&self, session_id: &str) -> Vec<ChatMessage> {
+        self.get(session_id).unwrap_or_else(|| self.seed.clone())
+    }
+
+    /// Ensure a session exists and mark it as most-recently-used. A new
+    /// session starts with the seed messages.
     pub(super) fn touch_or_create(&mut self, session_id: &str) {
         self.touch(session_id);
         if !self.manager.has_session(session_id).unwrap_or(false) {
             let _ =
                 self.manager
                     .create_session_with_id(session_id.to_string(), "core", "core-default");
+            for message in self.seed.clone() {
+                let _ = self
+                    .manager
+                    .add_message(session_id, chat_to_session_message(message));
+            }
         }
     }
 
@@ -62,6 +119,38 @@ impl SessionStore {
         }
     }
 
+    /// Copy the history of `source` into a new session `target`.
+    ///
+    /// Returns `false` (and creates nothing) when `source` does not exist.
+    pub(super) fn fork(&mut self, source: &str, target: &str) -> bool {
+        let Some(history) = self.get(source) else {
+            return false;
+        };
+        self.touch(source);
+        self.replace_history(target, history);
+        if let Some(title) = self.title(source) {
+            self.set_title(target, &title);
+        }
+        true
+    }
+
+    /// Title of `session_id`, if it exists and has one.
+    pub(super) fn title(&self, session_id: &str) -> Option<String> {
+        if self.expired(session_id) {
+            return None;
+        }
+        self.manager
+            .get_session(session_id)
+            .ok()
+            .flatten()
+            .and_then(|session| session.title)
+    }
+
+    /// Set the title of an existing session; unknown sessions are ignored.
+    pub(super) fn set_title(&self, session_id: &str, title: &str) {
+        let _ = self.manager.update_title(session_id, title);
+    }
+
     /// Get the underlying session manager.
     pub(super) fn manager(&self) -> &SessionManager {
         &self.manager
@@ -83,16 +172,29 @@ impl SessionStore {
 
     // ── internal helpers ─────────────────────────────────────────────────────
 
+    /// Whether `session_id` has been idle for longer than the TTL.
+    fn expired(&self, session_id: &str) -> bool {
+        self.ttl.is_some_and(|ttl| {
+            self.last_access
+                .get(session_id)
+                .is_some_and(|at| at.elapsed() > ttl)
+        })
+    }
+
     /// Move `session_id` to the back of the access-order deque (most recent).
     ///
-    /// If the session is new and the store is at capacity, the front entry
-    /// (least recently used) is evicted first.
+    /// Sessions idle beyond the TTL are dropped first, so an expired
+    /// `session_id` comes back as a new session. If the session is new and
+    /// the store is at capacity, the front entry (least recently used) is
+    /// evicted too.
     fn touch(&mut self, session_id: &str) {
+        self.evict_expired();
         if let Some(pos) = self.order.iter().position(|id| id == session_id) {
             self.order.remove(pos);
         } else if self.order.len() >= self.max_sessions
             && let Some(lru_id) = self.order.pop_front()
         {
+            self.last_access.remove(&lru_id);
             let _ = self.manager.delete_session(&lru_id);
             SessionLogger::new(&lru_id).debug(format!(
                 "Evicted LRU session from in-memory store | evicted_session={} active_sessions={}",
@@ -101,6 +203,27 @@ impl SessionStore {
             ));
         }
         self.order.push_back(session_id.to_string());
+        self.last_access
+            .insert(session_id.to_string(), Instant::now());
+    }
+
+    /// Drop every session idle beyond the TTL. `order` runs from least to
+    /// most recently used, so expired sessions are all at its front.
+    fn evict_expired(&mut self) {
+        while let Some(oldest) = self.order.front()
+            && self.expired(oldest)
+        {
+            let Some(id) = self.order.pop_front() else {
+                break;
+            };
+            self.last_access.remove(&id);
+            let _ = self.manager.delete_session(&id);
+            SessionLogger::new(&id).debug(format!(
+                "Evicted idle session from in-memory store | evicted_session={} active_sessions={}",
+                id,
+                self.order.len()
+            ));
+        }
     }
 }
 
@@ -157,16 +280,21 @@ fn session_message_to_chat(message: Message) -> ChatMessage {
             .collect()
     };
 
-    ChatMessage::with_parts(session_role_to_core(message.role), parts)
+    let created_at = DateTime::parse_from_rfc3339(&message.timestamp)
+        .map(|at| at.with_timezone(&Utc))
+        .unwrap_or_default();
+    ChatMessage::with_parts(session_role_to_core(message.role), parts).with_created_at(created_at)
 }
 
 fn chat_to_session_message(message: ChatMessage) -> Message {
-    Message::with_parts(
+    let mut stored = Message::with_parts(
         core_role_to_session(message.role),
         message
             .parts
             .into_iter()
             .map(core_part_to_session)
             .collect(),
-    )
+    );
+    stored.timestamp = message.created_at.to_rfc3339();
+    stored
 }
@@ -0,0 +1,35 @@
+//! Session titles derived from the first user message, as configured by
+//! [`SessionTitleConfig`](crate::config::SessionTitleConfig).
+
+use crate::domain::types::{ChatMessage, MessageRole};
+
+/// Minimal instruction for the `summarize` strategy.
+const SUMMARY_INSTRUCTION: &str = "Write a short title of at most six words for a conversation \
+that starts with the user message below. Reply with the title only, without quotes.";
+
+/// `text` on one line, cut to `max_chars` characters.
+pub(super) fn truncate(text: &str, max_chars: usize) -> String {
+    let single_line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let cut: String = single_line.chars().take(max_chars).collect();
+    cut.trim_end().to_string()
+}
+
+/// Messages asking the model to title a conversation opened by `first_message`.
+pub(super) fn summary_messages(first_message: &str) -> Vec<ChatMessage> {
+    vec![
+        ChatMessage::new(MessageRole::System, SUMMARY_INSTRUCTION),
+        ChatMessage::new(MessageRole::User, first_message),
+    ]
+}
+
+/// First non-empty line of the model's `reply` without a `Title:` prefix or
+/// surrounding quotes, cut to `max_chars`; `None` when nothing is left.
+pub(super) fn clean_summary(reply: &str, max_chars: usize) -> Option<String> {
+    let line = reply.lines().map(str::trim).find(|line| !line.is_empty())?;
+    let line = match line.split_once(':') {
+        Some((label, rest)) if label.trim().eq_ignore_ascii_case("title") => rest,
+        _ => line,
+    };
+    let title = truncate(line.trim().trim_matches(['"', '\'', '*', '`']), max_chars);
+    (!title.is_empty()).then_some(title)
+}
@@ -0,0 +1,173 @@
+//! Slash-command table for the STDIO loop.
+//!
+//! `COMMANDS` is the single list that dispatch, recommendations, and
+//! tab-completion are built from. Each entry names the [`Command`] it runs,
+//! and the dispatcher matches on that enum, so a command cannot be handled
+//! without being listed here, nor listed without being handled.
+
+/// What a slash command does, whichever of its names was typed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Command {
+    Help,
+    Config,
+    Log,
+    Steps,
+    Agent,
+    Fork,
+    Provider,
+    Reset,
+    ClearScreen,
+    Reload,
+    Exit,
+}
+
+pub(super) struct CommandSpec {
+    pub name: &'static str,
+    /// Other names that run the same command; not offered for completion.
+    pub aliases: &'static [&'static str],
+    pub command: Command,
+    /// Accepted first arguments, offered after `/<name> `.
+    pub args: &'static [&'static str],
+}
+
+pub(super) const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "help",
+        aliases: &["?"],
+        command: Command::Help,
+        args: &[],
+    },
+    CommandSpec {
+        name: "config",
+        aliases: &[],
+        command: Command::Config,
+        args: &["edit", "validate"],
+    },
+    CommandSpec {
+        name: "log",
+        aliases: &["logs"],
+        command: Command::Log,
+        args: &[],
+    },
+    CommandSpec {
+        name: "steps",
+        aliases: &["tool", "toolsteps"],
+        command: Command::Steps,
+        args: &[],
+    },
+    CommandSpec {
+        name: "agent",
+        aliases: &[],
+        command: Command::Agent,
+        args: &["on", "off", "toggle"],
+    },
+    CommandSpec {
+        name: "fork",
+        aliases: &[],
+        command: Command::Fork,
+        args: &[],
+    },
+    CommandSpec {
+        name: "provider",
+        aliases: &[],
+        command: Command::Provider,
+        args: &["default"],
+    },
+    CommandSpec {
+        name: "reset",
+        aliases: &["clear"],
+        command: Command::Reset,
+        args: &[],
+    },
+    CommandSpec {
+        name: "cls",
+        aliases: &["clear-screen"],
+        command: Command::ClearScreen,
+        args: &[],
+    },
+    CommandSpec {
+        name: "reload",
+        aliases: &[],
+        command: Command::Reload,
+        args: &[],
+    },
+    CommandSpec {
+        name: "exit",
+        aliases: &["keluar", "q"],
+        command: Command::Exit,
+        args: &[],
+    },
+    CommandSpec {
+        name: "quit",
+        aliases: &[],
+        command: Command::Exit,
+        args: &[],
+    },
+];
+
+/// The command `name` (lowercase, without the leading `/`) runs, by its
+/// listed name or one of its aliases.
+pub(super) fn resolve_command(name: &str) -> Option<Command> {
+    COMMANDS
+        .iter()
+        .find(|spec| spec.name == name || spec.aliases.contains(&name))
+        .map(|spec| spec.command)
+}
+
+pub fn suggest_commands(prefix: &str) -> Vec<&'static str> {
+    let normalized = prefix.trim().to_ascii_lowercase();
+    let names = COMMANDS.iter().map(|spec| spec.name);
+    let mut suggestions: Vec<&'static str> = if normalized.is_empty() {
+        names.collect()
+    } else {
+        names
+            .filter(|cmd| cmd.starts_with(&normalized) || cmd.contains(&normalized))
+            .collect()
+    };
+    suggestions.sort_unstable();
+    suggestions.truncate(6);
+    suggestions
+}
+
+/// Tab-completion candidates for a partially typed command line.
+///
+/// Returns the byte offset the candidates replace from, and the candidates
+/// themselves. Lines that are not commands (no leading `/` or `:`) and
+/// positions past the first argument produce no candidates.
+pub fn complete_command(line: &str) -> (usize, Vec<&'static str>) {
+    let Some(rest) = line.strip_prefix(['/', ':']) else {
+        return (0, Vec::new());
+    };
+
+    match rest.split_once(char::is_whitespace) {
+        None => {
+            let typed = rest.to_ascii_lowercase();
+            let candidates = COMMANDS
+                .iter()
+                .map(|spec| spec.name)
+                .filter(|name| name.starts_with(&typed))
+                .collect();
+            (1, candidates)
+        }
+        Some((name, arg)) => {
+            let arg = arg.trim_start();
+            if arg.contains(char::is_whitespace) {
+                return (line.len(), Vec::new());
+            }
+            let name = name.to_ascii_lowercase();
+            let typed = arg.to_ascii_lowercase();
+            let candidates = COMMANDS
+                .iter()
+                .find(|spec| spec.name == name)
+                .map(|spec| {
+                    spec.args
+                        .iter()
+                        .copied()
+                        .filter(|candidate| candidate.starts_with(&typed))
+                        .collect()
+                })
+                .unwrap_or_default();
+            (line.len() - arg.len(), candidates)
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! Line input for the interactive STDIO loop.
+//!
+//! Plain mode reads stdin line by line, which keeps piped input working.
+//! With the `line-editor` feature and a terminal on stdin, input goes through
+//! `rustyline` instead, giving an in-memory history ring recalled with the
+//! Up/Down keys, Tab completion of slash commands and, optionally, a history
+//! file that survives restarts.
+
+use super::StdioError;
+use std::path::PathBuf;
+use tokio::io::{self, AsyncBufReadExt, AsyncWriteExt, BufReader, Lines, Stdin};
+
+/// Default number of entries kept in the STDIO history ring.
+pub const DEFAULT_HISTORY_SIZE: usize = 500;
+
+/// Options for [`run_with_options`](super::run_with_options).
+#[derive(Debug, Clone)]
+pub struct StdioOptions {
+    /// Use the line editor when stdin is a terminal. Ignored on builds
+    /// without the `line-editor` feature.
+    pub line_editor: bool,
+    /// File the history is loaded from at start and written back to on exit.
+    pub history_file: Option<PathBuf>,
+    /// Maximum number of entries kept in the history ring.
+    pub history_size: usize,
+    /// Directory `/config edit` reads and writes (`client.toml`, `.env`,
+    /// `app.pc`); the usual `config/` layout when `None`.
+    pub config_dir: Option<PathBuf>,
+    /// Start in agent mode; `/agent` still switches at any time. Callers
+    /// usually take it from
+    /// [`AppConfig::stdio_default_agent_mode`](crate::config::AppConfig::stdio_default_agent_mode).
+    pub agent_mode: bool,
+}
+
+impl Default for StdioOptions {
+    fn default() -> Self {
+        Self {
+            line_editor: true,
+            history_file: None,
+            history_size: DEFAULT_HISTORY_SIZE,
+            config_dir: None,
+            agent_mode: true,
+        }
+    }
+}
+
+pub(super) enum ReadOutcome {
+    Line(String),
+    /// Stdin closed (Ctrl+D or end of a pipe).
+    Eof,
+    /// Ctrl+C while the line editor owns the terminal.
+    #[cfg_attr(not(feature = "line-editor"), allow(dead_code))]
+    Interrupted,
+}
+
+pub(super) enum LineInput {
+    Plain(Lines<BufReader<Stdin>>),
+    #[cfg(feature = "line-editor")]
+    Editor {
+        // `None` only while a blocking read is in flight.
+        editor: Option<Box<CommandEditor>>,
+        history_file: Option<PathBuf>,
+    },
+}
+
+impl LineInput {
+    pub(super) fn new(options: &StdioOptions) -> Self {
+        #[cfg(feature = "line-editor")]
+        if options.line_editor && std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+            match build_editor(options) {
+                Ok(editor) => {
+                    return LineInput::Editor {
+                        editor: Some(Box::new(editor)),
+                        history_file: options.history_file.clone(),
+                    };
+                }
+                Err(err) => crate::logging::StdioLogger::new("stdio").warn(format!(
+                    "Line editor unavailable, falling back to plain input | error={}",
+                    err
+                )),
+            }
+        }
+        #[cfg(not(feature = "line-editor"))]
+        let _ = options;
+
+        LineInput::Plain(BufReader::new(io::stdin()).lines())
+    }
+
+    pub(super) async fn read_line(
+        &mut self,
+        stdout: &mut io::Stdout,
+        prompt: &str,
+    ) -> Result<ReadOutcome, StdioError> {
+        match self {
+            LineInput::Plain(lines) => {
+                stdout.write_all(prompt.as_bytes()).await?;
+                stdout.flush().await?;
+                Ok(match lines.next_line().await? {
+                    Some(line) => ReadOutcome::Line(line),
+                    None => ReadOutcome::Eof,
+                })
+            }
+            #[cfg(feature = "line-editor")]
+            LineInput::Editor { editor, .. } => {
+                use rustyline::error::ReadlineError;
+
+                stdout.flush().await?;
+                let mut owned = editor.take().expect("line editor is always restored");
+                let prompt = prompt.to_string();
+                let (owned, result) = tokio::task::spawn_blocking(move || {
+                    let result = owned.readline(&prompt);
+                    (owned, result)
+                })
+                .await
+                .map_err(std::io::Error::other)?;
+                *editor = Some(owned);
+
+                match result {
+                    Ok(line) => Ok(ReadOutcome::Line(line)),
+                    Err(ReadlineError::Eof) => Ok(ReadOutcome::Eof),
+                    Err(ReadlineError::Interrupted) => Ok(ReadOutcome::Interrupted),
+                    Err(ReadlineError::Io(err)) => Err(err.into()),
+                    Err(other) => Err(std::io::Error::other(other).into()),
+                }
+            }
+        }
+    }
+
+    /// Add a submitted line to the history ring.
+    #[cfg_attr(not(feature = "line-editor"), allow(unused_variables))]
+    pub(super) fn record(&mut self, line: &str) {
+        #[cfg(feature = "line-editor")]
+        if let LineInput::Editor {
+            editor: Some(editor),
+            ..
+        } = self
+        {
+            let _ = editor.add_history_entry(line);
+        }
+    }
+
+    /// Write the history ring to the configured history file, if any.
+    pub(super) fn persist(&mut self) {
+        #[cfg(feature = "line-editor")]
+        if let LineInput::Editor {
+            editor: Some(editor),
+            history_file: Some(path),
+        } = self
+            && let Err(err) = editor.save_history(path)
+        {
+            crate::logging::StdioLogger::new("stdio").warn(format!(
+                "Failed to save STDIO history | path={} error={}",
+                path.display(),
+                err
+            ));
+        }
+    }
+}
+
+#[cfg(feature = "line-editor")]
+pub(super) type CommandEditor = rustyline::Editor<CommandHelper, rustyline::history::FileHistory>;
+
+/// Completes slash commands and their first argument from the command table.
+#[cfg(feature = "line-editor")]
+pub(super) struct CommandHelper;
+
+#[cfg(feature = "line-editor")]
+impl rustyline::completion::Completer for CommandHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let (start, candidates) = super::complete_command(&line[..pos]);
+        Ok((start, candidates.into_iter().map(str::to_string).collect()))
+    }
+}
+
+#[cfg(feature = "line-editor")]
+impl rustyline::hint::Hinter for CommandHelper {
+    type Hint = String;
+}
+
+#[cfg(feature = "line-editor")]
+impl rustyline::highlight::Highlighter for CommandHelper {}
+
+#[cfg(feature = "line-editor")]
+impl rustyline::validate::Validator for CommandHelper {}
+
+#[cfg(feature = "line-editor")]
+impl rustyline::Helper for CommandHelper {}
+
+#[cfg(feature = "line-editor")]
+fn build_editor(options: &StdioOptions) -> rustyline::Result<CommandEditor> {
+    let config = rustyline::Config::builder()
+        .max_history_size(options.history_size)?
+        .history_ignore_dups(true)?
+        .history_ignore_space(true)
+        .auto_add_history(false)
+        .completion_type(rustyline::CompletionType::List)
+        .build();
+    let mut editor = CommandEditor::with_config(config)?;
+    editor.set_helper(Some(CommandHelper));
+    if let Some(path) = &options.history_file {
+        // A missing file just means there is no history yet.
+        let _ = editor.load_history(path);
+    }
+    Ok(editor)
+}
@@ -1,21 +1,32 @@
+mod commands;
+mod line_input;
 mod stdio_render;
 mod tool_detection;
 
+pub use self::commands::{complete_command, suggest_commands};
+pub use self::line_input::{DEFAULT_HISTORY_SIZE, StdioOptions};
+
+use self::commands::{Command, resolve_command};
+use self::line_input::{LineInput, ReadOutcome};
 use self::stdio_render::{
-    print_banner, print_command_recommendations, print_help, print_logs, print_tool_steps, prompt,
-    show_config, write_line,
+    print_banner, print_command_recommendations, print_help, print_logs, print_tool_steps,
+    prompt_text, show_config, write_line,
 };
 use self::tool_detection::looks_like_tool_call;
 use crate::application::agent::{Agent, AgentOptions, AgentOutcome, AgentStep};
 use crate::application::client::{ChatRequest, ChatResult, McpClient};
+use crate::application::locale::{fill, strings};
 use crate::application::model_provider::ModelProvider;
-use crate::config::AppConfig;
+use crate::application::streaming::AgentEvent;
+use crate::config::postcard_config::ProviderConfig;
+use crate::config::{AppConfig, ConfigError};
 use crate::logging::StdioLogger;
 use serde_json::Value;
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use thiserror::Error;
-use tokio::io::{self, AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::io::{self, AsyncWriteExt};
 
 #[derive(Debug, Error)]
 pub enum StdioError {
@@ -26,20 +37,51 @@ pub enum StdioError {
 struct SessionState {
     session_id: Option<String>,
     agent_mode: bool,
+    provider: Option<String>,
+    /// Model and system prompt applied by `/reload`; `None` uses the
+    /// client's.
+    model: Option<String>,
+    system_prompt: Option<String>,
     last_logs: Vec<String>,
     last_steps: Vec<AgentStep>,
+    config_dir: Option<PathBuf>,
 }
 
 impl SessionState {
-    fn new() -> Self {
+    fn new(agent_mode: bool) -> Self {
         Self {
             session_id: None,
-            agent_mode: true,
+            agent_mode,
+            provider: None,
+            model: None,
+            system_prompt: None,
             last_logs: Vec::new(),
             last_steps: Vec::new(),
+            config_dir: None,
+        }
+    }
+
+    /// The runtime config this session reads: the `--config-dir` layout
+    /// when one was given, else the default lookup.
+    fn load_config(&self) -> Result<AppConfig, ConfigError> {
+        match &self.config_dir {
+            Some(dir) => crate::config::loader::load_config_in(dir),
+            None => AppConfig::load(None),
         }
     }
 
+    /// Provider definitions from the session's `app.pc`; none when it cannot
+    /// be read.
+    fn saved_providers(&self) -> Vec<ProviderConfig> {
+        let path = self
+            .config_dir
+            .as_ref()
+            .map(|dir| dir.join(crate::config::CONFIG_PATH));
+        crate::config::postcard_config::load_config(path.as_deref())
+            .map(|saved| saved.providers)
+            .unwrap_or_default()
+    }
+
     fn reset(&mut self) {
         self.session_id = None;
         self.last_logs.clear();
@@ -99,42 +141,43 @@ const SUCCESS: &str = "\x1b[32m";
 const WARN: &str = "\x1b[33m";
 const DIM: &str = "\x1b[2m";
 const RESET: &str = "\x1b[0m";
-
-const KNOWN_COMMANDS: [&str; 10] = [
-    "help",
-    "config",
-    "config edit",
-    "log",
-    "steps",
-    "agent",
-    "reset",
-    "reload",
-    "exit",
-    "quit",
-];
+/// Erase the screen and the scrollback, then home the cursor.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[3J\x1b[H";
 
 pub async fn run<P>(client: Arc<McpClient<P>>) -> Result<(), StdioError>
+where
+    P: ModelProvider + 'static,
+{
+    run_with_options(client, StdioOptions::default()).await
+}
+
+pub async fn run_with_options<P>(
+    client: Arc<McpClient<P>>,
+    options: StdioOptions,
+) -> Result<(), StdioError>
 where
     P: ModelProvider + 'static,
 {
     let mut stdout = io::stdout();
-    let stdin = BufReader::new(io::stdin());
-    let mut lines = stdin.lines();
-    let mut state = SessionState::new();
+    let mut input_source = LineInput::new(&options);
+    let mut state = SessionState::new(options.agent_mode);
+    state.config_dir = options.config_dir.clone();
 
-    print_banner(&mut stdout).await?;
+    print_banner(&mut stdout, state.agent_mode).await?;
     print_help(&mut stdout).await?;
 
     loop {
-        prompt(&mut stdout, &state).await?;
-        let line = match lines.next_line().await? {
-            Some(line) => line,
-            None => {
-                write_line(
-                    &mut stdout,
-                    "\nInput STDIN ditutup. Keluar dari mode STDIO.",
-                )
-                .await?;
+        let line = match input_source
+            .read_line(&mut stdout, &prompt_text(&state))
+            .await?
+        {
+            ReadOutcome::Line(line) => line,
+            ReadOutcome::Eof => {
+                write_line(&mut stdout, strings().stdio.stdin_closed).await?;
+                break;
+            }
+            ReadOutcome::Interrupted => {
+                write_line(&mut stdout, strings().stdio.interrupted).await?;
                 break;
             }
         };
@@ -143,6 +186,7 @@ where
         if input.is_empty() {
             continue;
         }
+        input_source.record(input);
 
         if matches!(input.chars().next(), Some('/') | Some(':')) {
             match handle_command(input, &mut state, client.as_ref(), &mut stdout).await? {
@@ -154,6 +198,7 @@ where
         }
     }
 
+    input_source.persist();
     stdout.flush().await?;
     Ok(())
 }
@@ -170,72 +215,91 @@ async fn handle_command<P: ModelProvider>(
     let args: Vec<String> = parts.map(|part| part.to_string()).collect();
 
     StdioLogger::new("stdio").debug(format!("Processing STDIO command | command={}", name));
+    let text = &strings().stdio;
 
-    match name.as_str() {
-        "" => {
-            print_command_recommendations(stdout, "").await?;
-            Ok(LoopControl::Continue)
-        }
-        "help" | "?" => {
+    if name.is_empty() {
+        print_command_recommendations(stdout, "").await?;
+        return Ok(LoopControl::Continue);
+    }
+    let Some(command) = resolve_command(&name) else {
+        write_line(stdout, &fill(text.unknown_command, &[("command", &name)])).await?;
+        print_command_recommendations(stdout, &name).await?;
+        return Ok(LoopControl::Continue);
+    };
+
+    match command {
+        Command::Help => {
             print_help(stdout).await?;
             Ok(LoopControl::Continue)
         }
-        "exit" | "quit" | "keluar" | "q" => {
-            write_line(stdout, "Menutup mode STDIO.").await?;
+        Command::Exit => {
+            write_line(stdout, text.exiting).await?;
             Ok(LoopControl::Exit)
         }
-        "reset" | "clear" => {
+        // `/clear` stays a session reset for existing users; `/cls` only
+        // clears the terminal.
+        Command::Reset => {
             state.reset();
-            write_line(stdout, "Riwayat sesi dihapus. Mulai sesi baru.").await?;
+            write_line(stdout, text.session_reset).await?;
             Ok(LoopControl::Continue)
         }
-        "reload" => {
-            write_line(stdout, "\nMemuat ulang konfigurasi...").await?;
-            match AppConfig::load(Some(Path::new(crate::config::CONFIG_PATH))) {
+        Command::ClearScreen => {
+            stdout.write_all(CLEAR_SCREEN.as_bytes()).await?;
+            Ok(LoopControl::Continue)
+        }
+        Command::Reload => {
+            write_line(stdout, text.reload_start).await?;
+            match state.load_config() {
                 Ok(config) => {
-                    write_line(stdout, "Konfigurasi berhasil dimuat dari file.").await?;
+                    let report = config.validate(&state.saved_providers());
+                    if !report.is_valid() {
+                        write_line(stdout, &report.render()).await?;
+                        let rejected = fill(
+                            text.reload_rejected,
+                            &[("count", &report.failures().count().to_string())],
+                        );
+                        write_line(stdout, &format!("{WARN}{rejected}{RESET}")).await?;
+                        return Ok(LoopControl::Continue);
+                    }
+                    state.provider = Some(config.default_provider.clone());
+                    state.model = Some(config.model.clone());
+                    if config.system_prompt.is_some() {
+                        state.system_prompt = config.system_prompt.clone();
+                    }
+                    write_line(stdout, text.reload_ok).await?;
                     write_line(stdout, &format!("- Provider: {}", config.default_provider)).await?;
                     write_line(stdout, &format!("- Model: {}", config.model)).await?;
-                    write_line(
-                        stdout,
-                        &format!(
-                            "- Prompt template: {} characters",
-                            config.prompt_template().len()
-                        ),
-                    )
-                    .await?;
-                    write_line(stdout, &format!("- Servers: {}", config.servers.len())).await?;
-                    write_line(stdout, &format!("- Tools: {}", config.tools.len())).await?;
+                    if let Some(prompt) = &state.system_prompt {
+                        write_line(
+                            stdout,
+                            &format!("- System prompt: {} characters", prompt.chars().count()),
+                        )
+                        .await?;
+                    }
                     write_line(stdout, "").await?;
+                    for line in text.reload_note {
+                        write_line(stdout, line).await?;
+                    }
+                }
+                Err(error) => {
                     write_line(
                         stdout,
-                        "CATATAN: Perubahan konfigurasi akan berlaku setelah restart aplikasi.",
-                    )
-                    .await?;
-                    write_line(
-                        stdout,
-                        "Untuk menerapkan konfigurasi baru, gunakan /exit lalu jalankan ulang.",
+                        &fill(text.reload_failed, &[("error", &error.to_string())]),
                     )
                     .await?;
                 }
-                Err(error) => {
-                    write_line(stdout, &format!("Gagal memuat konfigurasi: {}", error)).await?;
-                }
             }
             Ok(LoopControl::Continue)
         }
-        "agent" => {
+        Command::Agent => {
             let action = args.first().map(|value| value.to_ascii_lowercase());
             let new_mode = match action.as_deref() {
                 Some("on") => true,
                 Some("off") => false,
                 Some("toggle") | None => !state.agent_mode,
                 Some(other) => {
-                    write_line(
-                        stdout,
-                        &format!("Nilai agent '{other}' tidak dikenal. Gunakan on/off/toggle."),
-                    )
-                    .await?;
+                    write_line(stdout, &fill(text.agent_unknown_value, &[("value", other)]))
+                        .await?;
                     return Ok(LoopControl::Continue);
                 }
             };
@@ -243,37 +307,111 @@ async fn handle_command<P: ModelProvider>(
             write_line(
                 stdout,
                 if state.agent_mode {
-                    "Mode agent aktif. Pesan berikutnya akan menjalankan alur agent."
+                    text.agent_on
                 } else {
-                    "Mode chat langsung aktif. Pesan berikutnya dikirim langsung ke model."
+                    text.agent_off
                 },
             )
             .await?;
             Ok(LoopControl::Continue)
         }
-        "config" => {
+        Command::Fork => {
+            let Some(current) = state.session_id.clone() else {
+                write_line(stdout, text.fork_no_session).await?;
+                return Ok(LoopControl::Continue);
+            };
+            match client.fork_session(&current).await {
+                Some(forked) => {
+                    state.update_session(forked.clone());
+                    state.clear_logs();
+                    state.clear_steps();
+                    write_line(
+                        stdout,
+                        &fill(
+                            text.fork_done,
+                            &[("source", &current), ("session", &forked)],
+                        ),
+                    )
+                    .await?;
+                }
+                None => {
+                    write_line(stdout, &fill(text.fork_missing, &[("session", &current)])).await?;
+                }
+            }
+            Ok(LoopControl::Continue)
+        }
+        Command::Provider => {
+            match args.first().map(String::as_str) {
+                None | Some("default") => {
+                    state.provider = None;
+                    write_line(stdout, text.provider_default).await?;
+                }
+                Some(id) => {
+                    state.provider = Some(id.to_string());
+                    write_line(stdout, &fill(text.provider_set, &[("provider", id)])).await?;
+                }
+            }
+            Ok(LoopControl::Continue)
+        }
+        Command::Config => {
             let action = args.first().map(|v| v.to_ascii_lowercase());
             match action.as_deref() {
                 Some("edit") => {
                     #[cfg(feature = "wizard")]
                     {
-                        match crate::config::wizard::run_setup_menu().await {
+                        use crate::config::wizard::generators::WizardPaths;
+                        let paths = state
+                            .config_dir
+                            .as_deref()
+                            .map(WizardPaths::in_dir)
+                            .unwrap_or_default();
+                        match crate::config::wizard::run_setup_menu(&paths).await {
                             Ok(_) => {
-                                write_line(stdout, "\nKembali ke mode STDIO.").await?;
+                                write_line(stdout, text.config_edit_done).await?;
                             }
                             Err(e) => {
-                                write_line(stdout, &format!("Error dalam editor: {}", e)).await?;
+                                write_line(
+                                    stdout,
+                                    &fill(text.config_edit_failed, &[("error", &e.to_string())]),
+                                )
+                                .await?;
                             }
                         }
                     }
 
                     #[cfg(not(feature = "wizard"))]
                     {
-                        write_line(
-                            stdout,
-                            "Fitur wizard tidak aktif pada build ini. Gunakan --features wizard untuk mengedit config interaktif.",
-                        )
-                        .await?;
+                        write_line(stdout, text.wizard_unavailable).await?;
+                    }
+                }
+                Some("validate") => {
+                    write_line(stdout, text.config_validate_title).await?;
+                    match state.load_config() {
+                        Ok(config) => {
+                            let report = config.validate(&state.saved_providers());
+                            write_line(stdout, &report.render()).await?;
+                            let failed = report.failures().count();
+                            if failed == 0 {
+                                write_line(
+                                    stdout,
+                                    &format!("{SUCCESS}{}{RESET}", text.config_validate_ok),
+                                )
+                                .await?;
+                            } else {
+                                let summary = fill(
+                                    text.config_validate_failed,
+                                    &[("count", &failed.to_string())],
+                                );
+                                write_line(stdout, &format!("{WARN}{summary}{RESET}")).await?;
+                            }
+                        }
+                        Err(error) => {
+                            write_line(
+                                stdout,
+                                &fill(text.reload_failed, &[("error", &error.to_string())]),
+                            )
+                            .await?;
+                        }
                     }
                 }
                 _ => {
@@ -282,31 +420,22 @@ async fn handle_command<P: ModelProvider>(
             }
             Ok(LoopControl::Continue)
         }
-        "log" | "logs" => {
+        Command::Log => {
             if state.has_logs() {
                 print_logs(stdout, state.logs()).await?;
             } else {
-                write_line(stdout, "Belum ada log dari interaksi terakhir.").await?;
+                write_line(stdout, text.no_logs).await?;
             }
             Ok(LoopControl::Continue)
         }
-        "steps" | "tool" | "toolsteps" => {
+        Command::Steps => {
             if state.has_steps() {
                 print_tool_steps(stdout, state.steps()).await?;
             } else {
-                write_line(stdout, "Belum ada eksekusi tool pada interaksi terakhir.").await?;
+                write_line(stdout, text.no_steps).await?;
             }
             Ok(LoopControl::Continue)
         }
-        other => {
-            write_line(
-                stdout,
-                &format!("Perintah '{other}' tidak dikenal. Gunakan /help untuk bantuan."),
-            )
-            .await?;
-            print_command_recommendations(stdout, other).await?;
-            Ok(LoopControl::Continue)
-        }
     }
 }
 
@@ -317,10 +446,14 @@ async fn handle_prompt<P: ModelProvider + 'static>(
     stdout: &mut io::Stdout,
 ) -> Result<(), StdioError> {
     let log = StdioLogger::new(state.session_id.as_deref().unwrap_or("stdio"));
+    let text = &strings().stdio;
     if state.agent_mode {
         log.info("Processing interactive STDIO request in agent mode");
         let options = AgentOptions {
             session_id: state.session_id.clone(),
+            provider: state.provider.clone(),
+            model: state.model.clone(),
+            system_prompt: state.system_prompt.clone(),
             ..AgentOptions::default()
         };
         run_agent_interaction(client, state, message, stdout, options).await?;
@@ -331,11 +464,14 @@ async fn handle_prompt<P: ModelProvider + 'static>(
             .chat(ChatRequest {
                 prompt: message,
                 attachments: Vec::new(),
-                system_prompt: None,
+                system_prompt: state.system_prompt.clone(),
                 session_id: state.session_id.clone(),
                 raw_mode: false,
                 bypass_template: false,
                 force_json: false,
+                provider: state.provider.clone(),
+                model: state.model.clone(),
+                metadata: HashMap::new(),
             })
             .await
         {
@@ -348,13 +484,14 @@ async fn handle_prompt<P: ModelProvider + 'static>(
                 } = result;
 
                 if looks_like_tool_call(&content) {
-                    write_line(
-                        stdout,
-                        "\nRespons model memerlukan eksekusi tool. Mengalihkan ke mode agent otomatis.",
-                    )
-                    .await?;
+                    write_line(stdout, text.tool_call_redirect).await?;
                     state.reset();
-                    let options = AgentOptions::default();
+                    let options = AgentOptions {
+                        provider: state.provider.clone(),
+                        model: state.model.clone(),
+                        system_prompt: state.system_prompt.clone(),
+                        ..AgentOptions::default()
+                    };
                     run_agent_interaction(client, state, direct_prompt, stdout, options).await?;
                     stdout.flush().await?;
                     return Ok(());
@@ -362,11 +499,15 @@ async fn handle_prompt<P: ModelProvider + 'static>(
 
                 let changed = state.update_session(session_id.clone());
                 if changed {
-                    write_line(stdout, &format!("\nSession aktif: {}", session_id)).await?;
+                    write_line(
+                        stdout,
+                        &fill(text.session_active, &[("session", &session_id)]),
+                    )
+                    .await?;
                 } else {
                     write_line(stdout, "").await?;
                 }
-                write_line(stdout, "Assistant:").await?;
+                write_line(stdout, text.assistant_label).await?;
                 write_line(stdout, &content).await?;
                 state.clear_steps();
                 if logs.is_empty() {
@@ -374,12 +515,12 @@ async fn handle_prompt<P: ModelProvider + 'static>(
                 } else {
                     state.record_logs(logs);
                     write_line(stdout, "").await?;
-                    write_line(stdout, "(Gunakan /log untuk melihat log terbaru.)").await?;
+                    write_line(stdout, text.logs_hint).await?;
                 }
             }
             Err(err) => {
                 log.error(format!("STDIO chat request failed | error={}", err));
-                write_line(stdout, "\nPermintaan gagal:").await?;
+                write_line(stdout, text.request_failed).await?;
                 write_line(stdout, &err.user_message()).await?;
                 state.clear_logs();
                 state.clear_steps();
@@ -401,25 +542,45 @@ async fn run_agent_interaction<P>(
 where
     P: ModelProvider + 'static,
 {
-    let agent = Agent::new(client.clone());
-    match agent.run(prompt, options).await {
+    let text = &strings().stdio;
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+    let agent = Agent::new(client.clone()).with_event_sink(Arc::new(move |event: &AgentEvent| {
+        if let AgentEvent::ToolProgress { .. } = event {
+            let _ = progress_tx.send(event.clone());
+        }
+    }));
+    // Print tool progress while the run is in flight.
+    let run = agent.run(prompt, options);
+    tokio::pin!(run);
+    let result = loop {
+        tokio::select! {
+            result = &mut run => break result,
+            Some(event) = progress_rx.recv() => {
+                if let Some(line) = event.progress_label() {
+                    write_line(stdout, &line).await?;
+                }
+            }
+        }
+    };
+    match result {
         Ok(AgentOutcome {
             logs,
             session_id,
             response,
             steps,
+            ..
         }) => {
             let changed = state.update_session(session_id.clone());
             if changed {
                 write_line(
                     stdout,
-                    &format!("\nSession aktif diperbarui: {}", session_id),
+                    &fill(text.session_updated, &[("session", &session_id)]),
                 )
                 .await?;
             } else {
                 write_line(stdout, "").await?;
             }
-            write_line(stdout, "Agent:").await?;
+            write_line(stdout, text.agent_label).await?;
             let response_str = match response {
                 Value::String(s) => s,
                 v => serde_json::to_string(&v).unwrap_or_default(),
@@ -439,7 +600,7 @@ where
         Err(err) => {
             StdioLogger::new(state.session_id.as_deref().unwrap_or("stdio"))
                 .error(format!("Agent processing failed via STDIO | error={}", err));
-            write_line(stdout, "\nAgent mengalami kegagalan:").await?;
+            write_line(stdout, text.agent_failed).await?;
             write_line(stdout, &err.user_message()).await?;
             state.clear_logs();
             state.clear_steps();
@@ -448,19 +609,3 @@ where
 
     Ok(())
 }
-
-pub fn suggest_commands(prefix: &str) -> Vec<&'static str> {
-    let normalized = prefix.trim().to_ascii_lowercase();
-    let mut suggestions: Vec<&'static str> = if normalized.is_empty() {
-        KNOWN_COMMANDS.to_vec()
-    } else {
-        KNOWN_COMMANDS
-            .iter()
-            .copied()
-            .filter(|cmd| cmd.starts_with(&normalized) || cmd.contains(&normalized))
-            .collect()
-    };
-    suggestions.sort_unstable();
-    suggestions.truncate(6);
-    suggestions
-}
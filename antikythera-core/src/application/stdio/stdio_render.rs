@@ -2,8 +2,11 @@ use super::StdioError;
 use super::{ACCENT, DIM, RESET, SUCCESS, SessionState, WARN};
 use crate::application::agent::AgentStep;
 use crate::application::client::McpClient;
+use crate::application::locale::{fill, strings};
 use crate::application::model_provider::ModelProvider;
 use crate::config::CONFIG_PATH;
+use crate::constants::PREVIEW_MAX_CHARS;
+use crate::domain::text;
 use crate::logging::StdioLogger;
 use serde_json::to_string_pretty;
 use std::fs;
@@ -16,8 +19,9 @@ pub(super) async fn show_config<P: ModelProvider>(
     client: &McpClient<P>,
 ) -> Result<(), StdioError> {
     let snapshot = client.config_snapshot();
+    let text = &strings().stdio;
 
-    write_line(stdout, "\n=== Konfigurasi Aktif ===").await?;
+    write_line(stdout, text.config_title).await?;
     write_line(
         stdout,
         &format!("- Default provider : {}", snapshot.default_provider),
@@ -28,7 +32,7 @@ pub(super) async fn show_config<P: ModelProvider>(
         Some(value) => {
             write_line(stdout, &format!("- System prompt    : {}", preview(value))).await?
         }
-        None => write_line(stdout, "- System prompt    : (tidak disetel)").await?,
+        None => write_line(stdout, &format!("- System prompt    : {}", text.not_set)).await?,
     }
     write_line(
         stdout,
@@ -40,7 +44,7 @@ pub(super) async fn show_config<P: ModelProvider>(
     .await?;
 
     if snapshot.tools.is_empty() {
-        write_line(stdout, "- Tools            : (tidak ada)").await?;
+        write_line(stdout, &format!("- Tools            : {}", text.none)).await?;
     } else {
         write_line(stdout, "- Tools:").await?;
         for tool in &snapshot.tools {
@@ -56,7 +60,7 @@ pub(super) async fn show_config<P: ModelProvider>(
     }
 
     if snapshot.servers.is_empty() {
-        write_line(stdout, "- MCP servers      : (tidak ada)").await?;
+        write_line(stdout, &format!("- MCP servers      : {}", text.none)).await?;
     } else {
         write_line(stdout, "- MCP servers:").await?;
         for server in &snapshot.servers {
@@ -70,22 +74,33 @@ pub(super) async fn show_config<P: ModelProvider>(
             if !server.args.is_empty() {
                 line.push_str(&format!(" {}", server.args.join(" ")));
             }
+            if !server.enabled {
+                line.push(' ');
+                line.push_str(text.server_disabled);
+            }
             write_line(stdout, &line).await?;
         }
     }
 
-    write_line(stdout, "- Providers        : (dikelola oleh CLI)").await?;
+    write_line(
+        stdout,
+        &format!("- Providers        : {}", text.providers_managed),
+    )
+    .await?;
 
     write_line(
         stdout,
-        &format!("\n=== Berkas {} ===", Path::new(CONFIG_PATH).display()),
+        &fill(
+            text.config_file_title,
+            &[("path", &Path::new(CONFIG_PATH).display().to_string())],
+        ),
     )
     .await?;
 
     match fs::read_to_string(Path::new(CONFIG_PATH)) {
         Ok(raw) => {
             if raw.is_empty() {
-                write_line(stdout, "(Berkas kosong)").await?;
+                write_line(stdout, text.config_file_empty).await?;
             } else {
                 for line in raw.lines() {
                     write_line(stdout, line).await?;
@@ -93,11 +108,7 @@ pub(super) async fn show_config<P: ModelProvider>(
             }
         }
         Err(error) if error.kind() == ErrorKind::NotFound => {
-            write_line(
-                stdout,
-                "(Berkas belum tersedia. Menampilkan konfigurasi aktif dalam bentuk TOML.)",
-            )
-            .await?;
+            write_line(stdout, text.config_file_missing).await?;
             for line in snapshot.raw.lines() {
                 write_line(stdout, line).await?;
             }
@@ -109,14 +120,13 @@ pub(super) async fn show_config<P: ModelProvider>(
             ));
             write_line(
                 stdout,
-                &format!("Gagal membaca berkas konfigurasi: {error}"),
-            )
-            .await?;
-            write_line(
-                stdout,
-                "Konfigurasi aktif tetap seperti yang ditampilkan di atas.",
+                &fill(
+                    text.config_file_unreadable,
+                    &[("error", &error.to_string())],
+                ),
             )
             .await?;
+            write_line(stdout, text.config_unchanged).await?;
         }
     }
 
@@ -131,16 +141,21 @@ pub(super) async fn print_tool_steps(
         return Ok(());
     }
 
-    write_line(stdout, "\nLangkah tool:").await?;
+    let text = &strings().stdio;
+    write_line(stdout, text.steps_title).await?;
     for (index, step) in steps.iter().enumerate() {
-        let status = if step.success { "sukses" } else { "gagal" };
+        let status = if step.success {
+            text.step_ok
+        } else {
+            text.step_failed
+        };
         write_line(
             stdout,
             &format!("  {}. {} [{}]", index + 1, step.tool, status),
         )
         .await?;
         if let Some(message) = &step.message {
-            write_line(stdout, &format!("     catatan: {}", message)).await?;
+            write_line(stdout, &format!("     {}: {}", text.step_note, message)).await?;
         }
 
         if !step.input.is_null() {
@@ -167,14 +182,14 @@ pub(super) async fn print_logs(stdout: &mut io::Stdout, logs: &[String]) -> io::
     }
 
     write_line(stdout, "").await?;
-    write_line(stdout, "Log:").await?;
+    write_line(stdout, strings().stdio.logs_title).await?;
     for log in logs {
         write_line(stdout, &format!("  - {}", log)).await?;
     }
     Ok(())
 }
 
-pub(super) async fn print_banner(stdout: &mut io::Stdout) -> io::Result<()> {
+pub(super) async fn print_banner(stdout: &mut io::Stdout, agent_mode: bool) -> io::Result<()> {
     write_line(
         stdout,
         &format!("{ACCENT}============================================================{RESET}"),
@@ -190,73 +205,31 @@ pub(super) async fn print_banner(stdout: &mut io::Stdout) -> io::Result<()> {
         &format!("{ACCENT}============================================================{RESET}"),
     )
     .await?;
-    write_line(stdout, "Mode STDIO interaktif siap digunakan.").await?;
-    write_line(
-        stdout,
-        "Mode agent aktif secara default untuk memastikan jawaban final.",
-    )
-    .await?;
-    write_line(stdout, "Ketik pesan lalu tekan Enter untuk mengirim.").await?;
-    write_line(stdout, "Gunakan /help untuk daftar perintah.").await?;
-    write_line(
-        stdout,
-        &format!(
-            "{DIM}Tip: ketik '/' lalu nama perintah sebagian (contoh: /co) untuk rekomendasi.{RESET}"
-        ),
-    )
-    .await?;
+    let text = &strings().stdio;
+    for line in text.banner {
+        write_line(stdout, line).await?;
+    }
+    let mode = if agent_mode {
+        text.banner_agent_mode
+    } else {
+        text.banner_chat_mode
+    };
+    write_line(stdout, mode).await?;
+    write_line(stdout, &format!("{DIM}{}{RESET}", text.banner_tip)).await?;
     Ok(())
 }
 
 pub(super) async fn print_help(stdout: &mut io::Stdout) -> io::Result<()> {
-    write_line(stdout, &format!("\n{ACCENT}Perintah yang tersedia:{RESET}")).await?;
-    write_line(stdout, "  /help               Tampilkan bantuan ini").await?;
-    write_line(stdout, "  /config             Lihat konfigurasi MCP aktif").await?;
-    write_line(
-        stdout,
-        "  /config edit        Buka editor konfigurasi interaktif",
-    )
-    .await?;
-    write_line(
-        stdout,
-        "  /log                Tampilkan log interaksi terakhir",
-    )
-    .await?;
-    write_line(
-        stdout,
-        "  /steps              Tampilkan langkah tool terakhir",
-    )
-    .await?;
-    write_line(
-        stdout,
-        "  /agent [on|off]     Aktifkan atau nonaktifkan mode agent",
-    )
-    .await?;
-    write_line(
-        stdout,
-        "  /reset              Hapus session dan mulai percakapan baru",
-    )
-    .await?;
-    write_line(
-        stdout,
-        "  /reload             Muat ulang konfigurasi dari file",
-    )
-    .await?;
-    write_line(stdout, "  /exit               Keluar dari mode STDIO").await?;
-    write_line(
-        stdout,
-        "Ketik pesan tanpa awalan / untuk mengirim ke model.",
-    )
-    .await?;
-    write_line(
-        stdout,
-        &format!("{DIM}Rekomendasi cepat: '/', '/he', '/co', '/ag'.{RESET}"),
-    )
-    .await?;
+    let text = &strings().stdio;
+    write_line(stdout, &format!("\n{ACCENT}{}{RESET}", text.help_title)).await?;
+    for line in text.help {
+        write_line(stdout, line).await?;
+    }
+    write_line(stdout, &format!("{DIM}{}{RESET}", text.help_tip)).await?;
     Ok(())
 }
 
-pub(super) async fn prompt(stdout: &mut io::Stdout, state: &SessionState) -> io::Result<()> {
+pub(super) fn prompt_text(state: &SessionState) -> String {
     let label = if state.agent_mode { "agent" } else { "chat" };
     let session_chip = state
         .session_id
@@ -266,9 +239,7 @@ pub(super) async fn prompt(stdout: &mut io::Stdout, state: &SessionState) -> io:
             format!(" {DIM}[session:{short}]{RESET}")
         })
         .unwrap_or_default();
-    let rendered = format!("{SUCCESS}{label}{RESET}>{session_chip} ");
-    stdout.write_all(rendered.as_bytes()).await?;
-    stdout.flush().await
+    format!("{SUCCESS}{label}{RESET}>{session_chip} ")
 }
 
 pub(super) async fn write_line(stdout: &mut io::Stdout, line: &str) -> io::Result<()> {
@@ -278,20 +249,11 @@ pub(super) async fn write_line(stdout: &mut io::Stdout, line: &str) -> io::Resul
 }
 
 pub(super) fn preview(text: &str) -> String {
-    const LIMIT: usize = 120;
     let trimmed = text.trim();
     if trimmed.is_empty() {
-        return "(kosong)".to_string();
-    }
-    let mut result = String::new();
-    for (idx, ch) in trimmed.chars().enumerate() {
-        if idx >= LIMIT {
-            result.push_str("...");
-            break;
-        }
-        result.push(ch);
+        return strings().stdio.empty.to_string();
     }
-    result
+    text::preview(trimmed, PREVIEW_MAX_CHARS, "...")
 }
 
 pub(super) async fn print_command_recommendations(
@@ -302,7 +264,10 @@ pub(super) async fn print_command_recommendations(
     if suggestions.is_empty() {
         write_line(
             stdout,
-            &format!("{WARN}Tidak ada rekomendasi perintah untuk '/{prefix}'.{RESET}"),
+            &format!(
+                "{WARN}{}{RESET}",
+                fill(strings().stdio.no_recommendations, &[("prefix", prefix)])
+            ),
         )
         .await?;
         return Ok(());
@@ -311,7 +276,8 @@ pub(super) async fn print_command_recommendations(
     write_line(
         stdout,
         &format!(
-            "{ACCENT}Rekomendasi perintah:{RESET} {}",
+            "{ACCENT}{}{RESET} {}",
+            strings().stdio.recommendations,
             suggestions.join(", ")
         ),
     )
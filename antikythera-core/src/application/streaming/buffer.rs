@@ -87,6 +87,20 @@ impl AgentEventStream {
         });
     }
 
+    /// Push a progress update about a running tool call.
+    pub fn push_tool_progress(
+        &mut self,
+        tool_name: impl Into<String>,
+        percent: Option<u8>,
+        message: Option<String>,
+    ) {
+        self.push(AgentEvent::ToolProgress {
+            tool_name: tool_name.into(),
+            percent,
+            message,
+        });
+    }
+
     /// Number of currently buffered events.
     pub fn len(&self) -> usize {
         self.events.len()
@@ -1,6 +1,8 @@
 //! Core streaming types and events
 
+use crate::application::locale::{fill, strings};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Streaming mode requested by the host.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -41,8 +43,43 @@ pub enum AgentEvent {
         is_final: bool,
         original_message_count: usize,
     },
+    /// Progress reported by the server while a tool call is running.
+    ToolProgress {
+        tool_name: String,
+        /// Share of the work done, when the server reports a total.
+        percent: Option<u8>,
+        message: Option<String>,
+    },
 }
 
+impl AgentEvent {
+    /// One status line for a [`ToolProgress`](Self::ToolProgress) event,
+    /// e.g. `get_report: 40% — page 2`; `None` for other events.
+    pub fn progress_label(&self) -> Option<String> {
+        let Self::ToolProgress {
+            tool_name,
+            percent,
+            message,
+        } = self
+        else {
+            return None;
+        };
+        let mut label = match percent {
+            Some(percent) => format!("{tool_name}: {percent}%"),
+            None => fill(strings().stdio.tool_running, &[("tool", tool_name)]),
+        };
+        if let Some(message) = message.as_deref().filter(|m| !m.trim().is_empty()) {
+            label.push_str(" — ");
+            label.push_str(message.trim());
+        }
+        Some(label)
+    }
+}
+
+/// Callback that receives [`AgentEvent`]s as an agent run progresses, e.g. to
+/// forward them to a connected UI.
+pub type AgentEventSink = Arc<dyn Fn(&AgentEvent) + Send + Sync>;
+
 /// Tool event phase for structured tool events.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
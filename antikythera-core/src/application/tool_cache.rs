@@ -0,0 +1,95 @@
+//! Results of cacheable tools, shared by every agent run of a client.
+//!
+//! Only tools configured with `cacheable = true` take part. A successful
+//! call stores its output under the tool name and its arguments in
+//! canonical form (object keys sorted), so a later call with the same
+//! arguments, from any run or session, is answered from the cache until the
+//! entry is older than the client's TTL. Failed calls are not stored.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex as StdMutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// How long a result is reused when the client config sets no TTL.
+pub(super) const DEFAULT_TOOL_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Most results kept at once; the oldest is dropped to make room.
+const MAX_ENTRIES: usize = 256;
+
+/// A stored tool result.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedResult {
+    pub output: Value,
+    pub message: Option<String>,
+}
+
+pub(crate) struct ToolResultCache {
+    ttl: Duration,
+    entries: StdMutex<HashMap<(String, String), (Instant, CachedResult)>>,
+}
+
+impl ToolResultCache {
+    pub(super) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: StdMutex::new(HashMap::new()),
+        }
+    }
+
+    /// The result stored for `tool` called with `input`, if still fresh.
+    pub(crate) fn get(&self, tool: &str, input: &Value) -> Option<CachedResult> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(&cache_key(tool, input))
+            .filter(|(stored, _)| stored.elapsed() < self.ttl)
+            .map(|(_, result)| result.clone())
+    }
+
+    /// Store the result of `tool` called with `input`, after dropping
+    /// expired entries and, when still full, the oldest one.
+    pub(crate) fn insert(&self, tool: &str, input: &Value, result: CachedResult) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|_, (stored, _)| stored.elapsed() < self.ttl);
+        if entries.len() >= MAX_ENTRIES
+            && let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, (stored, _))| *stored)
+                .map(|(key, _)| key.clone())
+        {
+            entries.remove(&oldest);
+        }
+        entries.insert(cache_key(tool, input), (Instant::now(), result));
+    }
+}
+
+fn cache_key(tool: &str, input: &Value) -> (String, String) {
+    (tool.to_string(), canonical(input).to_string())
+}
+
+/// `value` with the keys of every object sorted, so arguments that differ
+/// only in key order share one entry. Missing arguments count as `{}`.
+fn canonical(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::Object(Default::default()),
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            Value::Object(
+                keys.into_iter()
+                    .map(|key| (key.clone(), canonical_nested(&map[key])))
+                    .collect(),
+            )
+        }
+        other => canonical_nested(other),
+    }
+}
+
+fn canonical_nested(value: &Value) -> Value {
+    match value {
+        Value::Object(_) => canonical(value),
+        Value::Array(items) => Value::Array(items.iter().map(canonical_nested).collect()),
+        other => other.clone(),
+    }
+}
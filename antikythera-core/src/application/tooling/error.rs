@@ -1,15 +1,28 @@
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum ToolInvokeError {
     #[error("MCP server '{server}' is not configured")]
     NotConfigured { server: String },
+    #[error("MCP server '{server}' is disabled in config")]
+    Disabled { server: String },
     #[error("failed to spawn MCP server '{server}': {source}")]
     Spawn {
         server: String,
         #[source]
         source: std::io::Error,
     },
+    #[error(
+        "MCP server '{server}': command '{command}' not found on PATH (searched: {})",
+        display_dirs(.searched)
+    )]
+    CommandNotFound {
+        server: String,
+        command: String,
+        /// The `PATH` directories that were searched, in order.
+        searched: Vec<PathBuf>,
+    },
     #[error("MCP server '{server}' transport error: {message}")]
     Transport { server: String, message: String },
     #[error("MCP server '{server}' returned invalid JSON: {source}")]
@@ -29,3 +42,13 @@ pub enum ToolInvokeError {
     #[error("MCP server '{server}' request cancelled")]
     Cancelled { server: String },
 }
+
+fn display_dirs(dirs: &[PathBuf]) -> String {
+    if dirs.is_empty() {
+        return "PATH is empty".to_string();
+    }
+    dirs.iter()
+        .map(|dir| dir.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -1,12 +1,93 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 use super::error::ToolInvokeError;
 
 /// MCP protocol version used during `initialize` handshake.
 pub const PROTOCOL_VERSION: &str = "2024-11-05";
 
+/// `initialize` request parameters sent by every transport.
+pub(crate) fn initialize_params() -> Value {
+    json!({
+        "protocolVersion": PROTOCOL_VERSION,
+        "clientInfo": {
+            "name": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+            "title": "CBT MCP Client"
+        },
+        "capabilities": {
+            "tools": {
+                "listChanged": true
+            }
+        }
+    })
+}
+
+/// What a `completion/complete` request completes an argument of.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompletionRef {
+    /// A prompt, by name.
+    Prompt(String),
+    /// A resource template, by URI template.
+    Resource(String),
+}
+
+impl CompletionRef {
+    fn to_value(&self) -> Value {
+        match self {
+            CompletionRef::Prompt(name) => json!({"type": "ref/prompt", "name": name}),
+            CompletionRef::Resource(uri) => json!({"type": "ref/resource", "uri": uri}),
+        }
+    }
+}
+
+/// Values a server suggests for an argument, best match first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Completion {
+    pub values: Vec<String>,
+    /// Matches in total, when the server reports more than it sent.
+    pub total: Option<u64>,
+    pub has_more: bool,
+}
+
+impl Completion {
+    /// Read the `completion` object of a `completion/complete` result.
+    pub(crate) fn from_result(result: &Value) -> Self {
+        let completion = &result["completion"];
+        Self {
+            values: completion["values"]
+                .as_array()
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            total: completion["total"].as_u64(),
+            has_more: completion["hasMore"].as_bool().unwrap_or(false),
+        }
+    }
+}
+
+/// `completion/complete` parameters for `argument` of `reference`, typed
+/// so far as `prefix`.
+pub(crate) fn completion_params(reference: &CompletionRef, argument: &str, prefix: &str) -> Value {
+    json!({
+        "ref": reference.to_value(),
+        "argument": {"name": argument, "value": prefix},
+    })
+}
+
+/// Whether an `initialize` result advertises the `completions` capability.
+pub(crate) fn advertises_completions(initialize_result: &Value) -> bool {
+    initialize_result
+        .pointer("/capabilities/completions")
+        .is_some()
+}
+
 /// Icon metadata for a tool, as defined by MCP spec.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ToolIcon {
@@ -69,5 +150,42 @@ pub trait ToolServerInterface: Send + Sync {
 
     async fn server_instructions(&self, server: &str) -> Option<String>;
 
+    /// Reconnect to `server` and return the instructions of the new
+    /// `initialize` result instead of the copy cached from the first one.
+    /// Calls still running on the old connection may fail.
+    ///
+    /// The default returns [`server_instructions`](Self::server_instructions)
+    /// for bridges that have nothing to refresh.
+    async fn refresh_server_instructions(
+        &self,
+        server: &str,
+    ) -> Result<Option<String>, ToolInvokeError> {
+        Ok(self.server_instructions(server).await)
+    }
+
     async fn tool_metadata(&self, server: &str, tool: &str) -> Option<ServerToolInfo>;
+
+    /// Check that `server` can be reached, connecting it if needed.
+    ///
+    /// The default reports every server as reachable, for bridges that have
+    /// no connection step.
+    async fn probe_server(&self, _server: &str) -> Result<(), ToolInvokeError> {
+        Ok(())
+    }
+
+    /// Ask `server` for values completing `argument` of `reference`, typed
+    /// so far as `prefix`, e.g. to suggest them while a user fills in the
+    /// argument.
+    ///
+    /// Servers that did not advertise the `completions` capability, and the
+    /// default for bridges without servers to ask, yield no values.
+    async fn complete_argument(
+        &self,
+        _server: &str,
+        _reference: &CompletionRef,
+        _argument: &str,
+        _prefix: &str,
+    ) -> Result<Completion, ToolInvokeError> {
+        Ok(Completion::default())
+    }
 }
@@ -1,19 +1,42 @@
 use super::error::ToolInvokeError;
 use super::process::McpProcessInner;
+use super::transport::MessageTransport;
 use serde_json::{Value, json};
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
-use tokio::process::ChildStdout;
 use tokio::sync::oneshot;
 
 use crate::logging::TransportLogger;
 
 impl McpProcessInner {
+    /// Send a request once an in-flight slot is free (see
+    /// `ServerConfig::max_inflight`) and wait for its response. The request
+    /// counts as use of the server for its idle timeout.
     pub(crate) async fn send_request(
         &self,
         method: &str,
         params: Value,
+    ) -> Result<Value, ToolInvokeError> {
+        let _slot = match &self.inflight {
+            Some(slots) => Some(
+                slots
+                    .acquire()
+                    .await
+                    .map_err(|_| self.transport_error("in-flight limiter closed"))?,
+            ),
+            None => None,
+        };
+        self.touch();
+        let result = self.send_request_now(method, params).await;
+        self.touch();
+        result
+    }
+
+    /// Send a request without taking an in-flight slot.
+    pub(crate) async fn send_request_now(
+        &self,
+        method: &str,
+        params: Value,
     ) -> Result<Value, ToolInvokeError> {
         let id = self.next_id();
         let (tx, rx) = oneshot::channel();
@@ -84,51 +107,27 @@ impl McpProcessInner {
                 source,
             })?;
 
-        let mut writer = self.writer.lock().await;
-        let stream = writer
-            .as_mut()
-            .ok_or_else(|| self.transport_error("writer not initialised"))?;
-        stream
-            .write_all(encoded.as_bytes())
+        let transport = self
+            .transport
+            .lock()
             .await
-            .map_err(|source| ToolInvokeError::Transport {
-                server: self.server.name.clone(),
-                message: source.to_string(),
-            })?;
-        stream
-            .write_all(b"\n")
-            .await
-            .map_err(|source| ToolInvokeError::Transport {
-                server: self.server.name.clone(),
-                message: source.to_string(),
-            })?;
-        stream
-            .flush()
+            .clone()
+            .ok_or_else(|| self.transport_error("writer not initialised"))?;
+        transport
+            .send(&encoded)
             .await
-            .map_err(|source| ToolInvokeError::Transport {
-                server: self.server.name.clone(),
-                message: source.to_string(),
-            })?;
-        Ok(())
+            .map_err(|source| self.transport_error(source.to_string()))
     }
 
-    pub(crate) async fn reader_loop(self: Arc<Self>, stdout: ChildStdout) {
-        let mut lines = BufReader::new(stdout).lines();
-        while let Ok(item) = lines.next_line().await {
+    pub(crate) async fn reader_loop(self: Arc<Self>, transport: Arc<dyn MessageTransport>) {
+        while let Ok(item) = transport.receive().await {
             match item {
                 Some(raw) => {
                     let trimmed = raw.trim();
                     if trimmed.is_empty() {
                         continue;
                     }
-                    if trimmed.starts_with('\u{1b}') {
-                        TransportLogger::new(&self.server.name).debug(format!(
-                            "skipping non-JSON ANSI log line from MCP server | server={} line={}",
-                            self.server.name, trimmed
-                        ));
-                        continue;
-                    }
-                    match serde_json::from_str::<Value>(&raw) {
+                    match serde_json::from_str::<Value>(trimmed) {
                         Ok(value) => {
                             if let Err(err) = self.process_inbound_message(value).await {
                                 TransportLogger::new(&self.server.name).warn(format!(
@@ -137,19 +136,32 @@ impl McpProcessInner {
                                 ));
                             }
                         }
-                        Err(source) => {
+                        // Servers often log plain text (or ANSI-coloured text) to
+                        // stdout; only a line that looks like JSON is worth a warning.
+                        Err(source) if looks_like_json(trimmed) => {
                             TransportLogger::new(&self.server.name).warn(format!(
                                 "received invalid JSON from MCP server | server={} line={} source={}",
                                 self.server.name, raw, source
                             ));
                         }
+                        Err(_) => {
+                            TransportLogger::new(&self.server.name).debug(format!(
+                                "skipping non-JSON output from MCP server | server={} line={}",
+                                self.server.name, trimmed
+                            ));
+                        }
                     }
                 }
                 None => break,
             }
         }
 
-        self.reset().await;
+        // A reset may already have replaced this transport with a new one;
+        // only tear down the connection this loop was reading from.
+        let current = self.transport.lock().await.clone();
+        if current.is_some_and(|current| Arc::ptr_eq(&current, &transport)) {
+            self.reset().await;
+        }
     }
 
     async fn process_inbound_message(&self, value: Value) -> Result<(), ToolInvokeError> {
@@ -245,13 +257,20 @@ impl McpProcessInner {
                 "received notification from server | server={} method={}",
                 self.server.name, method
             ));
-            if method == "notifications/tools/list_changed"
-                && let Err(err) = self.refresh_tools().await
-            {
-                TransportLogger::new(&self.server.name).warn(format!(
-                    "failed to refresh tool catalogue | server={} error={}",
-                    self.server.name, err
-                ));
+            match method {
+                "notifications/tools/list_changed" => {
+                    if let Err(err) = self.refresh_tools().await {
+                        TransportLogger::new(&self.server.name).warn(format!(
+                            "failed to refresh tool catalogue | server={} error={}",
+                            self.server.name, err
+                        ));
+                    }
+                }
+                "notifications/progress" => {
+                    let params = value.get("params").cloned().unwrap_or(Value::Null);
+                    self.forward_progress(&params).await;
+                }
+                _ => {}
             }
         }
     }
@@ -269,3 +288,7 @@ impl McpProcessInner {
         }
     }
 }
+
+fn looks_like_json(line: &str) -> bool {
+    line.starts_with('{') || line.starts_with('[')
+}
@@ -7,7 +7,7 @@ use super::envelope::{
     validate_tool_result_envelope,
 };
 use super::error::ToolInvokeError;
-use super::interface::{ServerToolInfo, ToolServerInterface};
+use super::interface::{Completion, CompletionRef, ServerToolInfo, ToolServerInterface};
 #[cfg(feature = "native-transport")]
 use super::process::McpProcess;
 use super::transport::{
@@ -47,6 +47,15 @@ impl ServerInstance {
         }
     }
 
+    async fn refresh_instructions(&self) -> Result<Option<String>, ToolInvokeError> {
+        match self {
+            #[cfg(feature = "native-transport")]
+            ServerInstance::Stdio(process) => process.refresh_instructions().await,
+            ServerInstance::Http(transport) => transport.refresh_instructions().await,
+            ServerInstance::Builtin(transport) => transport.refresh_instructions().await,
+        }
+    }
+
     async fn tool_metadata(&self, tool: &str) -> Option<ServerToolInfo> {
         match self {
             #[cfg(feature = "native-transport")]
@@ -55,6 +64,24 @@ impl ServerInstance {
             ServerInstance::Builtin(transport) => transport.tool_metadata(tool).await,
         }
     }
+
+    async fn complete(
+        &self,
+        reference: &CompletionRef,
+        argument: &str,
+        prefix: &str,
+    ) -> Result<Completion, ToolInvokeError> {
+        match self {
+            #[cfg(feature = "native-transport")]
+            ServerInstance::Stdio(process) => process.complete(reference, argument, prefix).await,
+            ServerInstance::Http(transport) => {
+                transport.complete(reference, argument, prefix).await
+            }
+            ServerInstance::Builtin(transport) => {
+                transport.complete(reference, argument, prefix).await
+            }
+        }
+    }
 }
 
 pub struct ServerManager {
@@ -93,12 +120,67 @@ impl ServerManager {
         instances.insert(name.to_string(), ServerInstance::Builtin(transport));
     }
 
+    /// Drop the cached connection to `server` so the next call to it starts
+    /// fresh. A STDIO process is killed first and its pending calls fail with
+    /// [`ToolInvokeError::Terminated`].
+    ///
+    /// Returns whether an instance was dropped; builtin transports cannot be
+    /// recreated and are kept. Unknown servers are
+    /// [`ToolInvokeError::NotConfigured`].
+    pub async fn reset_server(&self, server: &str) -> Result<bool, ToolInvokeError> {
+        let removed = {
+            let mut instances = match self.instances.lock() {
+                Ok(guard) => guard,
+                Err(e) => {
+                    TransportLogger::new(server).warn(format!(
+                        "ServerManager instances lock poisoned in reset_server: {}",
+                        e
+                    ));
+                    return Err(ToolInvokeError::Transport {
+                        server: server.to_string(),
+                        message: format!("ServerManager lock poisoned: {}", e),
+                    });
+                }
+            };
+            if !self.configs.contains_key(server) && !instances.contains_key(server) {
+                return Err(ToolInvokeError::NotConfigured {
+                    server: server.to_string(),
+                });
+            }
+            match instances.get(server) {
+                Some(ServerInstance::Builtin(_)) | None => None,
+                Some(_) => instances.remove(server),
+            }
+        };
+
+        let Some(instance) = removed else {
+            return Ok(false);
+        };
+        #[cfg(feature = "native-transport")]
+        if let ServerInstance::Stdio(process) = &instance {
+            process.reset().await;
+        }
+        drop(instance);
+        TransportLogger::new(server)
+            .info(format!("MCP server connection reset | server={}", server));
+        Ok(true)
+    }
+
     async fn ensure_instance(&self, server: &str) -> Result<(), ToolInvokeError> {
         if server.is_empty() {
             return Err(ToolInvokeError::NotConfigured {
                 server: server.to_string(),
             });
         }
+        if self
+            .configs
+            .get(server)
+            .is_some_and(|config| !config.enabled)
+        {
+            return Err(ToolInvokeError::Disabled {
+                server: server.to_string(),
+            });
+        }
 
         // Check if already exists
         {
@@ -294,6 +376,20 @@ impl ToolServerInterface for ServerManager {
         }
     }
 
+    /// Connects `server` first if needed; errors are returned, not logged.
+    async fn refresh_server_instructions(
+        &self,
+        server: &str,
+    ) -> Result<Option<String>, ToolInvokeError> {
+        self.ensure_instance(server).await?;
+        let instance = self
+            .get_instance(server)
+            .ok_or_else(|| ToolInvokeError::NotConfigured {
+                server: server.to_string(),
+            })?;
+        instance.refresh_instructions().await
+    }
+
     async fn tool_metadata(&self, server: &str, tool: &str) -> Option<ServerToolInfo> {
         match self.ensure_instance(server).await {
             Ok(()) => {
@@ -312,4 +408,26 @@ impl ToolServerInterface for ServerManager {
             }
         }
     }
+
+    /// Starts or connects `server` if needed; errors are returned, not logged.
+    async fn probe_server(&self, server: &str) -> Result<(), ToolInvokeError> {
+        self.ensure_instance(server).await
+    }
+
+    /// Starts or connects `server` if needed; errors are returned, not logged.
+    async fn complete_argument(
+        &self,
+        server: &str,
+        reference: &CompletionRef,
+        argument: &str,
+        prefix: &str,
+    ) -> Result<Completion, ToolInvokeError> {
+        self.ensure_instance(server).await?;
+        let instance = self
+            .get_instance(server)
+            .ok_or_else(|| ToolInvokeError::NotConfigured {
+                server: server.to_string(),
+            })?;
+        instance.complete(reference, argument, prefix).await
+    }
 }
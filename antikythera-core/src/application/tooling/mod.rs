@@ -4,8 +4,10 @@ mod interface;
 #[cfg(feature = "native-transport")]
 mod jsonrpc_client;
 mod manager;
+mod placeholders;
 #[cfg(feature = "native-transport")]
 mod process;
+mod progress;
 #[cfg(feature = "native-transport")]
 mod tool_catalogue;
 pub mod transport;
@@ -16,12 +18,18 @@ pub use envelope::{
 };
 pub use error::ToolInvokeError;
 pub use interface::{
-    PROTOCOL_VERSION, ServerToolInfo, TaskSupport, ToolAnnotations, ToolExecution, ToolIcon,
-    ToolServerInterface,
+    Completion, CompletionRef, PROTOCOL_VERSION, ServerToolInfo, TaskSupport, ToolAnnotations,
+    ToolExecution, ToolIcon, ToolServerInterface,
 };
 pub use manager::ServerManager;
+pub use placeholders::{PlaceholderError, Placeholders};
+#[cfg(feature = "native-transport")]
+pub use process::McpProcess;
+pub use progress::{ToolProgress, ToolProgressSink, current_call_progress, with_call_progress};
 #[cfg(feature = "native-transport")]
 pub use tool_catalogue::spawn_and_list_tools;
 pub use transport::{
     BuiltinTransport, HttpTransport, HttpTransportConfig, McpTransport, TransportMode,
 };
+#[cfg(feature = "native-transport")]
+pub use transport::{MessageTransport, StreamTransport};
@@ -0,0 +1,110 @@
+//! `${...}` placeholder expansion for server arguments.
+//!
+//! `${name}` resolves to a built-in value first (`config_dir`, `home`,
+//! `server`, plus anything added with [`Placeholders::with`]), then to the
+//! environment variable `name`. `${name:-fallback}` uses `fallback` when
+//! neither exists, and `$${` is a literal `${`. A bare `$NAME` is the
+//! environment variable `NAME`, left as written when it is unset.
+
+use crate::config::ServerConfig;
+use std::collections::HashMap;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PlaceholderError {
+    #[error("unknown placeholder '${{{0}}}' (no built-in, environment variable, or default)")]
+    Unknown(String),
+    #[error("unterminated placeholder in '{0}'")]
+    Unterminated(String),
+}
+
+/// Built-in values available to `${...}` templates.
+#[derive(Debug, Clone, Default)]
+pub struct Placeholders {
+    builtins: HashMap<String, String>,
+}
+
+impl Placeholders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Built-ins for spawning `server`: `config_dir`, `home` (when known),
+    /// and `server`.
+    pub fn for_server(server: &ServerConfig) -> Self {
+        let mut placeholders = Self::new()
+            .with(
+                "config_dir",
+                path_string(&crate::config::loader::loaded_config_dir()),
+            )
+            .with("server", server.name.clone());
+        if let Some(home) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")) {
+            placeholders = placeholders.with("home", path_string(Path::new(&home)));
+        }
+        placeholders
+    }
+
+    /// Add or replace a built-in value.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.builtins.insert(name.into(), value.into());
+        self
+    }
+
+    /// Expand every placeholder in `template`.
+    pub fn expand(&self, template: &str) -> Result<String, PlaceholderError> {
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('$') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            if let Some(escaped) = after.strip_prefix("${") {
+                out.push_str("${");
+                rest = escaped;
+                continue;
+            }
+            let Some(body) = after.strip_prefix('{') else {
+                let name_len = after
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                let name = &after[..name_len];
+                match std::env::var(name) {
+                    Ok(value) if !name.is_empty() => out.push_str(&value),
+                    _ => {
+                        out.push('$');
+                        out.push_str(name);
+                    }
+                }
+                rest = &after[name_len..];
+                continue;
+            };
+            let end = body
+                .find('}')
+                .ok_or_else(|| PlaceholderError::Unterminated(template.to_string()))?;
+            out.push_str(&self.resolve(&body[..end])?);
+            rest = &body[end + 1..];
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+
+    fn resolve(&self, expr: &str) -> Result<String, PlaceholderError> {
+        let (name, fallback) = match expr.split_once(":-") {
+            Some((name, fallback)) => (name.trim(), Some(fallback)),
+            None => (expr.trim(), None),
+        };
+        if let Some(value) = self.builtins.get(name) {
+            return Ok(value.clone());
+        }
+        if let Ok(value) = std::env::var(name) {
+            return Ok(value);
+        }
+        fallback
+            .map(str::to_string)
+            .ok_or_else(|| PlaceholderError::Unknown(name.to_string()))
+    }
+}
+
+fn path_string(path: &Path) -> String {
+    path.display().to_string()
+}
@@ -1,18 +1,45 @@
 use super::error::ToolInvokeError;
-use super::interface::{PROTOCOL_VERSION, ServerToolInfo};
+use super::interface::{
+    Completion, CompletionRef, ServerToolInfo, advertises_completions, completion_params,
+    initialize_params,
+};
+use super::placeholders::Placeholders;
+use super::progress::{ToolProgress, ToolProgressSink, current_call_progress};
+use super::transport::{MessageTransport, StreamTransport};
 use serde::Deserialize;
 use serde_json::{Map as JsonMap, Value, json};
 use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
-use std::sync::Arc;
-use std::sync::atomic::AtomicU64;
-use tokio::io::BufWriter;
-use tokio::process::{Child, ChildStdin, Command};
-use tokio::sync::{Mutex as AsyncMutex, oneshot};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::process::{Child, Command};
+use tokio::sync::{Mutex as AsyncMutex, Semaphore, oneshot};
 
 use crate::config::ServerConfig;
 use crate::logging::TransportLogger;
 
+/// Total spawn attempts when the OS reports a transient spawn error.
+const SPAWN_ATTEMPTS: u32 = 3;
+/// Base delay between spawn attempts; grows linearly with each retry.
+const SPAWN_RETRY_DELAY: Duration = Duration::from_millis(100);
+/// Shortest wait between idle checks while a call is still running.
+const IDLE_RECHECK_DELAY: Duration = Duration::from_millis(100);
+
+/// A `tools/call` awaiting its response, for routing its progress updates.
+pub(super) struct PendingProgress {
+    tool: String,
+    /// [`current_call_progress`] when the call was sent.
+    sink: Option<ToolProgressSink>,
+}
+
+/// JSON-RPC client for one MCP server reached over a [`MessageTransport`].
+///
+/// By default the server is the configured command, spawned on first use and
+/// respawned after a reset. [`McpProcess::connect`] runs the same protocol
+/// over any other transport, e.g. an in-memory pipe in tests.
 #[derive(Clone)]
 pub struct McpProcess {
     pub(super) inner: Arc<McpProcessInner>,
@@ -20,63 +47,147 @@ pub struct McpProcess {
 
 pub(super) struct McpProcessInner {
     pub(super) server: ServerConfig,
-    state: AsyncMutex<Option<RunningState>>,
-    pub(super) writer: AsyncMutex<Option<BufWriter<ChildStdin>>>,
+    pub(super) transport: AsyncMutex<Option<Arc<dyn MessageTransport>>>,
     pub(super) pending:
         AsyncMutex<HashMap<String, oneshot::Sender<Result<Value, ToolInvokeError>>>>,
     pub(super) id_counter: AtomicU64,
+    /// Slots for requests awaiting a response; `None` when `max_inflight`
+    /// is 0 (unlimited).
+    pub(super) inflight: Option<Semaphore>,
     instructions: AsyncMutex<Option<String>>,
+    /// The server advertised the `completions` capability.
+    completions: AtomicBool,
     pub(super) tool_cache: AsyncMutex<HashMap<String, ServerToolInfo>>,
-}
-
-struct RunningState {
-    child: Child,
+    /// Progress token of each pending `tools/call`.
+    pub(super) progress_tokens: AsyncMutex<HashMap<String, PendingProgress>>,
+    pub(super) progress_sink: Mutex<Option<ToolProgressSink>>,
+    /// Dropping this sender stops the keepalive task of the current process.
+    keepalive_stop: AsyncMutex<Option<oneshot::Sender<()>>>,
+    /// Dropping this sender stops the idle-timeout task of the current process.
+    idle_stop: AsyncMutex<Option<oneshot::Sender<()>>>,
+    /// When a call last started or finished, for `idle_timeout_secs`.
+    last_used: Mutex<Instant>,
 }
 
 impl McpProcess {
     pub fn new(server: ServerConfig) -> Self {
+        let inflight = (server.max_inflight > 0).then(|| Semaphore::new(server.max_inflight));
         Self {
             inner: Arc::new(McpProcessInner {
                 server,
-                state: AsyncMutex::new(None),
-                writer: AsyncMutex::new(None),
+                transport: AsyncMutex::new(None),
                 pending: AsyncMutex::new(HashMap::new()),
                 id_counter: AtomicU64::new(1),
+                inflight,
                 instructions: AsyncMutex::new(None),
+                completions: AtomicBool::new(false),
                 tool_cache: AsyncMutex::new(HashMap::new()),
+                progress_tokens: AsyncMutex::new(HashMap::new()),
+                progress_sink: Mutex::new(None),
+                keepalive_stop: AsyncMutex::new(None),
+                idle_stop: AsyncMutex::new(None),
+                last_used: Mutex::new(Instant::now()),
             }),
         }
     }
 
+    /// Run the MCP handshake for `server` over `transport` instead of
+    /// spawning its command.
+    pub async fn connect(
+        server: ServerConfig,
+        transport: Arc<dyn MessageTransport>,
+    ) -> Result<Self, ToolInvokeError> {
+        let process = Self::new(server);
+        process.inner.attach(transport).await?;
+        Ok(process)
+    }
+
     pub(super) async fn ensure_running(&self) -> Result<(), ToolInvokeError> {
+        self.inner.touch();
         self.inner.ensure_running().await
     }
 
-    pub(super) async fn call_tool(
+    pub async fn call_tool(&self, tool: &str, arguments: Value) -> Result<Value, ToolInvokeError> {
+        self.call_tool_with_meta(tool, arguments, JsonMap::new())
+            .await
+    }
+
+    /// Call `tool` with `meta` sent as the `_meta` of the `tools/call`
+    /// params. Unless `meta` already has a `progressToken`, one is generated
+    /// so the server can report progress to the [`ToolProgressSink`].
+    pub async fn call_tool_with_meta(
         &self,
         tool: &str,
         arguments: Value,
+        meta: JsonMap<String, Value>,
     ) -> Result<Value, ToolInvokeError> {
         self.ensure_running().await?;
-        self.inner.call_tool(tool, arguments).await
+        self.inner.call_tool(tool, arguments, meta).await
+    }
+
+    /// Forward `notifications/progress` from this server to `sink`
+    /// (`None` only logs them). Calls made inside
+    /// [`with_call_progress`](super::with_call_progress) also report to
+    /// that sink.
+    pub fn set_progress_sink(&self, sink: Option<ToolProgressSink>) {
+        if let Ok(mut current) = self.inner.progress_sink.lock() {
+            *current = sink;
+        }
     }
 
-    pub(super) async fn instructions(&self) -> Option<String> {
+    /// Instructions from the server's `initialize` result.
+    pub async fn instructions(&self) -> Option<String> {
         self.inner.instructions.lock().await.clone()
     }
 
-    pub(super) async fn tool_metadata(&self, tool: &str) -> Option<ServerToolInfo> {
+    /// Restart the server and return the instructions of its new
+    /// `initialize` result, so a server that changed its guidance is picked
+    /// up. MCP allows one `initialize` per connection, so this is a
+    /// [`reset`](Self::reset): pending calls fail with
+    /// [`ToolInvokeError::Terminated`], and a failed restart leaves no
+    /// instructions. A process made with [`connect`](Self::connect) has no
+    /// command to restart and is kept as is, with
+    /// [`ToolInvokeError::NotConfigured`].
+    pub async fn refresh_instructions(&self) -> Result<Option<String>, ToolInvokeError> {
+        self.inner.refresh_instructions().await
+    }
+
+    pub async fn tool_metadata(&self, tool: &str) -> Option<ServerToolInfo> {
         self.inner.tool_cache.lock().await.get(tool).cloned()
     }
+
+    /// Ask the server for values completing `argument` of `reference`,
+    /// typed so far as `prefix`. A server that did not advertise the
+    /// `completions` capability yields no values and is not asked.
+    pub async fn complete(
+        &self,
+        reference: &CompletionRef,
+        argument: &str,
+        prefix: &str,
+    ) -> Result<Completion, ToolInvokeError> {
+        self.ensure_running().await?;
+        if !self.inner.completions.load(Ordering::SeqCst) {
+            return Ok(Completion::default());
+        }
+        let params = completion_params(reference, argument, prefix);
+        let result = self
+            .inner
+            .send_request("completion/complete", params)
+            .await?;
+        Ok(Completion::from_result(&result))
+    }
+
+    /// Close the transport and fail pending calls with
+    /// [`ToolInvokeError::Terminated`]. The next call respawns the command.
+    pub async fn reset(&self) {
+        self.inner.reset().await;
+    }
 }
 
 impl McpProcessInner {
     async fn ensure_running(self: &Arc<Self>) -> Result<(), ToolInvokeError> {
-        {
-            let state = self.state.lock().await;
-            if state.is_some() {
-                return Ok(());
-            }
+        if self.transport.lock().await.is_some() {
+            return Ok(());
         }
 
         let command_path =
@@ -87,7 +198,8 @@ impl McpProcessInner {
                     server: format!("{}: no command path configured", self.server.name),
                 })?;
 
-        let mut command = Command::new(command_path);
+        let args = self.expand_args()?;
+        let mut command = Command::new(self.resolve_command(command_path)?);
         command
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
@@ -95,44 +207,39 @@ impl McpProcessInner {
         if let Some(dir) = &self.server.workdir {
             command.current_dir(dir);
         }
-        if !self.server.args.is_empty() {
-            command.args(&self.server.args);
+        command.args(args);
+        if self.server.clear_env {
+            command.env_clear();
         }
         for (key, value) in &self.server.env {
             command.env(key, value);
         }
 
-        let mut child = command.spawn().map_err(|source| ToolInvokeError::Spawn {
-            server: self.server.name.clone(),
-            source,
-        })?;
-
-        let stdin = child
-            .stdin
-            .take()
-            .ok_or_else(|| self.transport_error("failed to capture server stdin"))?;
-        let stdout = child
-            .stdout
-            .take()
-            .ok_or_else(|| self.transport_error("failed to capture server stdout"))?;
-
-        {
-            let mut writer = self.writer.lock().await;
-            *writer = Some(BufWriter::new(stdin));
-        }
+        let child = self.spawn_with_retry(&mut command).await?;
+        let transport = StreamTransport::from_child(child)
+            .map_err(|source| self.transport_error(source.to_string()))?
+            .with_framing(self.server.framing);
+        self.attach(Arc::new(transport)).await
+    }
 
-        {
-            let mut state = self.state.lock().await;
-            *state = Some(RunningState { child });
-        }
+    /// Start reading from `transport` and run the initialize handshake on it.
+    async fn attach(
+        self: &Arc<Self>,
+        transport: Arc<dyn MessageTransport>,
+    ) -> Result<(), ToolInvokeError> {
+        *self.transport.lock().await = Some(Arc::clone(&transport));
 
         let reader_self = Arc::clone(self);
         tokio::spawn(async move {
-            reader_self.reader_loop(stdout).await;
+            reader_self.reader_loop(transport).await;
         });
 
         match self.initialize_sequence().await {
-            Ok(_) => Ok(()),
+            Ok(_) => {
+                self.start_keepalive().await;
+                self.start_idle_timeout().await;
+                Ok(())
+            }
             Err(err) => {
                 self.reset().await;
                 Err(err)
@@ -140,25 +247,93 @@ impl McpProcessInner {
         }
     }
 
-    async fn initialize_sequence(self: &Arc<Self>) -> Result<(), ToolInvokeError> {
-        let params = json!({
-            "protocolVersion": PROTOCOL_VERSION,
-            "clientInfo": {
-                "name": env!("CARGO_PKG_NAME"),
-                "version": env!("CARGO_PKG_VERSION"),
-                "title": "CBT MCP Client"
-            },
-            "capabilities": {
-                "tools": {
-                    "listChanged": true
+    /// Expand `${...}` placeholders in the configured arguments.
+    fn expand_args(&self) -> Result<Vec<String>, ToolInvokeError> {
+        let placeholders = Placeholders::for_server(&self.server);
+        self.server
+            .args
+            .iter()
+            .map(|arg| placeholders.expand(arg))
+            .collect::<Result<_, _>>()
+            .map_err(|error| ToolInvokeError::Spawn {
+                server: self.server.name.clone(),
+                source: std::io::Error::new(std::io::ErrorKind::InvalidInput, error),
+            })
+    }
+
+    /// Look up a bare command name such as `mcp-time` on `PATH` (the
+    /// server's own `env.PATH` when set), so a missing binary is reported
+    /// with the directories searched instead of as a bare spawn error.
+    /// Commands with a directory part are returned unchanged.
+    fn resolve_command(&self, command: &Path) -> Result<PathBuf, ToolInvokeError> {
+        if command.is_absolute() || command.components().count() != 1 {
+            return Ok(command.to_path_buf());
+        }
+        let path_var = self
+            .server
+            .env
+            .get("PATH")
+            .map(OsString::from)
+            .or_else(|| std::env::var_os("PATH"))
+            .unwrap_or_default();
+        let cwd = self
+            .server
+            .workdir
+            .clone()
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        match which::which_in(command, Some(&path_var), cwd) {
+            Ok(resolved) => {
+                TransportLogger::new(&self.server.name).info(format!(
+                    "Resolved MCP server command on PATH | server={} command={} path={}",
+                    self.server.name,
+                    command.display(),
+                    resolved.display()
+                ));
+                Ok(resolved)
+            }
+            Err(_) => Err(ToolInvokeError::CommandNotFound {
+                server: self.server.name.clone(),
+                command: command.display().to_string(),
+                searched: std::env::split_paths(&path_var).collect(),
+            }),
+        }
+    }
+
+    /// Spawn the server, retrying a few times on errors that are usually
+    /// transient right after the executable was written (antivirus or
+    /// indexer locks on Windows `.cmd`/`.bat` files, busy binaries on Unix).
+    async fn spawn_with_retry(&self, command: &mut Command) -> Result<Child, ToolInvokeError> {
+        let mut attempt = 1;
+        loop {
+            match command.spawn() {
+                Ok(child) => return Ok(child),
+                Err(source) if attempt < SPAWN_ATTEMPTS && is_transient_spawn_error(&source) => {
+                    TransportLogger::new(&self.server.name).warn(format!(
+                        "MCP server spawn failed, retrying | server={} attempt={} error={}",
+                        self.server.name, attempt, source
+                    ));
+                    tokio::time::sleep(SPAWN_RETRY_DELAY * attempt).await;
+                    attempt += 1;
+                }
+                Err(source) => {
+                    return Err(ToolInvokeError::Spawn {
+                        server: self.server.name.clone(),
+                        source,
+                    });
                 }
             }
-        });
-        let init_result = self.send_request("initialize", params).await?;
+        }
+    }
+
+    async fn initialize_sequence(self: &Arc<Self>) -> Result<(), ToolInvokeError> {
+        let init_result = self.send_request("initialize", initialize_params()).await?;
         if let Some(text) = init_result.get("instructions").and_then(Value::as_str) {
             let mut instructions = self.instructions.lock().await;
             *instructions = Some(text.to_string());
         }
+        self.completions
+            .store(advertises_completions(&init_result), Ordering::SeqCst);
         self.send_notification("notifications/initialized", json!({}))
             .await?;
 
@@ -166,20 +341,219 @@ impl McpProcessInner {
         Ok(())
     }
 
+    async fn refresh_instructions(self: &Arc<Self>) -> Result<Option<String>, ToolInvokeError> {
+        if self.server.command.is_none() {
+            return Err(ToolInvokeError::NotConfigured {
+                server: format!(
+                    "{}: no command path configured to restart",
+                    self.server.name
+                ),
+            });
+        }
+        self.reset().await;
+        self.ensure_running().await?;
+        let fresh = self.instructions.lock().await.clone();
+        TransportLogger::new(&self.server.name).info(format!(
+            "Server instructions refreshed | server={} present={}",
+            self.server.name,
+            fresh.is_some()
+        ));
+        Ok(fresh)
+    }
+
+    /// Spawn the periodic `ping` task when `keepalive_interval_secs` is set.
+    ///
+    /// The task only holds a weak reference to the process and exits as soon
+    /// as `reset()` drops its stop sender, so it never outlives the child it
+    /// was started for.
+    async fn start_keepalive(self: &Arc<Self>) {
+        let interval_secs = self.server.keepalive_interval_secs;
+        if interval_secs == 0 {
+            return;
+        }
+        let interval = Duration::from_secs(interval_secs);
+        let (stop_tx, mut stop_rx) = oneshot::channel::<()>();
+        *self.keepalive_stop.lock().await = Some(stop_tx);
+
+        let weak = Arc::downgrade(self);
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut stop_rx => break,
+                    _ = tokio::time::sleep(interval) => {}
+                }
+                let Some(inner) = weak.upgrade() else {
+                    break;
+                };
+                let outcome = tokio::select! {
+                    _ = &mut stop_rx => break,
+                    outcome = inner.ping(interval) => outcome,
+                };
+                if let Err(err) = outcome {
+                    TransportLogger::new(&inner.server.name).warn(format!(
+                        "keepalive ping failed, resetting MCP server | server={} error={}",
+                        inner.server.name, err
+                    ));
+                    inner.reset().await;
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Record that the server is in use, postponing its idle timeout.
+    /// Every request but `ping` calls this when it is sent and answered.
+    pub(super) fn touch(&self) {
+        if let Ok(mut last_used) = self.last_used.lock() {
+            *last_used = Instant::now();
+        }
+    }
+
+    fn idle_for(&self) -> Duration {
+        self.last_used
+            .lock()
+            .map_or(Duration::ZERO, |last_used| last_used.elapsed())
+    }
+
+    /// Spawn the task that stops the process once it has gone
+    /// `idle_timeout_secs` without a call, when that is set.
+    ///
+    /// Like the keepalive task it holds only a weak reference and exits when
+    /// `reset()` drops its stop sender. A call still awaiting its response
+    /// keeps the process alive; the next call after a stop respawns it.
+    async fn start_idle_timeout(self: &Arc<Self>) {
+        let timeout_secs = self.server.idle_timeout_secs;
+        if timeout_secs == 0 {
+            return;
+        }
+        let timeout = Duration::from_secs(timeout_secs);
+        self.touch();
+        let (stop_tx, mut stop_rx) = oneshot::channel::<()>();
+        *self.idle_stop.lock().await = Some(stop_tx);
+
+        let weak = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut wait = timeout;
+            loop {
+                tokio::select! {
+                    _ = &mut stop_rx => break,
+                    _ = tokio::time::sleep(wait) => {}
+                }
+                let Some(inner) = weak.upgrade() else {
+                    break;
+                };
+                let idle = inner.idle_for();
+                if idle < timeout || !inner.pending.lock().await.is_empty() {
+                    wait = timeout.saturating_sub(idle).max(IDLE_RECHECK_DELAY);
+                    continue;
+                }
+                TransportLogger::new(&inner.server.name).info(format!(
+                    "MCP server idle, stopping until next use | server={} idle_secs={}",
+                    inner.server.name,
+                    idle.as_secs()
+                ));
+                inner.reset().await;
+                break;
+            }
+        });
+    }
+
+    async fn ping(&self, timeout: Duration) -> Result<(), ToolInvokeError> {
+        // With every in-flight slot taken the server is busy answering, and a
+        // ping would only queue behind those requests; skip this round.
+        let _slot = match &self.inflight {
+            Some(slots) => match slots.try_acquire() {
+                Ok(slot) => Some(slot),
+                Err(_) => return Ok(()),
+            },
+            None => None,
+        };
+        let ping = self.send_request_now("ping", json!({}));
+        match tokio::time::timeout(timeout, ping).await {
+            // A JSON-RPC error still proves the server is alive and reading stdin.
+            Ok(Ok(_)) | Ok(Err(ToolInvokeError::Rpc { .. })) => Ok(()),
+            Ok(Err(err)) => Err(err),
+            Err(_) => {
+                Err(self.transport_error(format!("no ping response within {}s", timeout.as_secs())))
+            }
+        }
+    }
+
     pub(super) async fn call_tool(
         &self,
         tool: &str,
         arguments: Value,
+        mut meta: JsonMap<String, Value>,
     ) -> Result<Value, ToolInvokeError> {
+        let token = match meta.get("progressToken").and_then(progress_token_key) {
+            Some(token) => token,
+            None => {
+                let id = self.id_counter.fetch_add(1, Ordering::SeqCst);
+                let token = format!("progress-{id}");
+                meta.insert("progressToken".to_string(), Value::String(token.clone()));
+                token
+            }
+        };
         let params = json!({
             "name": tool,
             "arguments": match arguments {
                 Value::Null => Value::Object(Default::default()),
                 other => other,
-            }
+            },
+            "_meta": meta,
         });
-        let response = self.send_request("tools/call", params).await?;
-        Ok(response)
+
+        let pending = PendingProgress {
+            tool: tool.to_string(),
+            sink: current_call_progress(),
+        };
+        self.progress_tokens
+            .lock()
+            .await
+            .insert(token.clone(), pending);
+        let response = self.send_request("tools/call", params).await;
+        self.progress_tokens.lock().await.remove(&token);
+        response
+    }
+
+    /// Log a `notifications/progress` and pass it to the sink of its call and
+    /// to the process-wide progress sink.
+    pub(super) async fn forward_progress(&self, params: &Value) {
+        let Some(token) = params.get("progressToken").and_then(progress_token_key) else {
+            return;
+        };
+        let (tool, call_sink) = match self.progress_tokens.lock().await.get(&token) {
+            Some(pending) => (Some(pending.tool.clone()), pending.sink.clone()),
+            None => (None, None),
+        };
+        let progress = ToolProgress {
+            server: self.server.name.clone(),
+            tool,
+            progress_token: token,
+            progress: params
+                .get("progress")
+                .and_then(Value::as_f64)
+                .unwrap_or(0.0),
+            total: params.get("total").and_then(Value::as_f64),
+            message: params
+                .get("message")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+        };
+        TransportLogger::new(&self.server.name).debug(format!(
+            "tool progress | server={} tool={} progress={} total={:?}",
+            self.server.name,
+            progress.tool.as_deref().unwrap_or("?"),
+            progress.progress,
+            progress.total
+        ));
+        if let Some(sink) = call_sink {
+            sink(&progress);
+        }
+        let sink = self.progress_sink.lock().ok().and_then(|sink| sink.clone());
+        if let Some(sink) = sink {
+            sink(&progress);
+        }
     }
 
     pub(crate) fn build_elicitation_ack(&self, params: Value) -> Value {
@@ -200,22 +574,12 @@ impl McpProcessInner {
     }
 
     pub(super) async fn reset(&self) {
-        {
-            let mut writer = self.writer.lock().await;
-            *writer = None;
-        }
-
-        let mut state = self.state.lock().await;
-        if let Some(mut running) = state.take() {
-            if let Err(err) = running.child.kill().await {
-                TransportLogger::new(&self.server.name).debug(format!(
-                    "failed to kill MCP server process (may have already exited) | server={} error={}",
-                    self.server.name, err
-                ));
-            }
-            let _ = running.child.wait().await;
+        self.keepalive_stop.lock().await.take();
+        self.idle_stop.lock().await.take();
+        let transport = self.transport.lock().await.take();
+        if let Some(transport) = transport {
+            transport.close().await;
         }
-        drop(state);
 
         self.fail_all_pending().await;
         self.tool_cache.lock().await.clear();
@@ -239,6 +603,24 @@ impl McpProcessInner {
     }
 }
 
+/// A progress token as a map key; MCP allows strings and integers.
+fn progress_token_key(token: &Value) -> Option<String> {
+    match token {
+        Value::String(token) => Some(token.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+fn is_transient_spawn_error(error: &std::io::Error) -> bool {
+    matches!(
+        error.kind(),
+        std::io::ErrorKind::PermissionDenied
+            | std::io::ErrorKind::ResourceBusy
+            | std::io::ErrorKind::ExecutableFileBusy
+    )
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct ElicitationCreateParams {
     #[serde(default)]
@@ -0,0 +1,52 @@
+//! Progress of running tool calls.
+//!
+//! STDIO servers report progress with `notifications/progress`;
+//! [`McpProcess`](super::McpProcess) turns those into [`ToolProgress`]
+//! values. Besides the process-wide sink, a caller can receive the progress
+//! of just the calls it makes by running them inside [`with_call_progress`]:
+//! the sink is picked up when the call is sent and used for every update
+//! about it, even though updates arrive on the reader task.
+
+use std::future::Future;
+use std::sync::Arc;
+
+/// One progress update about a running tool call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolProgress {
+    pub server: String,
+    /// Tool of the call the progress token belongs to; `None` when the token
+    /// is not one of this client's pending calls.
+    pub tool: Option<String>,
+    pub progress_token: String,
+    pub progress: f64,
+    pub total: Option<f64>,
+    pub message: Option<String>,
+}
+
+impl ToolProgress {
+    /// `progress` as a percentage of `total`, when the total is known.
+    pub fn percent(&self) -> Option<u8> {
+        let total = self.total.filter(|total| *total > 0.0)?;
+        Some((self.progress / total * 100.0).clamp(0.0, 100.0) as u8)
+    }
+}
+
+/// Callback that receives [`ToolProgress`] updates, e.g. to drive a
+/// progress bar for a slow tool.
+pub type ToolProgressSink = Arc<dyn Fn(&ToolProgress) + Send + Sync>;
+
+tokio::task_local! {
+    static CALL_PROGRESS: ToolProgressSink;
+}
+
+/// Run `future` with `sink` receiving the progress of the tool calls it
+/// starts.
+pub async fn with_call_progress<F: Future>(sink: ToolProgressSink, future: F) -> F::Output {
+    CALL_PROGRESS.scope(sink, future).await
+}
+
+/// Sink installed by the enclosing [`with_call_progress`], if any. Tool
+/// bridges call this when a call starts to report its progress.
+pub fn current_call_progress() -> Option<ToolProgressSink> {
+    CALL_PROGRESS.try_with(Arc::clone).ok()
+}
@@ -123,7 +123,8 @@ impl McpProcessInner {
 }
 
 /// Spawn an MCP server process and list its available tools.
-/// Returns a list of (tool_name, description) pairs.
+/// Returns a list of (tool_name, description) pairs, limited to the tools
+/// permitted by the server's `allowed_tools`/`blocked_tools`.
 pub async fn spawn_and_list_tools(
     config: &ServerConfig,
 ) -> Result<Vec<(String, String)>, ToolInvokeError> {
@@ -146,6 +147,7 @@ pub async fn spawn_and_list_tools(
         let tools = transport.list_tools().await;
         Ok(tools
             .into_iter()
+            .filter(|info| config.allows_tool(&info.name))
             .map(|info| (info.name, info.description.unwrap_or_default()))
             .collect())
     } else {
@@ -154,6 +156,7 @@ pub async fn spawn_and_list_tools(
         let cache = process.inner.tool_cache.lock().await;
         let tools: Vec<(String, String)> = cache
             .values()
+            .filter(|info| config.allows_tool(&info.name))
             .map(|info| {
                 (
                     info.name.clone(),
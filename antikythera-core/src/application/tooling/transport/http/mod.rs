@@ -18,7 +18,10 @@ use tokio::sync::Mutex as AsyncMutex;
 use super::McpTransport;
 use super::config::{HttpTransportConfig, TransportMode};
 use crate::application::tooling::error::ToolInvokeError;
-use crate::application::tooling::interface::{PROTOCOL_VERSION, ServerToolInfo};
+use crate::application::tooling::interface::{
+    Completion, CompletionRef, ServerToolInfo, advertises_completions, completion_params,
+    initialize_params,
+};
 
 /// HTTP Transport for MCP communication.
 #[derive(Clone)]
@@ -32,6 +35,8 @@ pub struct HttpTransportInner {
     pub id_counter: AtomicU64,
     pub connected: AtomicBool,
     pub instructions: AsyncMutex<Option<String>>,
+    /// The server advertised the `completions` capability.
+    pub completions: AtomicBool,
     pub tool_cache: AsyncMutex<HashMap<String, ServerToolInfo>>,
     pub session_endpoint: AsyncMutex<Option<String>>,
     pub active_mode: AsyncMutex<Option<TransportMode>>,
@@ -58,6 +63,7 @@ impl HttpTransport {
                 id_counter: AtomicU64::new(1),
                 connected: AtomicBool::new(false),
                 instructions: AsyncMutex::new(None),
+                completions: AtomicBool::new(false),
                 tool_cache: AsyncMutex::new(HashMap::new()),
                 session_endpoint: AsyncMutex::new(None),
                 active_mode: AsyncMutex::new(None),
@@ -231,25 +237,14 @@ impl McpTransport for HttpTransport {
         *self.inner.active_mode.lock().await = Some(detected_mode);
 
         // Initialize connection
-        let params = json!({
-            "protocolVersion": PROTOCOL_VERSION,
-            "clientInfo": {
-                "name": env!("CARGO_PKG_NAME"),
-                "version": env!("CARGO_PKG_VERSION"),
-                "title": "CBT MCP Client"
-            },
-            "capabilities": {
-                "tools": {
-                    "listChanged": true
-                }
-            }
-        });
-
-        let result = self.send_request("initialize", params).await?;
+        let result = self.send_request("initialize", initialize_params()).await?;
 
         if let Some(text) = result.get("instructions").and_then(Value::as_str) {
             *self.inner.instructions.lock().await = Some(text.to_string());
         }
+        self.inner
+            .completions
+            .store(advertises_completions(&result), Ordering::SeqCst);
 
         self.send_notification("notifications/initialized", json!({}))
             .await?;
@@ -311,6 +306,29 @@ impl McpTransport for HttpTransport {
         self.inner.instructions.lock().await.clone()
     }
 
+    /// Connect again with a new session, since MCP allows one `initialize`
+    /// per session, and return the instructions it answers with.
+    async fn refresh_instructions(&self) -> Result<Option<String>, ToolInvokeError> {
+        self.disconnect().await;
+        self.connect().await?;
+        Ok(self.instructions().await)
+    }
+
+    async fn complete(
+        &self,
+        reference: &CompletionRef,
+        argument: &str,
+        prefix: &str,
+    ) -> Result<Completion, ToolInvokeError> {
+        self.connect().await?;
+        if !self.inner.completions.load(Ordering::SeqCst) {
+            return Ok(Completion::default());
+        }
+        let params = completion_params(reference, argument, prefix);
+        let result = self.send_request("completion/complete", params).await?;
+        Ok(Completion::from_result(&result))
+    }
+
     async fn tool_metadata(&self, tool: &str) -> Option<ServerToolInfo> {
         self.inner.tool_cache.lock().await.get(tool).cloned()
     }
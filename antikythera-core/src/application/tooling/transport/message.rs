@@ -0,0 +1,198 @@
+//! Framed message transport for the STDIO JSON-RPC client.
+//!
+//! [`McpProcess`](crate::application::tooling::McpProcess) handles request
+//! correlation, server requests, and notifications; a [`MessageTransport`]
+//! only moves one JSON message at a time. [`StreamTransport`] implements it
+//! over a child process's stdin/stdout or over any async reader/writer pair,
+//! such as the in-memory [`StreamTransport::pipe`] used to test the protocol
+//! layer without spawning a process. Messages are framed as one per line or,
+//! for servers that need it, LSP-style with a `Content-Length` header.
+
+use crate::config::StdioFraming;
+use async_trait::async_trait;
+use std::io;
+use tokio::io::{
+    AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader, BufWriter,
+};
+use tokio::process::Child;
+use tokio::sync::Mutex as AsyncMutex;
+
+/// Size of each direction's buffer in [`StreamTransport::pipe`].
+const PIPE_BUFFER_BYTES: usize = 64 * 1024;
+/// Largest `Content-Length` body accepted from a server.
+const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+/// Moves framed JSON-RPC messages to and from an MCP server.
+///
+/// Reads and writes may run concurrently: the client keeps one task reading
+/// while requests are written from others.
+#[async_trait]
+pub trait MessageTransport: Send + Sync {
+    /// Frame `message`, write it, and flush.
+    async fn send(&self, message: &str) -> io::Result<()>;
+
+    /// Read the next message body; `None` at end of stream.
+    async fn receive(&self) -> io::Result<Option<String>>;
+
+    /// Close the connection. For a child process this kills it.
+    async fn close(&self);
+}
+
+type BoxedReader = Box<dyn AsyncRead + Send + Unpin>;
+type BoxedWriter = Box<dyn AsyncWrite + Send + Unpin>;
+
+/// [`MessageTransport`] over an async reader/writer pair, optionally owning
+/// the child process they belong to.
+pub struct StreamTransport {
+    reader: AsyncMutex<BufReader<BoxedReader>>,
+    writer: AsyncMutex<BufWriter<BoxedWriter>>,
+    child: AsyncMutex<Option<Child>>,
+    framing: StdioFraming,
+}
+
+impl StreamTransport {
+    /// Line-delimited transport over `reader` and `writer`.
+    pub fn new(
+        reader: impl AsyncRead + Send + Unpin + 'static,
+        writer: impl AsyncWrite + Send + Unpin + 'static,
+    ) -> Self {
+        Self::with_child(Box::new(reader), Box::new(writer), None)
+    }
+
+    fn with_child(reader: BoxedReader, writer: BoxedWriter, child: Option<Child>) -> Self {
+        Self {
+            reader: AsyncMutex::new(BufReader::new(reader)),
+            writer: AsyncMutex::new(BufWriter::new(writer)),
+            child: AsyncMutex::new(child),
+            framing: StdioFraming::default(),
+        }
+    }
+
+    /// Talk to `child` over its piped stdout and stdin. Closing the
+    /// transport kills the child and waits for it to exit.
+    pub fn from_child(mut child: Child) -> io::Result<Self> {
+        let missing = |stream: &str| {
+            io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                format!("failed to capture server {stream}"),
+            )
+        };
+        let stdout = child.stdout.take().ok_or_else(|| missing("stdout"))?;
+        let stdin = child.stdin.take().ok_or_else(|| missing("stdin"))?;
+        Ok(Self::with_child(
+            Box::new(stdout),
+            Box::new(stdin),
+            Some(child),
+        ))
+    }
+
+    /// Two transports connected to each other in memory: messages sent on
+    /// one are received by the other.
+    pub fn pipe() -> (Self, Self) {
+        let (left, right) = tokio::io::duplex(PIPE_BUFFER_BYTES);
+        let (left_read, left_write) = tokio::io::split(left);
+        let (right_read, right_write) = tokio::io::split(right);
+        (
+            Self::new(left_read, left_write),
+            Self::new(right_read, right_write),
+        )
+    }
+
+    /// Use `framing` for both directions.
+    pub fn with_framing(mut self, framing: StdioFraming) -> Self {
+        self.framing = framing;
+        self
+    }
+}
+
+#[async_trait]
+impl MessageTransport for StreamTransport {
+    async fn send(&self, message: &str) -> io::Result<()> {
+        let mut writer = self.writer.lock().await;
+        match self.framing {
+            StdioFraming::LineDelimited => {
+                writer.write_all(message.as_bytes()).await?;
+                writer.write_all(b"\n").await?;
+            }
+            StdioFraming::ContentLength => {
+                let header = format!("Content-Length: {}\r\n\r\n", message.len());
+                writer.write_all(header.as_bytes()).await?;
+                writer.write_all(message.as_bytes()).await?;
+            }
+        }
+        writer.flush().await
+    }
+
+    async fn receive(&self) -> io::Result<Option<String>> {
+        let mut reader = self.reader.lock().await;
+        match self.framing {
+            StdioFraming::LineDelimited => read_line(&mut reader).await,
+            StdioFraming::ContentLength => read_content_length(&mut reader).await,
+        }
+    }
+
+    async fn close(&self) {
+        let _ = self.writer.lock().await.shutdown().await;
+        if let Some(mut child) = self.child.lock().await.take() {
+            let _ = child.kill().await;
+            let _ = child.wait().await;
+        }
+    }
+}
+
+/// Next line without its `\n` or `\r\n`; `None` at end of stream.
+async fn read_line(reader: &mut BufReader<BoxedReader>) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    let trimmed = line.trim_end_matches(['\n', '\r']).len();
+    line.truncate(trimmed);
+    Ok(Some(line))
+}
+
+/// Read headers up to the blank line, then exactly `Content-Length` bytes.
+/// Other headers (e.g. `Content-Type`) are ignored.
+async fn read_content_length(reader: &mut BufReader<BoxedReader>) -> io::Result<Option<String>> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let mut length = None;
+    let mut saw_header = false;
+    loop {
+        let Some(line) = read_line(reader).await? else {
+            return if saw_header {
+                Err(io::ErrorKind::UnexpectedEof.into())
+            } else {
+                Ok(None)
+            };
+        };
+        if line.is_empty() {
+            if saw_header {
+                break;
+            }
+            continue;
+        }
+        saw_header = true;
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            let value = value.trim();
+            length = Some(
+                value
+                    .parse::<usize>()
+                    .map_err(|_| invalid(format!("invalid Content-Length '{value}'")))?,
+            );
+        }
+    }
+
+    let length = length.ok_or_else(|| invalid("message without Content-Length".to_string()))?;
+    if length > MAX_CONTENT_LENGTH {
+        return Err(invalid(format!(
+            "Content-Length {length} exceeds the {MAX_CONTENT_LENGTH} byte limit"
+        )));
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).await?;
+    String::from_utf8(body)
+        .map(Some)
+        .map_err(|error| invalid(error.to_string()))
+}
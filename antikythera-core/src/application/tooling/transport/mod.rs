@@ -7,6 +7,7 @@
 //!
 //! - `config` - Transport configuration types (TransportMode, HttpTransportConfig)
 //! - `builtin` - In-process built-in tool transport
+//! - `message` - Framed message transport under the STDIO JSON-RPC client
 //! - `http` - HTTP transport implementation
 //!   - `sse` - SSE listener and endpoint resolution
 //!   - `rpc` - JSON-RPC request/notification handling
@@ -15,6 +16,8 @@
 mod builtin;
 mod config;
 mod http;
+#[cfg(feature = "native-transport")]
+mod message;
 
 use crate::logging::TransportLogger;
 use async_trait::async_trait;
@@ -23,12 +26,14 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use super::error::ToolInvokeError;
-use super::interface::ServerToolInfo;
+use super::interface::{Completion, CompletionRef, ServerToolInfo};
 
 // Re-export public types
 pub use builtin::{BuiltinToolFn, BuiltinTransport, validate_arguments};
 pub use config::{HttpTransportConfig, TransportCapability, TransportMode};
 pub use http::HttpTransport;
+#[cfg(feature = "native-transport")]
+pub use message::{MessageTransport, StreamTransport};
 
 /// Transport trait for MCP communication.
 ///
@@ -52,6 +57,25 @@ pub trait McpTransport: Send + Sync {
     /// Get server instructions (from initialize response).
     async fn instructions(&self) -> Option<String>;
 
+    /// Reconnect with a fresh session and return the instructions of its
+    /// `initialize` result; a repeated `initialize` on one session is not
+    /// allowed by MCP. Transports whose instructions cannot change return
+    /// the cached value.
+    async fn refresh_instructions(&self) -> Result<Option<String>, ToolInvokeError> {
+        Ok(self.instructions().await)
+    }
+
+    /// Ask the server for values completing `argument` of `reference`.
+    /// Transports without a server that offers completions return none.
+    async fn complete(
+        &self,
+        _reference: &CompletionRef,
+        _argument: &str,
+        _prefix: &str,
+    ) -> Result<Completion, ToolInvokeError> {
+        Ok(Completion::default())
+    }
+
     /// Get tool metadata from cache.
     async fn tool_metadata(&self, tool: &str) -> Option<ServerToolInfo>;
 
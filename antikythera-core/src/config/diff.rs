@@ -0,0 +1,249 @@
+//! Differences between two [`AppConfig`]s, for previews of a config edit
+//! or a rollback before it is applied.
+//!
+//! [`AppConfig::diff`] compares the default provider and model, the
+//! servers and tools (matched by name), the prompt fields, and the
+//! per-provider prompt overrides. Entries report which of their fields
+//! differ rather than their values, so server `env` and `headers` never
+//! appear in a diff.
+
+use super::app::AppConfig;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Part of the config a [`ConfigChange`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSection {
+    /// The default provider
+    Provider,
+    /// The default model
+    Model,
+    Server,
+    Tool,
+    /// `system_prompt` and the fields of `[prompts]`
+    Prompt,
+    /// The prompt overrides of one provider, named by its ID
+    ProviderPrompts,
+}
+
+impl ConfigSection {
+    fn label(self) -> &'static str {
+        match self {
+            ConfigSection::Provider => "provider",
+            ConfigSection::Model => "model",
+            ConfigSection::Server => "server",
+            ConfigSection::Tool => "tool",
+            ConfigSection::Prompt => "prompt",
+            ConfigSection::ProviderPrompts => "provider prompts",
+        }
+    }
+}
+
+/// One difference found by [`AppConfig::diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum ConfigChange {
+    /// An entry only the new config has.
+    Added {
+        section: ConfigSection,
+        name: String,
+    },
+    /// An entry only the old config has.
+    Removed {
+        section: ConfigSection,
+        name: String,
+    },
+    /// An entry both configs have, with different settings.
+    Modified {
+        section: ConfigSection,
+        name: String,
+        fields: Vec<String>,
+    },
+    /// A single setting with a new value; `null` when unset.
+    Changed {
+        section: ConfigSection,
+        name: String,
+        before: Value,
+        after: Value,
+    },
+}
+
+impl ConfigChange {
+    /// One line: `+ server time`, `- tool get_time`,
+    /// `~ server time: args, env`, or `~ model model: "a" -> "b"`.
+    pub fn render(&self) -> String {
+        match self {
+            ConfigChange::Added { section, name } => format!("+ {} {name}", section.label()),
+            ConfigChange::Removed { section, name } => format!("- {} {name}", section.label()),
+            ConfigChange::Modified {
+                section,
+                name,
+                fields,
+            } => format!("~ {} {name}: {}", section.label(), fields.join(", ")),
+            ConfigChange::Changed {
+                section,
+                name,
+                before,
+                after,
+            } => format!("~ {} {name}: {before} -> {after}", section.label()),
+        }
+    }
+}
+
+impl AppConfig {
+    /// What changes going from `self` to `other`, in section order: the
+    /// default provider and model, servers, tools, prompt fields, then
+    /// provider prompt overrides. Entries within a section are sorted by
+    /// name. Identical configs give no changes.
+    pub fn diff(&self, other: &AppConfig) -> Vec<ConfigChange> {
+        let mut changes = Vec::new();
+        push_changed(
+            &mut changes,
+            ConfigSection::Provider,
+            "default_provider",
+            to_value(&self.default_provider),
+            to_value(&other.default_provider),
+        );
+        push_changed(
+            &mut changes,
+            ConfigSection::Model,
+            "model",
+            to_value(&self.model),
+            to_value(&other.model),
+        );
+        diff_entries(
+            &mut changes,
+            ConfigSection::Server,
+            self.servers.iter().map(|s| (s.name.as_str(), to_value(s))),
+            other.servers.iter().map(|s| (s.name.as_str(), to_value(s))),
+        );
+        diff_entries(
+            &mut changes,
+            ConfigSection::Tool,
+            self.tools.iter().map(|t| (t.name.as_str(), to_value(t))),
+            other.tools.iter().map(|t| (t.name.as_str(), to_value(t))),
+        );
+        push_changed(
+            &mut changes,
+            ConfigSection::Prompt,
+            "system_prompt",
+            to_value(&self.system_prompt),
+            to_value(&other.system_prompt),
+        );
+        let before = fields(to_value(&self.prompts));
+        let mut after = fields(to_value(&other.prompts));
+        for (name, value) in before {
+            let new = after.remove(&name).unwrap_or(Value::Null);
+            push_changed(&mut changes, ConfigSection::Prompt, &name, value, new);
+        }
+        for (name, value) in after {
+            push_changed(
+                &mut changes,
+                ConfigSection::Prompt,
+                &name,
+                Value::Null,
+                value,
+            );
+        }
+        diff_entries(
+            &mut changes,
+            ConfigSection::ProviderPrompts,
+            self.provider_prompts
+                .iter()
+                .map(|(id, prompts)| (id.as_str(), to_value(prompts))),
+            other
+                .provider_prompts
+                .iter()
+                .map(|(id, prompts)| (id.as_str(), to_value(prompts))),
+        );
+        changes
+    }
+}
+
+fn to_value(value: &impl Serialize) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+/// Fields of a serialized struct by name; nothing for a non-object.
+fn fields(value: Value) -> BTreeMap<String, Value> {
+    match value {
+        Value::Object(map) => map.into_iter().collect(),
+        _ => BTreeMap::new(),
+    }
+}
+
+fn push_changed(
+    changes: &mut Vec<ConfigChange>,
+    section: ConfigSection,
+    name: &str,
+    before: Value,
+    after: Value,
+) {
+    if before != after {
+        changes.push(ConfigChange::Changed {
+            section,
+            name: name.to_string(),
+            before,
+            after,
+        });
+    }
+}
+
+/// Compare two lists of named entries, given as their serialized form.
+fn diff_entries<'a>(
+    changes: &mut Vec<ConfigChange>,
+    section: ConfigSection,
+    before: impl Iterator<Item = (&'a str, Value)>,
+    after: impl Iterator<Item = (&'a str, Value)>,
+) {
+    let before: BTreeMap<&str, Value> = before.collect();
+    let mut after: BTreeMap<&str, Value> = after.collect();
+    let mut found = Vec::new();
+    for (name, old) in before {
+        let change = match after.remove(name) {
+            None => ConfigChange::Removed {
+                section,
+                name: name.to_string(),
+            },
+            Some(new) if new != old => ConfigChange::Modified {
+                section,
+                name: name.to_string(),
+                fields: changed_fields(old, new),
+            },
+            Some(_) => continue,
+        };
+        found.push((name, change));
+    }
+    found.extend(after.into_keys().map(|name| {
+        let change = ConfigChange::Added {
+            section,
+            name: name.to_string(),
+        };
+        (name, change)
+    }));
+    found.sort_by(|a, b| a.0.cmp(b.0));
+    changes.extend(found.into_iter().map(|(_, change)| change));
+}
+
+/// Names of the fields that differ between two serialized entries.
+fn changed_fields(before: Value, after: Value) -> Vec<String> {
+    let before = fields(before);
+    let mut after = fields(after);
+    let mut names: Vec<String> = before
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let new = after.remove(&name).unwrap_or(Value::Null);
+            (new != value).then_some(name)
+        })
+        .collect();
+    names.extend(
+        after
+            .into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(name, _)| name),
+    );
+    names.sort();
+    names
+}
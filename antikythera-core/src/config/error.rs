@@ -42,4 +42,14 @@ pub enum ConfigError {
 
     #[error("configuration cache error: {0}")]
     CacheError(String),
+
+    #[error("failed to parse seed history from {path:?}: {source}")]
+    SeedHistory {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("config version '{version}' not found in {dir:?}")]
+    VersionNotFound { version: String, dir: PathBuf },
 }
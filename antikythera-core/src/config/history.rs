@@ -0,0 +1,124 @@
+//! Versioned backups of the config file.
+//!
+//! With [`ConfigHistoryConfig`] set, each save first copies the file it
+//! replaces into the history directory as `<version>.<file name>`, where the
+//! version is the UTC time of the save (`20261016T161242123Z`). Versions
+//! sort by name, the oldest beyond `keep` are deleted, and any of them can
+//! be written back with [`ConfigHistoryConfig::rollback`].
+
+use super::app::ConfigHistoryConfig;
+use super::error::ConfigError;
+use super::serializer::write_atomic;
+use crate::logging::ConfigLogger;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One saved copy of a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigVersion {
+    /// Version ID: the UTC time the copy was taken.
+    pub id: String,
+    pub path: PathBuf,
+}
+
+impl ConfigHistoryConfig {
+    /// Versions of `config_path` in the history directory, newest first. A
+    /// missing directory has none.
+    pub fn versions(&self, config_path: &Path) -> Result<Vec<ConfigVersion>, ConfigError> {
+        let suffix = format!(".{}", file_name(config_path));
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error(&self.dir, e)),
+        };
+        let mut versions: Vec<ConfigVersion> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let id = name.strip_suffix(&suffix)?;
+                (!id.is_empty() && !id.contains('.')).then(|| ConfigVersion {
+                    id: id.to_string(),
+                    path: entry.path(),
+                })
+            })
+            .collect();
+        versions.sort_by(|a, b| b.id.cmp(&a.id));
+        Ok(versions)
+    }
+
+    /// Copy `previous`, the content `config_path` had before a save, into
+    /// the history and delete versions beyond `keep`.
+    pub fn record(
+        &self,
+        config_path: &Path,
+        previous: &[u8],
+    ) -> Result<ConfigVersion, ConfigError> {
+        fs::create_dir_all(&self.dir).map_err(|e| io_error(&self.dir, e))?;
+        let name = file_name(config_path);
+        let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%S%3fZ").to_string();
+        // Saves within the same millisecond get a counter so none is lost.
+        let mut id = stamp.clone();
+        let mut counter = 1;
+        while self.dir.join(format!("{id}.{name}")).exists() {
+            id = format!("{stamp}{counter:03}");
+            counter += 1;
+        }
+        let path = self.dir.join(format!("{id}.{name}"));
+        write_atomic(&path, previous).map_err(|e| io_error(&path, e))?;
+
+        for stale in self.versions(config_path)?.into_iter().skip(self.keep) {
+            let _ = fs::remove_file(&stale.path);
+        }
+        ConfigLogger::new("config").info(format!(
+            "Config version saved | file={} version={}",
+            config_path.display(),
+            id
+        ));
+        Ok(ConfigVersion { id, path })
+    }
+
+    /// Write `data` to `config_path` atomically, recording the content it
+    /// replaces. Nothing is recorded for a new file or when the write fails.
+    pub fn save(&self, config_path: &Path, data: &[u8]) -> Result<(), ConfigError> {
+        let previous = fs::read(config_path).ok();
+        write_atomic(config_path, data).map_err(|e| io_error(config_path, e))?;
+        if let Some(previous) = previous {
+            self.record(config_path, &previous)?;
+        }
+        Ok(())
+    }
+
+    /// Restore `config_path` to `version`. The content being replaced is
+    /// recorded first, so a rollback can itself be undone.
+    pub fn rollback(&self, config_path: &Path, version: &str) -> Result<(), ConfigError> {
+        let found = self
+            .versions(config_path)?
+            .into_iter()
+            .find(|candidate| candidate.id == version)
+            .ok_or_else(|| ConfigError::VersionNotFound {
+                version: version.to_string(),
+                dir: self.dir.clone(),
+            })?;
+        let data = fs::read(&found.path).map_err(|e| io_error(&found.path, e))?;
+        self.save(config_path, &data)?;
+        ConfigLogger::new("config").info(format!(
+            "Config rolled back | file={} version={}",
+            config_path.display(),
+            version
+        ));
+        Ok(())
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> ConfigError {
+    ConfigError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
@@ -1,17 +1,35 @@
-//! Configuration loader - Postcard-only
+//! Configuration loader
 //!
-//! All configuration is stored as a single Postcard binary file (`app.pc`).
+//! Configuration is normally stored as a single Postcard binary file
+//! (`app.pc`), but can also be read from TOML. Without an explicit path the
+//! first source found wins:
+//!
+//! 1. `config/config.toml` — combined file with client and model sections
+//! 2. `config/client.toml` plus `config/model.toml` (optional) — split files
+//! 3. `app.pc` — Postcard blob
+//!
+//! An explicit path is read according to its name: `client.toml` or
+//! `model.toml` loads the split pair from that directory, any other `.toml`
+//! file is read as a combined file, and everything else as Postcard.
 
-use super::app::{PromptsConfig, RestServerConfig};
+use super::app::{
+    AuditLogConfig, ConfigHistoryConfig, PromptsConfig, ReasoningConfig, RestServerConfig,
+    SessionTitleConfig,
+};
 use super::error::ConfigError;
 use super::postcard_config;
+use super::toml_config::RawConfig;
+use crate::constants::{CLIENT_TOML_FILE, COMBINED_TOML_FILE, MODEL_TOML_FILE, SPLIT_TOML_DIR};
 use crate::logging::ConfigLogger;
 use dotenvy::from_filename;
-use std::path::Path;
-use std::sync::Once;
+use std::path::{Path, PathBuf};
+use std::sync::{Once, RwLock};
 
 static ENV_LOADER: Once = Once::new();
 
+/// Directory of the most recently loaded config file.
+static LOADED_CONFIG_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
 /// Ensures environment variables are loaded from .env (project root)
 pub fn ensure_env_loaded() {
     ENV_LOADER.call_once(|| {
@@ -19,12 +37,142 @@ pub fn ensure_env_loaded() {
     });
 }
 
-/// Load and validate configuration from Postcard binary
+/// Directory holding the most recently loaded config file, made absolute.
+///
+/// Falls back to the current directory before any config has been loaded.
+/// Server argument templates expand `${config_dir}` to this path.
+pub fn loaded_config_dir() -> PathBuf {
+    let loaded = LOADED_CONFIG_DIR
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    let dir = loaded.unwrap_or_else(|| PathBuf::from("."));
+    std::path::absolute(&dir).unwrap_or(dir)
+}
+
+fn remember_config_dir(file: &Path) {
+    let dir = match file.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    *LOADED_CONFIG_DIR.write().unwrap_or_else(|e| e.into_inner()) = Some(dir);
+}
+
+/// Where the runtime configuration is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// One TOML file holding both the client and model sections.
+    CombinedToml(PathBuf),
+    /// `client.toml` plus an optional `model.toml` next to it.
+    SplitToml {
+        client: PathBuf,
+        model: Option<PathBuf>,
+    },
+    /// The Postcard binary blob.
+    Postcard(PathBuf),
+}
+
+impl ConfigSource {
+    /// The file to pass to [`load_config`] to read this source.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::CombinedToml(path) | Self::Postcard(path) => path,
+            Self::SplitToml { client, .. } => client,
+        }
+    }
+}
+
+/// Pick the configuration source under `root` using the precedence
+/// documented on this module.
+pub fn detect_config_source(root: &Path) -> ConfigSource {
+    detect_config_source_in(
+        &root.join(SPLIT_TOML_DIR),
+        &root.join(postcard_config::CONFIG_PATH),
+    )
+}
+
+/// The same precedence with the TOML files read from `config_dir` instead
+/// of `config/`, falling back to the Postcard blob at `postcard`.
+pub fn detect_config_source_in(config_dir: &Path, postcard: &Path) -> ConfigSource {
+    let combined = config_dir.join(COMBINED_TOML_FILE);
+    if combined.is_file() {
+        return ConfigSource::CombinedToml(combined);
+    }
+    let client = config_dir.join(CLIENT_TOML_FILE);
+    if client.is_file() {
+        return split_source(client);
+    }
+    ConfigSource::Postcard(postcard.to_path_buf())
+}
+
+/// Classify an explicitly given config path.
+pub fn config_source_for(path: &Path) -> ConfigSource {
+    if path.extension().is_none_or(|ext| ext != "toml") {
+        return ConfigSource::Postcard(path.to_path_buf());
+    }
+    let file_name = path.file_name().and_then(|name| name.to_str());
+    if file_name == Some(CLIENT_TOML_FILE) || file_name == Some(MODEL_TOML_FILE) {
+        split_source(path.with_file_name(CLIENT_TOML_FILE))
+    } else {
+        ConfigSource::CombinedToml(path.to_path_buf())
+    }
+}
+
+fn split_source(client: PathBuf) -> ConfigSource {
+    let model = client.with_file_name(MODEL_TOML_FILE);
+    ConfigSource::SplitToml {
+        model: model.is_file().then_some(model),
+        client,
+    }
+}
+
+/// Load and validate configuration from the detected (or given) source.
 pub fn load_config(path: Option<&Path>) -> Result<super::AppConfig, ConfigError> {
     ensure_env_loaded();
 
-    let config_path = path.unwrap_or_else(|| Path::new(postcard_config::CONFIG_PATH));
+    let source = match path {
+        Some(path) => config_source_for(path),
+        None => detect_config_source(Path::new(".")),
+    };
+    let logger = ConfigLogger::new("config");
+    match source {
+        ConfigSource::CombinedToml(path) => {
+            let config = RawConfig::read(&path)?.into_app_config();
+            remember_config_dir(&path);
+            logger.info(format!("Config loaded from: {}", path.display()));
+            Ok(config)
+        }
+        ConfigSource::SplitToml { client, model } => {
+            let mut raw = RawConfig::read(&client)?;
+            if let Some(model) = &model {
+                raw = raw.merge(RawConfig::read(model)?);
+            }
+            logger.info(format!(
+                "Config loaded from: {} + {}",
+                client.display(),
+                model
+                    .as_deref()
+                    .map_or_else(|| "(no model file)".into(), |m| m.display().to_string())
+            ));
+            remember_config_dir(&client);
+            Ok(raw.into_app_config())
+        }
+        ConfigSource::Postcard(path) => {
+            let config = load_postcard_config(&path)?;
+            remember_config_dir(&path);
+            Ok(config)
+        }
+    }
+}
+
+/// Load the layout the setup wizard writes to `config_dir`, as picked by
+/// `--config-dir`: its TOML files when present, else its `app.pc`.
+pub fn load_config_in(config_dir: &Path) -> Result<super::AppConfig, ConfigError> {
+    let postcard = config_dir.join(postcard_config::CONFIG_PATH);
+    load_config(Some(detect_config_source_in(config_dir, &postcard).path()))
+}
 
+fn load_postcard_config(config_path: &Path) -> Result<super::AppConfig, ConfigError> {
     if !config_path.exists() {
         return Err(ConfigError::NotFound {
             path: config_path.to_path_buf(),
@@ -56,7 +204,7 @@ pub fn load_config(path: Option<&Path>) -> Result<super::AppConfig, ConfigError>
 
             let fresh = postcard_config::PostcardAppConfig::default();
             if let Ok(fresh_data) = postcard_config::config_to_postcard(&fresh) {
-                let _ = std::fs::write(config_path, fresh_data);
+                let _ = super::serializer::write_atomic(config_path, fresh_data);
             }
             fresh
         }
@@ -73,7 +221,8 @@ pub fn load_config(path: Option<&Path>) -> Result<super::AppConfig, ConfigError>
     Ok(convert_to_app_config(&config))
 }
 
-/// Save configuration to Postcard binary
+/// Save configuration to Postcard binary, replacing the file atomically.
+/// With `config_history` set, the replaced file is kept as a version.
 pub fn save_config(config: &super::AppConfig, path: Option<&Path>) -> Result<(), ConfigError> {
     let config_path = path.unwrap_or_else(|| Path::new(postcard_config::CONFIG_PATH));
 
@@ -90,10 +239,15 @@ pub fn save_config(config: &super::AppConfig, path: Option<&Path>) -> Result<(),
         })?;
     }
 
-    std::fs::write(config_path, &data).map_err(|e| ConfigError::Io {
-        path: config_path.to_path_buf(),
-        source: e,
-    })?;
+    match &config.config_history {
+        Some(history) => history.save(config_path, &data)?,
+        None => {
+            super::serializer::write_atomic(config_path, &data).map_err(|e| ConfigError::Io {
+                path: config_path.to_path_buf(),
+                source: e,
+            })?
+        }
+    }
 
     // Log successful save
     let logger = ConfigLogger::new("config");
@@ -139,10 +293,76 @@ fn convert_to_app_config(pc: &postcard_config::PostcardAppConfig) -> super::AppC
             } else {
                 Some(pc.prompts.fallback_response_keys.clone())
             },
+            guard_tool_output: pc
+                .custom
+                .get(PromptsConfig::GUARD_TOOL_OUTPUT_KEY)
+                .map(|value| value.trim().eq_ignore_ascii_case("true")),
+            untrusted_tool_output_notice: pc
+                .custom
+                .get(PromptsConfig::UNTRUSTED_NOTICE_KEY)
+                .and_then(|value| opt_nonempty(value)),
+            always_fallback_guidance: pc
+                .custom
+                .get(PromptsConfig::ALWAYS_FALLBACK_GUIDANCE_KEY)
+                .map(|value| value.trim().eq_ignore_ascii_case("true")),
+            tool_schema_detail: pc
+                .custom
+                .get(PromptsConfig::TOOL_SCHEMA_DETAIL_KEY)
+                .and_then(|value| value.parse().ok()),
         },
+        provider_prompts: PromptsConfig::providers_from_custom(&pc.custom),
+        audit_log: AuditLogConfig::from_custom(&pc.custom),
+        config_history: ConfigHistoryConfig::from_custom(&pc.custom),
+        case_sensitive_tools: pc
+            .custom
+            .get(super::AppConfig::CASE_SENSITIVE_TOOLS_KEY)
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("true")),
+        public_tools: pc
+            .custom
+            .get(super::AppConfig::PUBLIC_TOOLS_KEY)
+            .map(|value| name_list(value)),
+        terminal_tools: pc
+            .custom
+            .get(super::AppConfig::TERMINAL_TOOLS_KEY)
+            .map(|value| name_list(value))
+            .unwrap_or_default(),
+        session_title: SessionTitleConfig::from_custom(&pc.custom),
+        reasoning: ReasoningConfig::from_custom(&pc.custom),
+        stdio_default_agent_mode: !pc
+            .custom
+            .get(super::AppConfig::STDIO_DEFAULT_AGENT_MODE_KEY)
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("false")),
+        seed_history: pc
+            .custom
+            .get(super::AppConfig::SEED_HISTORY_KEY)
+            .and_then(|value| opt_nonempty(value.trim()))
+            .map(std::path::PathBuf::from),
+        session_ttl_secs: pc
+            .custom
+            .get(super::AppConfig::SESSION_TTL_KEY)
+            .and_then(|value| value.trim().parse().ok()),
+        tool_cache_ttl_secs: pc
+            .custom
+            .get(super::AppConfig::TOOL_CACHE_TTL_KEY)
+            .and_then(|value| value.trim().parse().ok()),
+        max_prompt_chars: pc
+            .custom
+            .get(super::AppConfig::MAX_PROMPT_CHARS_KEY)
+            .and_then(|value| value.trim().parse().ok()),
+        agent_max_steps: (pc.agent.max_steps > 0).then_some(pc.agent.max_steps as usize),
     }
 }
 
+/// Names from a comma-separated `custom` value, blanks dropped.
+fn name_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 fn opt_nonempty(s: &str) -> Option<String> {
     if s.is_empty() {
         None
@@ -157,6 +377,88 @@ fn opt_nonempty(s: &str) -> Option<String> {
 /// is responsible for persisting those via the CLI's own config functions.
 /// This conversion preserves only the core-owned fields (server, prompts).
 fn convert_to_postcard_config(config: &super::AppConfig) -> postcard_config::PostcardAppConfig {
+    let mut custom = std::collections::HashMap::new();
+    if let Some(audit_log) = &config.audit_log {
+        audit_log.write_custom(&mut custom);
+    }
+    if let Some(history) = &config.config_history {
+        history.write_custom(&mut custom);
+    }
+    config.session_title.write_custom(&mut custom);
+    config.reasoning.write_custom(&mut custom);
+    PromptsConfig::write_providers_custom(&config.provider_prompts, &mut custom);
+    if config.case_sensitive_tools {
+        custom.insert(
+            super::AppConfig::CASE_SENSITIVE_TOOLS_KEY.to_string(),
+            true.to_string(),
+        );
+    }
+    if let Some(public_tools) = &config.public_tools {
+        custom.insert(
+            super::AppConfig::PUBLIC_TOOLS_KEY.to_string(),
+            public_tools.join(","),
+        );
+    }
+    if !config.terminal_tools.is_empty() {
+        custom.insert(
+            super::AppConfig::TERMINAL_TOOLS_KEY.to_string(),
+            config.terminal_tools.join(","),
+        );
+    }
+    if !config.stdio_default_agent_mode {
+        custom.insert(
+            super::AppConfig::STDIO_DEFAULT_AGENT_MODE_KEY.to_string(),
+            false.to_string(),
+        );
+    }
+    if let Some(path) = &config.seed_history {
+        custom.insert(
+            super::AppConfig::SEED_HISTORY_KEY.to_string(),
+            path.display().to_string(),
+        );
+    }
+    if let Some(ttl) = config.session_ttl_secs {
+        custom.insert(
+            super::AppConfig::SESSION_TTL_KEY.to_string(),
+            ttl.to_string(),
+        );
+    }
+    if let Some(ttl) = config.tool_cache_ttl_secs {
+        custom.insert(
+            super::AppConfig::TOOL_CACHE_TTL_KEY.to_string(),
+            ttl.to_string(),
+        );
+    }
+    if let Some(max) = config.max_prompt_chars {
+        custom.insert(
+            super::AppConfig::MAX_PROMPT_CHARS_KEY.to_string(),
+            max.to_string(),
+        );
+    }
+    if let Some(guard) = config.prompts.guard_tool_output {
+        custom.insert(
+            PromptsConfig::GUARD_TOOL_OUTPUT_KEY.to_string(),
+            guard.to_string(),
+        );
+    }
+    if let Some(always) = config.prompts.always_fallback_guidance {
+        custom.insert(
+            PromptsConfig::ALWAYS_FALLBACK_GUIDANCE_KEY.to_string(),
+            always.to_string(),
+        );
+    }
+    if let Some(detail) = config.prompts.tool_schema_detail {
+        custom.insert(
+            PromptsConfig::TOOL_SCHEMA_DETAIL_KEY.to_string(),
+            detail.as_str().to_string(),
+        );
+    }
+    if let Some(notice) = &config.prompts.untrusted_tool_output_notice {
+        custom.insert(
+            PromptsConfig::UNTRUSTED_NOTICE_KEY.to_string(),
+            notice.clone(),
+        );
+    }
     postcard_config::PostcardAppConfig {
         server: postcard_config::PostcardServerConfig {
             bind: config.rest_server.bind.clone(),
@@ -214,9 +516,12 @@ fn convert_to_postcard_config(config: &super::AppConfig) -> postcard_config::Pos
                 .clone()
                 .unwrap_or_default(),
         },
-        agent: postcard_config::AgentConfig::default(),
+        agent: postcard_config::AgentConfig {
+            max_steps: config.agent_max_steps.unwrap_or(0) as u32,
+            ..postcard_config::AgentConfig::default()
+        },
         security: crate::security::config::SecurityConfig::default(),
-        custom: std::collections::HashMap::new(),
+        custom,
     }
 }
 
@@ -8,6 +8,10 @@
 //!
 //! - **`app.pc`** - All settings (providers, model, prompts, agent, server)
 //!
+//! TOML is also accepted, as one combined `config/config.toml` or as split
+//! `config/client.toml` + `config/model.toml`; see [`loader`] for precedence.
+//! [`config_schema`] describes those files as a JSON Schema for editors.
+//!
 //! ## Key Types
 //!
 //! - [`AppConfig`] - Main runtime configuration struct (MCP-protocol concerns only)
@@ -18,11 +22,16 @@
 //!   provider/model fields; consumed directly by the CLI layer)
 
 pub mod app;
+mod diff;
 pub mod error;
+mod history;
 pub mod loader;
+mod schema;
 pub mod serializer;
 pub mod server;
+mod toml_config;
 pub mod tool;
+mod validation;
 #[cfg(feature = "wizard")]
 pub mod wizard;
 
@@ -31,10 +40,19 @@ pub mod postcard_config;
 
 pub use crate::constants::{CONFIG_PATH, ENV_PATH};
 
-pub use app::{AppConfig, DocServerConfig, PromptsConfig};
+pub use app::{
+    AppConfig, AuditLogConfig, ConfigHistoryConfig, DocServerConfig, PromptsConfig,
+    ReasoningConfig, ReasoningDelimiter, RestServerConfig, SessionTitleConfig,
+    SessionTitleStrategy, ToolSchemaDetail,
+};
+pub use diff::{ConfigChange, ConfigSection};
 pub use error::ConfigError;
-pub use server::{ServerConfig, TransportType};
+pub use history::ConfigVersion;
+pub use schema::config_schema;
+pub use serializer::write_atomic;
+pub use server::{ServerConfig, StdioFraming, TransportType};
 pub use tool::ToolConfig;
+pub use validation::{ConfigCheck, ConfigReport};
 
 // Re-export logging for config operations
 pub use crate::logging::ConfigLogger;
@@ -178,7 +178,8 @@ impl PromptsConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentConfig {
-    /// Maximum tool interaction steps
+    /// Step budget of an agent run that sets none; 0 leaves it unset so the
+    /// agent's built-in default of 8 applies
     pub max_steps: u32,
     /// Verbose logging
     pub verbose: bool,
@@ -191,7 +192,7 @@ pub struct AgentConfig {
 impl Default for AgentConfig {
     fn default() -> Self {
         Self {
-            max_steps: 10,
+            max_steps: 0,
             verbose: false,
             auto_execute_tools: true,
             session_timeout_secs: 300,
@@ -254,7 +255,7 @@ pub fn save_config(config: &PostcardAppConfig, path: Option<&Path>) -> Result<()
 
     let data = config_to_postcard(config)?;
 
-    std::fs::write(config_path, &data)
+    super::serializer::write_atomic(config_path, &data)
         .map_err(|e| format!("Failed to write config file: {}", e))?;
 
     Ok(())
@@ -0,0 +1,58 @@
+//! JSON Schema of the TOML config files, for editor autocomplete and
+//! validation.
+//!
+//! The schema is generated from the same types the TOML loader deserializes,
+//! so it cannot drift from what is actually accepted. Nested types are
+//! placed under `$defs`.
+
+use super::toml_config::RawConfig;
+use serde_json::{Map, Value, json};
+use utoipa::openapi::{RefOr, schema::Schema};
+use utoipa::{PartialSchema, ToSchema};
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+const OPENAPI_REF_PREFIX: &str = "#/components/schemas/";
+const DEFS_REF_PREFIX: &str = "#/$defs/";
+
+/// JSON Schema describing `client.toml`, `model.toml`, and the combined
+/// `config.toml`. Every key is optional, as each file holds only part of
+/// the config.
+pub fn config_schema() -> Value {
+    let mut nested: Vec<(String, RefOr<Schema>)> = Vec::new();
+    RawConfig::schemas(&mut nested);
+
+    let mut defs = Map::new();
+    for (name, schema) in nested {
+        defs.insert(name, to_json(&schema));
+    }
+
+    let mut root = to_json(&RawConfig::schema());
+    if let Value::Object(root) = &mut root {
+        root.insert("$schema".to_string(), json!(DRAFT));
+        root.insert("title".to_string(), json!(RawConfig::name()));
+        root.insert("$defs".to_string(), Value::Object(defs));
+    }
+    root
+}
+
+fn to_json(schema: &RefOr<Schema>) -> Value {
+    let mut value = serde_json::to_value(schema).unwrap_or_default();
+    rewrite_refs(&mut value);
+    value
+}
+
+/// Point OpenAPI component references at `$defs`.
+fn rewrite_refs(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get_mut("$ref")
+                && let Some(name) = reference.strip_prefix(OPENAPI_REF_PREFIX)
+            {
+                *reference = format!("{DEFS_REF_PREFIX}{name}");
+            }
+            map.values_mut().for_each(rewrite_refs);
+        }
+        Value::Array(items) => items.iter_mut().for_each(rewrite_refs),
+        _ => {}
+    }
+}
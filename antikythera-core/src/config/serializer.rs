@@ -1,5 +1,8 @@
 use super::AppConfig;
 use super::tool::ToolConfig;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 /// Convert AppConfig to TOML string representation
 pub fn to_raw_toml_string(config: &AppConfig) -> String {
@@ -49,3 +52,33 @@ fn render_config_raw(
 
     raw
 }
+
+/// Replace `path` with `contents` without ever leaving a partly written file.
+///
+/// The bytes go to `<name>.tmp` next to `path`, are flushed to disk, and the
+/// temp file is then renamed over `path`. A crash before the rename leaves
+/// the previous file untouched; the stale temp file is overwritten by the
+/// next write. Same signature as [`std::fs::write`].
+pub fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref();
+    let temp = temp_path(path);
+    let written = File::create(&temp).and_then(|mut file| {
+        file.write_all(contents.as_ref())?;
+        file.sync_all()
+    });
+    match written.and_then(|()| fs::rename(&temp, path)) {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            let _ = fs::remove_file(&temp);
+            Err(err)
+        }
+    }
+}
+
+/// Sibling temp file of `path`; same directory so the rename stays on one
+/// file system.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
@@ -10,6 +10,13 @@
 //! name = "time"
 //! command = "python"
 //! args = ["-m", "mcp_server_time"]
+//! keepalive_interval_secs = 60  # optional, 0 disables the ping
+//! idle_timeout_secs = 600  # optional; stop the process after 10 idle minutes
+//! framing = "content_length"  # optional, default "line_delimited"
+//! max_inflight = 1  # optional; 1 for servers that handle one request at a time
+//! blocked_tools = ["convert_time"]  # optional; see also `allowed_tools`
+//! clear_env = true  # optional; the process then sees only `env`
+//! enabled = false  # optional; keeps the entry but never starts the server
 //! ```
 //!
 //! ## Example - HTTP Server
@@ -24,6 +31,7 @@ use serde::{Deserialize, Serialize};
 use shellexpand;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use utoipa::ToSchema;
 
 /// Transport type for MCP server connection.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -36,6 +44,17 @@ pub enum TransportType {
     Builtin,
 }
 
+/// How JSON-RPC messages are framed on a STDIO server's stdin/stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StdioFraming {
+    /// One JSON message per line (the MCP STDIO transport)
+    #[default]
+    LineDelimited,
+    /// `Content-Length: N` header, blank line, then N bytes (LSP-style)
+    ContentLength,
+}
+
 /// Configuration for an MCP server connection.
 ///
 /// MCP servers can be connected via STDIO (subprocess) or HTTP.
@@ -47,10 +66,17 @@ pub struct ServerConfig {
     pub transport: TransportType,
     /// Path to the executable (for STDIO)
     pub command: Option<PathBuf>,
-    /// Command line arguments (for STDIO)
+    /// Command line arguments (for STDIO). `${...}` placeholders such as
+    /// `${config_dir}`, `${home}`, or environment variables are expanded at
+    /// spawn time; see [`crate::application::tooling::Placeholders`].
     pub args: Vec<String>,
     /// Environment variables (for STDIO)
     pub env: HashMap<String, String>,
+    /// Start the STDIO process with an empty environment, so it sees only
+    /// `env` and none of the client's own variables (such as resolved API
+    /// keys). Off by default: `env` is added to the inherited environment.
+    #[serde(default)]
+    pub clear_env: bool,
     /// Working directory (for STDIO)
     pub workdir: Option<PathBuf>,
     /// URL for HTTP transport
@@ -61,6 +87,68 @@ pub struct ServerConfig {
     pub default_timezone: Option<String>,
     /// Default city for location-based operations
     pub default_city: Option<String>,
+    /// Seconds between keepalive `ping` requests to a running STDIO server
+    /// (0 = disabled). A failed ping resets the process so the next call
+    /// respawns it.
+    #[serde(default)]
+    pub keepalive_interval_secs: u64,
+    /// Seconds a running STDIO server may go without a call before its
+    /// process is stopped (0 = never). The next call respawns it.
+    #[serde(default)]
+    pub idle_timeout_secs: u64,
+    /// Message framing on stdin/stdout (for STDIO)
+    #[serde(default)]
+    pub framing: StdioFraming,
+    /// Most requests awaiting a response from a STDIO server at once
+    /// (0 = unlimited). Further requests wait before they are written, so
+    /// 1 keeps a server that cannot handle parallel requests strictly serial.
+    #[serde(default)]
+    pub max_inflight: usize,
+    /// Tools exposed from this server (empty = all tools), by the name the
+    /// server lists them under, which is a tool's `remote_name` when set.
+    #[serde(default)]
+    pub allowed_tools: Vec<String>,
+    /// Tools hidden from this server, named like `allowed_tools`; takes
+    /// precedence over it.
+    #[serde(default)]
+    pub blocked_tools: Vec<String>,
+    /// Whether the server is used at all. A disabled server keeps its
+    /// settings but is never started, its tools are left out of the agent's
+    /// tool list, and calls to it fail with
+    /// [`ToolInvokeError::Disabled`](crate::application::tooling::ToolInvokeError::Disabled).
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// An enabled STDIO server with no command and every option at its default,
+/// meant to be filled in with struct update syntax.
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            transport: TransportType::Stdio,
+            command: None,
+            args: Vec::new(),
+            env: HashMap::new(),
+            clear_env: false,
+            workdir: None,
+            url: None,
+            headers: HashMap::new(),
+            default_timezone: None,
+            default_city: None,
+            keepalive_interval_secs: 0,
+            idle_timeout_secs: 0,
+            framing: StdioFraming::default(),
+            max_inflight: 0,
+            allowed_tools: Vec::new(),
+            blocked_tools: Vec::new(),
+            enabled: default_enabled(),
+        }
+    }
 }
 
 impl ServerConfig {
@@ -88,9 +176,22 @@ impl ServerConfig {
     pub fn url(&self) -> Option<&str> {
         self.url.as_deref()
     }
+
+    /// Check whether `tool`, a name as the server lists it, passes this
+    /// server's allow/block lists.
+    pub fn allows_tool(&self, tool: &str) -> bool {
+        let listed = |list: &[String]| list.iter().any(|name| name.eq_ignore_ascii_case(tool));
+        if listed(&self.blocked_tools) {
+            return false;
+        }
+        self.allowed_tools.is_empty() || listed(&self.allowed_tools)
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// One `[[servers]]` entry: an MCP server started with `command` (STDIO) or
+/// reached at `url` (HTTP).
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[schema(as = Server)]
 pub struct RawServer {
     pub name: String,
     /// Command for STDIO transport (optional if url is provided)
@@ -99,6 +200,9 @@ pub struct RawServer {
     pub args: Vec<String>,
     #[serde(default)]
     pub env: HashMap<String, String>,
+    /// Start the STDIO process with only `env`, not the inherited environment
+    #[serde(default)]
+    pub clear_env: bool,
     pub workdir: Option<String>,
     /// URL for HTTP transport (optional if command is provided)
     pub url: Option<String>,
@@ -109,6 +213,27 @@ pub struct RawServer {
     pub default_timezone: Option<String>,
     #[serde(default)]
     pub default_city: Option<String>,
+    /// Keepalive ping interval in seconds (0 = disabled)
+    #[serde(default)]
+    pub keepalive_interval_secs: u64,
+    /// Stop an unused STDIO process after this many seconds (0 = never)
+    #[serde(default)]
+    pub idle_timeout_secs: u64,
+    /// Message framing for STDIO (`line_delimited` or `content_length`)
+    #[serde(default)]
+    pub framing: StdioFraming,
+    /// Concurrent requests to a STDIO server (0 = unlimited)
+    #[serde(default)]
+    pub max_inflight: usize,
+    /// Only these tools are exposed (empty = all)
+    #[serde(default)]
+    pub allowed_tools: Vec<String>,
+    /// These tools are never exposed
+    #[serde(default)]
+    pub blocked_tools: Vec<String>,
+    /// Set to false to keep the entry without starting the server
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
 }
 
 impl From<RawServer> for ServerConfig {
@@ -133,7 +258,13 @@ impl From<RawServer> for ServerConfig {
         };
 
         let workdir = raw.workdir.map(|d| PathBuf::from(expand(&d)));
-        let args = raw.args.into_iter().map(|arg| expand(&arg)).collect();
+        // Only `~` here: `$`-templates in args are expanded at spawn time by
+        // `Placeholders`, which knows the built-ins and the `$${` escape.
+        let args = raw
+            .args
+            .into_iter()
+            .map(|arg| shellexpand::tilde(&arg).into_owned())
+            .collect();
 
         Self {
             name: raw.name,
@@ -141,11 +272,19 @@ impl From<RawServer> for ServerConfig {
             command,
             args,
             env: raw.env,
+            clear_env: raw.clear_env,
             workdir,
             url,
             headers: raw.headers,
             default_timezone: raw.default_timezone,
             default_city: raw.default_city,
+            keepalive_interval_secs: raw.keepalive_interval_secs,
+            idle_timeout_secs: raw.idle_timeout_secs,
+            framing: raw.framing,
+            max_inflight: raw.max_inflight,
+            allowed_tools: raw.allowed_tools,
+            blocked_tools: raw.blocked_tools,
+            enabled: raw.enabled,
         }
     }
 }
@@ -0,0 +1,162 @@
+//! TOML configuration sources.
+//!
+//! Besides the Postcard blob, the runtime config can be read from TOML, either
+//! split across `client.toml` (`[server]`, `[[servers]]`, `tools`,
+//! `case_sensitive_tools`, `public_tools`, `terminal_tools`, `stdio_default_agent_mode`, `seed_history`, `session_ttl_secs`, `tool_cache_ttl_secs`, `max_prompt_chars`, `[agent]`, `[audit_log]`, `[config_history]`, `[session_title]`, `[reasoning]`) and `model.toml` (`default_provider`, `model`,
+//! `system_prompt`, `prompt_template`, `[prompts]`, `[provider_prompts.<id>]`), or with all of those
+//! sections in a single combined `config.toml`. `[[providers]]` entries are accepted and ignored
+//! here; provider definitions are a CLI concern.
+
+use super::app::{
+    AppConfig, AuditLogConfig, ConfigHistoryConfig, PromptsConfig, ReasoningConfig,
+    RestServerConfig, SessionTitleConfig,
+};
+use super::error::ConfigError;
+use super::server::RawServer;
+use super::tool::RawTool;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use utoipa::ToSchema;
+
+/// A TOML config file: `client.toml`, `model.toml`, or a combined
+/// `config.toml`. Every key is optional.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[schema(as = Config)]
+pub(crate) struct RawConfig {
+    /// Provider ID used when a request names none
+    default_provider: Option<String>,
+    /// Model used when a request names none
+    model: Option<String>,
+    /// Instruction placed in `{{custom_instruction}}` of the prompt template
+    system_prompt: Option<String>,
+    /// Shorthand for `prompts.template`
+    prompt_template: Option<String>,
+    /// Tools offered to the model
+    #[serde(default)]
+    tools: Vec<RawTool>,
+    /// MCP servers that provide the tools
+    #[serde(default)]
+    servers: Vec<RawServer>,
+    /// Match tool names from the model with exact case
+    case_sensitive_tools: Option<bool>,
+    /// Tools shown in tool listings; unset lists every tool
+    public_tools: Option<Vec<String>>,
+    /// Tools whose successful result ends an agent run
+    #[serde(default)]
+    terminal_tools: Vec<String>,
+    /// Start interactive STDIO sessions in agent mode (default true)
+    stdio_default_agent_mode: Option<bool>,
+    /// JSON file of messages every new session starts with
+    #[schema(value_type = Option<String>)]
+    seed_history: Option<PathBuf>,
+    /// Seconds an idle session is kept in memory; unset keeps it
+    session_ttl_secs: Option<u64>,
+    /// Seconds a `cacheable` tool's result is reused
+    tool_cache_ttl_secs: Option<u64>,
+    /// Longest user prompt accepted, in characters; unset accepts any
+    max_prompt_chars: Option<usize>,
+    /// Agent run defaults
+    agent: Option<RawAgent>,
+    /// REST server settings
+    server: Option<RestServerConfig>,
+    prompts: Option<PromptsConfig>,
+    /// Prompt overrides per provider ID; unset keys come from `prompts`
+    #[serde(default)]
+    provider_prompts: HashMap<String, PromptsConfig>,
+    audit_log: Option<AuditLogConfig>,
+    config_history: Option<ConfigHistoryConfig>,
+    session_title: Option<SessionTitleConfig>,
+    reasoning: Option<ReasoningConfig>,
+}
+
+/// The `[agent]` section.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub(crate) struct RawAgent {
+    /// Step budget of a run that sets none; unset uses 8
+    max_steps: Option<usize>,
+}
+
+impl RawConfig {
+    /// Read and parse one TOML file.
+    pub(crate) fn read(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Err(ConfigError::NotFound {
+                path: path.to_path_buf(),
+            });
+        }
+        let text = std::fs::read_to_string(path).map_err(|e| ConfigError::Io {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+        toml::from_str(&text).map_err(|e| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    }
+
+    /// Merge a second file into this one. Scalars already set here win;
+    /// list sections are appended.
+    pub(crate) fn merge(mut self, other: RawConfig) -> Self {
+        self.default_provider = self.default_provider.or(other.default_provider);
+        self.model = self.model.or(other.model);
+        self.system_prompt = self.system_prompt.or(other.system_prompt);
+        self.prompt_template = self.prompt_template.or(other.prompt_template);
+        self.tools.extend(other.tools);
+        self.servers.extend(other.servers);
+        self.case_sensitive_tools = self.case_sensitive_tools.or(other.case_sensitive_tools);
+        self.public_tools = self.public_tools.or(other.public_tools);
+        self.terminal_tools.extend(other.terminal_tools);
+        self.stdio_default_agent_mode = self
+            .stdio_default_agent_mode
+            .or(other.stdio_default_agent_mode);
+        self.seed_history = self.seed_history.or(other.seed_history);
+        self.session_ttl_secs = self.session_ttl_secs.or(other.session_ttl_secs);
+        self.tool_cache_ttl_secs = self.tool_cache_ttl_secs.or(other.tool_cache_ttl_secs);
+        self.max_prompt_chars = self.max_prompt_chars.or(other.max_prompt_chars);
+        self.agent = self.agent.or(other.agent);
+        self.server = self.server.or(other.server);
+        self.prompts = self.prompts.or(other.prompts);
+        for (provider, prompts) in other.provider_prompts {
+            self.provider_prompts.entry(provider).or_insert(prompts);
+        }
+        self.audit_log = self.audit_log.or(other.audit_log);
+        self.config_history = self.config_history.or(other.config_history);
+        self.session_title = self.session_title.or(other.session_title);
+        self.reasoning = self.reasoning.or(other.reasoning);
+        self
+    }
+
+    pub(crate) fn into_app_config(self) -> AppConfig {
+        let defaults = AppConfig::default();
+        let mut prompts = self.prompts.unwrap_or_default();
+        if self.prompt_template.is_some() {
+            prompts.template = self.prompt_template;
+        }
+        AppConfig {
+            default_provider: self.default_provider.unwrap_or(defaults.default_provider),
+            model: self.model.unwrap_or(defaults.model),
+            system_prompt: self.system_prompt,
+            tools: self.tools.into_iter().map(Into::into).collect(),
+            servers: self.servers.into_iter().map(Into::into).collect(),
+            rest_server: self.server.unwrap_or_default(),
+            prompts,
+            provider_prompts: self.provider_prompts,
+            audit_log: self.audit_log,
+            config_history: self.config_history,
+            case_sensitive_tools: self.case_sensitive_tools.unwrap_or(false),
+            public_tools: self.public_tools,
+            terminal_tools: self.terminal_tools,
+            session_title: self.session_title.unwrap_or_default(),
+            reasoning: self.reasoning.unwrap_or_default(),
+            stdio_default_agent_mode: self
+                .stdio_default_agent_mode
+                .unwrap_or(defaults.stdio_default_agent_mode),
+            seed_history: self.seed_history,
+            session_ttl_secs: self.session_ttl_secs,
+            tool_cache_ttl_secs: self.tool_cache_ttl_secs,
+            max_prompt_chars: self.max_prompt_chars,
+            agent_max_steps: self.agent.and_then(|agent| agent.max_steps),
+        }
+    }
+}
@@ -10,9 +10,12 @@
 //! name = "get_current_time"
 //! description = "Get the current time in a timezone"
 //! server = "time"
+//! remote_name = "GetCurrentTime"  # optional, when the server's id differs
+//! cacheable = true                 # optional, reuse results of identical calls
 //! ```
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Configuration for an available tool.
 ///
@@ -27,10 +30,26 @@ pub struct ToolConfig {
     /// Name of the MCP server that provides this tool
     #[serde(default)]
     pub server: Option<String>,
+    /// Name the server expects in `tools/call`, when it differs from `name`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_name: Option<String>,
+    /// The tool is deterministic and side-effect free, so agents may reuse
+    /// the result of an earlier call with the same input
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub cacheable: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl ToolConfig {
+    /// Name to send in `tools/call`: `remote_name`, or `name` when unset.
+    pub fn remote_name(&self) -> &str {
+        self.remote_name.as_deref().unwrap_or(&self.name)
+    }
+}
+
+/// A `tools` entry: a tool name, or a table with its description and server.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 #[serde(untagged)]
+#[schema(as = Tool)]
 pub(crate) enum RawTool {
     Name(String),
     Detailed {
@@ -38,6 +57,10 @@ pub(crate) enum RawTool {
         description: Option<String>,
         #[serde(default)]
         server: Option<String>,
+        #[serde(default)]
+        remote_name: Option<String>,
+        #[serde(default)]
+        cacheable: bool,
     },
 }
 
@@ -48,15 +71,21 @@ impl From<RawTool> for ToolConfig {
                 name,
                 description: None,
                 server: None,
+                remote_name: None,
+                cacheable: false,
             },
             RawTool::Detailed {
                 name,
                 description,
                 server,
+                remote_name,
+                cacheable,
             } => Self {
                 name,
                 description,
                 server,
+                remote_name,
+                cacheable,
             },
         }
     }
@@ -0,0 +1,137 @@
+//! Sanity checks of a loaded [`AppConfig`], as printed by the STDIO
+//! `/config validate` command.
+//!
+//! Loading already rejects files that don't parse; these checks catch
+//! settings that parse but point at nothing: an unknown default provider or
+//! model, tools bound to servers that are not configured, or a bind address
+//! that is not `host:port`.
+
+use super::app::AppConfig;
+use super::postcard_config::ProviderConfig;
+use std::net::SocketAddr;
+
+/// One named check and, when it failed, why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigCheck {
+    pub label: String,
+    pub problem: Option<String>,
+}
+
+impl ConfigCheck {
+    fn passed(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            problem: None,
+        }
+    }
+
+    fn failed(label: impl Into<String>, problem: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            problem: Some(problem.into()),
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.problem.is_none()
+    }
+}
+
+/// Result of [`AppConfig::validate`], in check order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigReport {
+    pub checks: Vec<ConfigCheck>,
+}
+
+impl ConfigReport {
+    /// Whether every check passed.
+    pub fn is_valid(&self) -> bool {
+        self.checks.iter().all(ConfigCheck::is_ok)
+    }
+
+    /// Checks that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &ConfigCheck> {
+        self.checks.iter().filter(|check| !check.is_ok())
+    }
+
+    /// One checklist line per check: `[ok] label` or `[!!] label: problem`.
+    pub fn render(&self) -> String {
+        self.checks
+            .iter()
+            .map(|check| match &check.problem {
+                None => format!("[ok] {}", check.label),
+                Some(problem) => format!("[!!] {}: {}", check.label, problem),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl AppConfig {
+    /// Check the settings that loading accepts but that may not resolve.
+    ///
+    /// `providers` are the provider definitions of `app.pc`, since the
+    /// runtime config only names the default provider. A provider without
+    /// a model list accepts any model.
+    pub fn validate(&self, providers: &[ProviderConfig]) -> ConfigReport {
+        let mut checks = Vec::new();
+
+        let provider = providers
+            .iter()
+            .find(|provider| provider.id == self.default_provider);
+        let provider_label = format!("default provider '{}' exists", self.default_provider);
+        checks.push(match provider {
+            Some(_) => ConfigCheck::passed(provider_label),
+            None if providers.is_empty() => {
+                ConfigCheck::failed(provider_label, "no providers are configured")
+            }
+            None => {
+                let known: Vec<&str> = providers.iter().map(|p| p.id.as_str()).collect();
+                ConfigCheck::failed(provider_label, format!("known: {}", known.join(", ")))
+            }
+        });
+
+        let model_label = format!("default model '{}' is available", self.model);
+        checks.push(match provider {
+            Some(provider)
+                if !provider.models.is_empty()
+                    && !provider.models.iter().any(|m| m.name == self.model) =>
+            {
+                ConfigCheck::failed(
+                    model_label,
+                    format!("provider '{}' does not list it", provider.id),
+                )
+            }
+            Some(_) => ConfigCheck::passed(model_label),
+            None => ConfigCheck::failed(model_label, "its provider is not configured"),
+        });
+
+        let unbound: Vec<String> = self
+            .tools
+            .iter()
+            .filter_map(|tool| {
+                let server = tool.server.as_deref()?;
+                (!self.servers.iter().any(|s| s.name == server))
+                    .then(|| format!("{} -> {}", tool.name, server))
+            })
+            .collect();
+        let tools_label = "tool server bindings resolve";
+        checks.push(if unbound.is_empty() {
+            ConfigCheck::passed(tools_label)
+        } else {
+            ConfigCheck::failed(
+                tools_label,
+                format!("unknown servers for {}", unbound.join(", ")),
+            )
+        });
+
+        let bind = &self.rest_server.bind;
+        let bind_label = format!("bind address '{bind}' parses");
+        checks.push(match bind.parse::<SocketAddr>() {
+            Ok(_) => ConfigCheck::passed(bind_label),
+            Err(err) => ConfigCheck::failed(bind_label, err.to_string()),
+        });
+
+        ConfigReport { checks }
+    }
+}
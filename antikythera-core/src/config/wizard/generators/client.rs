@@ -1,18 +1,20 @@
 //! Client configuration generator
 //!
-//! Generates and modifies client.toml containing:
+//! Generates and modifies `client.toml` (in the [`WizardPaths`] config
+//! directory) containing:
 //! - `[[providers]]` - API provider configurations
 //! - `[[servers]]` - MCP server definitions
 //! - `[server]` - REST settings (CORS, docs)
 
-use crate::constants::CONFIG_PATH;
+use super::WizardPaths;
+use crate::config::serializer::write_atomic;
 use crate::logging::ConfigLogger;
 use std::error::Error;
 use std::fs;
-use std::path::Path;
 
 /// Generate the client configuration file
 pub fn generate(
+    paths: &WizardPaths,
     provider_id: &str,
     provider_type: &str,
     endpoint: &str,
@@ -62,33 +64,40 @@ models = [
         models_toml = models_toml,
     );
 
-    fs::create_dir_all("config").map_err(|e| {
+    let config_path = paths.client_toml();
+    fs::create_dir_all(&paths.config_dir).map_err(|e| {
         log.error(format!(
-            "Failed to create config directory | path=config error={}",
+            "Failed to create config directory | path={} error={}",
+            paths.config_dir.display(),
             e
         ));
         e
     })?;
-    log.info(format!("Writing config | path={}", CONFIG_PATH));
-    fs::write(CONFIG_PATH, config_content).map_err(|e| {
+    log.info(format!("Writing config | path={}", config_path.display()));
+    write_atomic(&config_path, config_content).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
-            CONFIG_PATH, e
+            config_path.display(),
+            e
         ));
         e
     })?;
 
     log.info(format!(
         "Config generated successfully | path={}",
-        CONFIG_PATH
+        config_path.display()
     ));
     Ok(())
 }
 
 /// Generate the .env file with API keys
-pub fn generate_env(api_key_env: &str, api_key: &str) -> Result<(), Box<dyn Error>> {
+pub fn generate_env(
+    paths: &WizardPaths,
+    api_key_env: &str,
+    api_key: &str,
+) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let env_path = Path::new(".env");
+    let env_path = &paths.env_file();
 
     let content = if env_path.exists() {
         log.info(format!(
@@ -128,7 +137,7 @@ pub fn generate_env(api_key_env: &str, api_key: &str) -> Result<(), Box<dyn Erro
     };
 
     log.info(format!("Writing .env | path={}", env_path.display()));
-    fs::write(env_path, content).map_err(|e| {
+    write_atomic(env_path, content).map_err(|e| {
         log.error(format!(
             "Failed to write .env | path={} error={}",
             env_path.display(),
@@ -142,13 +151,14 @@ pub fn generate_env(api_key_env: &str, api_key: &str) -> Result<(), Box<dyn Erro
 
 /// Add a new provider to the config
 pub fn add_provider(
+    paths: &WizardPaths,
     provider_id: &str,
     provider_type: &str,
     endpoint: &str,
     api_key_env: Option<&str>,
 ) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     log.info(format!("Reading config | path={}", config_path.display()));
     let content = fs::read_to_string(config_path).map_err(|e| {
@@ -178,7 +188,7 @@ endpoint = "{}""#,
         provider_id,
         config_path.display()
     ));
-    fs::write(config_path, updated).map_err(|e| {
+    write_atomic(config_path, updated).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
             config_path.display(),
@@ -192,12 +202,13 @@ endpoint = "{}""#,
 
 /// Update provider settings in config
 pub fn update_provider(
+    paths: &WizardPaths,
     provider_id: &str,
     new_endpoint: &str,
     new_api_key_env: &str,
 ) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     log.info(format!("Reading config | path={}", config_path.display()));
     let content = fs::read_to_string(config_path).map_err(|e| {
@@ -234,7 +245,7 @@ pub fn update_provider(
         provider_id,
         config_path.display()
     ));
-    fs::write(config_path, lines.join("\n")).map_err(|e| {
+    write_atomic(config_path, lines.join("\n")).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
             config_path.display(),
@@ -247,12 +258,13 @@ pub fn update_provider(
 
 /// Add a model to a provider
 pub fn add_model_to_provider(
+    paths: &WizardPaths,
     provider_id: &str,
     model_name: &str,
     display_name: &str,
 ) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     log.info(format!("Reading config | path={}", config_path.display()));
     let content = fs::read_to_string(config_path).map_err(|e| {
@@ -299,7 +311,7 @@ pub fn add_model_to_provider(
         provider_id,
         config_path.display()
     ));
-    fs::write(config_path, result).map_err(|e| {
+    write_atomic(config_path, result).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
             config_path.display(),
@@ -312,11 +324,12 @@ pub fn add_model_to_provider(
 
 /// Remove a model from a provider
 pub fn remove_model_from_provider(
+    paths: &WizardPaths,
     provider_id: &str,
     model_name: &str,
 ) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     log.info(format!("Reading config | path={}", config_path.display()));
     let content = fs::read_to_string(config_path).map_err(|e| {
@@ -360,7 +373,7 @@ pub fn remove_model_from_provider(
         provider_id,
         config_path.display()
     ));
-    fs::write(config_path, result).map_err(|e| {
+    write_atomic(config_path, result).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
             config_path.display(),
@@ -372,9 +385,14 @@ pub fn remove_model_from_provider(
 }
 
 /// Add a server to the config
-pub fn add_server(name: &str, command: &str, args: &[String]) -> Result<(), Box<dyn Error>> {
+pub fn add_server(
+    paths: &WizardPaths,
+    name: &str,
+    command: &str,
+    args: &[String],
+) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     if !config_path.exists() {
         log.error(format!(
@@ -421,7 +439,7 @@ command = "{}"{}"#,
         name,
         config_path.display()
     ));
-    fs::write(config_path, content).map_err(|e| {
+    write_atomic(config_path, content).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
             config_path.display(),
@@ -435,12 +453,13 @@ command = "{}"{}"#,
 
 /// Add an HTTP server to the config
 pub fn add_http_server(
+    paths: &WizardPaths,
     name: &str,
     url: &str,
     headers: &std::collections::HashMap<String, String>,
 ) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     if !config_path.exists() {
         log.error(format!(
@@ -485,7 +504,7 @@ url = "{}"{}"#,
         name,
         config_path.display()
     ));
-    fs::write(config_path, content).map_err(|e| {
+    write_atomic(config_path, content).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
             config_path.display(),
@@ -498,9 +517,9 @@ url = "{}"{}"#,
 }
 
 /// Remove a server from the config
-pub fn remove_server(server_name: &str) -> Result<(), Box<dyn Error>> {
+pub fn remove_server(paths: &WizardPaths, server_name: &str) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     log.info(format!("Reading config | path={}", config_path.display()));
     let content = fs::read_to_string(config_path).map_err(|e| {
@@ -547,7 +566,7 @@ pub fn remove_server(server_name: &str) -> Result<(), Box<dyn Error>> {
         server_name,
         config_path.display()
     ));
-    fs::write(config_path, result.trim_end()).map_err(|e| {
+    write_atomic(config_path, result.trim_end()).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
             config_path.display(),
@@ -559,9 +578,9 @@ pub fn remove_server(server_name: &str) -> Result<(), Box<dyn Error>> {
 }
 
 /// Get current CORS origins from config
-pub fn get_cors_origins() -> Result<Vec<String>, Box<dyn Error>> {
+pub fn get_cors_origins(paths: &WizardPaths) -> Result<Vec<String>, Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     log.info(format!("Reading config | path={}", config_path.display()));
     let content = fs::read_to_string(config_path).map_err(|e| {
@@ -617,9 +636,9 @@ pub fn get_cors_origins() -> Result<Vec<String>, Box<dyn Error>> {
 }
 
 /// Add a CORS origin to the config
-pub fn add_cors_origin(origin: &str) -> Result<(), Box<dyn Error>> {
+pub fn add_cors_origin(paths: &WizardPaths, origin: &str) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     log.info(format!("Reading config | path={}", config_path.display()));
     let content = fs::read_to_string(config_path).map_err(|e| {
@@ -632,7 +651,7 @@ pub fn add_cors_origin(origin: &str) -> Result<(), Box<dyn Error>> {
     })?;
 
     // Check if origin already exists
-    let existing = get_cors_origins()?;
+    let existing = get_cors_origins(paths)?;
     if existing.iter().any(|o| o == origin) {
         log.error(format!("Origin already exists | origin={}", origin));
         return Err(format!("Origin '{}' already exists", origin).into());
@@ -696,7 +715,7 @@ pub fn add_cors_origin(origin: &str) -> Result<(), Box<dyn Error>> {
             origin,
             config_path.display()
         ));
-        fs::write(config_path, final_result.trim_end()).map_err(|e| {
+        write_atomic(config_path, final_result.trim_end()).map_err(|e| {
             log.error(format!(
                 "Failed to write config | path={} error={}",
                 config_path.display(),
@@ -710,7 +729,7 @@ pub fn add_cors_origin(origin: &str) -> Result<(), Box<dyn Error>> {
             origin,
             config_path.display()
         ));
-        fs::write(config_path, result.trim_end()).map_err(|e| {
+        write_atomic(config_path, result.trim_end()).map_err(|e| {
             log.error(format!(
                 "Failed to write config | path={} error={}",
                 config_path.display(),
@@ -724,9 +743,9 @@ pub fn add_cors_origin(origin: &str) -> Result<(), Box<dyn Error>> {
 }
 
 /// Remove a CORS origin from the config
-pub fn remove_cors_origin(origin: &str) -> Result<(), Box<dyn Error>> {
+pub fn remove_cors_origin(paths: &WizardPaths, origin: &str) -> Result<(), Box<dyn Error>> {
     let log = ConfigLogger::new("config");
-    let config_path = Path::new(CONFIG_PATH);
+    let config_path = &paths.client_toml();
 
     log.info(format!("Reading config | path={}", config_path.display()));
     let content = fs::read_to_string(config_path).map_err(|e| {
@@ -782,7 +801,7 @@ pub fn remove_cors_origin(origin: &str) -> Result<(), Box<dyn Error>> {
         origin,
         config_path.display()
     ));
-    fs::write(config_path, result.trim_end()).map_err(|e| {
+    write_atomic(config_path, result.trim_end()).map_err(|e| {
         log.error(format!(
             "Failed to write config | path={} error={}",
             config_path.display(),
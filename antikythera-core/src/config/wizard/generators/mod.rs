@@ -1,3 +1,58 @@
 //! Configuration generators
 
 pub mod client;
+
+use crate::config::loader::{ConfigSource, detect_config_source_in};
+use crate::constants::{CLIENT_TOML_FILE, CONFIG_PATH, ENV_PATH, SPLIT_TOML_DIR};
+use std::path::{Path, PathBuf};
+
+/// Where the generators write, and where loads of their output look.
+///
+/// The default keeps the usual layout: `config/client.toml`, with `.env`
+/// and `app.pc` in the working directory. [`WizardPaths::in_dir`] puts all
+/// three in one directory, e.g. one per profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WizardPaths {
+    /// Directory holding `client.toml`.
+    pub config_dir: PathBuf,
+    /// Directory holding `.env` and `app.pc`.
+    pub root: PathBuf,
+}
+
+impl Default for WizardPaths {
+    fn default() -> Self {
+        Self {
+            config_dir: PathBuf::from(SPLIT_TOML_DIR),
+            root: PathBuf::from("."),
+        }
+    }
+}
+
+impl WizardPaths {
+    /// Keep every generated file in `dir`.
+    pub fn in_dir(dir: impl AsRef<Path>) -> Self {
+        let dir = dir.as_ref().to_path_buf();
+        Self {
+            config_dir: dir.clone(),
+            root: dir,
+        }
+    }
+
+    pub fn client_toml(&self) -> PathBuf {
+        self.config_dir.join(CLIENT_TOML_FILE)
+    }
+
+    pub fn env_file(&self) -> PathBuf {
+        self.root.join(ENV_PATH)
+    }
+
+    pub fn postcard(&self) -> PathBuf {
+        self.root.join(CONFIG_PATH)
+    }
+
+    /// The source a load of this layout reads, with the loader's usual
+    /// precedence.
+    pub fn config_source(&self) -> ConfigSource {
+        detect_config_source_in(&self.config_dir, &self.postcard())
+    }
+}
@@ -7,11 +7,12 @@ pub mod prompts;
 pub mod ui;
 
 use crate::config::postcard_config;
-use generators::client;
+use generators::{WizardPaths, client};
 use std::error::Error;
 
-/// Run the setup menu (accessible from mode selector)
-pub async fn run_setup_menu() -> Result<bool, Box<dyn Error>> {
+/// Run the setup menu (accessible from mode selector), reading and writing
+/// the files named by `paths`.
+pub async fn run_setup_menu(paths: &WizardPaths) -> Result<bool, Box<dyn Error>> {
     loop {
         ui::print_header("Setup Menu");
         antikythera_log::cli_print!("  [1] Manage Providers");
@@ -22,18 +23,19 @@ pub async fn run_setup_menu() -> Result<bool, Box<dyn Error>> {
 
         match choice.as_str() {
             "0" => return Ok(true),
-            "1" => manage_providers().await?,
-            "2" => edit_prompt_template().await?,
+            "1" => manage_providers(paths).await?,
+            "2" => edit_prompt_template(paths).await?,
             _ => ui::print_error("Invalid option"),
         }
     }
 }
 
-async fn manage_providers() -> Result<(), Box<dyn Error>> {
+async fn manage_providers(paths: &WizardPaths) -> Result<(), Box<dyn Error>> {
     ui::print_header("Manage Providers");
 
-    let config =
-        postcard_config::load_config(None).map_err(|e| format!("Failed to load config: {}", e))?;
+    let postcard = paths.postcard();
+    let config = postcard_config::load_config(Some(&postcard))
+        .map_err(|e| format!("Failed to load config: {}", e))?;
 
     if config.providers.is_empty() {
         ui::print_warning("No providers configured.");
@@ -84,7 +86,7 @@ async fn manage_providers() -> Result<(), Box<dyn Error>> {
             let current_api_key = &provider.api_key;
             let new_api_key_env = prompts::prompt_text("API Key env var", Some(current_api_key))?;
 
-            client::update_provider(&provider.id, &new_endpoint, &new_api_key_env)?;
+            client::update_provider(paths, &provider.id, &new_endpoint, &new_api_key_env)?;
             ui::print_success("Provider updated!");
         }
         "2" => {
@@ -95,7 +97,7 @@ async fn manage_providers() -> Result<(), Box<dyn Error>> {
                     let provider_id = &config.providers[n - 1].id;
                     let mut cfg = config.clone();
                     cfg.model.default_provider = provider_id.clone();
-                    postcard_config::save_config(&cfg, None)?;
+                    postcard_config::save_config(&cfg, Some(&postcard))?;
                     ui::print_success(&format!("Default provider set to '{}'!", provider_id));
                 }
                 _ => ui::print_error("Invalid selection"),
@@ -107,11 +109,12 @@ async fn manage_providers() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-async fn edit_prompt_template() -> Result<(), Box<dyn Error>> {
+async fn edit_prompt_template(paths: &WizardPaths) -> Result<(), Box<dyn Error>> {
     ui::print_header("Manage Prompt Template");
 
-    let config =
-        postcard_config::load_config(None).map_err(|e| format!("Failed to load config: {}", e))?;
+    let postcard = paths.postcard();
+    let config = postcard_config::load_config(Some(&postcard))
+        .map_err(|e| format!("Failed to load config: {}", e))?;
 
     ui::print_info("Current prompt template:");
     ui::print_divider();
@@ -136,7 +139,7 @@ async fn edit_prompt_template() -> Result<(), Box<dyn Error>> {
             let default_template = postcard_config::PromptsConfig::default_template();
             let mut cfg = config.clone();
             cfg.prompts.template = default_template.to_string();
-            postcard_config::save_config(&cfg, None)?;
+            postcard_config::save_config(&cfg, Some(&postcard))?;
             ui::print_success("Prompt template reset to default!");
         }
         "2" => {
@@ -153,7 +156,7 @@ async fn edit_prompt_template() -> Result<(), Box<dyn Error>> {
                 let template = lines.join("\n");
                 let mut cfg = config.clone();
                 cfg.prompts.template = template;
-                postcard_config::save_config(&cfg, None)?;
+                postcard_config::save_config(&cfg, Some(&postcard))?;
                 ui::print_success("Prompt template updated!");
             }
         }
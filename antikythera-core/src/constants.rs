@@ -5,6 +5,21 @@
 /// Unified Postcard configuration file path (project root)
 pub const CONFIG_PATH: &str = "app.pc";
 
+/// Combined TOML configuration file (client and model sections in one file)
+pub const COMBINED_TOML_PATH: &str = "config/config.toml";
+
+/// File name of the combined TOML configuration inside a config directory
+pub const COMBINED_TOML_FILE: &str = "config.toml";
+
+/// Directory holding the split TOML configuration files
+pub const SPLIT_TOML_DIR: &str = "config";
+
+/// Split TOML file with `[server]`, `[[servers]]` and `tools`
+pub const CLIENT_TOML_FILE: &str = "client.toml";
+
+/// Split TOML file with provider/model routing and prompts
+pub const MODEL_TOML_FILE: &str = "model.toml";
+
 /// Environment file path (project root)
 pub const ENV_PATH: &str = ".env";
 
@@ -13,3 +28,12 @@ pub const ENV_EXAMPLE_PATH: &str = ".env.example";
 
 /// Default Gemini API path (fallback when not specified in config)
 pub const DEFAULT_GEMINI_API_PATH: &str = "v1beta/models";
+
+/// Characters of a message shown in one-line STDIO previews
+pub const PREVIEW_MAX_CHARS: usize = 120;
+
+/// Characters of a message kept in session summary snippets
+pub const SNIPPET_MAX_CHARS: usize = 160;
+
+/// Characters of a tool description logged during server discovery
+pub const TOOL_DESCRIPTION_PREVIEW_CHARS: usize = 50;
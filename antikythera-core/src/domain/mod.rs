@@ -1,4 +1,5 @@
 pub mod content;
 pub mod entities;
 pub mod sanitize;
+pub mod text;
 pub mod types;
@@ -0,0 +1,13 @@
+//! Character-safe text previews.
+
+/// `text` cut to at most `max_chars` characters, with `ellipsis` appended
+/// when anything was cut.
+///
+/// Counts `char`s rather than bytes, so multi-byte text such as accented
+/// letters or emoji is never split inside a character.
+pub fn preview(text: &str, max_chars: usize, ellipsis: &str) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}{ellipsis}", &text[..end]),
+        None => text.to_string(),
+    }
+}
@@ -1,24 +1,36 @@
 pub use antikythera_session::{MessagePart, MessageRole};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ChatMessage {
     pub role: MessageRole,
     pub parts: Vec<MessagePart>,
+    /// When the message was created. Payloads written before this field
+    /// existed load as the Unix epoch, so they sort ahead of newer messages.
+    #[serde(default)]
+    pub created_at: DateTime<Utc>,
 }
 
 impl ChatMessage {
     /// Create a new text-only message (backwards compatible)
     pub fn new(role: MessageRole, content: impl Into<String>) -> Self {
+        Self::with_parts(role, vec![MessagePart::text(content)])
+    }
+
+    /// Create a message with multiple parts
+    pub fn with_parts(role: MessageRole, parts: Vec<MessagePart>) -> Self {
         Self {
             role,
-            parts: vec![MessagePart::text(content)],
+            parts,
+            created_at: Utc::now(),
         }
     }
 
-    /// Create a message with multiple parts
-    pub fn with_parts(role: MessageRole, parts: Vec<MessagePart>) -> Self {
-        Self { role, parts }
+    /// Replace the creation time, e.g. when restoring a stored message.
+    pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = created_at;
+        self
     }
 
     /// Get the text content of the message (concatenated from all text parts)
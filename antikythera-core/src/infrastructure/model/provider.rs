@@ -24,6 +24,8 @@ use super::types::{ModelError, ModelRequest, ModelResponse};
 /// Runtime container for a provider backend
 struct ProviderRuntime {
     models: HashSet<String>,
+    /// Model used for requests naming a model this backend does not serve.
+    default_model: Option<String>,
     client: Box<dyn ModelClient>,
 }
 
@@ -67,12 +69,24 @@ impl DynamicModelProvider {
     ) -> Self {
         let runtime = ProviderRuntime {
             models: models.into_iter().collect(),
+            default_model: None,
             client,
         };
         self.backends.insert(id.into(), runtime);
         self
     }
 
+    /// Send requests for `provider` to `model` when they name a model the
+    /// backend does not serve, e.g. another provider's model left selected
+    /// after switching providers. Without it such requests fail with
+    /// [`ModelError::ModelNotFound`]. Ignored for unregistered providers.
+    pub fn with_default_model(mut self, provider: &str, model: impl Into<String>) -> Self {
+        if let Some(runtime) = self.backends.get_mut(provider) {
+            runtime.default_model = Some(model.into());
+        }
+        self
+    }
+
     /// Check if a backend for the given provider ID is registered.
     pub fn contains(&self, provider: &str) -> bool {
         self.backends.contains_key(provider)
@@ -83,6 +97,30 @@ impl DynamicModelProvider {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl ModelProvider for DynamicModelProvider {
     async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        let (runtime, request) = self.route(request)?;
+        runtime.client.chat(request).await
+    }
+
+    async fn chat_stream(
+        &self,
+        request: ModelRequest,
+        on_chunk: &(dyn for<'c> Fn(&'c str) + Send + Sync),
+    ) -> Result<ModelResponse, ModelError> {
+        let (runtime, request) = self.route(request)?;
+        runtime.client.chat_stream(request, on_chunk).await
+    }
+}
+
+impl DynamicModelProvider {
+    /// Backend registered for the request's provider, with the request's
+    /// model replaced by the backend's default when it does not serve it.
+    fn route(
+        &self,
+        mut request: ModelRequest,
+    ) -> Result<(&ProviderRuntime, ModelRequest), ModelError> {
+        if self.backends.is_empty() {
+            return Err(ModelError::NoProviders);
+        }
         let provider_id = &request.provider;
 
         let runtime = self
@@ -91,9 +129,12 @@ impl ModelProvider for DynamicModelProvider {
             .ok_or_else(|| ModelError::provider_not_found(provider_id))?;
 
         if !runtime.supports(&request.model) {
-            return Err(ModelError::model_not_found(provider_id, &request.model));
+            match &runtime.default_model {
+                Some(model) => request.model = model.clone(),
+                None => return Err(ModelError::model_not_found(provider_id, &request.model)),
+            }
         }
 
-        runtime.client.chat(request).await
+        Ok((runtime, request))
     }
 }
@@ -9,6 +9,24 @@ use async_trait::async_trait;
 pub trait ModelProvider: Send + Sync {
     /// Send a chat request to the model provider
     async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError>;
+
+    /// Send a chat request, passing the reply to `on_chunk` piece by piece
+    /// as it arrives. The returned response holds the whole reply.
+    ///
+    /// Providers without incremental delivery keep this default, which
+    /// calls [`chat`](Self::chat) and passes the reply as a single chunk.
+    /// The callback spells out `for<'c>` so `async_trait` keeps it
+    /// higher-ranked instead of tying chunks to the call's lifetime.
+    async fn chat_stream(
+        &self,
+        request: ModelRequest,
+        on_chunk: &(dyn for<'c> Fn(&'c str) + Send + Sync),
+    ) -> Result<ModelResponse, ModelError> {
+        let response = self.chat(request).await?;
+        let content = response.message.content();
+        on_chunk(&content);
+        Ok(response)
+    }
 }
 
 /// Trait for individual model clients
@@ -20,4 +38,17 @@ pub trait ModelClient: Send + Sync {
 
     /// Send a chat request
     async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError>;
+
+    /// Send a chat request, passing the reply to `on_chunk` as it arrives.
+    /// Defaults to [`chat`](Self::chat) with the reply as a single chunk.
+    async fn chat_stream(
+        &self,
+        request: ModelRequest,
+        on_chunk: &(dyn for<'c> Fn(&'c str) + Send + Sync),
+    ) -> Result<ModelResponse, ModelError> {
+        let response = self.chat(request).await?;
+        let content = response.message.content();
+        on_chunk(&content);
+        Ok(response)
+    }
 }
@@ -6,6 +6,7 @@
 //! to strings by the provider implementation layer (CLI or SDK) before
 //! constructing this error.
 
+use crate::application::locale::{Locale, current_locale, fill};
 use crate::domain::types::{ChatMessage, MessageRole};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -70,6 +71,8 @@ impl ModelResponse {
 /// Model errors
 #[derive(Debug, Error)]
 pub enum ModelError {
+    #[error("no model providers configured; run setup")]
+    NoProviders,
     #[error("provider '{provider}' is not configured")]
     ProviderNotFound { provider: String },
     #[error("model '{model}' is not available for provider '{provider}'")]
@@ -81,6 +84,14 @@ pub enum ModelError {
     /// referenced in core's public API surface.
     #[error("network error calling provider '{provider}': {message}")]
     Network { provider: String, message: String },
+    /// HTTP 429 that outlasted the provider's retries.
+    #[error("provider '{provider}' is rate limiting requests (HTTP 429) after {retries} retries")]
+    RateLimited {
+        provider: String,
+        retries: u32,
+        /// Wait the provider asked for in its last `Retry-After`, if any.
+        retry_after_secs: Option<u64>,
+    },
     #[error("provider '{provider}' returned invalid response: {reason}")]
     InvalidResponse { provider: String, reason: String },
     #[error("host-delegated provider '{provider}' failed: {message}")]
@@ -120,6 +131,18 @@ impl ModelError {
         }
     }
 
+    pub fn rate_limited(
+        provider: impl Into<String>,
+        retries: u32,
+        retry_after_secs: Option<u64>,
+    ) -> Self {
+        Self::RateLimited {
+            provider: provider.into(),
+            retries,
+            retry_after_secs,
+        }
+    }
+
     pub fn invalid_response(provider: impl Into<String>, reason: impl Into<String>) -> Self {
         Self::InvalidResponse {
             provider: provider.into(),
@@ -140,28 +163,54 @@ impl ModelError {
         }
     }
 
-    /// User-friendly error message in Indonesian
+    /// User-friendly error message in the process-wide locale.
     pub fn user_message(&self) -> String {
+        self.user_message_in(current_locale())
+    }
+
+    /// User-friendly error message in `locale`.
+    pub fn user_message_in(&self, locale: Locale) -> String {
+        let text = &locale.strings().errors;
         match self {
-            ModelError::ProviderNotFound { provider } => format!(
-                "Penyedia model '{provider}' tidak ditemukan. Periksa pengaturan client.toml."
-            ),
-            ModelError::ModelNotFound { provider, model } => {
-                format!("Model '{model}' tidak tersedia pada penyedia '{provider}'.")
+            ModelError::NoProviders => text.no_providers.to_string(),
+            ModelError::ProviderNotFound { provider } => {
+                fill(text.provider_not_found, &[("provider", provider)])
             }
+            ModelError::ModelNotFound { provider, model } => fill(
+                text.model_not_found,
+                &[("provider", provider), ("model", model)],
+            ),
             ModelError::MissingApiKey { provider } => {
-                format!("Penyedia '{provider}' memerlukan API key.")
+                fill(text.missing_api_key, &[("provider", provider)])
             }
             ModelError::Network { provider, message } => {
                 // The provider implementation already stringified the transport error.
-                format!("Kesalahan jaringan pada '{provider}': {message}")
+                fill(text.network, &[("provider", provider), ("error", message)])
             }
-            ModelError::InvalidResponse { provider, .. } => {
-                format!("Respons dari '{provider}' tidak valid.")
+            ModelError::RateLimited {
+                provider,
+                retries,
+                retry_after_secs,
+            } => {
+                let message = fill(
+                    text.rate_limited,
+                    &[("provider", provider), ("retries", &retries.to_string())],
+                );
+                match retry_after_secs {
+                    Some(secs) => format!(
+                        "{message} {}",
+                        fill(text.rate_limited_wait, &[("secs", &secs.to_string())])
+                    ),
+                    None => message,
+                }
             }
-            ModelError::HostDelegate { provider, message } => {
-                format!("Host gagal memproses permintaan model untuk '{provider}': {message}")
+            ModelError::InvalidResponse { provider, .. } => {
+                fill(text.invalid_model_response, &[("provider", provider)])
             }
+            ModelError::HostDelegate { provider, message } => fill(
+                text.host_delegate,
+                &[("provider", provider), ("error", message)],
+            ),
             ModelError::Unsupported { message } => message.clone(),
         }
     }
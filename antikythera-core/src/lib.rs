@@ -37,9 +37,9 @@ pub use application::resilience::{
     with_retry_if,
 };
 pub use application::streaming::{
-    AgentEvent, AgentEventStream, BufferPolicy, ClientInputStream, InMemoryStreamingResponse,
-    StreamingBuffer, StreamingMode, StreamingPhase2Options, StreamingRequest, StreamingResponse,
-    StreamingSnapshot, ToolEventPhase,
+    AgentEvent, AgentEventSink, AgentEventStream, BufferPolicy, ClientInputStream,
+    InMemoryStreamingResponse, StreamingBuffer, StreamingMode, StreamingPhase2Options,
+    StreamingRequest, StreamingResponse, StreamingSnapshot, ToolEventPhase,
 };
 pub mod streaming {
     pub use crate::application::streaming::*;
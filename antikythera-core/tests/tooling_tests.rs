@@ -1,2 +1,16 @@
 #[path = "tooling_tests/part_06.rs"]
 mod part_06;
+#[path = "tooling_tests/part_07.rs"]
+mod part_07;
+#[path = "tooling_tests/part_08.rs"]
+mod part_08;
+#[path = "tooling_tests/part_09.rs"]
+mod part_09;
+#[path = "tooling_tests/part_10.rs"]
+mod part_10;
+#[path = "tooling_tests/part_11.rs"]
+mod part_11;
+#[path = "tooling_tests/part_12.rs"]
+mod part_12;
+#[path = "tooling_tests/part_13.rs"]
+mod part_13;
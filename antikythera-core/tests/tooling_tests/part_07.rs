@@ -0,0 +1,207 @@
+//! Keepalive, idle timeout, and manual reset of STDIO MCP processes, driven by a shell stub
+//! that answers the handshake, tool calls, and completions but never answers `ping`.
+#![cfg(unix)]
+
+use antikythera_core::application::tooling::{
+    CompletionRef, ServerManager, ToolInvokeError, ToolServerInterface,
+};
+use antikythera_core::config::ServerConfig;
+use serde_json::json;
+use std::collections::HashMap;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const UNRESPONSIVE_STUB: &str = r#"#!/bin/sh
+echo started >> "$SPAWN_LOG"
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"capabilities":{"completions":{}}}}\n' "$id" ;;
+    *'"method":"tools/list"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[]}}\n' "$id" ;;
+    *'"method":"tools/call"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"content":[]}}\n' "$id" ;;
+    *'"method":"completion/complete"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"completion":{"values":[]}}}\n' "$id" ;;
+  esac
+done
+"#;
+
+fn write_stub(dir: &Path) -> PathBuf {
+    let path = dir.join("stub.sh");
+    std::fs::write(&path, UNRESPONSIVE_STUB).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+fn stub_config(dir: &Path, keepalive_interval_secs: u64) -> ServerConfig {
+    ServerConfig {
+        name: "stub".to_string(),
+        command: Some(write_stub(dir)),
+        env: HashMap::from([(
+            "SPAWN_LOG".to_string(),
+            dir.join("spawns.log").display().to_string(),
+        )]),
+        keepalive_interval_secs,
+        ..Default::default()
+    }
+}
+
+fn spawn_count(dir: &Path) -> usize {
+    std::fs::read_to_string(dir.join("spawns.log"))
+        .map(|log| log.lines().count())
+        .unwrap_or(0)
+}
+
+#[tokio::test]
+async fn failed_keepalive_resets_process_and_next_call_respawns() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = ServerManager::new(vec![stub_config(dir.path(), 1)]);
+
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+    assert_eq!(spawn_count(dir.path()), 1);
+
+    // One interval until the ping, one more for it to time out.
+    tokio::time::sleep(Duration::from_millis(3500)).await;
+
+    tokio::time::timeout(
+        Duration::from_secs(5),
+        manager.invoke_tool("stub", "echo", json!({})),
+    )
+    .await
+    .expect("call after keepalive reset should not hang")
+    .unwrap();
+    assert_eq!(spawn_count(dir.path()), 2);
+}
+
+#[tokio::test]
+async fn zero_interval_disables_keepalive() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = ServerManager::new(vec![stub_config(dir.path(), 0)]);
+
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+
+    assert_eq!(spawn_count(dir.path()), 1);
+}
+
+#[tokio::test]
+async fn idle_process_is_stopped_and_next_call_respawns() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = ServerManager::new(vec![ServerConfig {
+        idle_timeout_secs: 1,
+        ..stub_config(dir.path(), 0)
+    }]);
+
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+    // Calls inside the window keep the same process.
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+    assert_eq!(spawn_count(dir.path()), 1);
+
+    tokio::time::sleep(Duration::from_millis(1600)).await;
+    tokio::time::timeout(
+        Duration::from_secs(5),
+        manager.invoke_tool("stub", "echo", json!({})),
+    )
+    .await
+    .expect("call after idle stop should not hang")
+    .unwrap();
+    assert_eq!(spawn_count(dir.path()), 2);
+}
+
+#[tokio::test]
+async fn completion_requests_postpone_the_idle_timeout() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = ServerManager::new(vec![ServerConfig {
+        idle_timeout_secs: 1,
+        ..stub_config(dir.path(), 0)
+    }]);
+
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+    for _ in 0..3 {
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        manager
+            .complete_argument(
+                "stub",
+                &CompletionRef::Prompt("weather".to_string()),
+                "city",
+                "",
+            )
+            .await
+            .unwrap();
+    }
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+
+    assert_eq!(spawn_count(dir.path()), 1);
+}
+
+#[tokio::test]
+async fn reset_server_drops_cached_process_and_next_call_respawns() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = ServerManager::new(vec![stub_config(dir.path(), 0)]);
+
+    // Nothing cached yet, so there is nothing to drop.
+    assert!(!manager.reset_server("stub").await.unwrap());
+
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+    assert!(manager.reset_server("stub").await.unwrap());
+    assert!(!manager.reset_server("stub").await.unwrap());
+
+    manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap();
+    assert_eq!(spawn_count(dir.path()), 2);
+}
+
+#[tokio::test]
+async fn reset_server_rejects_unknown_server() {
+    let manager = ServerManager::new(Vec::new());
+
+    assert!(matches!(
+        manager.reset_server("missing").await,
+        Err(ToolInvokeError::NotConfigured { server }) if server == "missing"
+    ));
+}
+
+#[tokio::test]
+async fn disabled_server_is_never_started_and_calls_fail_clearly() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = ServerManager::new(vec![ServerConfig {
+        enabled: false,
+        ..stub_config(dir.path(), 0)
+    }]);
+
+    let err = manager
+        .invoke_tool("stub", "echo", json!({}))
+        .await
+        .unwrap_err();
+    assert!(matches!(&err, ToolInvokeError::Disabled { server } if server == "stub"));
+    assert_eq!(err.to_string(), "MCP server 'stub' is disabled in config");
+    assert!(manager.probe_server("stub").await.is_err());
+    assert_eq!(spawn_count(dir.path()), 0);
+}
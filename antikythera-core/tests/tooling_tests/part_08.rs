@@ -0,0 +1,85 @@
+//! `${...}` placeholder expansion in STDIO server arguments.
+
+use antikythera_core::application::tooling::{
+    PlaceholderError, Placeholders, ToolInvokeError, spawn_and_list_tools,
+};
+use antikythera_core::config::{AppConfig, ServerConfig};
+
+#[test]
+fn server_args_expand_config_dir_placeholder() {
+    let placeholders = Placeholders::new().with("config_dir", "/srv/antikythera");
+
+    assert_eq!(
+        placeholders.expand("--data=${config_dir}/data").unwrap(),
+        "--data=/srv/antikythera/data"
+    );
+    assert_eq!(placeholders.expand("${port:-8080}").unwrap(), "8080");
+    assert_eq!(
+        placeholders.expand("$${config_dir}").unwrap(),
+        "${config_dir}"
+    );
+    assert_eq!(
+        placeholders.expand("${no_such_placeholder_xyz}"),
+        Err(PlaceholderError::Unknown(
+            "no_such_placeholder_xyz".to_string()
+        ))
+    );
+}
+
+#[tokio::test]
+async fn unknown_arg_placeholder_fails_spawn_with_clear_error() {
+    let config = ServerConfig {
+        name: "templated".to_string(),
+        command: Some("non_existent_command_xyz".into()),
+        args: vec!["--root=${no_such_placeholder_xyz}".to_string()],
+        ..Default::default()
+    };
+
+    let error = spawn_and_list_tools(&config).await.unwrap_err();
+    assert!(matches!(error, ToolInvokeError::Spawn { ref server, .. } if server == "templated"));
+    assert!(error.to_string().contains("no_such_placeholder_xyz"));
+}
+
+#[test]
+fn toml_args_keep_templates_for_spawn_time_expansion() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(
+        &path,
+        r#"
+[[servers]]
+name = "templated"
+command = "mcp-templated"
+args = ["${config_dir:-/x}", "$${config_dir}", "${config_dir}/$HOME", "$NO_SUCH_VAR_XYZ"]
+"#,
+    )
+    .unwrap();
+    let config = AppConfig::load(Some(&path)).unwrap();
+    let args = &config.servers[0].args;
+    // Loading leaves `$`-templates alone.
+    assert_eq!(
+        args,
+        &[
+            "${config_dir:-/x}",
+            "$${config_dir}",
+            "${config_dir}/$HOME",
+            "$NO_SUCH_VAR_XYZ"
+        ]
+    );
+
+    let home = std::env::var("HOME").unwrap();
+    let placeholders = Placeholders::new().with("config_dir", "/srv/antikythera");
+    let expanded: Vec<String> = args
+        .iter()
+        .map(|arg| placeholders.expand(arg).unwrap())
+        .collect();
+    assert_eq!(
+        expanded,
+        [
+            "/srv/antikythera".to_string(),
+            "${config_dir}".to_string(),
+            format!("/srv/antikythera/{home}"),
+            "$NO_SUCH_VAR_XYZ".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,416 @@
+//! JSON-RPC protocol layer of `McpProcess` and the message framings of
+//! `StreamTransport`, driven over in-memory pipes instead of processes.
+
+use antikythera_core::application::tooling::{
+    McpProcess, MessageTransport, StreamTransport, ToolInvokeError,
+};
+use antikythera_core::config::{ServerConfig, StdioFraming};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+fn pipe_config() -> ServerConfig {
+    ServerConfig {
+        name: "pipe".to_string(),
+        ..Default::default()
+    }
+}
+
+async fn read_message(server: &StreamTransport) -> Value {
+    let line = server.receive().await.unwrap().expect("client message");
+    serde_json::from_str(&line).unwrap()
+}
+
+async fn reply(server: &StreamTransport, request: &Value, result: Value) {
+    let response = json!({ "jsonrpc": "2.0", "id": request["id"], "result": result });
+    server.send(&response.to_string()).await.unwrap();
+}
+
+/// Answer the handshake with one `echo` tool and return once the client has
+/// listed tools.
+async fn handshake(server: &StreamTransport) {
+    let initialize = read_message(server).await;
+    assert_eq!(initialize["method"], "initialize");
+    reply(
+        server,
+        &initialize,
+        json!({ "instructions": "Gunakan echo." }),
+    )
+    .await;
+    assert_eq!(
+        read_message(server).await["method"],
+        "notifications/initialized"
+    );
+    let list = read_message(server).await;
+    assert_eq!(list["method"], "tools/list");
+    reply(
+        server,
+        &list,
+        json!({ "tools": [{ "name": "echo", "description": "Echo input" }] }),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn handshake_and_tool_call_run_over_an_in_memory_pipe() {
+    let (client_end, server_end) = StreamTransport::pipe();
+    let server = tokio::spawn(async move {
+        handshake(&server_end).await;
+        let call = read_message(&server_end).await;
+        assert_eq!(call["method"], "tools/call");
+        let text = call["params"]["arguments"]["text"].clone();
+        reply(
+            &server_end,
+            &call,
+            json!({ "content": [{ "type": "text", "text": text }] }),
+        )
+        .await;
+    });
+
+    let process = McpProcess::connect(pipe_config(), Arc::new(client_end))
+        .await
+        .unwrap();
+    assert_eq!(
+        process.instructions().await.as_deref(),
+        Some("Gunakan echo.")
+    );
+    assert!(process.tool_metadata("echo").await.is_some());
+
+    let result = process
+        .call_tool("echo", json!({ "text": "halo" }))
+        .await
+        .unwrap();
+    assert_eq!(result["content"][0]["text"], "halo");
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn server_ping_is_answered_while_a_call_is_pending() {
+    let (client_end, server_end) = StreamTransport::pipe();
+    let server = tokio::spawn(async move {
+        handshake(&server_end).await;
+        let call = read_message(&server_end).await;
+        let ping = json!({ "jsonrpc": "2.0", "id": 99, "method": "ping" });
+        server_end.send(&ping.to_string()).await.unwrap();
+        let pong = read_message(&server_end).await;
+        assert_eq!(pong["id"], 99);
+        assert_eq!(pong["result"], json!({ "ok": true }));
+        reply(&server_end, &call, json!({ "content": [] })).await;
+    });
+
+    let process = McpProcess::connect(pipe_config(), Arc::new(client_end))
+        .await
+        .unwrap();
+    process.call_tool("echo", json!({})).await.unwrap();
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn pending_call_fails_when_the_server_hangs_up() {
+    let (client_end, server_end) = StreamTransport::pipe();
+    let server = tokio::spawn(async move {
+        handshake(&server_end).await;
+        read_message(&server_end).await;
+        server_end.close().await;
+    });
+
+    let process = McpProcess::connect(pipe_config(), Arc::new(client_end))
+        .await
+        .unwrap();
+    let error = process.call_tool("echo", json!({})).await.unwrap_err();
+    assert!(
+        matches!(&error, ToolInvokeError::Terminated { server } if server == "pipe"),
+        "{error}"
+    );
+    server.await.unwrap();
+}
+
+/// A transport with `framing` and the raw stream on the other side.
+fn framed_transport(framing: StdioFraming) -> (StreamTransport, tokio::io::DuplexStream) {
+    let (ours, theirs) = tokio::io::duplex(4096);
+    let (read, write) = tokio::io::split(ours);
+    (
+        StreamTransport::new(read, write).with_framing(framing),
+        theirs,
+    )
+}
+
+async fn read_raw(stream: &mut tokio::io::DuplexStream, len: usize) -> String {
+    let mut bytes = vec![0; len];
+    stream.read_exact(&mut bytes).await.unwrap();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[tokio::test]
+async fn line_delimited_framing_writes_and_reads_one_message_per_line() {
+    let (transport, mut raw) = framed_transport(StdioFraming::LineDelimited);
+
+    transport.send(r#"{"id":1}"#).await.unwrap();
+    assert_eq!(read_raw(&mut raw, 9).await, "{\"id\":1}\n");
+
+    raw.write_all(b"{\"id\":2}\r\n\n{\"id\":3}\n")
+        .await
+        .unwrap();
+    drop(raw);
+    assert_eq!(
+        transport.receive().await.unwrap().as_deref(),
+        Some(r#"{"id":2}"#)
+    );
+    assert_eq!(transport.receive().await.unwrap().as_deref(), Some(""));
+    assert_eq!(
+        transport.receive().await.unwrap().as_deref(),
+        Some(r#"{"id":3}"#)
+    );
+    assert_eq!(transport.receive().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn content_length_framing_writes_and_reads_exact_bodies() {
+    let (transport, mut raw) = framed_transport(StdioFraming::ContentLength);
+
+    transport.send(r#"{"id":1}"#).await.unwrap();
+    assert_eq!(
+        read_raw(&mut raw, 29).await,
+        "Content-Length: 8\r\n\r\n{\"id\":1}"
+    );
+
+    // Two messages in one write, a body spanning lines, and an extra header.
+    let multi_line = "{\"text\":\n\"a\"}";
+    let incoming = format!(
+        "content-length: {}\r\nContent-Type: application/json\r\n\r\n{}Content-Length: 8\r\n\r\n{{\"id\":2}}",
+        multi_line.len(),
+        multi_line
+    );
+    raw.write_all(incoming.as_bytes()).await.unwrap();
+    drop(raw);
+    assert_eq!(
+        transport.receive().await.unwrap().as_deref(),
+        Some(multi_line)
+    );
+    assert_eq!(
+        transport.receive().await.unwrap().as_deref(),
+        Some(r#"{"id":2}"#)
+    );
+    assert_eq!(transport.receive().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn content_length_framing_rejects_a_message_without_length() {
+    let (transport, mut raw) = framed_transport(StdioFraming::ContentLength);
+
+    raw.write_all(b"Content-Type: application/json\r\n\r\n{}")
+        .await
+        .unwrap();
+    let error = transport.receive().await.unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[tokio::test]
+async fn handshake_runs_over_content_length_framing() {
+    let (client_end, server_end) = StreamTransport::pipe();
+    let client_end = client_end.with_framing(StdioFraming::ContentLength);
+    let server_end = server_end.with_framing(StdioFraming::ContentLength);
+    let server = tokio::spawn(async move {
+        handshake(&server_end).await;
+    });
+
+    let process = McpProcess::connect(pipe_config(), Arc::new(client_end))
+        .await
+        .unwrap();
+    assert!(process.tool_metadata("echo").await.is_some());
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn plain_log_lines_are_skipped_quietly_and_malformed_json_is_warned() {
+    use antikythera_core::LogLevel;
+    use antikythera_core::logging::get_logger;
+    use antikythera_log::LogFilter;
+
+    let (client_end, server_end) = StreamTransport::pipe();
+    let server = tokio::spawn(async move {
+        for noise in [
+            "Starting weather server v1.2",
+            "\u{1b}[32mINFO\u{1b}[0m listening on stdio",
+            r#"{"jsonrpc": "2.0", "id": "#,
+        ] {
+            server_end.send(noise).await.unwrap();
+        }
+        handshake(&server_end).await;
+    });
+
+    let config = ServerConfig {
+        name: "noisy".to_string(),
+        ..pipe_config()
+    };
+    let process = McpProcess::connect(config, Arc::new(client_end))
+        .await
+        .unwrap();
+    assert!(process.tool_metadata("echo").await.is_some());
+    server.await.unwrap();
+
+    let logger = get_logger("noisy");
+    let warnings = logger.get_logs(&LogFilter::new().min_level(LogLevel::Warn));
+    assert_eq!(warnings.entries.len(), 1, "{:?}", warnings.entries);
+    assert!(warnings.entries[0].message.contains("invalid JSON"));
+    let skipped = logger
+        .get_logs(&LogFilter::new())
+        .entries
+        .into_iter()
+        .filter(|entry| entry.message.contains("skipping non-JSON output"))
+        .count();
+    assert_eq!(skipped, 2);
+}
+
+#[tokio::test]
+async fn refresh_instructions_keeps_a_connection_it_cannot_restart() {
+    let (client_end, server_end) = StreamTransport::pipe();
+    // Hanging up would reset the process and clear its instructions.
+    let (done_tx, done_rx) = tokio::sync::oneshot::channel::<()>();
+    let server = tokio::spawn(async move {
+        handshake(&server_end).await;
+        let _ = done_rx.await;
+    });
+
+    let process = McpProcess::connect(pipe_config(), Arc::new(client_end))
+        .await
+        .unwrap();
+    let before = process.instructions().await;
+
+    // No `initialize` is sent again: MCP allows only one per connection.
+    let error = process.refresh_instructions().await.unwrap_err();
+    assert!(
+        matches!(error, ToolInvokeError::NotConfigured { .. }),
+        "{error}"
+    );
+    assert_eq!(process.instructions().await, before);
+    assert!(process.tool_metadata("echo").await.is_some());
+    done_tx.send(()).unwrap();
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn max_inflight_holds_requests_until_the_previous_one_is_answered() {
+    let (client_end, server_end) = StreamTransport::pipe();
+    // A server that would interleave replies if it saw two calls at once.
+    let server = tokio::spawn(async move {
+        handshake(&server_end).await;
+        for _ in 0..2 {
+            let call = read_message(&server_end).await;
+            let early =
+                tokio::time::timeout(std::time::Duration::from_millis(50), server_end.receive())
+                    .await;
+            assert!(early.is_err(), "second request arrived before the reply");
+            let text = call["params"]["arguments"]["text"].clone();
+            reply(
+                &server_end,
+                &call,
+                json!({ "content": [{ "type": "text", "text": text }] }),
+            )
+            .await;
+        }
+    });
+
+    let config = ServerConfig {
+        max_inflight: 1,
+        ..pipe_config()
+    };
+    let process = McpProcess::connect(config, Arc::new(client_end))
+        .await
+        .unwrap();
+    let (first, second) = tokio::join!(
+        process.call_tool("echo", json!({ "text": "satu" })),
+        process.call_tool("echo", json!({ "text": "dua" })),
+    );
+    let mut replies = vec![
+        first.unwrap()["content"][0]["text"].clone(),
+        second.unwrap()["content"][0]["text"].clone(),
+    ];
+    replies.sort_by_key(|reply| reply.to_string());
+    assert_eq!(replies, vec![json!("dua"), json!("satu")]);
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn tool_calls_carry_a_progress_token_and_progress_reaches_the_sink() {
+    use antikythera_core::application::tooling::ToolProgress;
+    use std::sync::Mutex;
+
+    let (client_end, server_end) = StreamTransport::pipe();
+    let server = tokio::spawn(async move {
+        handshake(&server_end).await;
+        let call = read_message(&server_end).await;
+        assert_eq!(call["params"]["_meta"]["trace"], "abc");
+        let token = call["params"]["_meta"]["progressToken"].clone();
+        assert!(token.is_string(), "{call}");
+        let progress = json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/progress",
+            "params": { "progressToken": token, "progress": 50, "total": 100, "message": "setengah" }
+        });
+        server_end.send(&progress.to_string()).await.unwrap();
+        reply(&server_end, &call, json!({ "content": [] })).await;
+    });
+
+    let process = McpProcess::connect(pipe_config(), Arc::new(client_end))
+        .await
+        .unwrap();
+    let seen: Arc<Mutex<Vec<ToolProgress>>> = Arc::default();
+    let sink_seen = Arc::clone(&seen);
+    process.set_progress_sink(Some(Arc::new(move |progress: &ToolProgress| {
+        sink_seen.lock().unwrap().push(progress.clone());
+    })));
+
+    let mut meta = serde_json::Map::new();
+    meta.insert("trace".to_string(), json!("abc"));
+    process
+        .call_tool_with_meta("echo", json!({}), meta)
+        .await
+        .unwrap();
+    server.await.unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1);
+    assert_eq!(seen[0].server, "pipe");
+    assert_eq!(seen[0].tool.as_deref(), Some("echo"));
+    assert_eq!(seen[0].progress, 50.0);
+    assert_eq!(seen[0].total, Some(100.0));
+    assert_eq!(seen[0].message.as_deref(), Some("setengah"));
+}
+
+#[tokio::test]
+async fn progress_of_a_call_reaches_the_sink_of_its_caller() {
+    use antikythera_core::application::tooling::{ToolProgress, with_call_progress};
+    use std::sync::Mutex;
+
+    let (client_end, server_end) = StreamTransport::pipe();
+    let server = tokio::spawn(async move {
+        handshake(&server_end).await;
+        let call = read_message(&server_end).await;
+        let progress = json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/progress",
+            "params": { "progressToken": call["params"]["_meta"]["progressToken"], "progress": 3, "total": 4 }
+        });
+        server_end.send(&progress.to_string()).await.unwrap();
+        reply(&server_end, &call, json!({ "content": [] })).await;
+    });
+
+    let process = McpProcess::connect(pipe_config(), Arc::new(client_end))
+        .await
+        .unwrap();
+    let seen: Arc<Mutex<Vec<ToolProgress>>> = Arc::default();
+    let sink_seen = Arc::clone(&seen);
+    let sink = Arc::new(move |progress: &ToolProgress| {
+        sink_seen.lock().unwrap().push(progress.clone());
+    });
+    with_call_progress(sink, process.call_tool("echo", json!({})))
+        .await
+        .unwrap();
+    server.await.unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1);
+    assert_eq!(seen[0].tool.as_deref(), Some("echo"));
+    assert_eq!(seen[0].percent(), Some(75));
+}
@@ -0,0 +1,66 @@
+//! Environment of spawned STDIO MCP processes, checked with a shell stub
+//! that writes its environment to a file when it starts.
+#![cfg(unix)]
+
+use antikythera_core::application::tooling::{ServerManager, ToolServerInterface};
+use antikythera_core::config::ServerConfig;
+use serde_json::json;
+use std::collections::HashMap;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+const ENV_DUMP_STUB: &str = r#"#!/bin/sh
+env > "$ENV_DUMP"
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{}}\n' "$id" ;;
+    *'"method":"tools/list"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[]}}\n' "$id" ;;
+    *'"method":"tools/call"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"content":[]}}\n' "$id" ;;
+  esac
+done
+"#;
+
+/// Spawn the stub once and return the variable names it saw.
+async fn spawned_env(dir: &Path, clear_env: bool) -> Vec<String> {
+    let stub = dir.join("env-stub.sh");
+    std::fs::write(&stub, ENV_DUMP_STUB).unwrap();
+    std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755)).unwrap();
+    let dump = dir.join(format!("env-{clear_env}.txt"));
+    let config = ServerConfig {
+        name: "env".to_string(),
+        command: Some(stub),
+        env: HashMap::from([
+            ("ENV_DUMP".to_string(), dump.display().to_string()),
+            (
+                "PATH".to_string(),
+                std::env::var("PATH").unwrap_or_default(),
+            ),
+        ]),
+        clear_env,
+        ..Default::default()
+    };
+
+    let manager = ServerManager::new(vec![config]);
+    manager.invoke_tool("env", "echo", json!({})).await.unwrap();
+    std::fs::read_to_string(dump)
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.split_once('=').map(|(name, _)| name.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn clear_env_keeps_the_parent_environment_from_the_server() {
+    // Set by cargo for every test process.
+    assert!(std::env::var_os("CARGO_MANIFEST_DIR").is_some());
+    let dir = tempfile::tempdir().unwrap();
+
+    let inherited = spawned_env(dir.path(), false).await;
+    assert!(inherited.iter().any(|name| name == "CARGO_MANIFEST_DIR"));
+
+    let isolated = spawned_env(dir.path(), true).await;
+    assert!(isolated.iter().any(|name| name == "ENV_DUMP"));
+    assert!(!isolated.iter().any(|name| name == "CARGO_MANIFEST_DIR"));
+    assert!(!isolated.iter().any(|name| name.starts_with("CARGO")));
+}
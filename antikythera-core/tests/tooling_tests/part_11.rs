@@ -0,0 +1,79 @@
+//! `completion/complete` against STDIO shell stubs, with and without the
+//! `completions` capability.
+#![cfg(unix)]
+
+use antikythera_core::application::tooling::{CompletionRef, ServerManager, ToolServerInterface};
+use antikythera_core::config::ServerConfig;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+const COMPLETING_STUB: &str = r#"#!/bin/sh
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"capabilities":{"completions":{}}}}\n' "$id" ;;
+    *'"method":"tools/list"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[]}}\n' "$id" ;;
+    *'"method":"completion/complete"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"completion":{"values":["Jakarta","Jayapura"],"total":2,"hasMore":false}}}\n' "$id" ;;
+  esac
+done
+"#;
+
+const PLAIN_STUB: &str = r#"#!/bin/sh
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{}}\n' "$id" ;;
+    *'"method":"tools/list"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[]}}\n' "$id" ;;
+    *'"method":"completion/complete"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"completion":{"values":["unexpected"]}}}\n' "$id" ;;
+  esac
+done
+"#;
+
+fn stub_config(dir: &Path, name: &str, script: &str) -> ServerConfig {
+    let path = dir.join(format!("{name}.sh"));
+    std::fs::write(&path, script).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    ServerConfig {
+        name: name.to_string(),
+        command: Some(path),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn completions_are_returned_by_a_server_that_advertises_them() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = ServerManager::new(vec![stub_config(dir.path(), "cities", COMPLETING_STUB)]);
+
+    let completion = manager
+        .complete_argument(
+            "cities",
+            &CompletionRef::Prompt("weather".to_string()),
+            "city",
+            "Ja",
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(completion.values, vec!["Jakarta", "Jayapura"]);
+    assert_eq!(completion.total, Some(2));
+    assert!(!completion.has_more);
+}
+
+#[tokio::test]
+async fn server_without_completions_capability_is_not_asked() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = ServerManager::new(vec![stub_config(dir.path(), "plain", PLAIN_STUB)]);
+
+    let completion = manager
+        .complete_argument(
+            "plain",
+            &CompletionRef::Resource("file:///{path}".to_string()),
+            "path",
+            "",
+        )
+        .await
+        .unwrap();
+
+    assert!(completion.values.is_empty());
+}
@@ -0,0 +1,90 @@
+//! Resolution of bare STDIO server commands on `PATH`.
+
+use antikythera_core::application::tooling::{ToolInvokeError, spawn_and_list_tools};
+use antikythera_core::config::ServerConfig;
+use std::collections::HashMap;
+use std::path::Path;
+
+fn server(command: &str, path: &Path) -> ServerConfig {
+    ServerConfig {
+        name: "time".to_string(),
+        command: Some(command.into()),
+        env: HashMap::from([("PATH".to_string(), path.display().to_string())]),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn bare_command_missing_from_path_names_the_searched_directories() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let error = spawn_and_list_tools(&server("mcp-time-missing-xyz", dir.path()))
+        .await
+        .unwrap_err();
+
+    match &error {
+        ToolInvokeError::CommandNotFound {
+            server,
+            command,
+            searched,
+        } => {
+            assert_eq!(server, "time");
+            assert_eq!(command, "mcp-time-missing-xyz");
+            assert_eq!(searched, &vec![dir.path().to_path_buf()]);
+        }
+        other => panic!("expected CommandNotFound, got {other:?}"),
+    }
+    let message = error.to_string();
+    assert!(message.contains("not found on PATH"));
+    assert!(message.contains(&dir.path().display().to_string()));
+}
+
+#[tokio::test]
+async fn command_with_a_directory_part_is_spawned_as_given() {
+    let dir = tempfile::tempdir().unwrap();
+    let command = dir.path().join("mcp-time-missing-xyz");
+
+    let error = spawn_and_list_tools(&server(&command.display().to_string(), dir.path()))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ToolInvokeError::Spawn { .. }), "{error:?}");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn bare_command_is_found_on_the_server_path() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let script = dir.path().join("mcp-time-stub");
+    std::fs::write(
+        &script,
+        r#"#!/bin/sh
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{}}\n' "$id" ;;
+    *'"method":"tools/list"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[{"name":"get_time"}]}}\n' "$id" ;;
+  esac
+done
+"#,
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+    // The stub itself needs `sed` from the usual PATH.
+    let path = std::env::join_paths(
+        std::iter::once(dir.path().to_path_buf())
+            .chain(std::env::split_paths(&std::env::var_os("PATH").unwrap())),
+    )
+    .unwrap();
+    let mut config = server("mcp-time-stub", dir.path());
+    config
+        .env
+        .insert("PATH".to_string(), path.to_string_lossy().into_owned());
+
+    let tools = spawn_and_list_tools(&config).await.unwrap();
+
+    assert_eq!(tools.len(), 1);
+    assert_eq!(tools[0].0, "get_time");
+}
@@ -0,0 +1,65 @@
+//! Refreshing server instructions, checked with a shell stub that reads its
+//! instructions from a file when it starts and, like a compliant server,
+//! rejects a second `initialize`.
+#![cfg(unix)]
+
+use antikythera_core::application::tooling::McpProcess;
+use antikythera_core::config::ServerConfig;
+use serde_json::json;
+use std::collections::HashMap;
+use std::os::unix::fs::PermissionsExt;
+
+const INSTRUCTIONS_STUB: &str = r#"#!/bin/sh
+instructions=$(cat "$INSTRUCTIONS")
+initialized=
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*)
+      if [ -n "$initialized" ]; then
+        printf '{"jsonrpc":"2.0","id":"%s","error":{"code":-32600,"message":"already initialized"}}\n' "$id"
+      else
+        initialized=1
+        printf '{"jsonrpc":"2.0","id":"%s","result":{"instructions":"%s"}}\n' "$id" "$instructions"
+      fi ;;
+    *'"method":"tools/list"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[]}}\n' "$id" ;;
+    *'"method":"tools/call"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"content":[]}}\n' "$id" ;;
+  esac
+done
+"#;
+
+#[tokio::test]
+async fn refresh_instructions_restarts_the_server() {
+    let dir = tempfile::tempdir().unwrap();
+    let stub = dir.path().join("instructions-stub.sh");
+    std::fs::write(&stub, INSTRUCTIONS_STUB).unwrap();
+    std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755)).unwrap();
+    let instructions = dir.path().join("instructions.txt");
+    std::fs::write(&instructions, "Gunakan echo.").unwrap();
+
+    let process = McpProcess::new(ServerConfig {
+        name: "instructions".to_string(),
+        command: Some(stub),
+        env: HashMap::from([(
+            "INSTRUCTIONS".to_string(),
+            instructions.display().to_string(),
+        )]),
+        ..Default::default()
+    });
+    process.call_tool("echo", json!({})).await.unwrap();
+    assert_eq!(
+        process.instructions().await.as_deref(),
+        Some("Gunakan echo.")
+    );
+
+    std::fs::write(&instructions, "Gunakan echo dua kali.").unwrap();
+    assert_eq!(
+        process.refresh_instructions().await.unwrap().as_deref(),
+        Some("Gunakan echo dua kali.")
+    );
+    assert_eq!(
+        process.instructions().await.as_deref(),
+        Some("Gunakan echo dua kali.")
+    );
+    process.call_tool("echo", json!({})).await.unwrap();
+}
@@ -28,8 +28,9 @@ impl AgentRunnerRuntime {
             .join(" | ");
 
         let max_chars = policy.summary_max_chars.max(120);
-        if text.len() > max_chars {
-            text.truncate(max_chars);
+        // Cut on a character boundary; byte offsets can fall inside one.
+        if let Some((end, _)) = text.char_indices().nth(max_chars) {
+            text.truncate(end);
             text.push_str("...");
         }
 
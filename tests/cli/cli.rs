@@ -71,3 +71,31 @@ fn cli_execution_mode_defaults_to_auto() {
     let cli = Cli::parse_from(["antikythera"]);
     assert_eq!(cli.execution_mode, "auto");
 }
+
+#[test]
+fn cli_list_flags_are_off_by_default_and_combine() {
+    let cli = Cli::parse_from(["antikythera"]);
+    assert!(!cli.list_providers && !cli.list_models);
+
+    let cli = Cli::parse_from([
+        "antikythera",
+        "--list-providers",
+        "--list-models",
+        "--config",
+        "other.pc",
+    ]);
+    assert!(cli.list_providers && cli.list_models);
+    assert_eq!(cli.config.as_deref(), Some("other.pc"));
+}
+
+#[test]
+fn cli_config_dir_is_optional_and_parsed_as_a_path() {
+    let cli = Cli::parse_from(["antikythera"]);
+    assert_eq!(cli.config_dir, None);
+
+    let cli = Cli::parse_from(["antikythera", "--config-dir", "profiles/kerja"]);
+    assert_eq!(
+        cli.config_dir.as_deref(),
+        Some(std::path::Path::new("profiles/kerja"))
+    );
+}
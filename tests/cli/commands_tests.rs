@@ -1,9 +1,15 @@
 use antikythera_cli::infrastructure::llm::ModelProviderConfig;
 use antikythera_cli::infrastructure::llm::types::ModelInfo;
+use antikythera_cli::presentation::listing::{model_lines, provider_lines, server_check_lines};
 use antikythera_cli::presentation::tui::handlers::commands::{
-    find_provider, render_config_snapshot, render_provider_catalog, resolve_provider_selection,
+    find_provider, provider_summaries, render_config_snapshot, render_provider_catalog,
+    render_runtime_summary, render_server_list, resolve_provider_selection,
 };
+use antikythera_cli::runtime::ServerCheck;
 use antikythera_core::application::client::ClientConfigSnapshot;
+use antikythera_core::config::ServerConfig;
+use antikythera_core::config::server::RawServer;
+use std::collections::HashMap;
 
 fn make_provider(id: &str, models: &[&str]) -> ModelProviderConfig {
     ModelProviderConfig {
@@ -12,6 +18,14 @@ fn make_provider(id: &str, models: &[&str]) -> ModelProviderConfig {
         endpoint: "http://localhost".to_string(),
         api_key: None,
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: models
             .iter()
             .map(|m| ModelInfo {
@@ -144,6 +158,67 @@ fn render_snapshot_none_system_prompt() {
     assert!(out.contains("<none>"));
 }
 
+// ── /servers ─────────────────────────────────────────────────────────
+#[test]
+fn server_list_marks_disabled_servers() {
+    let server = |value: serde_json::Value| -> ServerConfig {
+        serde_json::from_value::<RawServer>(value).unwrap().into()
+    };
+    let servers = vec![
+        server(serde_json::json!({ "name": "time", "command": "uvx" })),
+        server(
+            serde_json::json!({ "name": "search", "url": "http://localhost:9000", "enabled": false }),
+        ),
+    ];
+
+    assert_eq!(
+        render_server_list(&servers),
+        "- time [aktif]\n- search [nonaktif]"
+    );
+    assert!(render_server_list(&[]).contains("Tidak ada server"));
+}
+
+// ── /whoami ──────────────────────────────────────────────────────────
+#[test]
+fn whoami_summary_lists_providers_without_secrets() {
+    let mut provider = make_provider("gateway", &["llama3"]);
+    provider.endpoint = "https://llm.internal:8443".to_string();
+    provider.api_key = Some("sk-very-secret".to_string());
+    provider
+        .headers
+        .insert("X-Tenant".to_string(), "acme".to_string());
+
+    let snap = ClientConfigSnapshot {
+        model: "llama3".to_string(),
+        default_provider: "gateway".to_string(),
+        system_prompt: Some("internal instructions".to_string()),
+        prompt_template: String::new(),
+        tools: vec![],
+        servers: vec![],
+        raw: "api_key = \"sk-very-secret\"".to_string(),
+    };
+    let summary = snap.summary(provider_summaries(&[provider]));
+    assert_eq!(summary.providers.len(), 1);
+    assert_eq!(summary.providers[0].id, "gateway");
+    assert_eq!(summary.providers[0].provider_type, "test");
+    assert_eq!(summary.tool_count, 0);
+    assert!(!summary.protocol_version.is_empty());
+
+    let out = render_runtime_summary(&summary);
+    assert!(out.contains("gateway [test]"));
+    assert!(out.contains(&summary.protocol_version));
+    let json = serde_json::to_string(&summary).unwrap();
+    for secret in [
+        "sk-very-secret",
+        "llm.internal",
+        "acme",
+        "internal instructions",
+    ] {
+        assert!(!out.contains(secret), "{out}");
+        assert!(!json.contains(secret), "{json}");
+    }
+}
+
 // ── process_command parsing (name extraction logic) ──────────────────
 fn parse_command_name(input: &str) -> String {
     let command = input.trim_start_matches('/').trim();
@@ -172,3 +247,56 @@ fn parse_edge_cases() {
     assert_eq!(parse_command_name("   "), "");
     assert_eq!(parse_command_name("/ unknown"), "unknown");
 }
+
+// ── --list-providers / --list-models ─────────────────────────────────
+#[test]
+fn provider_lines_are_tab_separated_in_config_order() {
+    let providers = vec![
+        make_provider("ollama", &["llama3"]),
+        make_provider("gemini", &[]),
+    ];
+    assert_eq!(
+        provider_lines(&providers),
+        vec![
+            "ollama\ttest\thttp://localhost",
+            "gemini\ttest\thttp://localhost"
+        ]
+    );
+}
+
+#[test]
+fn server_check_lines_show_reachability_tools_and_error() {
+    let checks = vec![
+        ServerCheck {
+            name: "time".to_string(),
+            tools: Some(2),
+            error: None,
+        },
+        ServerCheck {
+            name: "web".to_string(),
+            tools: None,
+            error: Some("timed out after 15s".to_string()),
+        },
+    ];
+    assert_eq!(
+        server_check_lines(&checks),
+        vec!["time\tyes\t2\t-", "web\tno\t-\ttimed out after 15s"]
+    );
+}
+
+#[test]
+fn model_lines_mark_only_the_default_pair() {
+    let providers = vec![
+        make_provider("gemini", &["gemini-pro", "gemini-flash"]),
+        make_provider("ollama", &["gemini-flash"]),
+    ];
+    assert_eq!(
+        model_lines(&providers, "gemini", "gemini-flash"),
+        vec![
+            "  gemini\tgemini-pro",
+            "* gemini\tgemini-flash",
+            "  ollama\tgemini-flash",
+        ]
+    );
+    assert!(model_lines(&[], "gemini", "gemini-flash").is_empty());
+}
@@ -1,8 +1,8 @@
 use antikythera_cli::config::{
-    AppConfig, config_from_postcard, config_to_postcard, load_app_config, normalize_provider_type,
-    recommended_default_config,
+    AppConfig, ConfigPaths, config_from_postcard, config_to_postcard, load_app_config,
+    normalize_provider_type, recommended_default_config,
 };
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[test]
 fn roundtrip_postcard_uses_typed_result() {
@@ -54,3 +54,97 @@ fn normalize_provider_type_maps_known_aliases() {
     assert_eq!(normalize_provider_type("LOCALAI"), "ollama");
     assert_eq!(normalize_provider_type("openai"), "openai");
 }
+
+#[test]
+fn wizard_output_in_a_config_dir_is_what_the_cli_loads() {
+    use antikythera_core::config::wizard::generators::{WizardPaths, client};
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let profile = dir.path().join("kerja");
+    let paths = WizardPaths::in_dir(&profile);
+    client::generate(
+        &paths,
+        "gemini",
+        "gemini",
+        "https://generativelanguage.googleapis.com",
+        "GEMINI_API_KEY",
+        &[("gemini-2.0-flash".to_string(), "Gemini Flash".to_string())],
+    )
+    .expect("generate client.toml");
+    client::add_server(&paths, "time", "mcp-time", &[]).expect("add server");
+    client::generate_env(&paths, "GEMINI_API_KEY", "rahasia").expect("generate .env");
+
+    assert!(profile.join("client.toml").is_file());
+    assert_eq!(
+        std::fs::read_to_string(profile.join(".env")).expect("read .env"),
+        "GEMINI_API_KEY=rahasia\n"
+    );
+
+    let resolved = ConfigPaths::resolve(None, Some(&profile));
+    assert_eq!(resolved.runtime, Some(profile.join("client.toml")));
+    assert_eq!(resolved.postcard, Some(profile.join("app.pc")));
+    let config = antikythera_core::config::AppConfig::load(resolved.runtime.as_deref())
+        .expect("load wizard output");
+    assert_eq!(config.servers.len(), 1);
+    assert_eq!(config.servers[0].name, "time");
+
+    let explicit = ConfigPaths::resolve(Some("lain.pc"), Some(&profile));
+    assert_eq!(explicit.runtime, Some(PathBuf::from("lain.pc")));
+    assert_eq!(ConfigPaths::resolve(None, None), ConfigPaths::default());
+}
+
+#[test]
+fn provider_type_registry_drives_the_default_catalog() {
+    use antikythera_cli::domain::entities::{PROVIDER_TYPES, ProviderType};
+
+    let config = recommended_default_config();
+    assert_eq!(config.providers.len(), PROVIDER_TYPES.len());
+    for (provider, info) in config.providers.iter().zip(PROVIDER_TYPES) {
+        assert_eq!(provider.provider_type, info.name);
+        assert_eq!(provider.endpoint, info.default_endpoint);
+        assert_eq!(provider.api_key, info.api_key_env.unwrap_or_default());
+    }
+
+    assert_eq!(ProviderType::parse(" Google "), Some(ProviderType::Gemini));
+    assert_eq!(ProviderType::Gemini.info().name, "gemini");
+    assert_eq!(
+        ProviderType::OpenAi.info().default_api_path,
+        Some("/v1/chat/completions")
+    );
+    assert_eq!(normalize_provider_type(" Mistral "), "mistral");
+}
+
+#[test]
+fn config_text_round_trips_as_json_and_toml() {
+    use antikythera_cli::config::{ConfigFormat, config_from_text, config_to_text};
+
+    let mut config = recommended_default_config();
+    config
+        .custom
+        .insert("tools.public".to_string(), "get_time".to_string());
+    let expected = serde_json::to_value(&config).unwrap();
+
+    let json = config_to_text(&config, ConfigFormat::default()).expect("json");
+    assert!(json.trim_start().starts_with('{'));
+    let toml = config_to_text(&config, ConfigFormat::Toml).expect("toml");
+    assert!(toml.contains("[[providers]]"));
+
+    for (text, format) in [(json, ConfigFormat::Json), (toml, ConfigFormat::Toml)] {
+        let parsed = config_from_text(&text, format).expect("parse");
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), expected);
+    }
+    assert!(config_from_text("not = [valid", ConfigFormat::Toml).is_err());
+
+    assert_eq!(
+        ConfigFormat::from_path(Path::new("backup.TOML")),
+        ConfigFormat::Toml
+    );
+    assert_eq!(
+        ConfigFormat::from_path(Path::new("backup.json")),
+        ConfigFormat::Json
+    );
+    assert_eq!(
+        ConfigFormat::from_path(Path::new("backup")),
+        ConfigFormat::Json
+    );
+}
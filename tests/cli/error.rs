@@ -43,3 +43,29 @@ fn cli_result_err_wraps_cli_error() {
     let result: CliResult<i32> = Err(CliError::Config("oops".to_string()));
     assert!(result.is_err());
 }
+
+#[test]
+fn app_errors_map_to_distinct_exit_codes() {
+    use antikythera_cli::error::AppError;
+    use antikythera_core::config::ConfigError;
+    use antikythera_core::infrastructure::model::ModelError;
+
+    let config: AppError = CliError::Validation("bad input".to_string()).into();
+    assert_eq!(config.exit_code(), 2);
+    let missing: AppError = ConfigError::NotFound {
+        path: "config/client.toml".into(),
+    }
+    .into();
+    assert_eq!(missing.exit_code(), 2);
+    assert!(missing.to_string().contains("antikythera-config init"));
+
+    let provider: AppError = ModelError::NoProviders.into();
+    assert_eq!(provider.exit_code(), 3);
+    assert_eq!(provider.to_string(), ModelError::NoProviders.user_message());
+
+    let io: AppError = std::io::Error::other("broken pipe").into();
+    assert_eq!(io.exit_code(), 4);
+    assert_eq!(AppError::Task("failed".to_string()).exit_code(), 5);
+    let other: AppError = CliError::Unsupported("x".to_string()).into();
+    assert_eq!(other.exit_code(), 1);
+}
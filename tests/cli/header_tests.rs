@@ -0,0 +1,21 @@
+use antikythera_cli::presentation::tui::render::header::backend_label;
+
+#[test]
+fn header_shows_the_selection_before_any_reply() {
+    assert_eq!(
+        backend_label(("ollama", "llama3.2"), None),
+        "ollama / llama3.2"
+    );
+}
+
+#[test]
+fn header_shows_the_backend_that_answered_when_it_differs() {
+    assert_eq!(
+        backend_label(("gemini", "flash"), Some(("gemini", "flash"))),
+        "gemini / flash"
+    );
+    assert_eq!(
+        backend_label(("openai", "gpt-4o-mini"), Some(("gemini", "flash"))),
+        "dijawab gemini / flash · dipilih openai / gpt-4o-mini"
+    );
+}
@@ -0,0 +1,65 @@
+use antikythera_cli::cli::Cli;
+use antikythera_cli::presentation::log_tail::{LogFollower, render_entry};
+use clap::Parser;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+fn append(path: &std::path::Path, text: &str) {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .unwrap()
+        .write_all(text.as_bytes())
+        .unwrap();
+}
+
+#[test]
+fn tail_logs_flag_takes_an_optional_path() {
+    assert_eq!(Cli::parse_from(["antikythera"]).tail_logs, None);
+    assert_eq!(
+        Cli::parse_from(["antikythera", "--tail-logs"]).tail_logs,
+        Some(None)
+    );
+    assert_eq!(
+        Cli::parse_from(["antikythera", "--tail-logs", "audit.jsonl"]).tail_logs,
+        Some(Some(PathBuf::from("audit.jsonl")))
+    );
+}
+
+#[test]
+fn follower_reads_only_new_complete_lines() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    append(&path, "lama\n");
+
+    let mut follower = LogFollower::new(&path);
+    assert!(follower.poll().unwrap().is_empty());
+
+    append(&path, "satu\ndu");
+    assert_eq!(follower.poll().unwrap(), vec!["satu".to_string()]);
+    append(&path, "a\n");
+    assert_eq!(follower.poll().unwrap(), vec!["dua".to_string()]);
+}
+
+#[test]
+fn follower_starts_over_after_truncation() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("audit.jsonl");
+    append(&path, "entri panjang sebelum rotasi\n");
+    let mut follower = LogFollower::new(&path);
+
+    fs::write(&path, "baru\n").unwrap();
+    assert_eq!(follower.poll().unwrap(), vec!["baru".to_string()]);
+}
+
+#[test]
+fn audit_entries_render_on_one_line() {
+    let line = r#"{"timestamp":"2026-01-02T03:04:05Z","session_id":"s-1","tool":"get_weather","input":{"city":"Jakarta"},"success":true,"output":"Cerah"}"#;
+    assert_eq!(
+        render_entry(line),
+        r#"2026-01-02T03:04:05Z  s-1  get_weather  ok  {"city":"Jakarta"} -> Cerah"#
+    );
+    assert_eq!(render_entry("bukan json"), "bukan json");
+}
@@ -0,0 +1,66 @@
+use antikythera_cli::presentation::tui::TuiOptions;
+use antikythera_cli::presentation::tui::render::markdown::render_markdown;
+use ratatui::style::Modifier;
+use ratatui::text::Line;
+use std::collections::HashMap;
+
+fn plain(line: &Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+#[test]
+fn headings_drop_hashes_and_are_bold() {
+    let lines = render_markdown("## Ringkasan");
+    assert_eq!(plain(&lines[0]), "Ringkasan");
+    assert!(
+        lines[0].spans[0]
+            .style
+            .add_modifier
+            .contains(Modifier::BOLD)
+    );
+}
+
+#[test]
+fn bullet_and_numbered_lists_get_markers() {
+    let lines = render_markdown("- satu\n2. dua");
+    assert_eq!(plain(&lines[0]), "• satu");
+    assert_eq!(plain(&lines[1]), "2. dua");
+}
+
+#[test]
+fn inline_code_and_bold_are_separate_spans() {
+    let lines = render_markdown("jalankan `cargo test` lalu **cek** hasil");
+    let spans = &lines[0].spans;
+    assert_eq!(plain(&lines[0]), "jalankan cargo test lalu cek hasil");
+    let code = spans.iter().find(|s| s.content == "cargo test").unwrap();
+    assert!(code.style.bg.is_some());
+    let bold = spans.iter().find(|s| s.content == "cek").unwrap();
+    assert!(bold.style.add_modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn fenced_code_block_has_background_and_keeps_content_raw() {
+    let lines = render_markdown("```rust\nlet x = **1**;\n```\nselesai");
+    assert_eq!(lines.len(), 3);
+    assert!(plain(&lines[0]).contains("rust"));
+    assert!(plain(&lines[1]).contains("let x = **1**;"));
+    assert!(lines[1].spans[0].style.bg.is_some());
+    assert_eq!(plain(&lines[2]), "selesai");
+}
+
+#[test]
+fn unmatched_markers_are_left_as_text() {
+    let lines = render_markdown("2 * 3 = 6 and `open");
+    assert_eq!(plain(&lines[0]), "2 * 3 = 6 and `open");
+}
+
+#[test]
+fn tui_options_read_markdown_toggle_from_custom_map() {
+    assert!(TuiOptions::from_custom(&HashMap::new()).render_markdown);
+
+    let custom = HashMap::from([("tui.render_markdown".to_string(), "false".to_string())]);
+    assert!(!TuiOptions::from_custom(&custom).render_markdown);
+}
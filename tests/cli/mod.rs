@@ -41,3 +41,30 @@ mod log_panel_tests;
 
 #[path = "stdio_tests.rs"]
 mod stdio_tests;
+
+#[path = "markdown_tests.rs"]
+mod markdown_tests;
+
+#[path = "turn_log_tests.rs"]
+mod turn_log_tests;
+
+#[path = "model_picker_tests.rs"]
+mod model_picker_tests;
+
+#[path = "tool_steps_tests.rs"]
+mod tool_steps_tests;
+
+#[path = "navigation_tests.rs"]
+mod navigation_tests;
+
+#[path = "quit_prompt_tests.rs"]
+mod quit_prompt_tests;
+
+#[path = "header_tests.rs"]
+mod header_tests;
+
+#[path = "log_tail_tests.rs"]
+mod log_tail_tests;
+
+#[path = "theme_tests.rs"]
+mod theme_tests;
@@ -0,0 +1,26 @@
+use antikythera_cli::presentation::tui::render::model_picker_overlay::render_model_picker;
+
+fn plain(line: &ratatui::text::Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+#[test]
+fn cursor_and_active_model_are_marked() {
+    let models = vec![
+        "llama3".to_string(),
+        "qwen2.5".to_string(),
+        "mistral".to_string(),
+    ];
+    let lines = render_model_picker(&models, 2, "qwen2.5");
+
+    let rendered: Vec<String> = lines.iter().map(plain).collect();
+    assert_eq!(rendered, vec!["  ○ llama3", "  ◉ qwen2.5", "▶ ○ mistral"]);
+}
+
+#[test]
+fn empty_model_list_renders_nothing() {
+    assert!(render_model_picker(&[], 0, "llama3").is_empty());
+}
@@ -0,0 +1,52 @@
+use antikythera_cli::presentation::tui::navigation::{NavAction, PAGE_SIZE};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+fn key(code: KeyCode) -> NavAction {
+    NavAction::from(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+#[test]
+fn keys_map_to_navigation_actions() {
+    assert_eq!(key(KeyCode::Up), NavAction::Up);
+    assert_eq!(key(KeyCode::Down), NavAction::Down);
+    assert_eq!(key(KeyCode::PageUp), NavAction::PageUp);
+    assert_eq!(key(KeyCode::PageDown), NavAction::PageDown);
+    assert_eq!(key(KeyCode::Home), NavAction::Home);
+    assert_eq!(key(KeyCode::End), NavAction::End);
+    assert_eq!(key(KeyCode::Enter), NavAction::Select);
+    assert_eq!(key(KeyCode::Esc), NavAction::Back);
+    assert_eq!(key(KeyCode::Char('c')), NavAction::None);
+    assert_eq!(
+        NavAction::from(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+        NavAction::ForceQuit
+    );
+}
+
+#[test]
+fn apply_moves_within_bounds() {
+    let last = 50;
+    assert_eq!(NavAction::Up.apply(0, last), 0);
+    assert_eq!(NavAction::Down.apply(last, last), last);
+    assert_eq!(NavAction::PageDown.apply(5, last), 5 + PAGE_SIZE);
+    assert_eq!(NavAction::PageDown.apply(45, last), last);
+    assert_eq!(NavAction::PageUp.apply(5, last), 0);
+    assert_eq!(NavAction::Home.apply(30, last), 0);
+    assert_eq!(NavAction::End.apply(3, last), last);
+    assert_eq!(NavAction::Select.apply(7, last), 7);
+}
+
+#[test]
+fn vi_keys_navigate_and_control_chords_do_not() {
+    assert_eq!(key(KeyCode::Char('j')), NavAction::Down);
+    assert_eq!(key(KeyCode::Char('k')), NavAction::Up);
+    assert_eq!(key(KeyCode::Char('g')), NavAction::Home);
+    assert_eq!(
+        NavAction::from(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+        NavAction::End
+    );
+    assert_eq!(key(KeyCode::Char('q')), NavAction::None);
+    assert_eq!(
+        NavAction::from(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL)),
+        NavAction::None
+    );
+}
@@ -0,0 +1,35 @@
+use antikythera_cli::presentation::tui::TuiOptions;
+use antikythera_cli::presentation::tui::render::quit_overlay::render_quit_prompt;
+use ratatui::text::Line;
+use std::collections::HashMap;
+
+fn plain(line: &Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+#[test]
+fn confirm_exit_is_on_unless_disabled_in_custom_map() {
+    assert!(TuiOptions::from_custom(&HashMap::new()).confirm_exit);
+
+    let custom = HashMap::from([("tui.confirm_exit".to_string(), "false".to_string())]);
+    assert!(!TuiOptions::from_custom(&custom).confirm_exit);
+
+    let custom = HashMap::from([("tui.confirm_exit".to_string(), "nanti".to_string())]);
+    assert!(TuiOptions::from_custom(&custom).confirm_exit);
+}
+
+#[test]
+fn quit_prompt_names_what_would_be_lost_and_the_choices() {
+    let pending = render_quit_prompt(true);
+    assert!(plain(&pending[0]).contains("masih diproses"));
+
+    let unsaved = render_quit_prompt(false);
+    assert!(plain(&unsaved[0]).contains("belum tersimpan"));
+    let choices: Vec<String> = unsaved[2..].iter().map(plain).collect();
+    assert!(choices[0].starts_with('s'));
+    assert!(choices[1].starts_with('y'));
+    assert!(choices[2].starts_with("n/Esc"));
+}
@@ -1,8 +1,13 @@
 use antikythera_cli::infrastructure::llm::ModelProviderConfig;
 use antikythera_cli::runtime::{
-    default_provider_template, detect_provider_from_env, materialize_runtime_config,
+    check_servers, default_provider_template, detect_provider_from_env, materialize_runtime_config,
+    warm_up_providers, warmup_targets,
 };
-use antikythera_core::AppConfig;
+use antikythera_core::config::ServerConfig;
+use antikythera_core::infrastructure::model::{
+    ModelError, ModelProvider, ModelRequest, ModelResponse,
+};
+use antikythera_core::{AppConfig, ClientConfig, McpClient};
 use serial_test::serial;
 
 fn sample_config() -> AppConfig {
@@ -14,6 +19,20 @@ fn sample_config() -> AppConfig {
         servers: Vec::new(),
         rest_server: Default::default(),
         prompts: Default::default(),
+        provider_prompts: Default::default(),
+        audit_log: None,
+        config_history: None,
+        case_sensitive_tools: false,
+        public_tools: None,
+        terminal_tools: Vec::new(),
+        session_title: Default::default(),
+        reasoning: Default::default(),
+        stdio_default_agent_mode: true,
+        seed_history: None,
+        session_ttl_secs: None,
+        tool_cache_ttl_secs: None,
+        max_prompt_chars: None,
+        agent_max_steps: None,
     }
 }
 
@@ -96,3 +115,138 @@ fn detect_provider_from_env_uses_openai_when_only_openai_key_present() {
     }
     assert_eq!(result, "openai");
 }
+
+/// Succeeds for every provider except `down`.
+struct WarmupProvider {
+    calls: std::sync::Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait::async_trait]
+impl ModelProvider for WarmupProvider {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push((request.provider.clone(), request.model.clone()));
+        if request.provider == "down" {
+            return Err(ModelError::Network {
+                provider: request.provider,
+                message: "connection refused".to_string(),
+            });
+        }
+        Ok(ModelResponse::new("pong".to_string(), None))
+    }
+}
+
+fn provider_with_models(id: &str, models: &[&str]) -> ModelProviderConfig {
+    let mut provider = default_provider_template("ollama").expect("ollama template");
+    provider.id = id.to_string();
+    for model in models {
+        provider.ensure_model(model);
+    }
+    provider
+}
+
+#[test]
+fn warmup_targets_use_the_selected_model_and_each_providers_first_model() {
+    let providers = vec![
+        provider_with_models("ollama", &["qwen2.5", "llama3.2"]),
+        provider_with_models("lab", &["mistral", "phi3"]),
+        provider_with_models("empty", &[]),
+    ];
+
+    assert_eq!(
+        warmup_targets(&sample_config(), &providers),
+        vec![
+            ("ollama".to_string(), "llama3.2".to_string()),
+            ("lab".to_string(), "mistral".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn warmup_reports_failures_without_stopping_other_providers() {
+    let client = McpClient::new(
+        WarmupProvider {
+            calls: std::sync::Mutex::new(Vec::new()),
+        },
+        ClientConfig::new("ollama", "llama3.2"),
+    );
+
+    let reports = warm_up_providers(
+        &client,
+        vec![
+            ("ollama".to_string(), "llama3.2".to_string()),
+            ("down".to_string(), "mistral".to_string()),
+        ],
+    )
+    .await;
+
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].provider, "ollama");
+    assert!(reports[0].error.is_none());
+    assert_eq!(reports[1].model, "mistral");
+    assert!(
+        reports[1]
+            .error
+            .as_deref()
+            .is_some_and(|error| error.contains("connection refused"))
+    );
+}
+
+#[cfg(unix)]
+fn stub_server(dir: &std::path::Path, name: &str, script: &str) -> ServerConfig {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.join(format!("{name}.sh"));
+    std::fs::write(&path, script).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    ServerConfig {
+        name: name.to_string(),
+        command: Some(path),
+        ..Default::default()
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn check_servers_reports_each_server_in_config_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let answering = stub_server(
+        dir.path(),
+        "time",
+        r#"#!/bin/sh
+while IFS= read -r line; do
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+  case "$line" in
+    *'"method":"initialize"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{}}\n' "$id" ;;
+    *'"method":"tools/list"'*) printf '{"jsonrpc":"2.0","id":"%s","result":{"tools":[{"name":"get_time"},{"name":"get_date"}]}}\n' "$id" ;;
+  esac
+done
+"#,
+    );
+    // Reads requests but never answers, keeping stdout open.
+    let silent = stub_server(
+        dir.path(),
+        "silent",
+        "#!/bin/sh\nwhile read -r line; do :; done\n",
+    );
+    let disabled = ServerConfig {
+        enabled: false,
+        ..stub_server(dir.path(), "off", "#!/bin/sh\n")
+    };
+
+    let checks = check_servers(
+        &[answering, silent, disabled],
+        std::time::Duration::from_secs(2),
+    )
+    .await;
+
+    let names: Vec<&str> = checks.iter().map(|check| check.name.as_str()).collect();
+    assert_eq!(names, vec!["time", "silent", "off"]);
+    assert!(checks[0].reachable());
+    assert_eq!(checks[0].tools, Some(2));
+    assert!(!checks[1].reachable());
+    assert!(checks[1].error.as_deref().unwrap().contains("timed out"));
+    assert!(checks[2].error.as_deref().unwrap().contains("disabled"));
+}
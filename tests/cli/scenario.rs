@@ -86,6 +86,9 @@ async fn greet_then_ask_time() {
             raw_mode: false,
             bypass_template: false,
             force_json: false,
+            provider: None,
+            model: None,
+            metadata: std::collections::HashMap::new(),
         })
         .await
         .expect("giliran sapaan harus berhasil");
@@ -112,6 +115,9 @@ async fn greet_then_ask_time() {
             raw_mode: false,
             bypass_template: false,
             force_json: false,
+            provider: None,
+            model: None,
+            metadata: std::collections::HashMap::new(),
         })
         .await
         .expect("giliran tanya waktu harus berhasil");
@@ -1,4 +1,6 @@
-use antikythera_core::application::stdio::suggest_commands;
+use antikythera_core::application::locale::{LOCALE_KEY, Locale, fill};
+use antikythera_core::application::stdio::{complete_command, suggest_commands};
+use std::collections::HashMap;
 
 #[test]
 fn suggest_commands_returns_defaults_for_empty_prefix() {
@@ -18,3 +20,74 @@ fn suggest_commands_returns_empty_for_unknown_prefix() {
     let suggestions = suggest_commands("zzzzz");
     assert!(suggestions.is_empty());
 }
+
+#[test]
+fn complete_command_offers_matching_command_names() {
+    let (start, candidates) = complete_command("/re");
+    assert_eq!(start, 1);
+    assert_eq!(candidates, vec!["reset", "reload"]);
+
+    let (_, candidates) = complete_command("/cl");
+    assert_eq!(candidates, vec!["cls"]);
+}
+
+#[test]
+fn complete_command_offers_subarguments() {
+    let (start, candidates) = complete_command("/agent o");
+    assert_eq!(start, "/agent ".len());
+    assert_eq!(candidates, vec!["on", "off"]);
+
+    let (_, candidates) = complete_command(":config ");
+    assert_eq!(candidates, vec!["edit", "validate"]);
+}
+
+#[test]
+fn complete_command_ignores_plain_prompts_and_extra_arguments() {
+    assert!(complete_command("halo").1.is_empty());
+    assert!(complete_command("/agent on extra").1.is_empty());
+    assert!(complete_command("/help ").1.is_empty());
+}
+
+#[test]
+fn locale_codes_parse_with_region_and_case() {
+    assert_eq!(Locale::from_code("id"), Some(Locale::Id));
+    assert_eq!(Locale::from_code("EN"), Some(Locale::En));
+    assert_eq!(Locale::from_code("en-US"), Some(Locale::En));
+    assert_eq!(Locale::from_code("fr"), None);
+}
+
+#[test]
+fn locale_from_custom_falls_back_to_indonesian() {
+    let mut custom = HashMap::new();
+    assert_eq!(Locale::from_custom(&custom), Locale::Id);
+
+    custom.insert(LOCALE_KEY.to_string(), "en".to_string());
+    assert_eq!(Locale::from_custom(&custom), Locale::En);
+
+    custom.insert(LOCALE_KEY.to_string(), "xx".to_string());
+    assert_eq!(Locale::from_custom(&custom), Locale::Id);
+}
+
+#[test]
+fn locale_tables_translate_stdio_text() {
+    let id = &Locale::Id.strings().stdio;
+    let en = &Locale::En.strings().stdio;
+    assert_eq!(id.help.len(), en.help.len());
+    assert_eq!(id.help_title, "Perintah yang tersedia:");
+    assert_eq!(en.help_title, "Available commands:");
+    assert_eq!(id.banner.len(), en.banner.len());
+    assert!(en.banner_chat_mode.contains("/agent on"));
+    assert!(id.banner_agent_mode.contains("/agent off"));
+}
+
+#[test]
+fn fill_replaces_named_placeholders() {
+    let text = fill(
+        Locale::En.strings().errors.model_not_found,
+        &[("provider", "ollama"), ("model", "llama3")],
+    );
+    assert_eq!(
+        text,
+        "Model 'llama3' is not available on provider 'ollama'."
+    );
+}
@@ -0,0 +1,46 @@
+use antikythera_cli::presentation::tui::theme::{THEME_PRESET_KEY, Theme};
+use ratatui::style::Color;
+use std::collections::HashMap;
+
+fn custom(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn unconfigured_theme_keeps_the_original_colors() {
+    let theme = Theme::from_custom(&HashMap::new());
+    assert_eq!(theme, Theme::dark());
+    assert_eq!(theme.accent, Color::Cyan);
+    assert_eq!(theme.highlight, Color::Yellow);
+    assert_eq!(theme.code_bg, Color::Rgb(40, 44, 52));
+}
+
+#[test]
+fn preset_names_ignore_case_and_dashes() {
+    assert_eq!(Theme::preset("Light"), Some(Theme::light()));
+    assert_eq!(Theme::preset("high-contrast"), Some(Theme::high_contrast()));
+    assert_eq!(Theme::preset("solarized"), None);
+
+    let theme = Theme::from_custom(&custom(&[(THEME_PRESET_KEY, "solarized")]));
+    assert_eq!(theme, Theme::dark());
+}
+
+#[test]
+fn slot_overrides_apply_on_top_of_the_preset() {
+    let theme = Theme::from_custom(&custom(&[
+        (THEME_PRESET_KEY, "high_contrast"),
+        ("theme.accent", "#00d7ff"),
+        ("theme.muted", "light-blue"),
+        ("theme.code_bg", "236"),
+        ("theme.unknown", "red"),
+        ("theme.error", "not-a-color"),
+    ]));
+    assert_eq!(theme.accent, Color::Rgb(0, 215, 255));
+    assert_eq!(theme.muted, Color::LightBlue);
+    assert_eq!(theme.code_bg, Color::Indexed(236));
+    assert_eq!(theme.error, Theme::high_contrast().error);
+    assert_eq!(theme.highlight, Theme::high_contrast().highlight);
+}
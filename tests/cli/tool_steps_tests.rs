@@ -0,0 +1,73 @@
+use antikythera_cli::presentation::tui::render::tool_steps::format_tool_steps;
+use antikythera_core::application::agent::AgentStep;
+use serde_json::{Value, json};
+
+fn steps() -> Vec<AgentStep> {
+    vec![
+        AgentStep {
+            tool: "get_time".to_string(),
+            input: json!({ "city": "Jakarta" }),
+            success: true,
+            output: json!({ "time": "10:00" }),
+            message: None,
+            cached: false,
+        },
+        AgentStep {
+            tool: "search".to_string(),
+            input: Value::Null,
+            success: false,
+            output: Value::Null,
+            message: Some("timeout".to_string()),
+            cached: false,
+        },
+    ]
+}
+
+#[test]
+fn collapsed_steps_are_one_line_each() {
+    assert_eq!(
+        format_tool_steps(&steps(), false),
+        "🔧 called get_time → success\n🔧 called search → failed"
+    );
+}
+
+#[test]
+fn expanded_steps_show_note_input_and_output() {
+    let text = format_tool_steps(&steps(), true);
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines[0], "🔧 called get_time → success");
+    assert!(lines.contains(&"   in :   \"city\": \"Jakarta\""));
+    assert!(lines.contains(&"   out:   \"time\": \"10:00\""));
+    let failed = lines
+        .iter()
+        .position(|line| *line == "🔧 called search → failed")
+        .unwrap();
+    // Null input and output are left out.
+    assert_eq!(&lines[failed + 1..], ["   catatan: timeout"]);
+}
+
+#[test]
+fn long_output_is_cut() {
+    let step = AgentStep {
+        tool: "dump".to_string(),
+        input: Value::Null,
+        success: true,
+        output: json!("x".repeat(5_000)),
+        message: None,
+        cached: false,
+    };
+    let text = format_tool_steps(&[step], true);
+    assert!(text.chars().count() < 1_100);
+    assert!(text.ends_with('…'));
+}
+
+#[test]
+fn cached_steps_are_marked() {
+    let mut steps = steps();
+    steps[0].cached = true;
+    assert_eq!(
+        format_tool_steps(&steps[..1], false),
+        "🔧 called get_time → success (cached)"
+    );
+}
@@ -0,0 +1,40 @@
+use antikythera_cli::presentation::tui::render::turn_log_overlay::render_turn_logs;
+
+fn plain(line: &ratatui::text::Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+#[test]
+fn placeholder_before_first_turn() {
+    let lines = render_turn_logs(&[], None);
+    assert_eq!(lines.len(), 1);
+    assert!(plain(&lines[0]).contains("Belum ada giliran"));
+}
+
+#[test]
+fn header_carries_timestamp_and_lines_are_numbered() {
+    let logs = vec![
+        "Provider 'ollama' with model 'llama3'".to_string(),
+        "User: halo".to_string(),
+    ];
+    let lines = render_turn_logs(&logs, Some("2026-10-16T08:30:12.123456+00:00"));
+
+    assert_eq!(
+        plain(&lines[0]),
+        "Diterima 2026-10-16 08:30:12 UTC · 2 baris"
+    );
+    assert_eq!(
+        plain(&lines[2]),
+        "1 │ Provider 'ollama' with model 'llama3'"
+    );
+    assert_eq!(plain(&lines[3]), "2 │ User: halo");
+}
+
+#[test]
+fn empty_turn_is_reported() {
+    let lines = render_turn_logs(&[], Some("2026-10-16T08:30:12+00:00"));
+    assert!(plain(&lines[2]).contains("tidak ada log"));
+}
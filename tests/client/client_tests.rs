@@ -1,9 +1,18 @@
-use antikythera_core::application::client::{ChatRequest, ClientConfig, McpClient};
-use antikythera_core::domain::types::MessagePart;
+use antikythera_core::application::agent::{Agent, AgentError, AgentOptions};
+use antikythera_core::application::client::{ChatRequest, ClientConfig, McpClient, McpError};
+use antikythera_core::application::locale::Locale;
+use antikythera_core::config::{
+    ReasoningConfig, ReasoningDelimiter, ServerConfig, SessionTitleConfig, SessionTitleStrategy,
+    ToolConfig, TransportType,
+};
+use antikythera_core::domain::types::{ChatMessage, MessagePart, MessageRole};
 use antikythera_core::infrastructure::model::{
     ModelError, ModelProvider, ModelRequest, ModelResponse,
 };
 use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 struct MockProvider {
     response: String,
@@ -42,6 +51,9 @@ async fn prepare_and_complete_chat_preserve_session_history() {
             raw_mode: false,
             bypass_template: false,
             force_json: false,
+            provider: None,
+            model: None,
+            metadata: HashMap::new(),
         })
         .await
         .unwrap();
@@ -55,6 +67,9 @@ async fn prepare_and_complete_chat_preserve_session_history() {
             raw_mode: false,
             bypass_template: false,
             force_json: false,
+            provider: None,
+            model: None,
+            metadata: HashMap::new(),
         })
         .await;
 
@@ -98,6 +113,9 @@ async fn chat_preserves_attachments_through_session() {
             raw_mode: false,
             bypass_template: false,
             force_json: false,
+            provider: None,
+            model: None,
+            metadata: HashMap::new(),
         })
         .await
         .unwrap();
@@ -111,6 +129,9 @@ async fn chat_preserves_attachments_through_session() {
             raw_mode: false,
             bypass_template: false,
             force_json: false,
+            provider: None,
+            model: None,
+            metadata: HashMap::new(),
         })
         .await;
 
@@ -133,3 +154,1109 @@ async fn chat_preserves_attachments_through_session() {
         "cek riwayat"
     );
 }
+
+fn filtered_server(allowed: &[&str], blocked: &[&str]) -> ServerConfig {
+    ServerConfig {
+        name: "time".to_string(),
+        transport: TransportType::Builtin,
+        allowed_tools: allowed.iter().map(|name| name.to_string()).collect(),
+        blocked_tools: blocked.iter().map(|name| name.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+fn tool(name: &str, server: Option<&str>) -> ToolConfig {
+    ToolConfig {
+        name: name.to_string(),
+        description: None,
+        server: server.map(str::to_string),
+        remote_name: None,
+        cacheable: false,
+    }
+}
+
+#[test]
+fn blocked_tools_take_precedence_over_allowed_tools() {
+    let server = filtered_server(&["get_time", "convert_time"], &["Convert_Time"]);
+
+    assert!(server.allows_tool("get_time"));
+    assert!(!server.allows_tool("convert_time"));
+    assert!(!server.allows_tool("list_zones"));
+    assert!(filtered_server(&[], &[]).allows_tool("list_zones"));
+}
+
+#[test]
+fn client_drops_tools_filtered_by_their_server() {
+    let config = ClientConfig::new("host", "gpt-host")
+        .with_servers(vec![filtered_server(&[], &["convert_time"])])
+        .with_tools(vec![
+            tool("get_time", Some("time")),
+            tool("convert_time", Some("time")),
+            tool("convert_time", Some("other")),
+            tool("search", None),
+        ]);
+
+    let client = McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        config,
+    );
+
+    let kept: Vec<(&str, Option<&str>)> = client
+        .tools()
+        .iter()
+        .map(|tool| (tool.name.as_str(), tool.server.as_deref()))
+        .collect();
+    assert_eq!(
+        kept,
+        vec![
+            ("get_time", Some("time")),
+            ("convert_time", Some("other")),
+            ("search", None),
+        ]
+    );
+}
+
+#[test]
+fn server_filters_match_the_remote_name() {
+    let renamed = ToolConfig {
+        remote_name: Some("time.GetCurrent".to_string()),
+        ..tool("get_time", Some("time"))
+    };
+    let by_remote = ClientConfig::new("host", "gpt-host")
+        .with_servers(vec![filtered_server(&[], &["time.GetCurrent"])]);
+    let by_local = ClientConfig::new("host", "gpt-host")
+        .with_servers(vec![filtered_server(&[], &["get_time"])]);
+    let allowed = ClientConfig::new("host", "gpt-host")
+        .with_servers(vec![filtered_server(&["TIME.GetCurrent"], &[])]);
+
+    assert!(!by_remote.permits_tool(&renamed));
+    assert!(by_local.permits_tool(&renamed));
+    assert!(allowed.permits_tool(&renamed));
+    assert!(!allowed.permits_tool(&tool("get_time", Some("time"))));
+}
+
+#[test]
+fn client_drops_every_tool_of_a_disabled_server() {
+    let config = ClientConfig::new("host", "gpt-host")
+        .with_servers(vec![ServerConfig {
+            enabled: false,
+            ..filtered_server(&[], &[])
+        }])
+        .with_tools(vec![
+            tool("get_time", Some("time")),
+            tool("search", Some("other")),
+        ]);
+
+    let client = McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        config,
+    );
+
+    let kept: Vec<&str> = client
+        .tools()
+        .iter()
+        .map(|tool| tool.name.as_str())
+        .collect();
+    assert_eq!(kept, vec!["search"]);
+}
+
+#[test]
+fn public_tools_narrow_the_listing_but_not_the_client_tools() {
+    let config = ClientConfig::new("host", "gpt-host")
+        .with_tools(vec![
+            tool("get_time", Some("time")),
+            tool("admin_reset", Some("time")),
+        ])
+        .with_public_tools(vec!["GET_TIME".to_string()]);
+    let client = McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        config,
+    );
+
+    let listed: Vec<&str> = client
+        .public_tools()
+        .iter()
+        .map(|tool| tool.name.as_str())
+        .collect();
+    assert_eq!(listed, vec!["get_time"]);
+    assert_eq!(client.tools().len(), 2);
+}
+
+/// Reports how many user turns it was shown, after a short delay that gives a
+/// concurrent request the chance to interleave.
+struct SlowCountingProvider;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for SlowCountingProvider {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        let users = request
+            .messages
+            .iter()
+            .filter(|message| message.role == MessageRole::User)
+            .count();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        Ok(ModelResponse::new(format!("users={users}"), None))
+    }
+}
+
+fn session_request(prompt: &str, session_id: &str) -> ChatRequest {
+    ChatRequest {
+        prompt: prompt.to_string(),
+        session_id: Some(session_id.to_string()),
+        ..ChatRequest::default()
+    }
+}
+
+#[tokio::test]
+async fn concurrent_chats_on_one_session_run_in_order() {
+    let client = McpClient::new(SlowCountingProvider, ClientConfig::new("host", "gpt-host"));
+
+    let (first, second) = tokio::join!(
+        client.chat(session_request("halo", "shared")),
+        client.chat(session_request("lanjut", "shared")),
+    );
+    let mut replies = vec![first.unwrap().content, second.unwrap().content];
+    replies.sort();
+    assert_eq!(replies, vec!["users=1", "users=2"]);
+
+    let prepared = client.prepare_chat(session_request("cek", "shared")).await;
+    let roles: Vec<MessageRole> = prepared
+        .model_request
+        .messages
+        .iter()
+        .filter(|message| message.role != MessageRole::System)
+        .map(|message| message.role)
+        .collect();
+    assert_eq!(
+        roles,
+        vec![
+            MessageRole::User,
+            MessageRole::Assistant,
+            MessageRole::User,
+            MessageRole::Assistant,
+            MessageRole::User,
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn different_sessions_are_not_serialized() {
+    let client = McpClient::new(SlowCountingProvider, ClientConfig::new("host", "gpt-host"));
+
+    let started = tokio::time::Instant::now();
+    let (first, second) = tokio::join!(
+        client.chat(session_request("halo", "alpha")),
+        client.chat(session_request("halo", "beta")),
+    );
+
+    assert_eq!(first.unwrap().content, "users=1");
+    assert_eq!(second.unwrap().content, "users=1");
+    // Both 20ms provider calls overlapped instead of running back to back.
+    assert!(started.elapsed() < std::time::Duration::from_millis(40));
+}
+
+/// Echoes the prompt after a short delay, fails prompts starting with
+/// "fail", and records the most calls it saw at once.
+#[derive(Default)]
+struct BatchProvider {
+    running: std::sync::atomic::AtomicUsize,
+    peak: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for BatchProvider {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        use std::sync::atomic::Ordering;
+
+        let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(running, Ordering::SeqCst);
+        let prompt = request
+            .messages
+            .iter()
+            .rev()
+            .find(|message| message.role == MessageRole::User)
+            .map(|message| message.content())
+            .unwrap_or_default();
+        // Earlier prompts take longer, so they finish after later ones.
+        let delay = 50u64.saturating_sub(prompt.len() as u64 * 5);
+        tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+        self.running.fetch_sub(1, Ordering::SeqCst);
+
+        if prompt.starts_with("fail") {
+            return Err(ModelError::Network {
+                provider: request.provider,
+                message: format!("rejected {prompt}"),
+            });
+        }
+        Ok(ModelResponse::new(format!("echo {prompt}"), None))
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn chat_batch_keeps_input_order_with_mixed_results() {
+    let provider = BatchProvider::default();
+    let peak = provider.peak.clone();
+    let client = McpClient::new(
+        provider,
+        ClientConfig::new("host", "gpt-host").with_max_concurrent_agents(2, None),
+    );
+    let prompts = ["a", "fail-b", "c-longer", "fail-d-longer", "e-longest"];
+
+    let results = client
+        .chat_batch(
+            prompts
+                .iter()
+                .map(|prompt| ChatRequest {
+                    prompt: prompt.to_string(),
+                    ..ChatRequest::default()
+                })
+                .collect(),
+        )
+        .await;
+
+    assert_eq!(results.len(), prompts.len());
+    for (prompt, result) in prompts.iter().zip(&results) {
+        match result {
+            Ok(chat) => assert_eq!(chat.content, format!("echo {prompt}")),
+            Err(McpError::Model(err)) => {
+                assert!(prompt.starts_with("fail"));
+                assert!(err.to_string().contains(&format!("rejected {prompt}")));
+            }
+            Err(other) => panic!("unexpected error: {other}"),
+        }
+    }
+    assert_eq!(results.iter().filter(|result| result.is_err()).count(), 2);
+
+    let mut sessions: Vec<&str> = results
+        .iter()
+        .flatten()
+        .map(|chat| chat.session_id.as_str())
+        .collect();
+    sessions.sort();
+    sessions.dedup();
+    assert_eq!(sessions.len(), 3);
+    assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+/// Answers as whichever known provider the request names.
+struct RoutingProvider;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for RoutingProvider {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        match request.provider.as_str() {
+            "host" | "backup" => Ok(ModelResponse::new(
+                format!(r#"{{"action":"final","response":"{}"}}"#, request.provider),
+                None,
+            )),
+            other => Err(ModelError::provider_not_found(other)),
+        }
+    }
+}
+
+#[tokio::test]
+async fn request_provider_overrides_the_default() {
+    let client = McpClient::new(RoutingProvider, ClientConfig::new("host", "gpt-host"));
+
+    let result = client
+        .chat(ChatRequest {
+            prompt: "halo".to_string(),
+            provider: Some("backup".to_string()),
+            ..ChatRequest::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.provider, "backup");
+
+    let error = client
+        .chat(ChatRequest {
+            prompt: "halo".to_string(),
+            provider: Some("missing".to_string()),
+            ..ChatRequest::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        McpError::Model(ModelError::ProviderNotFound { ref provider }) if provider == "missing"
+    ));
+}
+
+#[tokio::test]
+async fn agent_options_provider_reaches_every_model_call() {
+    let client = Arc::new(McpClient::new(
+        RoutingProvider,
+        ClientConfig::new("host", "gpt-host"),
+    ));
+
+    let outcome = Agent::new(client.clone())
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                provider: Some("backup".to_string()),
+                ..AgentOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome.response, serde_json::json!("backup"));
+
+    let error = Agent::new(client)
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                provider: Some("missing".to_string()),
+                ..AgentOptions::default()
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        AgentError::Client(McpError::Model(ModelError::ProviderNotFound { .. }))
+    ));
+}
+
+/// Replies with the model each request was sent to.
+struct ModelEchoProvider;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for ModelEchoProvider {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        Ok(ModelResponse::new(
+            format!(r#"{{"action":"final","response":"{}"}}"#, request.model),
+            None,
+        ))
+    }
+}
+
+#[tokio::test]
+async fn request_model_overrides_the_default_within_one_session() {
+    let client = Arc::new(McpClient::new(
+        ModelEchoProvider,
+        ClientConfig::new("host", "gpt-host"),
+    ));
+
+    let first = client
+        .chat(session_request("halo", "s-model"))
+        .await
+        .unwrap();
+    assert!(first.content.contains("gpt-host"));
+
+    let second = client
+        .chat(ChatRequest {
+            model: Some("gpt-mini".to_string()),
+            metadata: HashMap::new(),
+            ..session_request("lanjut", "s-model")
+        })
+        .await
+        .unwrap();
+    assert!(second.content.contains("gpt-mini"));
+
+    // The override keeps the session's history; it only changes the model.
+    let prepared = client.prepare_chat(session_request("cek", "s-model")).await;
+    assert_eq!(prepared.model, "gpt-host");
+    let users = prepared
+        .model_request
+        .messages
+        .iter()
+        .filter(|message| message.role == MessageRole::User)
+        .count();
+    assert_eq!(users, 3);
+
+    let outcome = Agent::new(client)
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                model: Some("gpt-mini".to_string()),
+                ..AgentOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome.response, serde_json::json!("gpt-mini"));
+}
+
+#[tokio::test]
+async fn request_metadata_fills_system_prompt_placeholders() {
+    let client = McpClient::new(
+        ModelEchoProvider,
+        ClientConfig::new("host", "gpt-host").with_system_prompt(
+            "Pengguna {{metadata.user_id}}{{metadata.tidak_ada}}, bahasa {{metadata.locale}}.",
+        ),
+    );
+
+    let prepared = client
+        .prepare_chat(ChatRequest {
+            prompt: "halo".to_string(),
+            metadata: HashMap::from([
+                ("user_id".to_string(), "u-42".to_string()),
+                ("locale".to_string(), "{{metadata.user_id}}".to_string()),
+            ]),
+            ..ChatRequest::default()
+        })
+        .await;
+
+    let system = prepared
+        .model_request
+        .messages
+        .iter()
+        .find(|message| message.role == MessageRole::System)
+        .expect("system prompt")
+        .content();
+    assert!(
+        system.contains("Pengguna u-42, bahasa {{metadata.user_id}}."),
+        "{system}"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn forked_session_copies_history_and_then_diverges() {
+    let client = McpClient::new(SlowCountingProvider, ClientConfig::new("host", "gpt-host"));
+    client.chat(session_request("halo", "main")).await.unwrap();
+
+    let forked = client.fork_session("main").await.expect("main exists");
+    assert_ne!(forked, "main");
+
+    let on_fork = client
+        .chat(session_request("coba A", &forked))
+        .await
+        .unwrap();
+    assert_eq!(on_fork.content, "users=2");
+    client
+        .chat(session_request("coba A lagi", &forked))
+        .await
+        .unwrap();
+
+    // The original session did not see the forked turns.
+    let on_main = client
+        .chat(session_request("coba B", "main"))
+        .await
+        .unwrap();
+    assert_eq!(on_main.content, "users=2");
+}
+
+#[tokio::test]
+async fn forking_an_unknown_session_returns_none() {
+    let client = McpClient::new(SlowCountingProvider, ClientConfig::new("host", "gpt-host"));
+    assert!(client.fork_session("tidak-ada").await.is_none());
+}
+
+#[tokio::test(start_paused = true)]
+async fn popping_the_last_exchange_drops_it_from_the_next_context() {
+    let client = McpClient::new(SlowCountingProvider, ClientConfig::new("host", "gpt-host"));
+    client.chat(session_request("halo", "main")).await.unwrap();
+    client.chat(session_request("lagi", "main")).await.unwrap();
+
+    assert_eq!(client.pop_last_exchange("main").await, 2);
+
+    // Regenerated: one earlier exchange plus the prompt sent again.
+    let again = client.chat(session_request("lagi", "main")).await.unwrap();
+    assert_eq!(again.content, "users=2");
+    assert_eq!(client.pop_last_exchange("tidak-ada").await, 0);
+}
+
+/// Streams a fixed reply in three chunks.
+struct ChunkProvider;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for ChunkProvider {
+    async fn chat(&self, _request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        Ok(ModelResponse::new("Halo dunia!".to_string(), None))
+    }
+
+    async fn chat_stream(
+        &self,
+        _request: ModelRequest,
+        on_chunk: &(dyn for<'c> Fn(&'c str) + Send + Sync),
+    ) -> Result<ModelResponse, ModelError> {
+        for chunk in ["Halo", " dunia", "!"] {
+            on_chunk(chunk);
+        }
+        Ok(ModelResponse::new("Halo dunia!".to_string(), None))
+    }
+}
+
+#[tokio::test]
+async fn chat_streaming_passes_each_chunk_and_persists_the_whole_reply_once() {
+    let client = McpClient::new(ChunkProvider, ClientConfig::new("host", "gpt-host"));
+    let chunks = std::sync::Mutex::new(Vec::new());
+
+    let result = client
+        .chat_streaming(session_request("sapa aku", "stream"), |chunk| {
+            chunks.lock().unwrap().push(chunk.to_string());
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.into_inner().unwrap(), vec!["Halo", " dunia", "!"]);
+    assert_eq!(result.content, "Halo dunia!");
+    assert_eq!(result.session_id, "stream");
+    assert!(
+        result
+            .logs
+            .iter()
+            .any(|entry| entry == "Streamed reply in 3 chunks"),
+        "{:?}",
+        result.logs
+    );
+
+    let next = client.prepare_chat(session_request("lagi", "stream")).await;
+    let replies: Vec<String> = next
+        .model_request
+        .messages
+        .iter()
+        .filter(|message| message.role == MessageRole::Assistant)
+        .map(|message| message.content())
+        .collect();
+    assert_eq!(replies, vec!["Halo dunia!"]);
+}
+
+#[tokio::test]
+async fn chat_streaming_without_provider_streaming_yields_one_chunk() {
+    let client = McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        ClientConfig::new("host", "gpt-host"),
+    );
+    let chunks = std::sync::Mutex::new(Vec::new());
+
+    let result = client
+        .chat_streaming(session_request("halo", "plain"), |chunk| {
+            chunks.lock().unwrap().push(chunk.to_string());
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.into_inner().unwrap(), vec!["plain:siap"]);
+    assert_eq!(result.content, "plain:siap");
+}
+
+#[tokio::test]
+async fn loaded_history_is_in_chronological_order() {
+    use chrono::{Duration, Utc};
+
+    let client = McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        ClientConfig::new("host", "gpt-host"),
+    );
+    let start = Utc::now() - Duration::minutes(5);
+    let reply = |content: &str, minutes: i64| {
+        let mut response = ModelResponse::new(content.to_string(), None);
+        response.message = response
+            .message
+            .with_created_at(start + Duration::minutes(minutes));
+        response
+    };
+
+    let mut first = client
+        .prepare_chat(session_request("pertama", "ordered"))
+        .await;
+    first.user_message = first.user_message.with_created_at(start);
+    let mut second = client
+        .prepare_chat(session_request("kedua", "ordered"))
+        .await;
+    second.user_message = second
+        .user_message
+        .with_created_at(start + Duration::minutes(2));
+
+    // The later exchange is stored first, as after a restart or a slow turn.
+    client
+        .complete_chat(second, reply("jawaban kedua", 3))
+        .await
+        .unwrap();
+    client
+        .complete_chat(first, reply("jawaban pertama", 1))
+        .await
+        .unwrap();
+
+    let prepared = client.prepare_chat(session_request("cek", "ordered")).await;
+    let history: Vec<_> = prepared
+        .model_request
+        .messages
+        .iter()
+        .filter(|message| message.role != MessageRole::System)
+        .collect();
+    assert_eq!(
+        history
+            .iter()
+            .map(|message| message.content())
+            .collect::<Vec<_>>(),
+        vec![
+            "pertama",
+            "jawaban pertama",
+            "kedua",
+            "jawaban kedua",
+            "cek"
+        ]
+    );
+    assert!(
+        history
+            .windows(2)
+            .all(|pair| pair[0].created_at <= pair[1].created_at)
+    );
+}
+
+/// Answers title requests (no session id) with `title_reply` and chat turns
+/// with "ok"; records the model of every title request.
+struct TitleProvider {
+    title_reply: Option<String>,
+    title_models: Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for TitleProvider {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        if request.session_id.is_some() {
+            return Ok(ModelResponse::new("ok".to_string(), None));
+        }
+        self.title_models.lock().unwrap().push(request.model);
+        match &self.title_reply {
+            Some(reply) => Ok(ModelResponse::new(reply.clone(), None)),
+            None => Err(ModelError::provider_not_found("mock")),
+        }
+    }
+}
+
+/// A client whose title requests are answered with `title_reply` (an error
+/// when `None`), and the models those requests named.
+fn titled_client(
+    title_reply: Option<&str>,
+    session_title: SessionTitleConfig,
+) -> (McpClient<TitleProvider>, Arc<std::sync::Mutex<Vec<String>>>) {
+    let title_models = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = McpClient::new(
+        TitleProvider {
+            title_reply: title_reply.map(str::to_string),
+            title_models: title_models.clone(),
+        },
+        ClientConfig::new("mock", "mock-model").with_session_title(session_title),
+    );
+    (client, title_models)
+}
+
+const LONG_PROMPT: &str = "Tolong   jelaskan\ncara kerja protokol MCP untuk klien yang memanggil tool dari beberapa server";
+
+#[tokio::test]
+async fn first_message_is_truncated_into_the_session_title_by_default() {
+    let (client, title_models) = titled_client(Some("unused"), SessionTitleConfig::default());
+
+    let first = client
+        .chat(session_request(LONG_PROMPT, "judul"))
+        .await
+        .unwrap();
+    assert_eq!(
+        first.title.as_deref(),
+        Some("Tolong jelaskan cara kerja protokol MCP untuk klien yang mem")
+    );
+    assert!(title_models.lock().unwrap().is_empty());
+
+    // Later turns keep the first title.
+    let second = client
+        .chat(session_request("pertanyaan lain", "judul"))
+        .await
+        .unwrap();
+    assert_eq!(second.title, first.title);
+    assert_eq!(client.session_title("judul").await, first.title);
+    assert_eq!(client.session_title("tidak-ada").await, None);
+}
+
+#[tokio::test]
+async fn summarize_strategy_asks_the_configured_model_and_falls_back_to_truncation() {
+    let summarize = SessionTitleConfig {
+        strategy: SessionTitleStrategy::Summarize,
+        model: Some("judul-kecil".to_string()),
+        max_chars: 60,
+    };
+    let (client, title_models) =
+        titled_client(Some("Title: \"Cara Kerja MCP\"\n"), summarize.clone());
+    let result = client
+        .chat(session_request(LONG_PROMPT, "ringkas"))
+        .await
+        .unwrap();
+    assert_eq!(result.title.as_deref(), Some("Cara Kerja MCP"));
+    assert_eq!(*title_models.lock().unwrap(), vec!["judul-kecil"]);
+
+    let (failing, _) = titled_client(None, summarize);
+    let result = failing
+        .chat(session_request("halo dunia", "gagal"))
+        .await
+        .unwrap();
+    assert_eq!(result.title.as_deref(), Some("halo dunia"));
+}
+
+#[tokio::test]
+async fn agent_runs_title_their_session_from_the_user_prompt() {
+    let client = McpClient::new(RoutingProvider, ClientConfig::new("host", "mock-model"));
+
+    let outcome = Agent::new(Arc::new(client))
+        .run("jam berapa sekarang?".to_string(), AgentOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(outcome.title.as_deref(), Some("jam berapa sekarang?"));
+}
+
+#[tokio::test]
+async fn reasoning_is_stripped_before_the_reply_is_returned_or_stored() {
+    let client = McpClient::new(
+        MockProvider {
+            response: "unused".to_string(),
+        },
+        ClientConfig::new("host", "gpt-host").with_reasoning(ReasoningConfig {
+            delimiters: vec![
+                ReasoningDelimiter::new("<think>", "</think>"),
+                ReasoningDelimiter::new("[[reason]]", "[[/reason]]"),
+            ],
+            log: false,
+        }),
+    );
+    let prepared = client.prepare_chat(session_request("halo", "nalar")).await;
+
+    // The opening tag came with the prompt template, so only the close shows up.
+    let reply = "menimbang sapaan</think>Halo! [[reason]]tambahkan tawaran bantuan[[/reason]]Ada yang bisa dibantu?";
+    let result = client
+        .complete_chat(prepared, ModelResponse::new(reply.to_string(), None))
+        .await
+        .unwrap();
+
+    assert_eq!(result.content, "Halo! Ada yang bisa dibantu?");
+    assert!(
+        !result
+            .logs
+            .iter()
+            .any(|line| line.starts_with("Reasoning:"))
+    );
+    let next = client
+        .prepare_chat(session_request("lanjut", "nalar"))
+        .await;
+    assert!(
+        next.model_request
+            .messages
+            .iter()
+            .any(|message| message.content() == "Halo! Ada yang bisa dibantu?")
+    );
+}
+
+/// Fails the test if the model is ever called.
+struct UnreachableProvider;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for UnreachableProvider {
+    async fn chat(&self, _request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        panic!("prepare_chat must not call the model");
+    }
+}
+
+#[tokio::test]
+async fn prepare_chat_previews_the_request_without_touching_the_session() {
+    let client = McpClient::new(
+        UnreachableProvider,
+        ClientConfig::new("host", "gpt-host").with_system_prompt("Jawab singkat."),
+    );
+
+    let first = client
+        .prepare_chat(session_request("halo", "preview"))
+        .await;
+    let second = client
+        .prepare_chat(session_request("halo", "preview"))
+        .await;
+
+    assert_eq!(
+        first.model_request.messages.len(),
+        second.model_request.messages.len()
+    );
+    let json = serde_json::to_value(&first.model_request).unwrap();
+    assert_eq!(json["session_id"], "preview");
+    assert_eq!(json["messages"][1]["parts"][0]["text"], "halo");
+    assert_eq!(first.model_request.provider, "host");
+    assert_eq!(first.model_request.model, "gpt-host");
+    let roles: Vec<MessageRole> = first
+        .model_request
+        .messages
+        .iter()
+        .map(|message| message.role)
+        .collect();
+    assert_eq!(roles, vec![MessageRole::System, MessageRole::User]);
+}
+
+#[tokio::test]
+async fn provider_prompts_override_the_global_prompts_for_that_provider() {
+    use antikythera_core::config::PromptsConfig;
+
+    let client = McpClient::new(
+        ModelEchoProvider,
+        ClientConfig::new("gemini", "gemini-2.0-flash")
+            .with_prompts(PromptsConfig {
+                template: Some("Global. {{custom_instruction}}".to_string()),
+                json_retry_message: Some("Ulangi sebagai JSON.".to_string()),
+                ..PromptsConfig::default()
+            })
+            .with_provider_prompts(
+                "ollama",
+                PromptsConfig {
+                    template: Some("Balas hanya JSON. {{custom_instruction}}".to_string()),
+                    ..PromptsConfig::default()
+                },
+            )
+            .with_system_prompt("Ringkas."),
+    );
+    let system_prompt = |provider: Option<&str>| {
+        let request = ChatRequest {
+            prompt: "halo".to_string(),
+            provider: provider.map(str::to_string),
+            ..ChatRequest::default()
+        };
+        let client = &client;
+        async move {
+            client
+                .prepare_chat(request)
+                .await
+                .model_request
+                .messages
+                .iter()
+                .find(|message| message.role == MessageRole::System)
+                .expect("system prompt")
+                .content()
+        }
+    };
+
+    assert_eq!(
+        system_prompt(Some("ollama")).await,
+        "Balas hanya JSON. Ringkas."
+    );
+    assert_eq!(system_prompt(None).await, "Global. Ringkas.");
+
+    // Fields the override leaves unset still come from the global prompts.
+    let prompts = client.prompts_for(Some("ollama"));
+    assert_eq!(prompts.json_retry_message(), "Ulangi sebagai JSON.");
+}
+
+/// System prompt `prepare_chat` composes for a plain request on `config`.
+async fn composed_system_prompt(config: ClientConfig) -> String {
+    let client = McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        config,
+    );
+    client
+        .prepare_chat(ChatRequest {
+            prompt: "halo".to_string(),
+            ..ChatRequest::default()
+        })
+        .await
+        .model_request
+        .messages
+        .iter()
+        .find(|message| message.role == MessageRole::System)
+        .map(|message| message.content())
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn fallback_guidance_is_in_the_prompt_with_and_without_tools() {
+    use antikythera_core::config::PromptsConfig;
+
+    let prompts = PromptsConfig {
+        fallback_guidance: Some("Tolak permintaan di luar cakupan.".to_string()),
+        ..PromptsConfig::default()
+    };
+
+    let without_tools =
+        composed_system_prompt(ClientConfig::new("host", "gpt-host").with_prompts(prompts.clone()))
+            .await;
+    assert!(without_tools.contains("Tolak permintaan di luar cakupan."));
+
+    let with_tools = composed_system_prompt(
+        ClientConfig::new("host", "gpt-host")
+            .with_prompts(prompts)
+            .with_tools(vec![tool("get_time", None)]),
+    )
+    .await;
+    assert!(with_tools.contains("- get_time: No description available."));
+    assert!(with_tools.contains("Tolak permintaan di luar cakupan."));
+}
+
+#[tokio::test]
+async fn always_fallback_guidance_covers_templates_without_tool_guidance() {
+    use antikythera_core::config::PromptsConfig;
+
+    let prompts = |always: Option<bool>| PromptsConfig {
+        template: Some("Asisten toko. {{custom_instruction}}".to_string()),
+        fallback_guidance: Some("Tolak permintaan di luar cakupan.".to_string()),
+        always_fallback_guidance: always,
+        ..PromptsConfig::default()
+    };
+    let config = |always| {
+        ClientConfig::new("host", "gpt-host")
+            .with_prompts(prompts(always))
+            .with_system_prompt("Ringkas.")
+    };
+
+    assert_eq!(
+        composed_system_prompt(config(None)).await,
+        "Asisten toko. Ringkas."
+    );
+    assert_eq!(
+        composed_system_prompt(config(Some(true))).await,
+        "Asisten toko. Ringkas.\n\nTolak permintaan di luar cakupan."
+    );
+
+    // An empty template still gets it after the custom instruction.
+    let empty_template = ClientConfig::new("host", "gpt-host")
+        .with_prompts(PromptsConfig {
+            template: Some(String::new()),
+            ..prompts(Some(true))
+        })
+        .with_system_prompt("Ringkas.");
+    assert_eq!(
+        composed_system_prompt(empty_template).await,
+        "Ringkas.\n\nTolak permintaan di luar cakupan."
+    );
+
+    // The default template already carries it once through {{tool_guidance}}.
+    let default_template = composed_system_prompt(
+        ClientConfig::new("host", "gpt-host").with_prompts(PromptsConfig {
+            template: None,
+            ..prompts(Some(true))
+        }),
+    )
+    .await;
+    assert_eq!(
+        default_template
+            .matches("Tolak permintaan di luar cakupan.")
+            .count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn new_sessions_start_with_the_seed_history() {
+    let seed: Vec<ChatMessage> = serde_json::from_str(
+        r#"[
+            {"role":"user","parts":[{"type":"text","text":"Berapa 2+2?"}]},
+            {"role":"assistant","parts":[{"type":"text","text":"4"}]}
+        ]"#,
+    )
+    .unwrap();
+    let client = McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        ClientConfig::new("host", "gpt-host").with_seed_history(seed),
+    );
+    let contents = |messages: &[ChatMessage]| -> Vec<String> {
+        messages
+            .iter()
+            .filter(|message| message.role != MessageRole::System)
+            .map(ChatMessage::content)
+            .collect()
+    };
+
+    let fresh = client.prepare_chat(session_request("halo", "baru")).await;
+    assert_eq!(
+        contents(&fresh.model_request.messages),
+        vec!["Berapa 2+2?", "4", "halo"]
+    );
+
+    client.chat(session_request("halo", "baru")).await.unwrap();
+    let next = client.prepare_chat(session_request("lanjut", "baru")).await;
+    assert_eq!(
+        contents(&next.model_request.messages),
+        vec!["Berapa 2+2?", "4", "halo", "baru:siap", "lanjut"]
+    );
+}
+
+#[tokio::test]
+async fn sessions_idle_past_the_ttl_start_fresh() {
+    let client = McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        ClientConfig::new("host", "gpt-host").with_session_ttl(Duration::from_millis(50)),
+    );
+    let contents = |messages: &[ChatMessage]| -> Vec<String> {
+        messages
+            .iter()
+            .filter(|message| message.role != MessageRole::System)
+            .map(ChatMessage::content)
+            .collect()
+    };
+
+    client.chat(session_request("halo", "lama")).await.unwrap();
+    let kept = client.prepare_chat(session_request("lanjut", "lama")).await;
+    assert_eq!(
+        contents(&kept.model_request.messages),
+        vec!["halo", "lama:siap", "lanjut"]
+    );
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let expired = client.prepare_chat(session_request("lagi", "lama")).await;
+    assert_eq!(contents(&expired.model_request.messages), vec!["lagi"]);
+
+    client.chat(session_request("lagi", "lama")).await.unwrap();
+    let reused = client.prepare_chat(session_request("lanjut", "lama")).await;
+    assert_eq!(
+        contents(&reused.model_request.messages),
+        vec!["lagi", "lama:siap", "lanjut"]
+    );
+}
+
+#[tokio::test]
+async fn prompts_over_the_limit_are_rejected_before_the_provider() {
+    let client = Arc::new(McpClient::new(
+        MockProvider {
+            response: "siap".to_string(),
+        },
+        ClientConfig::new("host", "gpt-host").with_max_prompt_chars(5),
+    ));
+
+    let err = client
+        .chat(session_request("terlalu panjang", "batas"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, McpError::PromptTooLong { chars: 15, max: 5 }));
+    assert_eq!(err.code(), "prompt_too_long");
+    assert_eq!(
+        err.user_message_in(Locale::En),
+        "The message is 15 characters long, over the limit of 5. Please shorten it and try again."
+    );
+
+    let agent_err = Agent::new(client.clone())
+        .run("terlalu panjang".to_string(), AgentOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        agent_err,
+        AgentError::Client(McpError::PromptTooLong { .. })
+    ));
+
+    let ok = client.chat(session_request("halo", "batas")).await.unwrap();
+    assert_eq!(ok.content, "batas:siap");
+    // The rejected prompt never reached the session history.
+    let next = client.prepare_chat(session_request("lagi", "batas")).await;
+    let history: Vec<String> = next
+        .model_request
+        .messages
+        .iter()
+        .filter(|message| message.role != MessageRole::System)
+        .map(ChatMessage::content)
+        .collect();
+    assert_eq!(history, vec!["halo", "batas:siap", "lagi"]);
+}
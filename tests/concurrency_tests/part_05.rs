@@ -41,6 +41,9 @@ async fn test_high_concurrency_execution() {
                     raw_mode: false,
                     bypass_template: false,
                     force_json: true,
+                    provider: None,
+                    model: None,
+                    metadata: std::collections::HashMap::new(),
                 })
                 .await;
 
@@ -10,6 +10,14 @@ fn test_model_provider_config_basic() {
         endpoint: "https://api.example.com".to_string(),
         api_key: Some("secret".to_string()),
         api_path: Some("/v1beta".to_string()),
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![ModelInfo {
             name: "model-1".to_string(),
             display_name: Some("Model 1".to_string()),
@@ -29,6 +37,14 @@ fn test_model_provider_config_no_api_key() {
         endpoint: "http://localhost:11434".to_string(),
         api_key: None,
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
 
@@ -43,6 +59,14 @@ fn test_model_provider_config_multiple_models() {
         endpoint: "https://api.openai.com".to_string(),
         api_key: Some("key".to_string()),
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![
             ModelInfo { name: "gpt-4".to_string(), display_name: None },
             ModelInfo { name: "gpt-3.5".to_string(), display_name: Some("GPT-3.5 Turbo".to_string()) },
@@ -60,6 +84,14 @@ fn test_model_provider_config_unicode_id() {
         endpoint: "https://api.example.com".to_string(),
         api_key: None,
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
 
@@ -74,6 +106,14 @@ fn test_model_provider_config_unicode_endpoint() {
         endpoint: "https://api.example.com/\u{6a21}\u{578b}".to_string(),
         api_key: None,
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
 
@@ -89,6 +129,14 @@ fn test_model_provider_config_very_long_id() {
         endpoint: "https://api.example.com".to_string(),
         api_key: None,
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
 
@@ -104,6 +152,14 @@ fn test_model_provider_config_very_long_api_key() {
         endpoint: "https://api.example.com".to_string(),
         api_key: Some(long_key.clone()),
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
 
@@ -119,6 +175,14 @@ fn test_model_provider_config_very_long_endpoint() {
         endpoint: long_url.clone(),
         api_key: None,
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
 
@@ -133,6 +197,14 @@ fn test_model_provider_config_clone() {
         endpoint: "https://api.example.com".to_string(),
         api_key: Some("secret".to_string()),
         api_path: Some("/v1".to_string()),
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
 
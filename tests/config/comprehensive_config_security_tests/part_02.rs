@@ -6,15 +6,10 @@
 fn test_server_config_stdio() {
     let config = ServerConfig {
         name: "test-server".to_string(),
-        transport: TransportType::Stdio,
         command: Some(PathBuf::from("/usr/bin/server")),
         args: vec!["--verbose".to_string()],
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
         default_timezone: Some("UTC".to_string()),
-        default_city: None,
+        ..Default::default()
     };
 
     assert!(config.is_stdio());
@@ -26,14 +21,8 @@ fn test_server_config_http() {
     let config = ServerConfig {
         name: "remote-server".to_string(),
         transport: TransportType::Http,
-        command: None,
-        args: vec![],
-        env: HashMap::new(),
-        workdir: None,
         url: Some("http://localhost:3000".to_string()),
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert!(config.is_http());
@@ -48,15 +37,10 @@ fn test_server_config_with_environment_variables() {
 
     let config = ServerConfig {
         name: "server".to_string(),
-        transport: TransportType::Stdio,
         command: Some(PathBuf::from("node")),
         args: vec!["index.js".to_string()],
         env,
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert_eq!(config.env.len(), 2);
@@ -71,14 +55,9 @@ fn test_server_config_with_http_headers() {
     let config = ServerConfig {
         name: "api-server".to_string(),
         transport: TransportType::Http,
-        command: None,
-        args: vec![],
-        env: HashMap::new(),
-        workdir: None,
         url: Some("https://api.example.com".to_string()),
         headers,
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert_eq!(config.headers.len(), 2);
@@ -88,15 +67,8 @@ fn test_server_config_with_http_headers() {
 fn test_server_config_unicode_name() {
     let config = ServerConfig {
         name: "\u{30b5}\u{30fc}\u{30d0}\u{30fc}_\u{1f680}".to_string(),
-        transport: TransportType::Stdio,
         command: Some(PathBuf::from("server")),
-        args: vec![],
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert_eq!(config.name, "\u{30b5}\u{30fc}\u{30d0}\u{30fc}_\u{1f680}");
@@ -107,15 +79,8 @@ fn test_server_config_very_long_name() {
     let long_name = "s".repeat(100_000);
     let config = ServerConfig {
         name: long_name.clone(),
-        transport: TransportType::Stdio,
         command: Some(PathBuf::from("server")),
-        args: vec![],
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert_eq!(config.name.len(), 100_000);
@@ -130,15 +95,9 @@ fn test_server_config_many_args() {
 
     let config = ServerConfig {
         name: "server".to_string(),
-        transport: TransportType::Stdio,
         command: Some(PathBuf::from("cmd")),
         args,
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert_eq!(config.args.len(), 1000);
@@ -153,15 +112,9 @@ fn test_server_config_many_env_vars() {
 
     let config = ServerConfig {
         name: "server".to_string(),
-        transport: TransportType::Stdio,
         command: Some(PathBuf::from("cmd")),
-        args: vec![],
         env,
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert_eq!(config.env.len(), 500);
@@ -172,14 +125,11 @@ fn test_server_config_clone() {
     let original = ServerConfig {
         name: "server".to_string(),
         transport: TransportType::Http,
-        command: None,
-        args: vec![],
-        env: HashMap::new(),
         workdir: Some(PathBuf::from("/tmp")),
         url: Some("http://localhost:3000".to_string()),
-        headers: HashMap::new(),
         default_timezone: Some("UTC".to_string()),
         default_city: Some("New York".to_string()),
+        ..Default::default()
     };
 
     let cloned = original.clone();
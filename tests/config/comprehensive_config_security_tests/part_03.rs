@@ -20,15 +20,9 @@ fn test_sql_injection_in_provider_id() {
 fn test_command_injection_in_server_args() {
     let config = ServerConfig {
         name: "server".to_string(),
-        transport: TransportType::Stdio,
         command: Some(PathBuf::from("node")),
         args: vec!["'; rm -rf /; echo '".to_string()],
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     // Config layer stores as-is; caller validates before execution
@@ -39,15 +33,8 @@ fn test_command_injection_in_server_args() {
 fn test_path_traversal_in_command() {
     let config = ServerConfig {
         name: "server".to_string(),
-        transport: TransportType::Stdio,
         command: Some(PathBuf::from("../../../../etc/passwd")),
-        args: vec![],
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert_eq!(config.command.as_ref().unwrap(), &PathBuf::from("../../../../etc/passwd"));
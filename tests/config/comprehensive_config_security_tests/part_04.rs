@@ -19,15 +19,7 @@ fn test_empty_provider_id() {
 fn test_empty_server_name() {
     let config = ServerConfig {
         name: "".to_string(),
-        transport: TransportType::Stdio,
-        command: None,
-        args: vec![],
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     assert_eq!(config.name, "");
@@ -24,13 +24,8 @@ fn test_many_servers_in_config() {
             name: format!("server-{}", i),
             transport: if i % 2 == 0 { TransportType::Stdio } else { TransportType::Http },
             command: Some(PathBuf::from(format!("/bin/server-{}", i))),
-            args: vec![],
-            env: HashMap::new(),
-            workdir: None,
             url: Some(format!("http://localhost:{}", 3000 + i)),
-            headers: HashMap::new(),
-            default_timezone: None,
-            default_city: None,
+            ..Default::default()
         };
 
         assert_eq!(config.name, format!("server-{}", i));
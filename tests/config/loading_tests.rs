@@ -1,9 +1,11 @@
 // Config loading tests - testing AppConfig::load behavior.
 //
-// The application stores all configuration as a single Postcard binary (app.pc).
-// Tests verify: file-not-found error, self-heal on corrupt data, and correct
-// field values on a valid binary.
+// The application stores all configuration as a single Postcard binary (app.pc)
+// or as TOML (combined config.toml, or split client.toml + model.toml).
+// Tests verify: file-not-found error, self-heal on corrupt data, correct
+// field values on a valid binary, and TOML source precedence.
 
+use antikythera_core::config::loader::{ConfigSource, detect_config_source, load_config_in};
 use antikythera_core::config::postcard_config::{
     ModelConfig, PostcardAppConfig, config_to_postcard,
 };
@@ -31,9 +33,13 @@ fn minimal_postcard_config() -> PostcardAppConfig {
     }
 }
 
-// Split into 5 parts for consistent test organization.
+// Split into 9 parts for consistent test organization.
 include!("loading_tests/part_01.rs");
 include!("loading_tests/part_02.rs");
 include!("loading_tests/part_03.rs");
 include!("loading_tests/part_04.rs");
 include!("loading_tests/part_05.rs");
+include!("loading_tests/part_06.rs");
+include!("loading_tests/part_07.rs");
+include!("loading_tests/part_08.rs");
+include!("loading_tests/part_09.rs");
@@ -0,0 +1,397 @@
+const CLIENT_TOML: &str = r#"
+tools = ["get_current_time"]
+
+[server]
+cors_origins = ["http://localhost:3000"]
+
+[[providers]]
+id = "ollama"
+type = "ollama"
+endpoint = "http://127.0.0.1:11434"
+
+[[servers]]
+name = "time"
+command = "mcp-time"
+"#;
+
+const MODEL_TOML: &str = r#"
+default_provider = "ollama"
+model = "llama3"
+prompt_template = "Jawab singkat."
+"#;
+
+#[test]
+fn loads_combined_toml_file() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    // Scalars must precede tables in a single TOML document.
+    fs::write(&path, format!("{MODEL_TOML}\n{CLIENT_TOML}")).expect("write config.toml");
+
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+
+    assert_eq!(config.default_provider, "ollama");
+    assert_eq!(config.model, "llama3");
+    assert_eq!(config.prompt_template(), "Jawab singkat.");
+    assert_eq!(config.servers.len(), 1);
+    assert_eq!(config.servers[0].name, "time");
+    assert_eq!(config.tools.len(), 1);
+    assert_eq!(config.rest_server.cors_origins, vec!["http://localhost:3000"]);
+}
+
+#[test]
+fn loads_split_toml_files_from_client_path() {
+    let dir = tempdir().expect("tempdir");
+    fs::write(dir.path().join("client.toml"), CLIENT_TOML).expect("write client.toml");
+    fs::write(dir.path().join("model.toml"), MODEL_TOML).expect("write model.toml");
+
+    let config =
+        AppConfig::load(Some(&dir.path().join("client.toml"))).expect("load split config");
+
+    assert_eq!(config.model, "llama3");
+    assert_eq!(config.tools.len(), 1);
+    assert_eq!(config.tools[0].name, "get_current_time");
+}
+
+#[test]
+fn combined_toml_takes_precedence_over_split_and_postcard() {
+    let dir = tempdir().expect("tempdir");
+    let root = dir.path();
+    assert_eq!(
+        detect_config_source(root),
+        ConfigSource::Postcard(root.join("app.pc"))
+    );
+
+    fs::create_dir(root.join("config")).expect("create config dir");
+    fs::write(root.join("config/client.toml"), CLIENT_TOML).expect("write client.toml");
+    assert_eq!(
+        detect_config_source(root),
+        ConfigSource::SplitToml {
+            client: root.join("config/client.toml"),
+            model: None,
+        }
+    );
+
+    fs::write(root.join("config/config.toml"), MODEL_TOML).expect("write config.toml");
+    assert_eq!(
+        detect_config_source(root),
+        ConfigSource::CombinedToml(root.join("config/config.toml"))
+    );
+}
+
+#[test]
+fn reports_toml_parse_errors() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "model = ").expect("write config.toml");
+
+    let result = AppConfig::load(Some(&path));
+    assert!(matches!(result, Err(ConfigError::Parse { .. })));
+}
+
+#[test]
+fn session_title_settings_load_from_toml_and_postcard_custom() {
+    use antikythera_core::config::{SessionTitleConfig, SessionTitleStrategy};
+
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "[session_title]\nstrategy = \"summarize\"\nmodel = \"tiny\"\n")
+        .expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    let expected = SessionTitleConfig {
+        strategy: SessionTitleStrategy::Summarize,
+        model: Some("tiny".to_string()),
+        max_chars: 60,
+    };
+    assert_eq!(config.session_title, expected);
+
+    let mut pc = minimal_postcard_config();
+    expected.write_custom(&mut pc.custom);
+    pc.custom
+        .insert(SessionTitleConfig::MAX_CHARS_KEY.to_string(), "bukan angka".to_string());
+    let path = write_postcard_config(dir.path(), &pc);
+    let config = AppConfig::load(Some(&path)).expect("load postcard config");
+    assert_eq!(config.session_title, expected);
+
+    let config = AppConfig::load(Some(&write_postcard_config(
+        dir.path(),
+        &minimal_postcard_config(),
+    )))
+    .expect("load postcard config");
+    assert_eq!(config.session_title, SessionTitleConfig::default());
+}
+
+#[test]
+fn reasoning_settings_load_from_toml_and_postcard_custom() {
+    use antikythera_core::config::{ReasoningConfig, ReasoningDelimiter};
+
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(
+        &path,
+        "[reasoning]\ndelimiters = [{ open = \"<reasoning>\", close = \"</reasoning>\" }]\nlog = true\n",
+    )
+    .expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    let expected = ReasoningConfig {
+        delimiters: vec![ReasoningDelimiter::new("<reasoning>", "</reasoning>")],
+        log: true,
+    };
+    assert_eq!(config.reasoning, expected);
+
+    let mut pc = minimal_postcard_config();
+    expected.write_custom(&mut pc.custom);
+    let path = write_postcard_config(dir.path(), &pc);
+    let config = AppConfig::load(Some(&path)).expect("load postcard config");
+    assert_eq!(config.reasoning, expected);
+
+    let config = AppConfig::load(Some(&write_postcard_config(
+        dir.path(),
+        &minimal_postcard_config(),
+    )))
+    .expect("load postcard config");
+    assert_eq!(config.reasoning, ReasoningConfig::default());
+    assert_eq!(
+        config.reasoning.delimiters,
+        vec![ReasoningDelimiter::new("<think>", "</think>")]
+    );
+}
+
+#[test]
+fn stdio_default_agent_mode_loads_from_toml_and_postcard_custom() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "stdio_default_agent_mode = false\n").expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    assert!(!config.stdio_default_agent_mode);
+
+    let mut pc = minimal_postcard_config();
+    pc.custom.insert(
+        AppConfig::STDIO_DEFAULT_AGENT_MODE_KEY.to_string(),
+        "false".to_string(),
+    );
+    let path = write_postcard_config(dir.path(), &pc);
+    let config = AppConfig::load(Some(&path)).expect("load postcard config");
+    assert!(!config.stdio_default_agent_mode);
+
+    let config = AppConfig::load(Some(&write_postcard_config(
+        dir.path(),
+        &minimal_postcard_config(),
+    )))
+    .expect("load postcard config");
+    assert!(config.stdio_default_agent_mode);
+}
+
+#[test]
+fn seed_history_path_loads_and_its_messages_parse() {
+    let dir = tempdir().expect("tempdir");
+    let seed = dir.path().join("seed.json");
+    fs::write(
+        &seed,
+        r#"[{"role":"user","parts":[{"type":"text","text":"Contoh"}]}]"#,
+    )
+    .expect("write seed.json");
+
+    let path = dir.path().join("config.toml");
+    fs::write(&path, format!("seed_history = {:?}\n", seed.display().to_string()))
+        .expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    assert_eq!(config.seed_history.as_deref(), Some(seed.as_path()));
+    let messages = config.load_seed_history().expect("parse seed history");
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].content(), "Contoh");
+
+    let mut pc = minimal_postcard_config();
+    pc.custom.insert(
+        AppConfig::SEED_HISTORY_KEY.to_string(),
+        seed.display().to_string(),
+    );
+    let config = AppConfig::load(Some(&write_postcard_config(dir.path(), &pc)))
+        .expect("load postcard config");
+    assert_eq!(config.seed_history.as_deref(), Some(seed.as_path()));
+
+    fs::write(&seed, "not json").expect("overwrite seed.json");
+    assert!(matches!(
+        config.load_seed_history(),
+        Err(ConfigError::SeedHistory { .. })
+    ));
+    assert!(AppConfig::default().load_seed_history().unwrap().is_empty());
+}
+
+#[test]
+fn provider_prompts_load_from_toml_and_postcard_custom() {
+    use antikythera_core::config::PromptsConfig;
+
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(
+        &path,
+        "[prompts]\ntool_guidance = \"Global.\"\n\n[provider_prompts.ollama]\njson_retry_message = \"Hanya JSON.\"\n",
+    )
+    .expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    let ollama = &config.provider_prompts["ollama"];
+    assert_eq!(ollama.json_retry_message.as_deref(), Some("Hanya JSON."));
+    assert_eq!(ollama.tool_guidance, None);
+    assert_eq!(config.prompts.tool_guidance.as_deref(), Some("Global."));
+
+    let mut pc = minimal_postcard_config();
+    PromptsConfig::write_providers_custom(&config.provider_prompts, &mut pc.custom);
+    let path = write_postcard_config(dir.path(), &pc);
+    let config = AppConfig::load(Some(&path)).expect("load postcard config");
+    assert_eq!(
+        config.provider_prompts["ollama"].json_retry_message.as_deref(),
+        Some("Hanya JSON.")
+    );
+
+    let config = AppConfig::load(Some(&write_postcard_config(
+        dir.path(),
+        &minimal_postcard_config(),
+    )))
+    .expect("load postcard config");
+    assert!(config.provider_prompts.is_empty());
+}
+
+#[test]
+fn interrupted_save_leaves_the_previous_config_intact() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("nested").join("app.pc");
+    let temp = dir.path().join("nested").join("app.pc.tmp");
+    let with_model = |model: &str| AppConfig {
+        model: model.to_string(),
+        ..AppConfig::default()
+    };
+
+    with_model("llama3").save(&path).expect("first save");
+    assert!(!temp.exists());
+
+    // A save killed before its rename leaves only a partial temp file.
+    let full = fs::read(&path).expect("read app.pc");
+    fs::write(&temp, &full[..full.len() / 2]).expect("write partial temp file");
+    let loaded = AppConfig::load(Some(&path)).expect("load after interrupted save");
+    assert_eq!(loaded.model, "llama3");
+
+    with_model("qwen3").save(&path).expect("second save");
+    assert_eq!(
+        AppConfig::load(Some(&path)).expect("load").model,
+        "qwen3"
+    );
+    assert!(!temp.exists());
+
+    // A write that fails cleans up its temp file.
+    let blocked = dir.path().join("blocked");
+    fs::create_dir_all(blocked.join("inner")).expect("create dir");
+    assert!(antikythera_core::config::write_atomic(&blocked, b"data").is_err());
+    assert!(!dir.path().join("blocked.tmp").exists());
+}
+
+#[test]
+fn public_tools_load_from_toml_and_postcard_custom() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "public_tools = [\"get_time\", \"search\"]\n").expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    let expected = Some(vec!["get_time".to_string(), "search".to_string()]);
+    assert_eq!(config.public_tools, expected);
+
+    let mut pc = minimal_postcard_config();
+    pc.custom.insert(
+        AppConfig::PUBLIC_TOOLS_KEY.to_string(),
+        "get_time, search".to_string(),
+    );
+    let path = write_postcard_config(dir.path(), &pc);
+    let config = AppConfig::load(Some(&path)).expect("load postcard config");
+    assert_eq!(config.public_tools, expected);
+
+    let config = AppConfig::load(Some(&write_postcard_config(
+        dir.path(),
+        &minimal_postcard_config(),
+    )))
+    .expect("load postcard config");
+    assert_eq!(config.public_tools, None);
+}
+
+#[test]
+fn terminal_tools_load_from_toml_and_postcard_custom() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "terminal_tools = [\"submit\"]\n").expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    assert_eq!(config.terminal_tools, vec!["submit"]);
+
+    let mut pc = minimal_postcard_config();
+    pc.custom.insert(
+        AppConfig::TERMINAL_TOOLS_KEY.to_string(),
+        "submit, finish".to_string(),
+    );
+    let path = write_postcard_config(dir.path(), &pc);
+    let config = AppConfig::load(Some(&path)).expect("load postcard config");
+    assert_eq!(config.terminal_tools, vec!["submit", "finish"]);
+}
+
+#[test]
+fn session_ttl_loads_from_toml_and_postcard_custom() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "session_ttl_secs = 1800\n").expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    assert_eq!(config.session_ttl_secs, Some(1800));
+
+    let mut pc = minimal_postcard_config();
+    pc.custom
+        .insert(AppConfig::SESSION_TTL_KEY.to_string(), "600".to_string());
+    let path = write_postcard_config(dir.path(), &pc);
+    let config = AppConfig::load(Some(&path)).expect("load postcard config");
+    assert_eq!(config.session_ttl_secs, Some(600));
+
+    let config = AppConfig::load(Some(&write_postcard_config(
+        dir.path(),
+        &minimal_postcard_config(),
+    )))
+    .expect("load postcard config");
+    assert_eq!(config.session_ttl_secs, None);
+}
+
+#[test]
+fn agent_max_steps_loads_from_toml_and_survives_a_save() {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(&path, "[agent]\nmax_steps = 12\n").expect("write config.toml");
+    let config = AppConfig::load(Some(&path)).expect("load combined config");
+    assert_eq!(config.agent_max_steps, Some(12));
+
+    let saved = dir.path().join("saved.pc");
+    config.save(&saved).expect("save");
+    let config = AppConfig::load(Some(&saved)).expect("load saved config");
+    assert_eq!(config.agent_max_steps, Some(12));
+
+    // Unset stays unset, so the agent's own default of 8 applies.
+    let config = AppConfig::load(Some(&write_postcard_config(
+        dir.path(),
+        &minimal_postcard_config(),
+    )))
+    .expect("load postcard config");
+    assert_eq!(config.agent_max_steps, None);
+    config.save(&saved).expect("save");
+    let config = AppConfig::load(Some(&saved)).expect("load saved config");
+    assert_eq!(config.agent_max_steps, None);
+}
+
+#[test]
+fn load_config_in_reads_the_chosen_directory() {
+    let dir = tempdir().expect("tempdir");
+    let profile = dir.path().join("profile");
+    fs::create_dir_all(&profile).expect("create profile dir");
+
+    // Only the Postcard blob: it is read from the directory, not `./app.pc`.
+    write_postcard_config(&profile, &minimal_postcard_config());
+    let config = load_config_in(&profile).expect("load postcard in dir");
+    assert_eq!(config.model, "test-model");
+
+    // TOML files in the directory win over its `app.pc`.
+    fs::write(profile.join("client.toml"), CLIENT_TOML).expect("write client.toml");
+    fs::write(profile.join("model.toml"), MODEL_TOML).expect("write model.toml");
+    let config = load_config_in(&profile).expect("load toml in dir");
+    assert_eq!(config.model, "llama3");
+
+    assert!(load_config_in(&dir.path().join("missing")).is_err());
+}
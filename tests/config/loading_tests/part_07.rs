@@ -0,0 +1,64 @@
+// ---------------------------------------------------------------------------
+// AppConfig::validate -- settings that load but don't resolve
+// ---------------------------------------------------------------------------
+
+fn provider_with_models(id: &str, models: &[&str]) -> antikythera_core::config::postcard_config::ProviderConfig {
+    antikythera_core::config::postcard_config::ProviderConfig {
+        id: id.to_string(),
+        provider_type: "ollama".to_string(),
+        endpoint: "http://127.0.0.1:11434".to_string(),
+        api_key: String::new(),
+        models: models
+            .iter()
+            .map(|name| antikythera_core::config::postcard_config::ModelInfo {
+                name: name.to_string(),
+                display_name: String::new(),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn validate_passes_a_config_whose_settings_resolve() {
+    let dir = tempdir().unwrap();
+    let path = write_postcard_config(dir.path(), &minimal_postcard_config());
+    let config = AppConfig::load(Some(&path)).expect("load");
+
+    let report = config.validate(&[provider_with_models("test-provider", &["test-model"])]);
+
+    assert!(report.is_valid(), "{}", report.render());
+    assert_eq!(report.checks.len(), 4);
+    assert!(report.render().lines().all(|line| line.starts_with("[ok] ")));
+}
+
+#[test]
+fn validate_lists_every_unresolved_setting() {
+    let mut config = AppConfig {
+        default_provider: "gemini".to_string(),
+        model: "gemini-2.0-flash".to_string(),
+        ..AppConfig::default()
+    };
+    config.tools.push(antikythera_core::config::ToolConfig {
+        name: "get_time".to_string(),
+        description: None,
+        server: Some("time".to_string()),
+        remote_name: None,
+        cacheable: false,
+    });
+    config.rest_server.bind = "localhost".to_string();
+
+    let report = config.validate(&[provider_with_models("ollama", &["llama3"])]);
+
+    assert!(!report.is_valid());
+    assert_eq!(report.failures().count(), 4);
+    let rendered = report.render();
+    assert!(rendered.contains("[!!] default provider 'gemini' exists: known: ollama"));
+    assert!(rendered.contains("[!!] default model 'gemini-2.0-flash' is available"));
+    assert!(rendered.contains("unknown servers for get_time -> time"));
+    assert!(rendered.contains("[!!] bind address 'localhost' parses"));
+
+    // A provider without a model list accepts any model.
+    config.default_provider = "ollama".to_string();
+    let report = config.validate(&[provider_with_models("ollama", &[])]);
+    assert!(report.checks[1].is_ok());
+}
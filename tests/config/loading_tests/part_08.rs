@@ -0,0 +1,45 @@
+// ---------------------------------------------------------------------------
+// Config history -- versions kept on save, and rollback
+// ---------------------------------------------------------------------------
+
+#[test]
+fn save_keeps_the_replaced_config_and_rollback_restores_it() {
+    use antikythera_core::config::ConfigHistoryConfig;
+
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("app.pc");
+    let history = ConfigHistoryConfig {
+        dir: dir.path().join("history"),
+        keep: 2,
+    };
+    let with_model = |model: &str| AppConfig {
+        model: model.to_string(),
+        config_history: Some(history.clone()),
+        ..AppConfig::default()
+    };
+
+    // The first save replaces nothing, so there is no version yet.
+    with_model("llama3").save(&path).expect("first save");
+    assert!(history.versions(&path).expect("versions").is_empty());
+
+    with_model("qwen3").save(&path).expect("second save");
+    let versions = history.versions(&path).expect("versions");
+    assert_eq!(versions.len(), 1);
+    let llama3 = versions[0].id.clone();
+
+    history.rollback(&path, &llama3).expect("rollback");
+    let restored = AppConfig::load(Some(&path)).expect("load");
+    assert_eq!(restored.model, "llama3");
+    assert_eq!(restored.config_history, Some(history.clone()));
+
+    // The rolled-back qwen3 config became a version itself; with keep = 2
+    // one more save prunes the oldest.
+    with_model("phi3").save(&path).expect("third save");
+    let versions = history.versions(&path).expect("versions");
+    assert_eq!(versions.len(), 2);
+    assert!(versions.iter().all(|version| version.id != llama3));
+    assert!(versions[0].id > versions[1].id, "newest first");
+
+    let error = history.rollback(&path, "19700101T000000000Z").unwrap_err();
+    assert!(matches!(error, ConfigError::VersionNotFound { .. }));
+}
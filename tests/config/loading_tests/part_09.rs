@@ -0,0 +1,148 @@
+// ---------------------------------------------------------------------------
+// Config diff -- changes between two loaded configs
+// ---------------------------------------------------------------------------
+
+fn load_toml(text: &str) -> AppConfig {
+    let dir = tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    fs::write(&path, text).expect("write config.toml");
+    AppConfig::load(Some(&path)).expect("load combined config")
+}
+
+const DIFF_BASE_TOML: &str = r#"
+default_provider = "ollama"
+model = "llama3"
+tools = ["get_time", { name = "search", server = "web" }]
+
+[prompts]
+template = "Jawab singkat."
+
+[provider_prompts.openai]
+template = "Be brief."
+
+[[servers]]
+name = "time"
+command = "mcp-time"
+
+[[servers]]
+name = "web"
+command = "mcp-web"
+"#;
+
+#[test]
+fn identical_configs_have_no_changes() {
+    let config = load_toml(DIFF_BASE_TOML);
+    assert!(config.diff(&config.clone()).is_empty());
+}
+
+#[test]
+fn diff_reports_provider_and_model_changes() {
+    use antikythera_core::config::{ConfigChange, ConfigSection};
+
+    let before = load_toml(DIFF_BASE_TOML);
+    let mut after = before.clone();
+    after.default_provider = "gemini".to_string();
+    after.model = "gemini-2.0-flash".to_string();
+
+    assert_eq!(
+        before.diff(&after),
+        vec![
+            ConfigChange::Changed {
+                section: ConfigSection::Provider,
+                name: "default_provider".to_string(),
+                before: serde_json::json!("ollama"),
+                after: serde_json::json!("gemini"),
+            },
+            ConfigChange::Changed {
+                section: ConfigSection::Model,
+                name: "model".to_string(),
+                before: serde_json::json!("llama3"),
+                after: serde_json::json!("gemini-2.0-flash"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_reports_added_removed_and_modified_servers_and_tools() {
+    let before = load_toml(DIFF_BASE_TOML);
+    let after = load_toml(
+        r#"
+default_provider = "ollama"
+model = "llama3"
+tools = ["get_time", { name = "fetch", server = "web" }]
+
+[prompts]
+template = "Jawab singkat."
+
+[provider_prompts.openai]
+template = "Be brief."
+
+[[servers]]
+name = "time"
+command = "mcp-time"
+args = ["--utc"]
+
+[[servers]]
+name = "files"
+command = "mcp-files"
+"#,
+    );
+
+    let rendered: Vec<String> = before.diff(&after).iter().map(|c| c.render()).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "+ server files",
+            "~ server time: args",
+            "- server web",
+            "+ tool fetch",
+            "- tool search",
+        ]
+    );
+}
+
+#[test]
+fn diff_reports_prompt_fields_and_provider_prompt_overrides() {
+    use antikythera_core::config::PromptsConfig;
+
+    let before = load_toml(DIFF_BASE_TOML);
+    let mut after = before.clone();
+    after.system_prompt = Some("Kamu asisten.".to_string());
+    after.prompts.template = Some("Jawab lengkap.".to_string());
+    after.provider_prompts.remove("openai");
+    after
+        .provider_prompts
+        .insert("gemini".to_string(), PromptsConfig::default());
+
+    let rendered: Vec<String> = before.diff(&after).iter().map(|c| c.render()).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            r#"~ prompt system_prompt: null -> "Kamu asisten.""#,
+            r#"~ prompt template: "Jawab singkat." -> "Jawab lengkap.""#,
+            "+ provider prompts gemini",
+            "- provider prompts openai",
+        ]
+    );
+}
+
+#[test]
+fn config_changes_serialize_with_a_change_tag() {
+    use antikythera_core::config::{ConfigChange, ConfigSection};
+
+    let change = ConfigChange::Modified {
+        section: ConfigSection::Server,
+        name: "time".to_string(),
+        fields: vec!["args".to_string()],
+    };
+    assert_eq!(
+        serde_json::to_value(&change).unwrap(),
+        serde_json::json!({
+            "change": "modified",
+            "section": "server",
+            "name": "time",
+            "fields": ["args"],
+        })
+    );
+}
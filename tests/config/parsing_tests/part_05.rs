@@ -9,3 +9,48 @@ fn parses_prompt_template_from_postcard() {
 
     assert_eq!(config.prompt_template(), "Be helpful.");
 }
+
+#[test]
+fn config_schema_describes_the_toml_files() {
+    let schema = antikythera_core::config::config_schema();
+
+    assert_eq!(
+        schema["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    for key in ["default_provider", "model", "tools", "servers", "prompts", "server"] {
+        assert!(schema["properties"].get(key).is_some(), "missing {key}");
+    }
+    assert_eq!(
+        schema["properties"]["servers"]["items"]["$ref"],
+        "#/$defs/Server"
+    );
+    let server = &schema["$defs"]["Server"];
+    assert_eq!(server["required"], serde_json::json!(["name"]));
+    assert!(server["properties"].get("max_inflight").is_some());
+    assert_eq!(
+        schema["$defs"]["StdioFraming"]["enum"],
+        serde_json::json!(["line_delimited", "content_length"])
+    );
+
+    // Every reference resolves inside the document.
+    fn refs(value: &serde_json::Value, found: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let Some(serde_json::Value::String(reference)) = map.get("$ref") {
+                    found.push(reference.clone());
+                }
+                map.values().for_each(|v| refs(v, found));
+            }
+            serde_json::Value::Array(items) => items.iter().for_each(|v| refs(v, found)),
+            _ => {}
+        }
+    }
+    let mut found = Vec::new();
+    refs(&schema, &mut found);
+    assert!(!found.is_empty());
+    for reference in found {
+        let name = reference.strip_prefix("#/$defs/").expect("local reference");
+        assert!(schema["$defs"].get(name).is_some(), "dangling {reference}");
+    }
+}
@@ -56,6 +56,9 @@ async fn test_stress_shared_state_parallel() {
                     raw_mode: false,
                     bypass_template: false,
                     force_json: true,
+                    provider: None,
+                    model: None,
+                    metadata: std::collections::HashMap::new(),
                 })
                 .await;
 
@@ -3,7 +3,10 @@
 // Tests for provider type detection and helper methods.
 // Uses CLI's ModelProviderConfig directly — no file I/O required.
 
-use antikythera_cli::infrastructure::llm::ModelProviderConfig;
+use antikythera_cli::infrastructure::llm::{
+    ModelProviderConfig, OpenAiFlavor, ProxyConfig, TlsConfig,
+};
+use antikythera_core::application::locale::Locale;
 use antikythera_core::domain::content::{
     ContentItem, FileContent, FileMetadata, parse_step_output,
 };
@@ -12,8 +15,9 @@ use antikythera_core::infrastructure::model::{HostModelResponse, ModelError};
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as BASE64;
 use serde_json::json;
+use std::collections::HashMap;
 
-// Split into 8 parts for consistent test organization.
+// Split into 14 parts for consistent test organization.
 include!("type_detection_tests/part_01.rs");
 include!("type_detection_tests/part_02.rs");
 include!("type_detection_tests/part_03.rs");
@@ -22,3 +26,9 @@ include!("type_detection_tests/part_05.rs");
 include!("type_detection_tests/part_06.rs");
 include!("type_detection_tests/part_07.rs");
 include!("type_detection_tests/part_08.rs");
+include!("type_detection_tests/part_09.rs");
+include!("type_detection_tests/part_10.rs");
+include!("type_detection_tests/part_11.rs");
+include!("type_detection_tests/part_12.rs");
+include!("type_detection_tests/part_13.rs");
+include!("type_detection_tests/part_14.rs");
@@ -6,6 +6,14 @@ fn is_gemini_case_insensitive() {
         endpoint: "https://example.com".to_string(),
         api_key: None,
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
     assert!(config.is_gemini());
@@ -6,6 +6,14 @@ fn provider_type_mixed_case() {
         endpoint: "http://localhost:11434".to_string(),
         api_key: None,
         api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
         models: vec![],
     };
     assert!(config.is_ollama());
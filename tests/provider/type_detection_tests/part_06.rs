@@ -41,3 +41,53 @@ fn host_response_requires_text_or_message() {
     let error = response.into_model_response("host").unwrap_err();
     assert!(matches!(error, ModelError::InvalidResponse { .. }));
 }
+
+#[test]
+fn provider_not_found_message_follows_locale() {
+    let error = ModelError::ProviderNotFound {
+        provider: "ollama".to_string(),
+    };
+
+    assert_eq!(
+        error.user_message_in(Locale::Id),
+        "Penyedia model 'ollama' tidak ditemukan. Periksa pengaturan client.toml."
+    );
+    assert_eq!(
+        error.user_message_in(Locale::En),
+        "Model provider 'ollama' was not found. Check the client.toml settings."
+    );
+}
+
+#[test]
+fn empty_provider_list_is_reported_as_no_providers() {
+    use antikythera_cli::infrastructure::llm::build_provider_from_configs;
+
+    let Err(error) = build_provider_from_configs(&[]) else {
+        panic!("an empty provider list should not build");
+    };
+    assert!(matches!(error, ModelError::NoProviders));
+    assert_eq!(error.to_string(), "no model providers configured; run setup");
+    assert_eq!(
+        error.user_message_in(Locale::En),
+        "No model providers are configured. Run setup to add one."
+    );
+}
+
+#[tokio::test]
+async fn chat_without_registered_providers_fails_with_no_providers() {
+    use antikythera_core::infrastructure::model::{
+        DynamicModelProvider, ModelProvider, ModelRequest,
+    };
+
+    let error = DynamicModelProvider::new()
+        .chat(ModelRequest {
+            provider: "ollama".to_string(),
+            model: "llama3".to_string(),
+            messages: vec![ChatMessage::new(MessageRole::User, "hello")],
+            session_id: None,
+            params: Default::default(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(error, ModelError::NoProviders));
+}
@@ -0,0 +1,118 @@
+/// Accept one HTTP request, answer it as Ollama would, and return the raw
+/// request head (lower-cased) for inspection.
+async fn serve_one_ollama_reply(listener: tokio::net::TcpListener) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let (mut socket, _) = listener.accept().await.unwrap();
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let read = socket.read(&mut buf).await.unwrap();
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..read]);
+    }
+    let body = r#"{"message":{"role":"assistant","content":"ok"},"done":true}"#;
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    socket.write_all(response.as_bytes()).await.unwrap();
+    String::from_utf8_lossy(&request).to_ascii_lowercase()
+}
+
+#[tokio::test]
+async fn provider_headers_are_sent_with_env_interpolation() {
+    use antikythera_cli::infrastructure::llm::{ModelInfo, build_provider_from_configs};
+    use antikythera_core::infrastructure::model::{ModelProvider, ModelRequest};
+
+    unsafe {
+        std::env::set_var("ANTIKYTHERA_TEST_GATEWAY_KEY", "s3cret");
+    }
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_one_ollama_reply(listener));
+
+    let config = ModelProviderConfig {
+        id: "gateway".to_string(),
+        provider_type: "ollama".to_string(),
+        endpoint,
+        api_key: None,
+        api_path: None,
+        headers: HashMap::from([
+            ("X-Tenant-Id".to_string(), "acme".to_string()),
+            (
+                "X-Gateway-Key".to_string(),
+                "${ANTIKYTHERA_TEST_GATEWAY_KEY}".to_string(),
+            ),
+            (
+                "X-Unset".to_string(),
+                "${ANTIKYTHERA_TEST_UNSET_HEADER_VAR}".to_string(),
+            ),
+        ]),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
+        models: vec![ModelInfo {
+            name: "llama3".to_string(),
+            display_name: None,
+        }],
+    };
+    let provider = build_provider_from_configs(&[config]).unwrap();
+
+    let response = provider
+        .chat(ModelRequest {
+            provider: "gateway".to_string(),
+            model: "llama3".to_string(),
+            messages: vec![ChatMessage::new(MessageRole::User, "hello")],
+            session_id: None,
+            params: Default::default(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.message.content(), "ok");
+
+    let head = server.await.unwrap();
+    assert!(head.contains("x-tenant-id: acme"), "{head}");
+    assert!(head.contains("x-gateway-key: s3cret"), "{head}");
+    assert!(!head.contains("x-unset"), "{head}");
+}
+
+#[test]
+fn provider_headers_round_trip_through_custom_map() {
+    use antikythera_cli::infrastructure::llm::{
+        PROVIDER_HEADERS_KEY_PREFIX, apply_provider_headers, write_provider_headers,
+    };
+
+    let mut provider = ModelProviderConfig {
+        id: "gateway".to_string(),
+        provider_type: "openai".to_string(),
+        endpoint: "https://llm.internal".to_string(),
+        api_key: None,
+        api_path: None,
+        headers: HashMap::from([("X-Route".to_string(), "blue".to_string())]),
+        proxy: None,
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
+        models: vec![],
+    };
+    let mut custom = HashMap::new();
+    write_provider_headers(std::slice::from_ref(&provider), &mut custom);
+    assert!(custom.contains_key(&format!("{PROVIDER_HEADERS_KEY_PREFIX}gateway")));
+
+    let expected = provider.headers.clone();
+    provider.headers.clear();
+    let mut providers = vec![provider];
+    apply_provider_headers(&mut providers, &custom);
+    assert_eq!(providers[0].headers, expected);
+}
@@ -0,0 +1,134 @@
+fn proxied_ollama(endpoint: String, proxy: ProxyConfig) -> ModelProviderConfig {
+    ModelProviderConfig {
+        id: "local".to_string(),
+        provider_type: "ollama".to_string(),
+        endpoint,
+        api_key: None,
+        api_path: None,
+        headers: HashMap::new(),
+        proxy: Some(proxy),
+        tls: Default::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
+        models: vec![antikythera_cli::infrastructure::llm::ModelInfo {
+            name: "llama3".to_string(),
+            display_name: None,
+        }],
+    }
+}
+
+async fn ask(config: ModelProviderConfig) -> String {
+    use antikythera_cli::infrastructure::llm::build_provider_from_configs;
+    use antikythera_core::infrastructure::model::{ModelProvider, ModelRequest};
+
+    let provider = build_provider_from_configs(&[config]).unwrap();
+    provider
+        .chat(ModelRequest {
+            provider: "local".to_string(),
+            model: "llama3".to_string(),
+            messages: vec![ChatMessage::new(MessageRole::User, "hello")],
+            session_id: None,
+            params: Default::default(),
+        })
+        .await
+        .unwrap()
+        .message
+        .content()
+}
+
+#[tokio::test]
+async fn model_requests_go_through_configured_proxy() {
+    let proxy = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy_url = format!("http://{}", proxy.local_addr().unwrap());
+    let server = tokio::spawn(serve_one_ollama_reply(proxy));
+
+    let config = proxied_ollama(
+        "http://llm.example.invalid:11434".to_string(),
+        ProxyConfig::new(proxy_url).unwrap().with_no_proxy(Vec::new()),
+    );
+    assert_eq!(ask(config).await, "ok");
+
+    let head = server.await.unwrap();
+    assert!(
+        head.starts_with("post http://llm.example.invalid:11434/api/chat "),
+        "{head}"
+    );
+}
+
+#[tokio::test]
+async fn loopback_endpoints_bypass_the_proxy_by_default() {
+    let ollama = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", ollama.local_addr().unwrap());
+    let server = tokio::spawn(serve_one_ollama_reply(ollama));
+
+    // Nothing listens on the proxy; the request only succeeds by bypassing it.
+    let config = proxied_ollama(
+        endpoint,
+        ProxyConfig::new("http://proxy.example.invalid:3128").unwrap(),
+    );
+    assert_eq!(ask(config).await, "ok");
+    assert!(server.await.unwrap().starts_with("post /api/chat "));
+}
+
+#[test]
+fn proxy_settings_from_custom_map_prefer_per_provider_url() {
+    use antikythera_cli::infrastructure::llm::{
+        PROVIDER_PROXY_KEY_PREFIX, PROXY_NO_PROXY_KEY, PROXY_URL_KEY, apply_proxy_settings,
+    };
+
+    let mut providers = vec![
+        proxied_ollama("http://127.0.0.1:11434".to_string(), ProxyConfig::new("http://a:1").unwrap()),
+        proxied_ollama("https://api.openai.com".to_string(), ProxyConfig::new("http://a:1").unwrap()),
+    ];
+    providers[1].id = "openai".to_string();
+    providers.iter_mut().for_each(|p| p.proxy = None);
+
+    let custom = HashMap::from([
+        (PROXY_URL_KEY.to_string(), "http://proxy.corp:3128".to_string()),
+        (PROXY_NO_PROXY_KEY.to_string(), "localhost, .corp.internal".to_string()),
+        (
+            format!("{PROVIDER_PROXY_KEY_PREFIX}openai"),
+            "https://egress.corp:8443".to_string(),
+        ),
+    ]);
+    apply_proxy_settings(&mut providers, &custom).unwrap();
+
+    let no_proxy = vec!["localhost".to_string(), ".corp.internal".to_string()];
+    assert_eq!(
+        providers[0].proxy,
+        Some(ProxyConfig {
+            url: "http://proxy.corp:3128".to_string(),
+            no_proxy: no_proxy.clone(),
+        })
+    );
+    assert_eq!(
+        providers[1].proxy,
+        Some(ProxyConfig {
+            url: "https://egress.corp:8443".to_string(),
+            no_proxy,
+        })
+    );
+}
+
+#[test]
+fn invalid_proxy_url_is_rejected_at_load() {
+    use antikythera_cli::infrastructure::llm::{PROXY_URL_KEY, apply_proxy_settings};
+
+    let mut providers = vec![proxied_ollama(
+        "http://127.0.0.1:11434".to_string(),
+        ProxyConfig::new("http://a:1").unwrap(),
+    )];
+    providers[0].proxy = None;
+
+    for bad in ["ftp://proxy.corp:21", "proxy.corp:3128"] {
+        let custom = HashMap::from([(PROXY_URL_KEY.to_string(), bad.to_string())]);
+        let error = apply_proxy_settings(&mut providers, &custom).unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("provider 'local'"), "{message}");
+        assert!(message.contains(bad), "{message}");
+    }
+    assert!(providers[0].proxy.is_none());
+}
@@ -0,0 +1,80 @@
+fn tls_fixture(name: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join("tls")
+        .join(name)
+}
+
+fn provider_named(id: &str) -> ModelProviderConfig {
+    ModelProviderConfig {
+        id: id.to_string(),
+        provider_type: "openai".to_string(),
+        endpoint: "https://llm.internal".to_string(),
+        api_key: None,
+        api_path: None,
+        headers: HashMap::new(),
+        proxy: None,
+        tls: TlsConfig::default(),
+        pool: Default::default(),
+        rate_limit: Default::default(),
+        openai_flavor: Default::default(),
+        api_version: None,
+        default_model: None,
+        models: vec![],
+    }
+}
+
+#[test]
+fn tls_settings_load_ca_cert_and_danger_flag_from_custom_map() {
+    use antikythera_cli::infrastructure::llm::{PROVIDER_TLS_KEY_PREFIX, apply_tls_settings};
+
+    let ca_cert = tls_fixture("gateway_ca.pem");
+    let custom = HashMap::from([
+        (
+            format!("{PROVIDER_TLS_KEY_PREFIX}gateway"),
+            json!({ "ca_cert": ca_cert }).to_string(),
+        ),
+        (
+            format!("{PROVIDER_TLS_KEY_PREFIX}lab"),
+            json!({ "danger_accept_invalid_certs": true }).to_string(),
+        ),
+    ]);
+    let mut providers = vec![
+        provider_named("gateway"),
+        provider_named("lab"),
+        provider_named("openai"),
+    ];
+    apply_tls_settings(&mut providers, &custom).unwrap();
+
+    assert_eq!(providers[0].tls.ca_cert.as_deref(), Some(ca_cert.as_path()));
+    assert!(!providers[0].tls.danger_accept_invalid_certs);
+    assert!(providers[0].tls.load_ca_cert().unwrap().is_some());
+    assert!(providers[1].tls.danger_accept_invalid_certs);
+    assert_eq!(providers[2].tls, TlsConfig::default());
+}
+
+#[test]
+fn unusable_ca_cert_is_rejected_at_load() {
+    use antikythera_cli::infrastructure::llm::{PROVIDER_TLS_KEY_PREFIX, apply_tls_settings};
+
+    let dir = tempfile::tempdir().unwrap();
+    let garbage = dir.path().join("garbage.pem");
+    std::fs::write(&garbage, "not a certificate").unwrap();
+
+    for (path, expected) in [
+        (dir.path().join("missing.pem"), "cannot read CA certificate"),
+        (garbage, "invalid CA certificate"),
+    ] {
+        let custom = HashMap::from([(
+            format!("{PROVIDER_TLS_KEY_PREFIX}gateway"),
+            json!({ "ca_cert": path }).to_string(),
+        )]);
+        let mut providers = vec![provider_named("gateway")];
+        let message = apply_tls_settings(&mut providers, &custom)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("provider 'gateway'"), "{message}");
+        assert!(message.contains(expected), "{message}");
+        assert_eq!(providers[0].tls, TlsConfig::default());
+    }
+}
@@ -0,0 +1,168 @@
+#[test]
+fn pool_settings_apply_to_every_provider() {
+    use antikythera_cli::infrastructure::llm::{
+        HttpPoolConfig, POOL_IDLE_TIMEOUT_SECS_KEY, POOL_MAX_IDLE_PER_HOST_KEY,
+        apply_http_pool_settings,
+    };
+
+    let mut providers = vec![provider_named("gateway"), provider_named("openai")];
+    apply_http_pool_settings(&mut providers, &HashMap::new()).unwrap();
+    assert_eq!(providers[0].pool, HttpPoolConfig::default());
+
+    let custom = HashMap::from([
+        (POOL_MAX_IDLE_PER_HOST_KEY.to_string(), "8".to_string()),
+        (POOL_IDLE_TIMEOUT_SECS_KEY.to_string(), " 30 ".to_string()),
+    ]);
+    apply_http_pool_settings(&mut providers, &custom).unwrap();
+    for provider in &providers {
+        assert_eq!(provider.pool.pool_max_idle_per_host, Some(8));
+        assert_eq!(provider.pool.pool_idle_timeout_secs, Some(30));
+    }
+}
+
+#[test]
+fn invalid_pool_setting_is_rejected_at_load() {
+    use antikythera_cli::infrastructure::llm::{
+        POOL_IDLE_TIMEOUT_SECS_KEY, apply_http_pool_settings,
+    };
+
+    let custom = HashMap::from([(POOL_IDLE_TIMEOUT_SECS_KEY.to_string(), "-5".to_string())]);
+    let mut providers = vec![provider_named("gateway")];
+    let message = apply_http_pool_settings(&mut providers, &custom)
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains(POOL_IDLE_TIMEOUT_SECS_KEY), "{message}");
+    assert_eq!(providers[0].pool, Default::default());
+}
+
+#[tokio::test]
+async fn requests_succeed_with_tuned_pool() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_one_ollama_reply(listener));
+
+    let config = ModelProviderConfig {
+        proxy: None,
+        pool: antikythera_cli::infrastructure::llm::HttpPoolConfig {
+            pool_max_idle_per_host: Some(0),
+            pool_idle_timeout_secs: Some(1),
+        },
+        ..proxied_ollama(endpoint, ProxyConfig::new("http://127.0.0.1:9").unwrap())
+    };
+
+    assert_eq!(ask(config).await, "ok");
+    assert!(server.await.unwrap().starts_with("post /api/chat "));
+}
+
+/// Answer one connection per entry of `statuses` with that status line and
+/// headers; the last answer carries an Ollama reply. Returns the number of
+/// requests served.
+async fn serve_statuses(listener: tokio::net::TcpListener, statuses: Vec<&'static str>) -> usize {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mut served = 0;
+    for status in statuses {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let read = socket.read(&mut buf).await.unwrap();
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buf[..read]);
+        }
+        let body = r#"{"message":{"role":"assistant","content":"ok"},"done":true}"#;
+        let response = format!(
+            "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+        served += 1;
+    }
+    served
+}
+
+#[tokio::test]
+async fn rate_limited_request_is_retried_after_retry_after() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_statuses(
+        listener,
+        vec!["429 Too Many Requests\r\nretry-after: 0", "200 OK"],
+    ));
+
+    let config = ModelProviderConfig {
+        proxy: None,
+        ..proxied_ollama(endpoint, ProxyConfig::new("http://127.0.0.1:9").unwrap())
+    };
+
+    assert_eq!(ask(config).await, "ok");
+    assert_eq!(server.await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn rate_limit_wait_beyond_the_cap_fails_without_retrying() {
+    use antikythera_cli::infrastructure::llm::build_provider_from_configs;
+    use antikythera_core::infrastructure::model::{ModelProvider, ModelRequest};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_statuses(
+        listener,
+        vec!["429 Too Many Requests\r\nretry-after: 120"],
+    ));
+
+    let config = ModelProviderConfig {
+        proxy: None,
+        ..proxied_ollama(endpoint, ProxyConfig::new("http://127.0.0.1:9").unwrap())
+    };
+    let error = build_provider_from_configs(&[config])
+        .unwrap()
+        .chat(ModelRequest {
+            provider: "local".to_string(),
+            model: "llama3".to_string(),
+            messages: vec![ChatMessage::new(MessageRole::User, "hello")],
+            session_id: None,
+            params: Default::default(),
+        })
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            ModelError::RateLimited {
+                retries: 0,
+                retry_after_secs: Some(120),
+                ..
+            }
+        ),
+        "{error}"
+    );
+    assert!(
+        error
+            .user_message_in(Locale::En)
+            .contains("rate limiting requests")
+    );
+    assert_eq!(server.await.unwrap(), 1);
+}
+
+#[test]
+fn rate_limit_settings_apply_to_every_provider() {
+    use antikythera_cli::infrastructure::llm::{
+        RATE_LIMIT_MAX_RETRIES_KEY, RateLimitRetry, apply_rate_limit_settings,
+    };
+
+    let mut providers = vec![provider_named("gateway"), provider_named("openai")];
+    apply_rate_limit_settings(&mut providers, &HashMap::new()).unwrap();
+    assert_eq!(providers[0].rate_limit, RateLimitRetry::default());
+
+    let custom = HashMap::from([(RATE_LIMIT_MAX_RETRIES_KEY.to_string(), "0".to_string())]);
+    apply_rate_limit_settings(&mut providers, &custom).unwrap();
+    assert!(providers.iter().all(|p| p.rate_limit.max_retries == 0));
+
+    let custom = HashMap::from([(RATE_LIMIT_MAX_RETRIES_KEY.to_string(), "x".to_string())]);
+    assert!(apply_rate_limit_settings(&mut providers, &custom).is_err());
+}
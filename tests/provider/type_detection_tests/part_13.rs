@@ -0,0 +1,119 @@
+/// Answer one request with a non-streaming OpenAI reply and return the
+/// request head, lowercased.
+async fn serve_one_openai_reply(listener: tokio::net::TcpListener) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let (mut socket, _) = listener.accept().await.unwrap();
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let read = socket.read(&mut buf).await.unwrap();
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..read]);
+    }
+    let body = r#"{"choices":[{"message":{"role":"assistant","content":"ok"}}]}"#;
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    socket.write_all(response.as_bytes()).await.unwrap();
+    String::from_utf8_lossy(&request).to_ascii_lowercase()
+}
+
+/// Send one prompt through an OpenAI-compatible provider of `flavor` and
+/// return the request head it received.
+async fn openai_request_head(flavor: OpenAiFlavor, api_version: Option<&str>) -> String {
+    use antikythera_cli::infrastructure::llm::build_provider_from_configs;
+    use antikythera_core::infrastructure::model::{ModelProvider, ModelRequest};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}/", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_one_openai_reply(listener));
+
+    let config = ModelProviderConfig {
+        endpoint,
+        api_key: Some("sk-test".to_string()),
+        openai_flavor: flavor,
+        api_version: api_version.map(str::to_string),
+        ..provider_named("gateway")
+    };
+    let reply = build_provider_from_configs(&[config])
+        .unwrap()
+        .chat(ModelRequest {
+            provider: "gateway".to_string(),
+            model: "gpt-4o-prod".to_string(),
+            messages: vec![ChatMessage::new(MessageRole::User, "hello")],
+            session_id: None,
+            params: Default::default(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(reply.message.content(), "ok");
+    server.await.unwrap()
+}
+
+#[tokio::test]
+async fn openai_flavor_posts_to_api_path_with_bearer_auth() {
+    let head = openai_request_head(OpenAiFlavor::OpenAi, None).await;
+
+    assert!(head.starts_with("post /v1/chat/completions "), "{head}");
+    assert!(head.contains("authorization: bearer sk-test"), "{head}");
+    assert!(!head.contains("api-key:"), "{head}");
+}
+
+#[tokio::test]
+async fn azure_flavor_posts_to_the_deployment_with_api_key_header() {
+    use antikythera_cli::infrastructure::llm::AZURE_DEFAULT_API_VERSION;
+
+    let head = openai_request_head(OpenAiFlavor::Azure, None).await;
+    assert!(
+        head.starts_with(&format!(
+            "post /openai/deployments/gpt-4o-prod/chat/completions?api-version={AZURE_DEFAULT_API_VERSION} "
+        )),
+        "{head}"
+    );
+    assert!(head.contains("api-key: sk-test"), "{head}");
+    assert!(!head.contains("authorization:"), "{head}");
+
+    let head = openai_request_head(OpenAiFlavor::Azure, Some("2024-02-01")).await;
+    assert!(head.contains("?api-version=2024-02-01 "), "{head}");
+}
+
+#[test]
+fn openai_flavor_settings_load_from_custom_map() {
+    use antikythera_cli::infrastructure::llm::{
+        PROVIDER_API_VERSION_KEY_PREFIX, PROVIDER_OPENAI_FLAVOR_KEY_PREFIX,
+        apply_openai_flavor_settings,
+    };
+
+    let custom = HashMap::from([
+        (
+            format!("{PROVIDER_OPENAI_FLAVOR_KEY_PREFIX}azure-prod"),
+            " Azure ".to_string(),
+        ),
+        (
+            format!("{PROVIDER_API_VERSION_KEY_PREFIX}azure-prod"),
+            "2024-02-01".to_string(),
+        ),
+    ]);
+    let mut providers = vec![provider_named("azure-prod"), provider_named("openai")];
+    apply_openai_flavor_settings(&mut providers, &custom).unwrap();
+
+    assert_eq!(providers[0].openai_flavor, OpenAiFlavor::Azure);
+    assert_eq!(providers[0].api_version.as_deref(), Some("2024-02-01"));
+    assert_eq!(providers[1].openai_flavor, OpenAiFlavor::OpenAi);
+    assert_eq!(providers[1].api_version, None);
+
+    let custom = HashMap::from([(
+        format!("{PROVIDER_OPENAI_FLAVOR_KEY_PREFIX}openai"),
+        "bedrock".to_string(),
+    )]);
+    let message = apply_openai_flavor_settings(&mut providers, &custom)
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("provider 'openai'"), "{message}");
+    assert!(message.contains("expected openai or azure"), "{message}");
+}
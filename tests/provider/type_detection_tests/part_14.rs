@@ -0,0 +1,62 @@
+/// Replies with the name of the model it was asked for.
+struct ModelEchoClient;
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl antikythera_core::infrastructure::model::traits::ModelClient for ModelEchoClient {
+    fn id(&self) -> &str {
+        "echo"
+    }
+
+    async fn chat(
+        &self,
+        request: antikythera_core::infrastructure::model::ModelRequest,
+    ) -> Result<antikythera_core::infrastructure::model::ModelResponse, ModelError> {
+        Ok(antikythera_core::infrastructure::model::ModelResponse::new(
+            request.model,
+            None,
+        ))
+    }
+}
+
+#[tokio::test]
+async fn switching_providers_falls_back_to_the_provider_default_model() {
+    use antikythera_core::infrastructure::model::{
+        DynamicModelProvider, ModelProvider, ModelRequest,
+    };
+
+    let provider = DynamicModelProvider::new()
+        .register("ollama", vec!["llama3".into()], Box::new(ModelEchoClient))
+        .register("openai", vec!["gpt-4o".into()], Box::new(ModelEchoClient))
+        .with_default_model("ollama", "llama3");
+    let request = |provider: &str, model: &str| ModelRequest {
+        provider: provider.to_string(),
+        model: model.to_string(),
+        messages: vec![ChatMessage::new(MessageRole::User, "hello")],
+        session_id: None,
+        params: Default::default(),
+    };
+
+    let reply = provider.chat(request("ollama", "gpt-4o")).await.unwrap();
+    assert_eq!(reply.message.content(), "llama3");
+
+    let error = provider.chat(request("openai", "llama3")).await.unwrap_err();
+    assert!(matches!(error, ModelError::ModelNotFound { .. }));
+}
+
+#[test]
+fn provider_default_model_is_read_from_custom() {
+    use antikythera_cli::infrastructure::llm::{
+        PROVIDER_DEFAULT_MODEL_KEY_PREFIX, apply_provider_settings,
+    };
+
+    let mut providers = vec![provider_named("ollama"), provider_named("openai")];
+    let custom = HashMap::from([(
+        format!("{PROVIDER_DEFAULT_MODEL_KEY_PREFIX}ollama"),
+        " llama3 ".to_string(),
+    )]);
+    apply_provider_settings(&mut providers, &custom).unwrap();
+
+    assert_eq!(providers[0].default_model.as_deref(), Some("llama3"));
+    assert_eq!(providers[1].default_model, None);
+}
@@ -3,7 +3,8 @@ fn test_config_default_values() {
     let config = AppConfig::default();
 
     // Verify defaults
-    assert_eq!(config.agent.max_steps, 10);
+    // 0 leaves the step budget to the agent's own default.
+    assert_eq!(config.agent.max_steps, 0);
     assert!(!config.agent.verbose);
     assert!(config.agent.auto_execute_tools);
 }
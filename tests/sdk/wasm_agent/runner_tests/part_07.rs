@@ -62,3 +62,47 @@ fn keep_balanced_truncation_retains_head_and_tail() {
     );
 }
 
+
+#[test]
+#[serial_test::serial]
+fn summary_of_multibyte_history_is_cut_on_a_character_boundary() {
+    let policy = serde_json::json!({
+        "max_history_messages": 2,
+        "summarize_after_messages": 2,
+        "summary_max_chars": 120,
+        "truncation_strategy": "keep_newest"
+    });
+
+    let session_id = init(&serde_json::json!({"max_steps": 30}).to_string()).unwrap();
+    for _ in 0..2 {
+        let prepared = prepare_user_turn(
+            &serde_json::json!({
+                "prompt": "😀".repeat(150),
+                "session_id": session_id,
+                "system_prompt": "assistant",
+                "context_policy": policy
+            })
+            .to_string(),
+        )
+        .unwrap();
+        commit_llm_response(&prepared, &"é".repeat(150)).unwrap();
+    }
+
+    // Summarizing at 120 bytes would split an emoji; this must not panic.
+    prepare_user_turn(
+        &serde_json::json!({
+            "prompt": "trigger",
+            "session_id": session_id,
+            "system_prompt": "assistant",
+            "context_policy": policy
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let state: serde_json::Value =
+        serde_json::from_str(&get_state(&session_id).unwrap()).unwrap();
+    let summary = state["rolling_summary"]["text"].as_str().expect("summary");
+    assert!(summary.ends_with("..."));
+    assert_eq!(summary.chars().count(), 120 + 3);
+}
@@ -4,6 +4,7 @@
 
 use antikythera_core::config::AppConfig;
 use antikythera_core::domain::sanitize::{needs_sanitization, sanitize_for_toml};
+use antikythera_core::domain::text::preview;
 
 // Split into 5 parts for consistent test organization.
 include!("toml_tests/part_01.rs");
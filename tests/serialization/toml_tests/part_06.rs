@@ -50,3 +50,15 @@ fn test_clean_text_unchanged() {
     let result = sanitize_for_toml(input);
     assert_eq!(result, input);
 }
+
+#[test]
+fn test_preview_cuts_multibyte_text_on_character_boundaries() {
+    let text = "Halo 😀 dunia, café ñandú";
+
+    // Byte 6 falls inside the emoji; counting characters keeps it whole.
+    assert_eq!(preview(text, 6, "…"), "Halo 😀…");
+    assert_eq!(preview(text, 18, "..."), "Halo 😀 dunia, café...");
+    assert_eq!(preview(text, 100, "..."), text);
+    assert_eq!(preview("ñandú", 5, "..."), "ñandú");
+    assert_eq!(preview("ñandú", 0, ""), "");
+}
@@ -4,19 +4,19 @@
 // These tests gracefully skip if config files don't exist.
 
 use antikythera_core::application::discovery::loader::create_server_config;
-use antikythera_core::application::discovery::scanner::{extract_server_name, is_executable};
+use antikythera_core::application::discovery::scanner::extract_server_name;
 use antikythera_core::application::discovery::{
     DEFAULT_SERVERS_FOLDER, DiscoveredServer, DiscoveryError, DiscoverySummary, LoadStatus,
-    StartupDiscoveryResult, load_server, scan_folder,
+    StartupDiscoveryResult, ToolCatalogDiff, ToolDescriptionChange, load_server, scan_folder,
 };
-use antikythera_core::config::AppConfig;
 use antikythera_core::config::server::{RawServer, ServerConfig};
+use antikythera_core::config::{AppConfig, ToolConfig};
 use std::collections::HashMap;
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use tempfile::tempdir;
 
-// Split into 11 parts for consistent test organization.
+// Split into 12 parts for consistent test organization.
 include!("validation_tests/part_01.rs");
 include!("validation_tests/part_02.rs");
 include!("validation_tests/part_03.rs");
@@ -28,3 +28,4 @@ include!("validation_tests/part_08.rs");
 include!("validation_tests/part_09.rs");
 include!("validation_tests/part_10.rs");
 include!("validation_tests/part_11.rs");
+include!("validation_tests/part_12.rs");
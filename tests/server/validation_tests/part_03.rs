@@ -29,9 +29,9 @@ fn all_servers_have_valid_commands() {
                     cmd_path.display()
                 );
             }
-        } else if server.url.is_some() {
+        } else if let Some(url) = &server.url {
             // HTTP server - no local file to check
-            println!("  (HTTP server - URL: {})", server.url.as_ref().unwrap());
+            println!("  (HTTP server - URL: {})", url);
         } else {
             eprintln!(
                 "WARNING: '{}' has neither command nor URL configured",
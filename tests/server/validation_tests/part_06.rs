@@ -5,11 +5,19 @@ fn test_stdio_server_config() {
         command: Some("/path/to/server".to_string()),
         args: vec!["--port".to_string(), "8080".to_string()],
         env: HashMap::new(),
+        clear_env: false,
+        enabled: true,
         workdir: None,
         url: None,
         headers: HashMap::new(),
         default_timezone: None,
         default_city: None,
+        keepalive_interval_secs: 0,
+        idle_timeout_secs: 0,
+        framing: Default::default(),
+        max_inflight: 0,
+        allowed_tools: Vec::new(),
+        blocked_tools: Vec::new(),
     };
 
     let config = ServerConfig::from(raw);
@@ -27,6 +35,8 @@ fn test_http_server_config() {
         command: None,
         args: vec![],
         env: HashMap::new(),
+        clear_env: false,
+        enabled: true,
         workdir: None,
         url: Some("https://example.com/mcp".to_string()),
         headers: HashMap::from([(
@@ -35,6 +45,12 @@ fn test_http_server_config() {
         )]),
         default_timezone: None,
         default_city: None,
+        keepalive_interval_secs: 0,
+        idle_timeout_secs: 0,
+        framing: Default::default(),
+        max_inflight: 0,
+        allowed_tools: Vec::new(),
+        blocked_tools: Vec::new(),
     };
 
     let config = ServerConfig::from(raw);
@@ -57,11 +73,19 @@ fn test_builtin_server_config() {
         command: None,
         args: vec![],
         env: HashMap::new(),
+        clear_env: false,
+        enabled: true,
         workdir: None,
         url: None,
         headers: HashMap::new(),
         default_timezone: None,
         default_city: None,
+        keepalive_interval_secs: 0,
+        idle_timeout_secs: 0,
+        framing: Default::default(),
+        max_inflight: 0,
+        allowed_tools: Vec::new(),
+        blocked_tools: Vec::new(),
     };
 
     let config = ServerConfig::from(raw);
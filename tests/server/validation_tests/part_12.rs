@@ -0,0 +1,103 @@
+fn tool(name: &str, description: Option<&str>, server: &str) -> ToolConfig {
+    ToolConfig {
+        name: name.to_string(),
+        description: description.map(str::to_string),
+        server: Some(server.to_string()),
+        remote_name: None,
+        cacheable: false,
+    }
+}
+
+fn listed(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(name, description)| (name.to_string(), description.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_tool_catalog_diff_reports_added_removed_and_changed() {
+    let configured = vec![
+        tool("get_time", Some("Current time"), "time"),
+        tool("convert_time", Some("Convert between zones"), "time"),
+        tool("old_tool", Some("Gone after upgrade"), "time"),
+        tool("old_tool", Some("Same name, other server"), "weather"),
+    ];
+    let diff = ToolCatalogDiff::between(
+        "time",
+        &configured,
+        &listed(&[
+            ("get_time", "Current time"),
+            ("convert_time", "Convert a time between timezones"),
+            ("list_zones", "List known timezones"),
+        ]),
+    );
+
+    assert_eq!(diff.server, "time");
+    assert_eq!(
+        diff.added,
+        vec![(
+            "list_zones".to_string(),
+            "List known timezones".to_string()
+        )]
+    );
+    assert_eq!(diff.removed, vec!["old_tool".to_string()]);
+    assert_eq!(
+        diff.changed,
+        vec![ToolDescriptionChange {
+            name: "convert_time".to_string(),
+            before: Some("Convert between zones".to_string()),
+            after: "Convert a time between timezones".to_string(),
+        }]
+    );
+    assert!(!diff.is_empty());
+    assert_eq!(
+        diff.render(),
+        "time: +1 -1 ~1\n  + list_zones\n  - old_tool\n  ~ convert_time (description)"
+    );
+}
+
+#[test]
+fn test_tool_catalog_diff_apply_prunes_only_when_asked() {
+    let configured = vec![
+        tool("get_time", Some("Current time"), "time"),
+        tool("old_tool", None, "time"),
+        tool("old_tool", None, "weather"),
+    ];
+    let diff = ToolCatalogDiff::between(
+        "time",
+        &configured,
+        &listed(&[("get_time", "Current UTC time"), ("list_zones", "")]),
+    );
+
+    let mut kept = configured.clone();
+    diff.apply(&mut kept, false);
+    assert_eq!(
+        kept,
+        vec![
+            tool("get_time", Some("Current UTC time"), "time"),
+            tool("old_tool", None, "time"),
+            tool("old_tool", None, "weather"),
+            tool("list_zones", None, "time"),
+        ]
+    );
+
+    let mut pruned = configured;
+    diff.apply(&mut pruned, true);
+    assert_eq!(
+        pruned,
+        vec![
+            tool("get_time", Some("Current UTC time"), "time"),
+            tool("old_tool", None, "weather"),
+            tool("list_zones", None, "time"),
+        ]
+    );
+}
+
+#[test]
+fn test_tool_catalog_diff_is_empty_when_in_sync() {
+    let configured = vec![tool("get_time", None, "time")];
+    let diff = ToolCatalogDiff::between("time", &configured, &listed(&[("get_time", "")]));
+    assert!(diff.is_empty());
+    assert_eq!(diff.render(), "time: up to date");
+}
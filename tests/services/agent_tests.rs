@@ -0,0 +1,1572 @@
+use antikythera_core::application::agent::{Agent, AgentError, AgentOptions, ToolError};
+use antikythera_core::application::client::{ClientConfig, McpClient};
+use antikythera_core::application::locale::Locale;
+use antikythera_core::application::streaming::{AgentEvent, ToolEventPhase};
+use antikythera_core::application::tooling::{
+    ServerToolInfo, ToolInvokeError, ToolProgress, ToolServerInterface, current_call_progress,
+};
+use antikythera_core::config::{AuditLogConfig, PromptsConfig, ReasoningConfig, ToolConfig};
+use antikythera_core::infrastructure::model::{
+    ModelError, ModelProvider, ModelRequest, ModelResponse,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Replays a fixed list of responses, repeating the last one once exhausted.
+struct ScriptedProvider {
+    responses: Vec<String>,
+    calls: AtomicUsize,
+}
+
+impl ScriptedProvider {
+    fn new(responses: &[&str]) -> Self {
+        Self {
+            responses: responses.iter().map(|r| r.to_string()).collect(),
+            calls: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for ScriptedProvider {
+    async fn chat(&self, _request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        let index = self.calls.fetch_add(1, Ordering::SeqCst);
+        let content = self.responses[index.min(self.responses.len() - 1)].clone();
+        Ok(ModelResponse::new(content, None))
+    }
+}
+
+fn agent_with(responses: &[&str]) -> Agent<ScriptedProvider> {
+    let client = McpClient::new(
+        ScriptedProvider::new(responses),
+        ClientConfig::new("mock", "mock-model"),
+    );
+    Agent::new(Arc::new(client))
+}
+
+const UNKNOWN_TOOL_CALL: &str =
+    r#"{"action":"call_tool","tool":"missing_tool","input":{"q":"halo"}}"#;
+const FINAL_ANSWER: &str = r#"{"action":"final","response":"siap"}"#;
+
+#[tokio::test]
+async fn failed_tool_is_recorded_and_run_continues() {
+    let agent = agent_with(&[UNKNOWN_TOOL_CALL, FINAL_ANSWER]);
+
+    let outcome = agent
+        .run("halo".to_string(), AgentOptions::default())
+        .await
+        .expect("tool failure should be reported to the model, not abort the run");
+
+    assert_eq!(outcome.response, serde_json::json!("siap"));
+    assert_eq!(outcome.steps.len(), 1);
+    let step = &outcome.steps[0];
+    assert_eq!(step.tool, "missing_tool");
+    assert!(!step.success);
+    assert_eq!(step.input, serde_json::json!({"q": "halo"}));
+    assert!(
+        step.message
+            .as_deref()
+            .is_some_and(|message| message.contains("missing_tool"))
+    );
+}
+
+#[tokio::test]
+async fn abort_on_tool_error_restores_previous_behavior() {
+    let agent = agent_with(&[UNKNOWN_TOOL_CALL, FINAL_ANSWER]);
+
+    let result = agent
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                abort_on_tool_error: true,
+                ..AgentOptions::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AgentError::Tool(ToolError::UnknownTool(ref name))) if name == "missing_tool"
+    ));
+}
+
+#[tokio::test]
+async fn configured_default_max_steps_is_honored() {
+    let script = [
+        UNKNOWN_TOOL_CALL,
+        UNKNOWN_TOOL_CALL,
+        UNKNOWN_TOOL_CALL,
+        FINAL_ANSWER,
+    ];
+    let agent_with_budget = |steps: usize| {
+        let client = McpClient::new(
+            ScriptedProvider::new(&script),
+            ClientConfig::new("mock", "mock-model").with_max_steps(steps),
+        );
+        Agent::new(Arc::new(client))
+    };
+    let unset = McpClient::new(
+        ScriptedProvider::new(&script),
+        ClientConfig::new("mock", "mock-model"),
+    );
+    assert_eq!(unset.default_max_steps(), 8);
+
+    let outcome = agent_with_budget(3)
+        .run("halo".to_string(), AgentOptions::default())
+        .await
+        .expect("three tool calls fit the configured budget");
+    assert_eq!(outcome.steps.len(), 3);
+
+    let result = agent_with_budget(2)
+        .run("halo".to_string(), AgentOptions::default())
+        .await;
+    assert!(matches!(result, Err(AgentError::InvalidResponse(_))));
+
+    // A per-request budget overrides the configured one.
+    let result = agent_with_budget(3)
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                max_steps: Some(2),
+                ..AgentOptions::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(AgentError::InvalidResponse(_))));
+}
+
+#[tokio::test]
+async fn tool_calls_are_appended_to_audit_log() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("audit.jsonl");
+    let config = ClientConfig::new("mock", "mock-model").with_audit_log(AuditLogConfig {
+        path: path.clone(),
+        redact_keys: vec!["API_KEY".to_string()],
+    });
+    let client = McpClient::new(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"missing_tool","input":{"q":"halo","api_key":"rahasia"}}"#,
+            r#"{"action":"call_tool","tool":"list_tools","input":{}}"#,
+            FINAL_ANSWER,
+        ]),
+        config,
+    );
+
+    Agent::new(Arc::new(client))
+        .run("halo".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    // Audit writes are spawned; wait for both lines to land.
+    let mut lines = Vec::new();
+    for _ in 0..100 {
+        let text = std::fs::read_to_string(&path).unwrap_or_default();
+        lines = text.lines().map(str::to_string).collect();
+        if lines.len() >= 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(lines.len(), 2);
+
+    let entries: Vec<serde_json::Value> = lines
+        .iter()
+        .map(|line| serde_json::from_str(line).expect("audit line is JSON"))
+        .collect();
+    let missing = entries
+        .iter()
+        .find(|entry| entry["tool"] == "missing_tool")
+        .expect("failed call is audited");
+    assert_eq!(missing["success"], false);
+    assert_eq!(missing["input"]["q"], "halo");
+    assert_eq!(missing["input"]["api_key"], "[REDACTED]");
+    assert!(missing["timestamp"].is_string());
+
+    let listed = entries
+        .iter()
+        .find(|entry| entry["tool"] == "list_tools")
+        .expect("successful call is audited");
+    assert_eq!(listed["success"], true);
+}
+
+async fn final_response_for(reply: &str) -> serde_json::Value {
+    agent_with(&[reply])
+        .run("halo".to_string(), AgentOptions::default())
+        .await
+        .expect("final answer")
+        .response
+}
+
+#[tokio::test]
+async fn doubly_wrapped_final_responses_are_unwrapped() {
+    let nested = r#"{"action":"final","response":{"action":"final","response":"siap"}}"#;
+    assert_eq!(final_response_for(nested).await, serde_json::json!("siap"));
+
+    let json_in_string = r#"{"action":"final","response":"{\"response\": \"siap\"}"}"#;
+    assert_eq!(
+        final_response_for(json_in_string).await,
+        serde_json::json!("siap")
+    );
+
+    let fenced = r#"{"action":"final","response":"```json\n{\"action\":\"final\",\"response\":\"siap\"}\n```"}"#;
+    assert_eq!(final_response_for(fenced).await, serde_json::json!("siap"));
+
+    let fenced_prose = r#"{"action":"final","response":"```\nJawaban siap.\n```"}"#;
+    assert_eq!(
+        final_response_for(fenced_prose).await,
+        serde_json::json!("Jawaban siap.")
+    );
+}
+
+#[tokio::test]
+async fn plain_final_responses_pass_through() {
+    let prose = r#"{"action":"final","response":"Gunakan {response} sebagai placeholder."}"#;
+    assert_eq!(
+        final_response_for(prose).await,
+        serde_json::json!("Gunakan {response} sebagai placeholder.")
+    );
+
+    let code = r#"{"action":"final","response":"```rust\nfn main() {}\n```"}"#;
+    assert_eq!(
+        final_response_for(code).await,
+        serde_json::json!("```rust\nfn main() {}\n```")
+    );
+
+    let structured = r#"{"action":"final","response":{"content":"siap","data":"step_0"}}"#;
+    assert_eq!(
+        final_response_for(structured).await,
+        serde_json::json!({"content": "siap", "data": "step_0"})
+    );
+}
+
+#[tokio::test]
+async fn think_block_before_final_json_is_stripped_and_logged() {
+    let reply = "<think>\nPengguna menyapa. Cukup jawab singkat.\n</think>\n\n{\"action\":\"final\",\"response\":\"siap\"}";
+    let client = McpClient::new(
+        ScriptedProvider::new(&[reply]),
+        ClientConfig::new("mock", "mock-model").with_reasoning(ReasoningConfig {
+            log: true,
+            ..ReasoningConfig::default()
+        }),
+    );
+
+    let outcome = Agent::new(Arc::new(client))
+        .run("halo".to_string(), AgentOptions::default())
+        .await
+        .expect("final answer");
+
+    assert_eq!(outcome.response, serde_json::json!("siap"));
+    assert!(
+        outcome
+            .logs
+            .contains(&"Reasoning: Pengguna menyapa. Cukup jawab singkat.".to_string()),
+        "{:?}",
+        outcome.logs
+    );
+}
+
+/// Records the provider named by every model request.
+struct RecordingProvider {
+    script: ScriptedProvider,
+    providers: Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for RecordingProvider {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        self.providers
+            .lock()
+            .unwrap()
+            .push(request.provider.clone());
+        self.script.chat(request).await
+    }
+}
+
+#[tokio::test]
+async fn agent_provider_option_is_sent_on_every_model_request() {
+    let providers = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = McpClient::new(
+        RecordingProvider {
+            script: ScriptedProvider::new(&[UNKNOWN_TOOL_CALL, "bukan json", FINAL_ANSWER]),
+            providers: providers.clone(),
+        },
+        ClientConfig::new("mock", "mock-model"),
+    );
+
+    Agent::new(Arc::new(client))
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                provider: Some("local".to_string()),
+                ..AgentOptions::default()
+            },
+        )
+        .await
+        .expect("agent run");
+
+    // Tool call, malformed reply, JSON retry: every request names "local".
+    assert_eq!(*providers.lock().unwrap(), vec!["local"; 3]);
+}
+
+/// Records the last user message of every model request.
+struct PromptRecorder {
+    script: ScriptedProvider,
+    prompts: Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for PromptRecorder {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        if let Some(message) = request.messages.last() {
+            self.prompts.lock().unwrap().push(message.content());
+        }
+        self.script.chat(request).await
+    }
+}
+
+#[tokio::test]
+async fn agent_metadata_is_added_to_the_initial_user_request() {
+    let recorded = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = McpClient::new(
+        PromptRecorder {
+            script: ScriptedProvider::new(&[FINAL_ANSWER]),
+            prompts: recorded.clone(),
+        },
+        ClientConfig::new("mock", "mock-model"),
+    );
+
+    Agent::new(Arc::new(client))
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                metadata: [("user_id".to_string(), "u-42".to_string())].into(),
+                ..AgentOptions::default()
+            },
+        )
+        .await
+        .expect("agent run");
+
+    let first: serde_json::Value =
+        serde_json::from_str(&recorded.lock().unwrap()[0]).expect("JSON payload");
+    assert_eq!(first["action"], "user_request");
+    assert_eq!(first["metadata"], serde_json::json!({ "user_id": "u-42" }));
+}
+
+async fn tool_result_prompt(prompts: PromptsConfig) -> String {
+    let recorded = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = McpClient::new(
+        PromptRecorder {
+            script: ScriptedProvider::new(&[
+                r#"{"action":"call_tool","tool":"list_tools","input":{}}"#,
+                FINAL_ANSWER,
+            ]),
+            prompts: recorded.clone(),
+        },
+        ClientConfig::new("mock", "mock-model").with_prompts(prompts),
+    );
+    Agent::new(Arc::new(client))
+        .run("halo".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+    let recorded = recorded.lock().unwrap();
+    assert_eq!(recorded.len(), 2);
+    recorded[1].clone()
+}
+
+/// Records the model and last message of every model request.
+struct RequestRecorder {
+    script: ScriptedProvider,
+    requests: Arc<std::sync::Mutex<Vec<(String, String)>>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for RequestRecorder {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        let last = request
+            .messages
+            .last()
+            .map(|message| message.content())
+            .unwrap_or_default();
+        self.requests
+            .lock()
+            .unwrap()
+            .push((request.model.clone(), last));
+        self.script.chat(request).await
+    }
+}
+
+#[tokio::test]
+async fn oversized_tool_result_is_summarized_but_step_keeps_full_output() {
+    let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = McpClient::new(
+        RequestRecorder {
+            script: ScriptedProvider::new(&[
+                r#"{"action":"call_tool","tool":"list_tools","input":{}}"#,
+                "Tidak ada tool yang terdaftar.",
+                FINAL_ANSWER,
+            ]),
+            requests: requests.clone(),
+        },
+        ClientConfig::new("mock", "mock-model"),
+    );
+
+    let outcome = Agent::new(Arc::new(client))
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                summarize_tool_results: true,
+                summarizer_model: Some("mini".to_string()),
+                // list_tools with no tools returns `{}`.
+                summarize_threshold_chars: 1,
+                ..AgentOptions::default()
+            },
+        )
+        .await
+        .expect("agent run");
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3, "{requests:?}");
+    let (summary_model, summary_prompt) = &requests[1];
+    assert_eq!(summary_model, "mini");
+    assert!(summary_prompt.starts_with("Tool: list_tools"));
+
+    let payload: serde_json::Value = serde_json::from_str(&requests[2].1).expect("tool result");
+    let output = payload["tool_result"]["output"].as_str().expect("summary");
+    assert!(output.starts_with("[Summary of "), "{output}");
+    assert!(output.ends_with("Tidak ada tool yang terdaftar."));
+    assert!(payload["tool_result"]["message"].is_null());
+
+    // The recorded step keeps the full output.
+    assert_eq!(outcome.steps[0].output, serde_json::json!({}));
+    assert!(
+        outcome
+            .logs
+            .iter()
+            .any(|line| line.contains("output summarized"))
+    );
+}
+
+#[tokio::test]
+async fn small_tool_results_are_not_summarized() {
+    let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = McpClient::new(
+        RequestRecorder {
+            script: ScriptedProvider::new(&[
+                r#"{"action":"call_tool","tool":"list_tools","input":{}}"#,
+                FINAL_ANSWER,
+            ]),
+            requests: requests.clone(),
+        },
+        ClientConfig::new("mock", "mock-model"),
+    );
+
+    Agent::new(Arc::new(client))
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                summarize_tool_results: true,
+                ..AgentOptions::default()
+            },
+        )
+        .await
+        .expect("agent run");
+
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn guarded_tool_output_is_wrapped_as_untrusted() {
+    let prompt = tool_result_prompt(PromptsConfig {
+        guard_tool_output: Some(true),
+        ..PromptsConfig::default()
+    })
+    .await;
+    let payload: serde_json::Value = serde_json::from_str(&prompt).expect("tool result JSON");
+    let output = payload["tool_result"]["output"]
+        .as_str()
+        .expect("guarded output is one text block");
+
+    assert!(output.starts_with(PromptsConfig::default_untrusted_tool_output_notice()));
+    assert!(output.contains("<<<BEGIN UNTRUSTED TOOL OUTPUT>>>"));
+    assert!(
+        output
+            .trim_end()
+            .ends_with("<<<END UNTRUSTED TOOL OUTPUT>>>")
+    );
+    assert!(payload["tool_result"].get("message").is_none());
+}
+
+#[tokio::test]
+async fn tool_output_is_unwrapped_when_guard_is_off() {
+    let prompt = tool_result_prompt(PromptsConfig::default()).await;
+    let payload: serde_json::Value = serde_json::from_str(&prompt).expect("tool result JSON");
+
+    assert!(payload["tool_result"]["output"].is_object());
+    assert!(!prompt.contains("UNTRUSTED TOOL OUTPUT"));
+}
+
+/// Answers every tool call with a canned payload and records what was invoked.
+#[derive(Default)]
+struct StubBridge {
+    calls: std::sync::Mutex<Vec<(String, String, serde_json::Value)>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ToolServerInterface for StubBridge {
+    async fn invoke_tool(
+        &self,
+        server: &str,
+        tool: &str,
+        arguments: serde_json::Value,
+    ) -> Result<serde_json::Value, ToolInvokeError> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push((server.to_string(), tool.to_string(), arguments));
+        Ok(serde_json::json!({"content": [{"type": "text", "text": "Cerah, 31°C"}]}))
+    }
+
+    async fn server_instructions(&self, _server: &str) -> Option<String> {
+        None
+    }
+
+    async fn tool_metadata(&self, _server: &str, _tool: &str) -> Option<ServerToolInfo> {
+        None
+    }
+}
+
+#[tokio::test]
+async fn injected_bridge_serves_agent_tool_calls() {
+    let bridge = Arc::new(StubBridge::default());
+    let config = ClientConfig::new("mock", "mock-model").with_tools(vec![ToolConfig {
+        name: "get_weather".to_string(),
+        description: Some("Cuaca terkini".to_string()),
+        server: Some("stub".to_string()),
+        remote_name: None,
+        cacheable: false,
+    }]);
+    let client = McpClient::with_bridge(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Jakarta"}}"#,
+            FINAL_ANSWER,
+        ]),
+        config,
+        bridge.clone(),
+    );
+
+    let outcome = Agent::new(Arc::new(client))
+        .run("cuaca?".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    assert_eq!(
+        *bridge.calls.lock().unwrap(),
+        vec![(
+            "stub".to_string(),
+            "get_weather".to_string(),
+            serde_json::json!({"city": "Jakarta"})
+        )]
+    );
+    assert_eq!(outcome.steps.len(), 1);
+    assert!(outcome.steps[0].success);
+    assert!(outcome.steps[0].output.to_string().contains("Cerah"));
+}
+
+#[tokio::test]
+async fn tool_calls_are_sent_under_the_configured_remote_name() {
+    let bridge = Arc::new(StubBridge::default());
+    let config = ClientConfig::new("mock", "mock-model").with_tools(vec![ToolConfig {
+        name: "get_weather".to_string(),
+        description: Some("Cuaca terkini".to_string()),
+        server: Some("stub".to_string()),
+        remote_name: Some("weather.GetCurrent".to_string()),
+        cacheable: false,
+    }]);
+    let client = McpClient::with_bridge(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Bandung"}}"#,
+            FINAL_ANSWER,
+        ]),
+        config,
+        bridge.clone(),
+    );
+
+    let outcome = Agent::new(Arc::new(client))
+        .run("cuaca?".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    assert_eq!(
+        *bridge.calls.lock().unwrap(),
+        vec![(
+            "stub".to_string(),
+            "weather.GetCurrent".to_string(),
+            serde_json::json!({"city": "Bandung"})
+        )]
+    );
+    assert_eq!(outcome.steps[0].tool, "get_weather");
+}
+
+/// Answers every tool call with a chart: one text block and one PNG block.
+struct ChartBridge;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ToolServerInterface for ChartBridge {
+    async fn invoke_tool(
+        &self,
+        _server: &str,
+        _tool: &str,
+        _arguments: serde_json::Value,
+    ) -> Result<serde_json::Value, ToolInvokeError> {
+        Ok(serde_json::json!({"content": [
+            {"type": "image", "mimeType": "image/png", "data": "aGVsbG8h"},
+            {"type": "text", "text": "Grafik suhu minggu ini"}
+        ]}))
+    }
+
+    async fn server_instructions(&self, _server: &str) -> Option<String> {
+        None
+    }
+
+    async fn tool_metadata(&self, _server: &str, _tool: &str) -> Option<ServerToolInfo> {
+        None
+    }
+}
+
+#[tokio::test]
+async fn image_blocks_are_noted_in_the_step_message_and_kept_in_output() {
+    let config = ClientConfig::new("mock", "mock-model").with_tools(vec![ToolConfig {
+        name: "chart".to_string(),
+        description: None,
+        server: Some("stub".to_string()),
+        remote_name: None,
+        cacheable: false,
+    }]);
+    let client = McpClient::with_bridge(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"chart","input":{}}"#,
+            FINAL_ANSWER,
+        ]),
+        config,
+        Arc::new(ChartBridge),
+    );
+
+    let outcome = Agent::new(Arc::new(client))
+        .run("grafik?".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    let step = &outcome.steps[0];
+    assert_eq!(
+        step.message.as_deref(),
+        Some("Grafik suhu minggu ini\n[image: image/png, 6 bytes]")
+    );
+    assert_eq!(step.output["content"][0]["data"], "aGVsbG8h");
+}
+
+#[tokio::test]
+async fn event_sink_sees_tool_steps_and_completion_in_order() {
+    let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink_events = events.clone();
+    let client = McpClient::with_bridge(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Jakarta"}}"#,
+            FINAL_ANSWER,
+        ]),
+        ClientConfig::new("mock", "mock-model").with_tools(vec![ToolConfig {
+            name: "get_weather".to_string(),
+            description: Some("Cuaca terkini".to_string()),
+            server: Some("stub".to_string()),
+            remote_name: None,
+            cacheable: false,
+        }]),
+        Arc::new(StubBridge::default()),
+    );
+
+    Agent::new(Arc::new(client))
+        .with_event_sink(Arc::new(move |event: &AgentEvent| {
+            sink_events.lock().unwrap().push(event.clone());
+        }))
+        .run("cuaca?".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    let tool = |phase| AgentEvent::Tool {
+        tool_name: "get_weather".to_string(),
+        phase,
+    };
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            tool(ToolEventPhase::Started),
+            tool(ToolEventPhase::Finished),
+            AgentEvent::Completed,
+        ]
+    );
+}
+
+/// Reports halfway progress through the caller's progress sink before
+/// answering, as a bridge to a slow server would.
+struct ProgressBridge;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ToolServerInterface for ProgressBridge {
+    async fn invoke_tool(
+        &self,
+        server: &str,
+        tool: &str,
+        _arguments: serde_json::Value,
+    ) -> Result<serde_json::Value, ToolInvokeError> {
+        if let Some(sink) = current_call_progress() {
+            sink(&ToolProgress {
+                server: server.to_string(),
+                tool: Some(tool.to_string()),
+                progress_token: "report-1".to_string(),
+                progress: 2.0,
+                total: Some(4.0),
+                message: Some("halaman 2".to_string()),
+            });
+        }
+        Ok(serde_json::json!({"content": [{"type": "text", "text": "laporan siap"}]}))
+    }
+
+    async fn server_instructions(&self, _server: &str) -> Option<String> {
+        None
+    }
+
+    async fn tool_metadata(&self, _server: &str, _tool: &str) -> Option<ServerToolInfo> {
+        None
+    }
+}
+
+#[tokio::test]
+async fn tool_progress_reaches_the_event_sink_while_the_tool_runs() {
+    let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink_events = events.clone();
+    let client = McpClient::with_bridge(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"get_report","input":{}}"#,
+            FINAL_ANSWER,
+        ]),
+        ClientConfig::new("mock", "mock-model").with_tools(vec![ToolConfig {
+            name: "get_report".to_string(),
+            description: Some("Laporan bulanan".to_string()),
+            server: Some("stub".to_string()),
+            remote_name: None,
+            cacheable: false,
+        }]),
+        Arc::new(ProgressBridge),
+    );
+
+    Agent::new(Arc::new(client))
+        .with_event_sink(Arc::new(move |event: &AgentEvent| {
+            sink_events.lock().unwrap().push(event.clone());
+        }))
+        .run("laporan?".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    let events = events.lock().unwrap();
+    assert_eq!(
+        events[1],
+        AgentEvent::ToolProgress {
+            tool_name: "get_report".to_string(),
+            percent: Some(50),
+            message: Some("halaman 2".to_string()),
+        }
+    );
+    assert_eq!(
+        events[1].progress_label().as_deref(),
+        Some("get_report: 50% — halaman 2")
+    );
+    assert!(matches!(
+        events[2],
+        AgentEvent::Tool {
+            phase: ToolEventPhase::Finished,
+            ..
+        }
+    ));
+}
+
+fn time_tool(name: &str) -> ToolConfig {
+    ToolConfig {
+        name: name.to_string(),
+        description: Some("Waktu sekarang".to_string()),
+        server: Some("stub".to_string()),
+        remote_name: None,
+        cacheable: false,
+    }
+}
+
+async fn invoked_tools(config: ClientConfig, requested: &[&str]) -> Vec<String> {
+    let bridge = Arc::new(StubBridge::default());
+    let mut responses: Vec<String> = requested
+        .iter()
+        .map(|tool| format!(r#"{{"action":"call_tool","tool":"{tool}","input":{{}}}}"#))
+        .collect();
+    responses.push(FINAL_ANSWER.to_string());
+    let responses: Vec<&str> = responses.iter().map(String::as_str).collect();
+    let client = McpClient::with_bridge(ScriptedProvider::new(&responses), config, bridge.clone());
+
+    Agent::new(Arc::new(client))
+        .run("jam berapa?".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+    let calls = bridge.calls.lock().unwrap();
+    calls.iter().map(|(_, tool, _)| tool.clone()).collect()
+}
+
+#[tokio::test]
+async fn tool_names_are_matched_ignoring_case_but_dispatched_exactly() {
+    let config = ClientConfig::new("mock", "mock-model").with_tools(vec![time_tool("GetTime")]);
+
+    assert_eq!(
+        invoked_tools(config, &["gettime", "GETTIME"]).await,
+        vec!["GetTime", "GetTime"]
+    );
+}
+
+#[tokio::test]
+async fn case_sensitive_tools_keep_names_that_differ_only_in_case_apart() {
+    let config = ClientConfig::new("mock", "mock-model")
+        .with_tools(vec![time_tool("GetTime"), time_tool("gettime")])
+        .with_case_sensitive_tools(true);
+
+    assert_eq!(
+        invoked_tools(config.clone(), &["gettime", "GetTime"]).await,
+        vec!["gettime", "GetTime"]
+    );
+    // An exact match is required, so a third casing is an unknown tool.
+    assert!(invoked_tools(config, &["GETTIME"]).await.is_empty());
+}
+
+/// Tool-result prompts of a run that checks the weather, then the time.
+async fn two_step_prompts(options: AgentOptions) -> Vec<serde_json::Value> {
+    let recorded = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let config = ClientConfig::new("mock", "mock-model").with_tools(vec![
+        ToolConfig {
+            name: "get_weather".to_string(),
+            description: Some("Cuaca terkini".to_string()),
+            server: Some("stub".to_string()),
+            remote_name: None,
+            cacheable: false,
+        },
+        time_tool("GetTime"),
+    ]);
+    let client = McpClient::with_bridge(
+        PromptRecorder {
+            script: ScriptedProvider::new(&[
+                r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Jakarta"}}"#,
+                r#"{"action":"call_tool","tool":"GetTime","input":{}}"#,
+                FINAL_ANSWER,
+            ]),
+            prompts: recorded.clone(),
+        },
+        config,
+        Arc::new(StubBridge::default()),
+    );
+    Agent::new(Arc::new(client))
+        .run("cuaca dan jam?".to_string(), options)
+        .await
+        .expect("agent run");
+    let recorded = recorded.lock().unwrap();
+    assert_eq!(recorded.len(), 3);
+    recorded[1..]
+        .iter()
+        .map(|prompt| serde_json::from_str(prompt).expect("tool result JSON"))
+        .collect()
+}
+
+#[tokio::test]
+async fn scratchpad_carries_earlier_steps_into_later_prompts() {
+    let prompts = two_step_prompts(AgentOptions {
+        scratchpad: true,
+        ..AgentOptions::default()
+    })
+    .await;
+
+    // The last prompt's tool_result is about GetTime only; the scratchpad
+    // still remembers the weather lookup.
+    let last = &prompts[1];
+    assert!(!last["tool_result"].to_string().contains("Jakarta"));
+    let entries = last["scratchpad"].as_array().expect("scratchpad lines");
+    assert_eq!(entries.len(), 2);
+    let first = entries[0].as_str().unwrap();
+    assert!(first.contains("get_weather"), "{first}");
+    assert!(first.contains("Jakarta"), "{first}");
+    assert!(first.contains("Cerah"), "{first}");
+    assert!(entries[1].as_str().unwrap().contains("GetTime"));
+}
+
+#[tokio::test]
+async fn scratchpad_is_off_by_default_and_drops_oldest_steps_past_its_bound() {
+    let prompts = two_step_prompts(AgentOptions::default()).await;
+    assert!(
+        prompts
+            .iter()
+            .all(|prompt| prompt.get("scratchpad").is_none())
+    );
+
+    let prompts = two_step_prompts(AgentOptions {
+        scratchpad: true,
+        scratchpad_max_chars: 60,
+        ..AgentOptions::default()
+    })
+    .await;
+    let entries = prompts[1]["scratchpad"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0].as_str().unwrap().contains("GetTime"));
+}
+
+#[tokio::test]
+async fn repeating_the_same_tool_call_is_nudged_then_aborted() {
+    let agent = agent_with(&[UNKNOWN_TOOL_CALL]);
+
+    let result = agent.run("halo".to_string(), AgentOptions::default()).await;
+
+    assert!(
+        matches!(
+            &result,
+            Err(AgentError::ToolLoop { tool, repeats: 3 }) if tool == "missing_tool"
+        ),
+        "{result:?}"
+    );
+}
+
+#[tokio::test]
+async fn model_that_stops_after_the_nudge_finishes_with_loop_detected() {
+    let script = [
+        UNKNOWN_TOOL_CALL,
+        UNKNOWN_TOOL_CALL,
+        UNKNOWN_TOOL_CALL,
+        UNKNOWN_TOOL_CALL,
+        FINAL_ANSWER,
+    ];
+    let outcome = agent_with(&script)
+        .run("halo".to_string(), AgentOptions::default())
+        .await
+        .expect("the model answered after being nudged");
+
+    assert!(outcome.loop_detected);
+    assert_eq!(outcome.steps.len(), 3);
+    assert_eq!(outcome.response, serde_json::json!("siap"));
+
+    let outcome = agent_with(&script)
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                max_identical_tool_calls: 0,
+                ..AgentOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(!outcome.loop_detected);
+    assert_eq!(outcome.steps.len(), 4);
+}
+
+/// Keeps calling a tool until it is told the step budget is spent.
+struct UntilForcedProvider;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for UntilForcedProvider {
+    async fn chat(&self, request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        let forced = request
+            .messages
+            .last()
+            .is_some_and(|message| message.content().contains("step_limit_reached"));
+        let content = if forced {
+            r#"{"action":"final","response":"sejauh ini: belum lengkap"}"#
+        } else {
+            UNKNOWN_TOOL_CALL
+        };
+        Ok(ModelResponse::new(content.to_string(), None))
+    }
+}
+
+#[tokio::test]
+async fn step_limit_forces_a_final_answer_when_enabled() {
+    let agent = || {
+        Agent::new(Arc::new(McpClient::new(
+            UntilForcedProvider,
+            ClientConfig::new("mock", "mock-model"),
+        )))
+    };
+    let options = AgentOptions {
+        max_steps: Some(2),
+        max_identical_tool_calls: 0,
+        ..AgentOptions::default()
+    };
+
+    let result = agent().run("halo".to_string(), options.clone()).await;
+    assert!(
+        matches!(result, Err(AgentError::InvalidResponse(_))),
+        "{result:?}"
+    );
+
+    let outcome = agent()
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                force_final_on_step_limit: true,
+                ..options
+            },
+        )
+        .await
+        .expect("the forced turn answers");
+    assert!(outcome.hit_step_limit);
+    assert_eq!(outcome.steps.len(), 2);
+    assert_eq!(
+        outcome.response,
+        serde_json::json!("sejauh ini: belum lengkap")
+    );
+}
+
+#[tokio::test]
+async fn trace_records_the_action_of_every_turn() {
+    let agent = Agent::new(Arc::new(McpClient::new(
+        UntilForcedProvider,
+        ClientConfig::new("mock", "mock-model"),
+    )));
+    let options = AgentOptions {
+        max_steps: Some(2),
+        max_identical_tool_calls: 0,
+        force_final_on_step_limit: true,
+        ..AgentOptions::default()
+    };
+
+    let untraced = agent
+        .run("halo".to_string(), options.clone())
+        .await
+        .unwrap();
+    assert!(untraced.reasoning_trace.is_none());
+
+    let outcome = agent
+        .run(
+            "halo".to_string(),
+            AgentOptions {
+                trace: true,
+                ..options
+            },
+        )
+        .await
+        .unwrap();
+    // Two tool turns, the turn past the budget, and the forced final turn.
+    let trace = outcome.reasoning_trace.expect("trace requested");
+    let actions: Vec<&str> = trace
+        .iter()
+        .map(|turn| turn["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        actions,
+        vec!["call_tool", "call_tool", "call_tool", "final"]
+    );
+    assert_eq!(
+        trace[3]["response"],
+        serde_json::json!("sejauh ini: belum lengkap")
+    );
+}
+
+/// Answers each request only after the test opens the gate for it.
+struct GatedProvider {
+    gate: Arc<tokio::sync::Notify>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ModelProvider for GatedProvider {
+    async fn chat(&self, _request: ModelRequest) -> Result<ModelResponse, ModelError> {
+        self.gate.notified().await;
+        Ok(ModelResponse::new(FINAL_ANSWER.to_string(), None))
+    }
+}
+
+fn gated_client(
+    queue_timeout: Option<Duration>,
+) -> (Arc<McpClient<GatedProvider>>, Arc<tokio::sync::Notify>) {
+    let gate = Arc::new(tokio::sync::Notify::new());
+    let client = McpClient::new(
+        GatedProvider { gate: gate.clone() },
+        ClientConfig::new("mock", "mock-model").with_max_concurrent_agents(1, queue_timeout),
+    );
+    (Arc::new(client), gate)
+}
+
+#[tokio::test(start_paused = true)]
+async fn agent_run_past_the_cap_fails_once_the_queue_timeout_runs_out() {
+    let (client, gate) = gated_client(Some(Duration::from_millis(50)));
+    let first = tokio::spawn({
+        let client = client.clone();
+        async move {
+            Agent::new(client)
+                .run("satu".to_string(), AgentOptions::default())
+                .await
+        }
+    });
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let second = Agent::new(client.clone())
+        .run("dua".to_string(), AgentOptions::default())
+        .await;
+    assert!(matches!(second, Err(AgentError::Busy)), "{second:?}");
+
+    gate.notify_one();
+    first.await.unwrap().expect("first run");
+}
+
+#[tokio::test(start_paused = true)]
+async fn agent_run_past_the_cap_waits_for_a_free_slot() {
+    let (client, gate) = gated_client(None);
+    let spawn_run = |prompt: &str| {
+        let client = client.clone();
+        let prompt = prompt.to_string();
+        tokio::spawn(async move {
+            Agent::new(client)
+                .run(prompt, AgentOptions::default())
+                .await
+        })
+    };
+    let first = spawn_run("satu");
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    let second = spawn_run("dua");
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    assert!(!second.is_finished());
+
+    gate.notify_one();
+    first.await.unwrap().expect("first run");
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    gate.notify_one();
+    second.await.unwrap().expect("second run");
+}
+
+/// Answers `get_weather` at once and leaves every other tool hanging, as a
+/// stuck server would.
+struct SlowBridge;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ToolServerInterface for SlowBridge {
+    async fn invoke_tool(
+        &self,
+        _server: &str,
+        tool: &str,
+        _arguments: serde_json::Value,
+    ) -> Result<serde_json::Value, ToolInvokeError> {
+        if tool != "get_weather" {
+            tokio::time::sleep(Duration::from_secs(3600)).await;
+        }
+        Ok(serde_json::json!({"content": [{"type": "text", "text": "Cerah, 31°C"}]}))
+    }
+
+    async fn server_instructions(&self, _server: &str) -> Option<String> {
+        None
+    }
+
+    async fn tool_metadata(&self, _server: &str, _tool: &str) -> Option<ServerToolInfo> {
+        None
+    }
+}
+
+fn slow_tool_agent() -> Agent<ScriptedProvider> {
+    let client = McpClient::with_bridge(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Jakarta"}}"#,
+            r#"{"action":"call_tool","tool":"get_forecast","input":{"city":"Jakarta"}}"#,
+            FINAL_ANSWER,
+        ]),
+        ClientConfig::new("mock", "mock-model")
+            .with_tools(vec![time_tool("get_weather"), time_tool("get_forecast")])
+            .with_tool_timeout(Duration::from_secs(5)),
+        Arc::new(SlowBridge),
+    );
+    Agent::new(Arc::new(client))
+}
+
+#[tokio::test(start_paused = true)]
+async fn timed_out_tool_is_reported_to_the_model_and_run_continues() {
+    let outcome = slow_tool_agent()
+        .run("cuaca?".to_string(), AgentOptions::default())
+        .await
+        .expect("a timeout is reported like any other tool failure");
+
+    assert_eq!(outcome.steps.len(), 2);
+    assert!(outcome.steps[0].success);
+    let timed_out = &outcome.steps[1];
+    assert_eq!(timed_out.tool, "get_forecast");
+    assert!(!timed_out.success);
+    assert_eq!(
+        timed_out.message.as_deref(),
+        Some("tool 'get_forecast' did not finish within 5s")
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn aborting_on_a_timed_out_tool_keeps_the_completed_steps() {
+    let result = slow_tool_agent()
+        .run(
+            "cuaca?".to_string(),
+            AgentOptions {
+                abort_on_tool_error: true,
+                ..AgentOptions::default()
+            },
+        )
+        .await;
+
+    let Err(err) = result else {
+        panic!("the run should stop at the timed out tool");
+    };
+    assert!(matches!(
+        err,
+        AgentError::ToolTimeout { ref tool, after, .. }
+            if tool == "get_forecast" && after == Duration::from_secs(5)
+    ));
+    assert_eq!(err.partial_steps().len(), 1);
+    assert_eq!(err.partial_steps()[0].tool, "get_weather");
+    assert_eq!(
+        err.user_message_in(Locale::En),
+        "Tool \"get_forecast\" did not finish within 5 seconds."
+    );
+}
+
+/// Serves `online` and fails to connect to `offline`, recording which servers
+/// were asked for tool metadata.
+#[derive(Default)]
+struct PartlyOfflineBridge {
+    metadata_requests: std::sync::Mutex<Vec<String>>,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ToolServerInterface for PartlyOfflineBridge {
+    async fn invoke_tool(
+        &self,
+        _server: &str,
+        _tool: &str,
+        _arguments: serde_json::Value,
+    ) -> Result<serde_json::Value, ToolInvokeError> {
+        Ok(serde_json::json!({"content": [{"type": "text", "text": "Cerah, 31°C"}]}))
+    }
+
+    async fn server_instructions(&self, server: &str) -> Option<String> {
+        Some(format!("{server} siap"))
+    }
+
+    async fn tool_metadata(&self, server: &str, _tool: &str) -> Option<ServerToolInfo> {
+        self.metadata_requests
+            .lock()
+            .unwrap()
+            .push(server.to_string());
+        None
+    }
+
+    async fn probe_server(&self, server: &str) -> Result<(), ToolInvokeError> {
+        if server == "offline" {
+            return Err(ToolInvokeError::Transport {
+                server: server.to_string(),
+                message: "connection refused".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn tools_of_an_unreachable_server_are_marked_unavailable() {
+    let recorded = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let bridge = Arc::new(PartlyOfflineBridge::default());
+    let client = McpClient::with_bridge(
+        PromptRecorder {
+            script: ScriptedProvider::new(&[FINAL_ANSWER]),
+            prompts: recorded.clone(),
+        },
+        ClientConfig::new("mock", "mock-model").with_tools(vec![
+            ToolConfig {
+                name: "get_weather".to_string(),
+                description: Some("Cuaca terkini".to_string()),
+                server: Some("online".to_string()),
+                remote_name: None,
+                cacheable: false,
+            },
+            ToolConfig {
+                name: "get_tide".to_string(),
+                description: Some("Pasang surut".to_string()),
+                server: Some("offline".to_string()),
+                remote_name: None,
+                cacheable: false,
+            },
+        ]),
+        bridge.clone(),
+    );
+
+    Agent::new(Arc::new(client))
+        .run("cuaca?".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    let first: serde_json::Value =
+        serde_json::from_str(&recorded.lock().unwrap()[0]).expect("JSON payload");
+    let context = &first["tool_context"];
+    assert_eq!(
+        context["servers"],
+        serde_json::json!([{ "name": "online", "instruction": "online siap" }])
+    );
+    let tools = context["tools"].as_array().expect("tools");
+    let tool = |name: &str| {
+        tools
+            .iter()
+            .find(|tool| tool["name"] == name)
+            .expect("listed tool")
+    };
+    assert!(tool("get_weather").get("unavailable").is_none());
+    let reason = tool("get_tide")["unavailable"].as_str().expect("reason");
+    assert!(reason.contains("'offline' is unreachable"), "{reason}");
+    assert!(reason.contains("connection refused"), "{reason}");
+    // The offline server is not asked again for metadata.
+    assert_eq!(*bridge.metadata_requests.lock().unwrap(), vec!["online"]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn retried_run_with_the_same_idempotency_key_is_not_executed_again() {
+    let bridge = Arc::new(StubBridge::default());
+    let weather = r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Jakarta"}}"#;
+    let client = Arc::new(McpClient::with_bridge(
+        // One tool call per run that is actually executed.
+        ScriptedProvider::new(&[
+            weather,
+            FINAL_ANSWER,
+            weather,
+            FINAL_ANSWER,
+            weather,
+            FINAL_ANSWER,
+        ]),
+        ClientConfig::new("mock", "mock-model")
+            .with_tools(vec![time_tool("get_weather")])
+            .with_idempotency_window(Duration::from_secs(60)),
+        bridge.clone(),
+    ));
+    let run = |key: &str| {
+        let agent = Agent::new(client.clone());
+        let options = AgentOptions {
+            idempotency_key: Some(key.to_string()),
+            ..AgentOptions::default()
+        };
+        async move { agent.run("cuaca?".to_string(), options).await }
+    };
+    let calls = || bridge.calls.lock().unwrap().len();
+
+    let first = run("req-1").await.expect("first run");
+    let retried = run("req-1").await.expect("retried run");
+    assert_eq!(calls(), 1);
+    assert_eq!(retried.session_id, first.session_id);
+    assert_eq!(retried.steps, first.steps);
+
+    run("req-2").await.expect("run with another key");
+    assert_eq!(calls(), 2);
+
+    tokio::time::advance(Duration::from_secs(61)).await;
+    run("req-1").await.expect("run after the window");
+    assert_eq!(calls(), 3);
+}
+
+#[tokio::test]
+async fn terminal_tool_result_ends_the_run_without_another_model_call() {
+    let recorded = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let client = McpClient::with_bridge(
+        PromptRecorder {
+            script: ScriptedProvider::new(&[
+                r#"{"action":"call_tool","tool":"submit_report","input":{"id":7}}"#,
+                FINAL_ANSWER,
+            ]),
+            prompts: recorded.clone(),
+        },
+        ClientConfig::new("mock", "mock-model")
+            .with_tools(vec![time_tool("submit_report")])
+            .with_terminal_tools(vec!["Submit_Report".to_string()]),
+        Arc::new(StubBridge::default()),
+    );
+
+    let outcome = Agent::new(Arc::new(client))
+        .run("kirim laporan".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    assert_eq!(recorded.lock().unwrap().len(), 1);
+    assert_eq!(outcome.response, serde_json::json!("Cerah, 31°C"));
+    assert_eq!(outcome.steps.len(), 1);
+    assert_eq!(outcome.steps[0].tool, "submit_report");
+}
+
+#[test]
+fn summarized_schema_hint_keeps_property_names_and_types_only() {
+    use antikythera_core::application::agent::ToolDescriptor;
+    use antikythera_core::config::ToolSchemaDetail;
+
+    let descriptor = ToolDescriptor {
+        name: "forecast".to_string(),
+        title: None,
+        description: Some("Weather forecast".to_string()),
+        server: Some("weather".to_string()),
+        icons: None,
+        input_schema: Some(serde_json::json!({
+            "type": "object",
+            "description": "Forecast request",
+            "properties": {
+                "city": {
+                    "type": "string",
+                    "description": "City name",
+                    "examples": ["Jakarta"]
+                },
+                "days": {"type": "integer", "default": 3, "minimum": 1},
+                "units": {
+                    "type": "array",
+                    "items": {"type": "string", "description": "Unit"}
+                }
+            },
+            "required": ["city"],
+            "examples": [{"city": "Bandung"}]
+        })),
+        output_schema: None,
+        annotations: None,
+        execution: None,
+        unavailable: None,
+    };
+
+    let summary = descriptor
+        .schema_hint(ToolSchemaDetail::Summary)
+        .expect("summary");
+    let parsed: serde_json::Value = serde_json::from_str(&summary).expect("summary is JSON");
+    assert_eq!(
+        parsed,
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "city": {"type": "string"},
+                "days": {"type": "integer"},
+                "units": {"type": "array", "items": {"type": "string"}}
+            },
+            "required": ["city"]
+        })
+    );
+    assert!(!summary.contains("Jakarta"), "{summary}");
+
+    let full = descriptor
+        .schema_hint(ToolSchemaDetail::Full)
+        .expect("full schema");
+    assert!(full.contains("City name"), "{full}");
+    assert_eq!(descriptor.schema_hint(ToolSchemaDetail::Omit), None);
+    assert_eq!(
+        PromptsConfig::default().tool_schema_detail(),
+        ToolSchemaDetail::Full
+    );
+}
+
+#[tokio::test]
+async fn cacheable_tool_results_are_reused_across_runs() {
+    let bridge = Arc::new(StubBridge::default());
+    let tool = |name: &str, cacheable: bool| ToolConfig {
+        name: name.to_string(),
+        description: None,
+        server: Some("stub".to_string()),
+        remote_name: None,
+        cacheable,
+    };
+    let config = ClientConfig::new("mock", "mock-model")
+        .with_tools(vec![tool("get_weather", true), tool("send_report", false)]);
+    let client = Arc::new(McpClient::with_bridge(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Jakarta","unit":"c"}}"#,
+            r#"{"action":"call_tool","tool":"send_report","input":{}}"#,
+            FINAL_ANSWER,
+            r#"{"action":"call_tool","tool":"get_weather","input":{"unit":"c","city":"Jakarta"}}"#,
+            r#"{"action":"call_tool","tool":"send_report","input":{}}"#,
+            FINAL_ANSWER,
+        ]),
+        config,
+        bridge.clone(),
+    ));
+
+    let first = Agent::new(client.clone())
+        .run("cuaca?".to_string(), AgentOptions::default())
+        .await
+        .expect("first run");
+    let second = Agent::new(client)
+        .run("cuaca lagi?".to_string(), AgentOptions::default())
+        .await
+        .expect("second run");
+
+    let invoked: Vec<String> = bridge
+        .calls
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(_, tool, _)| tool.clone())
+        .collect();
+    assert_eq!(invoked, vec!["get_weather", "send_report", "send_report"]);
+    assert!(!first.steps[0].cached);
+    assert!(second.steps[0].cached);
+    assert!(second.steps[0].success);
+    assert_eq!(second.steps[0].output, first.steps[0].output);
+    assert!(!second.steps[1].cached);
+}
+
+#[tokio::test]
+async fn cache_hits_are_audited_as_cached() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let path = dir.path().join("audit.jsonl");
+    let config = ClientConfig::new("mock", "mock-model")
+        .with_tools(vec![ToolConfig {
+            name: "get_weather".to_string(),
+            description: None,
+            server: Some("stub".to_string()),
+            remote_name: None,
+            cacheable: true,
+        }])
+        .with_audit_log(AuditLogConfig {
+            path: path.clone(),
+            redact_keys: Vec::new(),
+        });
+    let client = Arc::new(McpClient::with_bridge(
+        ScriptedProvider::new(&[
+            r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Jakarta"}}"#,
+            r#"{"action":"call_tool","tool":"get_weather","input":{"city":"Jakarta"}}"#,
+            FINAL_ANSWER,
+        ]),
+        config,
+        Arc::new(StubBridge::default()),
+    ));
+
+    Agent::new(client)
+        .run("cuaca?".to_string(), AgentOptions::default())
+        .await
+        .expect("agent run");
+
+    let mut lines = Vec::new();
+    for _ in 0..100 {
+        let text = std::fs::read_to_string(&path).unwrap_or_default();
+        lines = text.lines().map(str::to_string).collect();
+        if lines.len() >= 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let cached: Vec<bool> = lines
+        .iter()
+        .map(|line| {
+            let entry: serde_json::Value = serde_json::from_str(line).expect("audit line is JSON");
+            assert_eq!(entry["tool"], "get_weather");
+            entry["cached"].as_bool().expect("cached flag")
+        })
+        .collect();
+    assert_eq!(cached.len(), 2);
+    assert_eq!(cached.iter().filter(|hit| **hit).count(), 1);
+}
@@ -4,6 +4,7 @@ use antikythera_core::domain::types::ChatMessage;
 use antikythera_core::domain::types::MessageRole;
 use antikythera_core::infrastructure::model::{ModelError, ModelRequest, ModelResponse};
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 struct MockModelProvider {
@@ -39,6 +40,9 @@ async fn test_chat_service_raw_mode_no_debug() {
             raw_mode: true,
             bypass_template: false,
             force_json: false,
+            provider: None,
+            model: None,
+            metadata: HashMap::new(),
         })
         .await;
 
@@ -64,6 +68,9 @@ async fn test_chat_service_raw_mode_with_debug() {
             raw_mode: true,
             bypass_template: false,
             force_json: false,
+            provider: None,
+            model: None,
+            metadata: HashMap::new(),
         })
         .await;
 
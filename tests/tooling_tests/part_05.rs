@@ -2,15 +2,8 @@
 async fn test_spawn_and_list_tools_stdio_invalid_command() {
     let config = ServerConfig {
         name: "test_stdio".to_string(),
-        transport: TransportType::Stdio,
         command: Some("non_existent_command_xyz".into()),
-        args: vec![],
-        env: HashMap::new(),
-        workdir: None,
-        url: None,
-        headers: HashMap::new(),
-        default_timezone: None,
-        default_city: None,
+        ..Default::default()
     };
 
     let result = spawn_and_list_tools(&config).await;
@@ -4,15 +4,9 @@
 
         let config = ServerConfig {
             name: "test-stdio".to_string(),
-            transport: TransportType::Stdio,
             command: Some(PathBuf::from("/path/to/server")),
             args: vec!["--port".to_string(), "8080".to_string()],
-            env: HashMap::new(),
-            workdir: None,
-            url: None,
-            headers: HashMap::new(),
-            default_timezone: None,
-            default_city: None,
+            ..Default::default()
         };
 
         assert!(config.is_stdio());
@@ -30,14 +24,9 @@
         let config = ServerConfig {
             name: "test-http".to_string(),
             transport: TransportType::Http,
-            command: None,
-            args: vec![],
-            env: HashMap::new(),
-            workdir: None,
             url: Some("https://api.example.com/mcp".to_string()),
             headers,
-            default_timezone: None,
-            default_city: None,
+            ..Default::default()
         };
 
         assert!(!config.is_stdio());
@@ -7,13 +7,8 @@
             name: "hybrid".to_string(),
             transport: TransportType::Http,
             command: Some(PathBuf::from("/fallback/path")),
-            args: vec![],
-            env: HashMap::new(),
-            workdir: None,
             url: Some("https://api.example.com".to_string()),
-            headers: HashMap::new(),
-            default_timezone: None,
-            default_city: None,
+            ..Default::default()
         };
 
         // With HTTP transport, url should be used
@@ -31,14 +26,9 @@
         let config = ServerConfig {
             name: "api-server".to_string(),
             transport: TransportType::Http,
-            command: None,
-            args: vec![],
-            env: HashMap::new(),
-            workdir: None,
             url: Some("https://api.example.com/mcp".to_string()),
             headers: headers.clone(),
-            default_timezone: None,
-            default_city: None,
+            ..Default::default()
         };
 
         assert_eq!(config.headers.len(), 2);